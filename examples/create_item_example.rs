@@ -48,7 +48,8 @@ fn main() -> Result<(), ZabbixApiError> {
         interface_id: "0".to_string(), // Use "0" for the first available agent interface, or provide a specific interface ID.
         delay: "1m".to_string(),       // Collect data every 1 minute.
         tags: Vec::<ZabbixHostTag>::new(), // Optional: Add item tags if needed. Made type explicit.
-                                       // Add other optional fields as necessary
+        preprocessing: vec![],         // Optional: Add preprocessing steps if needed.
+        ..Default::default()           // Add other optional fields as necessary
     };
 
     println!(
@@ -0,0 +1,403 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::action::model::{
+    ActionEvalType, ActionFilter, ActionOperation, ActionStatus, ZabbixAction,
+};
+use crate::error::ZabbixApiError;
+use crate::event::model::EventSource;
+use crate::flag;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateActionRequest {
+    pub name: String,
+    #[serde(rename = "eventsource")]
+    pub event_source: EventSource,
+    pub status: Option<ActionStatus>,
+    pub esc_period: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(serialize_with = "flag::serialize")]
+    pub pause_symptoms: Option<bool>,
+    #[serde(serialize_with = "flag::serialize")]
+    pub pause_suppressed: Option<bool>,
+    #[serde(serialize_with = "flag::serialize")]
+    pub notify_if_canceled: Option<bool>,
+    pub filter: Option<ActionFilter>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub operations: Vec<ActionOperation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recovery_operations: Vec<ActionOperation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub update_operations: Vec<ActionOperation>,
+}
+
+impl CreateActionRequest {
+    pub fn builder(name: impl ToString, event_source: EventSource) -> CreateActionRequestBuilder {
+        CreateActionRequestBuilder {
+            inner: CreateActionRequest {
+                name: name.to_string(),
+                event_source,
+                status: None,
+                esc_period: None,
+                #[cfg(feature = "v7")]
+                pause_symptoms: None,
+                pause_suppressed: None,
+                notify_if_canceled: None,
+                filter: None,
+                operations: vec![],
+                recovery_operations: vec![],
+                update_operations: vec![],
+            },
+        }
+    }
+}
+
+impl ZabbixAction {
+    /// Copy of the action under a new name. The action should be retrieved with
+    /// its filter and all operations selected.
+    pub fn copy_as(&self, name: impl ToString) -> Result<CreateActionRequest, ZabbixApiError> {
+        let name = name.to_string();
+
+        if name == self.name {
+            return Err(ZabbixApiError::InvalidRequestError(format!(
+                "action '{name}': copy requires a new name"
+            )));
+        }
+
+        let action = CreateActionRequest {
+            name,
+            event_source: self.event_source,
+            status: Some(self.status),
+            esc_period: self.esc_period.clone(),
+            #[cfg(feature = "v7")]
+            pause_symptoms: self.pause_symptoms,
+            pause_suppressed: self.pause_suppressed,
+            notify_if_canceled: self.notify_if_canceled,
+            filter: self.filter.clone(),
+            operations: self.operations.clone().unwrap_or_default(),
+            recovery_operations: self.recovery_operations.clone().unwrap_or_default(),
+            update_operations: self.update_operations.clone().unwrap_or_default(),
+        };
+
+        validate_action(
+            &action.name,
+            action.event_source,
+            action.filter.as_ref(),
+            &action.recovery_operations,
+            &action.update_operations,
+        )?;
+
+        Ok(action)
+    }
+}
+
+pub struct CreateActionRequestBuilder {
+    inner: CreateActionRequest,
+}
+
+impl CreateActionRequestBuilder {
+    pub fn disabled(mut self) -> Self {
+        self.inner.status = Some(ActionStatus::Disabled);
+        self
+    }
+
+    /// Default step duration of escalations.
+    pub fn esc_period(mut self, esc_period: impl ToString) -> Self {
+        self.inner.esc_period = Some(esc_period.to_string());
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn pause_symptoms(mut self, pause: bool) -> Self {
+        self.inner.pause_symptoms = Some(pause);
+        self
+    }
+
+    pub fn pause_suppressed(mut self, pause: bool) -> Self {
+        self.inner.pause_suppressed = Some(pause);
+        self
+    }
+
+    pub fn notify_if_canceled(mut self, notify: bool) -> Self {
+        self.inner.notify_if_canceled = Some(notify);
+        self
+    }
+
+    pub fn filter(mut self, filter: ActionFilter) -> Self {
+        self.inner.filter = Some(filter);
+        self
+    }
+
+    pub fn operation(mut self, operation: ActionOperation) -> Self {
+        self.inner.operations.push(operation);
+        self
+    }
+
+    pub fn recovery_operation(mut self, operation: ActionOperation) -> Self {
+        self.inner.recovery_operations.push(operation);
+        self
+    }
+
+    pub fn update_operation(mut self, operation: ActionOperation) -> Self {
+        self.inner.update_operations.push(operation);
+        self
+    }
+
+    /// Checks operation types and the filter formula.
+    pub fn build(self) -> Result<CreateActionRequest, ZabbixApiError> {
+        let action = self.inner;
+
+        validate_action(
+            &action.name,
+            action.event_source,
+            action.filter.as_ref(),
+            &action.recovery_operations,
+            &action.update_operations,
+        )?;
+
+        Ok(action)
+    }
+}
+
+pub(crate) fn validate_action(
+    name: &str,
+    event_source: EventSource,
+    filter: Option<&ActionFilter>,
+    recovery_operations: &[ActionOperation],
+    update_operations: &[ActionOperation],
+) -> Result<(), ZabbixApiError> {
+    let invalid = |message: &str| {
+        Err(ZabbixApiError::InvalidRequestError(format!(
+            "action '{name}': {message}"
+        )))
+    };
+
+    let supports_recovery = matches!(
+        event_source,
+        EventSource::Trigger | EventSource::Internal | EventSource::Service
+    );
+
+    let has_recovery = !recovery_operations.is_empty() || !update_operations.is_empty();
+
+    if has_recovery && !supports_recovery {
+        return invalid(
+            "recovery and update operations require a trigger, internal or service action",
+        );
+    }
+
+    if event_source == EventSource::Internal && !update_operations.is_empty() {
+        return invalid("internal actions don't support update operations");
+    }
+
+    validate_operations(name, filter, recovery_operations, update_operations)
+}
+
+/// Checks which don't depend on the event source, shared with action updates.
+pub(crate) fn validate_operations(
+    name: &str,
+    filter: Option<&ActionFilter>,
+    recovery_operations: &[ActionOperation],
+    update_operations: &[ActionOperation],
+) -> Result<(), ZabbixApiError> {
+    let invalid = |message: &str| {
+        Err(ZabbixApiError::InvalidRequestError(format!(
+            "action '{name}': {message}"
+        )))
+    };
+
+    if recovery_operations
+        .iter()
+        .any(|operation| !operation.operation_type.is_recovery())
+    {
+        return invalid("unsupported recovery operation type");
+    }
+
+    if update_operations
+        .iter()
+        .any(|operation| !operation.operation_type.is_update())
+    {
+        return invalid("unsupported update operation type");
+    }
+
+    if let Some(filter) = filter {
+        if filter.eval_type == ActionEvalType::Custom {
+            if filter.formula.as_deref().unwrap_or_default().is_empty() {
+                return invalid("custom filter requires a formula");
+            }
+
+            if filter
+                .conditions
+                .iter()
+                .any(|condition| condition.formula_id.is_none())
+            {
+                return invalid("custom filter conditions require formula ids");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateActionResponse {
+    #[serde(rename = "actionids")]
+    pub action_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateActionRequest;
+    use crate::action::model::ZabbixAction;
+    use crate::action::model::{
+        ActionCondition, ActionConditionOperator, ActionFilter, ActionMessage, ActionOperation,
+    };
+    use crate::event::model::{EventSeverity, EventSource};
+
+    #[test]
+    fn serialize_test() {
+        let request = CreateActionRequest::builder("Notify admins", EventSource::Trigger)
+            .esc_period("30m")
+            .pause_suppressed(true)
+            .filter(ActionFilter::custom(
+                "A and not B",
+                vec![
+                    ActionCondition::trigger_severity(
+                        ActionConditionOperator::GreaterOrEqual,
+                        EventSeverity::High,
+                    )
+                    .formula_id("A"),
+                    ActionCondition::event_tag_value(
+                        "env",
+                        ActionConditionOperator::Equals,
+                        "test",
+                    )
+                    .formula_id("B"),
+                ],
+            ))
+            .operation(
+                ActionOperation::send_message(ActionMessage::default_message())
+                    .to_user_group("7")
+                    .steps(1, 2),
+            )
+            .operation(
+                ActionOperation::remote_command("3")
+                    .on_current_host()
+                    .steps(3, 0)
+                    .step_duration("10m")
+                    .condition(ActionCondition::event_acknowledged(false)),
+            )
+            .recovery_operation(ActionOperation::notify_all_involved_on_recovery(
+                ActionMessage::custom("Resolved", "{EVENT.NAME} resolved").media_type("1"),
+            ))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Notify admins",
+                "eventsource": "0",
+                "esc_period": "30m",
+                "pause_suppressed": 1,
+                "filter": {
+                    "evaltype": "3",
+                    "formula": "A and not B",
+                    "conditions": [
+                        {"conditiontype": "4", "operator": "5", "value": "4", "formulaid": "A"},
+                        {"conditiontype": "26", "operator": "0", "value": "test", "value2": "env", "formulaid": "B"}
+                    ]
+                },
+                "operations": [
+                    {
+                        "operationtype": "0",
+                        "esc_step_from": "1",
+                        "esc_step_to": "2",
+                        "opmessage": {"default_msg": 1},
+                        "opmessage_grp": [{"usrgrpid": "7"}]
+                    },
+                    {
+                        "operationtype": "1",
+                        "esc_period": "10m",
+                        "esc_step_from": "3",
+                        "esc_step_to": "0",
+                        "opconditions": [{"conditiontype": "14", "operator": "0", "value": "0"}],
+                        "opcommand": {"scriptid": "3"},
+                        "opcommand_hst": [{"hostid": "0"}]
+                    }
+                ],
+                "recovery_operations": [
+                    {
+                        "operationtype": "11",
+                        "opmessage": {
+                            "default_msg": 0,
+                            "subject": "Resolved",
+                            "message": "{EVENT.NAME} resolved",
+                            "mediatypeid": "1"
+                        }
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_operations_are_rejected() {
+        assert!(
+            CreateActionRequest::builder("Discovery", EventSource::Discovery)
+                .operation(ActionOperation::add_host())
+                .recovery_operation(ActionOperation::notify_all_involved_on_recovery(
+                    ActionMessage::default_message()
+                ))
+                .build()
+                .is_err()
+        );
+
+        assert!(
+            CreateActionRequest::builder("Trigger", EventSource::Trigger)
+                .update_operation(ActionOperation::add_host())
+                .build()
+                .is_err()
+        );
+
+        assert!(
+            CreateActionRequest::builder("Trigger", EventSource::Trigger)
+                .filter(ActionFilter::custom(
+                    "A",
+                    vec![ActionCondition::problem_is_suppressed(false)]
+                ))
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn copies_require_a_new_name_and_valid_operations() {
+        let input = r#"
+            {"actionid":"3","name":"Notify admins","eventsource":"0","status":"0","esc_period":"1h",
+             "filter":{"evaltype":"0","conditions":[]},
+             "operations":[],"recovery_operations":[],"update_operations":[]}
+            "#;
+
+        let action: ZabbixAction = serde_json::from_str(input).unwrap();
+
+        let copy = action.copy_as("Notify admins (copy)").unwrap();
+
+        assert_eq!(copy.name, "Notify admins (copy)");
+        assert_eq!(copy.esc_period.as_deref(), Some("1h"));
+
+        assert!(action.copy_as("Notify admins").is_err());
+
+        let mut discovery = action.clone();
+        discovery.event_source = EventSource::Discovery;
+        discovery.recovery_operations =
+            Some(vec![ActionOperation::notify_all_involved_on_recovery(
+                ActionMessage::default_message(),
+            )]);
+
+        assert!(discovery.copy_as("Discovery (copy)").is_err());
+    }
+}
@@ -0,0 +1,38 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetActionsRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "actionids")]
+    pub action_ids: Option<Vec<String>>,
+    /// Return only actions that use the given host groups in conditions or operations.
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "triggerids")]
+    pub trigger_ids: Option<Vec<String>>,
+    #[serde(rename = "mediatypeids")]
+    pub media_type_ids: Option<Vec<String>>,
+    #[serde(rename = "usrgrpids")]
+    pub user_group_ids: Option<Vec<String>>,
+    #[serde(rename = "userids")]
+    pub user_ids: Option<Vec<String>>,
+    #[serde(rename = "scriptids")]
+    pub script_ids: Option<Vec<String>>,
+    #[serde(rename = "selectFilter")]
+    pub select_filter: Option<String>,
+    #[serde(rename = "selectOperations")]
+    pub select_operations: Option<String>,
+    #[serde(rename = "selectRecoveryOperations")]
+    pub select_recovery_operations: Option<String>,
+    #[serde(rename = "selectUpdateOperations")]
+    pub select_update_operations: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,760 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::event::model::{EventSeverity, EventSource};
+use crate::flag;
+use crate::host::create::InventoryMode;
+use crate::host::model::{ZabbixHostId, ZabbixHostTag};
+use crate::hostgroup::model::ZabbixHostGroupId;
+use crate::template::model::ZabbixTemplateId;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/object
+#[derive(Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixAction {
+    #[serde(rename = "actionid")]
+    pub action_id: String,
+    pub name: String,
+    #[serde(rename = "eventsource")]
+    pub event_source: EventSource,
+    pub status: ActionStatus,
+    /// Default step duration, for trigger, internal and service actions.
+    pub esc_period: Option<String>,
+    /// Pause escalation for symptom problems.
+    #[cfg(feature = "v7")]
+    #[serde(default, deserialize_with = "flag::deserialize")]
+    pub pause_symptoms: Option<bool>,
+    /// Pause escalation for suppressed problems.
+    #[serde(default, deserialize_with = "flag::deserialize")]
+    pub pause_suppressed: Option<bool>,
+    /// Notify about canceled escalations.
+    #[serde(default, deserialize_with = "flag::deserialize")]
+    pub notify_if_canceled: Option<bool>,
+    /// Filled when requested with `selectFilter`.
+    pub filter: Option<ActionFilter>,
+    /// Filled when requested with `selectOperations`.
+    pub operations: Option<Vec<ActionOperation>>,
+    /// Filled when requested with `selectRecoveryOperations`.
+    pub recovery_operations: Option<Vec<ActionOperation>>,
+    /// Filled when requested with `selectUpdateOperations`.
+    pub update_operations: Option<Vec<ActionOperation>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug, Default)]
+pub enum ActionStatus {
+    #[default]
+    #[serde(rename = "0")]
+    Enabled,
+    #[serde(rename = "1")]
+    Disabled,
+}
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/object#action-filter
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ActionFilter {
+    #[serde(rename = "evaltype")]
+    pub eval_type: ActionEvalType,
+    /// Custom expression with condition formula IDs, e.g. `A and (B or C)`.
+    #[serde(default, deserialize_with = "deserialize_non_empty")]
+    pub formula: Option<String>,
+    pub conditions: Vec<ActionCondition>,
+    /// Generated expression, returned by Zabbix only.
+    #[serde(skip_serializing)]
+    pub eval_formula: Option<String>,
+}
+
+impl ActionFilter {
+    pub fn and_or(conditions: Vec<ActionCondition>) -> Self {
+        Self::new(ActionEvalType::AndOr, None, conditions)
+    }
+
+    pub fn and(conditions: Vec<ActionCondition>) -> Self {
+        Self::new(ActionEvalType::And, None, conditions)
+    }
+
+    pub fn or(conditions: Vec<ActionCondition>) -> Self {
+        Self::new(ActionEvalType::Or, None, conditions)
+    }
+
+    /// Conditions need formula IDs used in the formula.
+    pub fn custom(formula: impl ToString, conditions: Vec<ActionCondition>) -> Self {
+        Self::new(
+            ActionEvalType::Custom,
+            Some(formula.to_string()),
+            conditions,
+        )
+    }
+
+    fn new(
+        eval_type: ActionEvalType,
+        formula: Option<String>,
+        conditions: Vec<ActionCondition>,
+    ) -> Self {
+        Self {
+            eval_type,
+            formula,
+            conditions,
+            eval_formula: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ActionEvalType {
+    #[serde(rename = "0")]
+    AndOr,
+    #[serde(rename = "1")]
+    And,
+    #[serde(rename = "2")]
+    Or,
+    #[serde(rename = "3")]
+    Custom,
+}
+
+/// Condition of an action filter or an operation.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/object#action-filter-condition
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ActionCondition {
+    #[serde(rename = "conditiontype")]
+    pub condition_type: ActionConditionType,
+    pub operator: ActionConditionOperator,
+    pub value: String,
+    /// Tag name for `EventTagValue` conditions.
+    #[serde(default, deserialize_with = "deserialize_non_empty")]
+    pub value2: Option<String>,
+    /// ID used in custom filter formulas.
+    #[serde(rename = "formulaid")]
+    pub formula_id: Option<String>,
+}
+
+impl ActionCondition {
+    pub fn new(
+        condition_type: ActionConditionType,
+        operator: ActionConditionOperator,
+        value: impl ToString,
+    ) -> Self {
+        Self {
+            condition_type,
+            operator,
+            value: value.to_string(),
+            value2: None,
+            formula_id: None,
+        }
+    }
+
+    pub fn host_group(operator: ActionConditionOperator, group_id: impl ToString) -> Self {
+        Self::new(ActionConditionType::HostGroup, operator, group_id)
+    }
+
+    pub fn host(operator: ActionConditionOperator, host_id: impl ToString) -> Self {
+        Self::new(ActionConditionType::Host, operator, host_id)
+    }
+
+    pub fn host_template(operator: ActionConditionOperator, template_id: impl ToString) -> Self {
+        Self::new(ActionConditionType::HostTemplate, operator, template_id)
+    }
+
+    pub fn trigger(operator: ActionConditionOperator, trigger_id: impl ToString) -> Self {
+        Self::new(ActionConditionType::Trigger, operator, trigger_id)
+    }
+
+    pub fn event_name(operator: ActionConditionOperator, name: impl ToString) -> Self {
+        Self::new(ActionConditionType::EventName, operator, name)
+    }
+
+    pub fn trigger_severity(operator: ActionConditionOperator, severity: EventSeverity) -> Self {
+        Self::new(
+            ActionConditionType::TriggerSeverity,
+            operator,
+            severity as u8,
+        )
+    }
+
+    /// Time period in the `1-7,00:00-24:00` format, with `In` or `NotIn`.
+    pub fn time_period(operator: ActionConditionOperator, period: impl ToString) -> Self {
+        Self::new(ActionConditionType::TimePeriod, operator, period)
+    }
+
+    pub fn problem_is_suppressed(suppressed: bool) -> Self {
+        let operator = match suppressed {
+            true => ActionConditionOperator::Yes,
+            false => ActionConditionOperator::No,
+        };
+
+        Self::new(ActionConditionType::ProblemIsSuppressed, operator, "")
+    }
+
+    pub fn event_tag(operator: ActionConditionOperator, tag: impl ToString) -> Self {
+        Self::new(ActionConditionType::EventTag, operator, tag)
+    }
+
+    pub fn event_tag_value(
+        tag: impl ToString,
+        operator: ActionConditionOperator,
+        value: impl ToString,
+    ) -> Self {
+        Self {
+            value2: Some(tag.to_string()),
+            ..Self::new(ActionConditionType::EventTagValue, operator, value)
+        }
+    }
+
+    pub fn host_name(operator: ActionConditionOperator, name: impl ToString) -> Self {
+        Self::new(ActionConditionType::HostName, operator, name)
+    }
+
+    pub fn host_metadata(operator: ActionConditionOperator, metadata: impl ToString) -> Self {
+        Self::new(ActionConditionType::HostMetadata, operator, metadata)
+    }
+
+    /// Operation condition on whether the problem is acknowledged.
+    pub fn event_acknowledged(acknowledged: bool) -> Self {
+        Self::new(
+            ActionConditionType::EventAcknowledged,
+            ActionConditionOperator::Equals,
+            acknowledged as u8,
+        )
+    }
+
+    pub fn formula_id(mut self, formula_id: impl ToString) -> Self {
+        self.formula_id = Some(formula_id.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ActionConditionType {
+    #[serde(rename = "0")]
+    HostGroup,
+    #[serde(rename = "1")]
+    Host,
+    #[serde(rename = "2")]
+    Trigger,
+    #[serde(rename = "3")]
+    EventName,
+    #[serde(rename = "4")]
+    TriggerSeverity,
+    #[serde(rename = "6")]
+    TimePeriod,
+    #[serde(rename = "7")]
+    HostIp,
+    #[serde(rename = "8")]
+    DiscoveredServiceType,
+    #[serde(rename = "9")]
+    DiscoveredServicePort,
+    #[serde(rename = "10")]
+    DiscoveryStatus,
+    #[serde(rename = "11")]
+    UptimeOrDowntimeDuration,
+    #[serde(rename = "12")]
+    ReceivedValue,
+    #[serde(rename = "13")]
+    HostTemplate,
+    /// Operation conditions only.
+    #[serde(rename = "14")]
+    EventAcknowledged,
+    #[serde(rename = "16")]
+    ProblemIsSuppressed,
+    #[serde(rename = "18")]
+    DiscoveryRule,
+    #[serde(rename = "19")]
+    DiscoveryCheck,
+    #[serde(rename = "20")]
+    Proxy,
+    #[serde(rename = "21")]
+    DiscoveryObject,
+    #[serde(rename = "22")]
+    HostName,
+    #[serde(rename = "23")]
+    EventType,
+    #[serde(rename = "24")]
+    HostMetadata,
+    #[serde(rename = "25")]
+    EventTag,
+    #[serde(rename = "26")]
+    EventTagValue,
+    #[serde(rename = "27")]
+    Service,
+    #[serde(rename = "28")]
+    ServiceName,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ActionConditionOperator {
+    #[serde(rename = "0")]
+    Equals,
+    #[serde(rename = "1")]
+    NotEquals,
+    #[serde(rename = "2")]
+    Contains,
+    #[serde(rename = "3")]
+    NotContains,
+    #[serde(rename = "4")]
+    In,
+    #[serde(rename = "5")]
+    GreaterOrEqual,
+    #[serde(rename = "6")]
+    LessOrEqual,
+    #[serde(rename = "7")]
+    NotIn,
+    #[serde(rename = "8")]
+    Matches,
+    #[serde(rename = "9")]
+    NotMatches,
+    #[serde(rename = "10")]
+    Yes,
+    #[serde(rename = "11")]
+    No,
+}
+
+/// Operation, recovery operation or update operation of an action.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/object#action-operation
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ActionOperation {
+    /// Returned by Zabbix only.
+    #[serde(rename = "operationid", skip_serializing)]
+    pub operation_id: Option<String>,
+    #[serde(rename = "operationtype")]
+    pub operation_type: ActionOperationType,
+    /// Step duration, `0` uses the action default.
+    pub esc_period: Option<String>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub esc_step_from: Option<u32>,
+    /// Last step, `0` repeats the operation infinitely.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub esc_step_to: Option<u32>,
+    /// How operation conditions are combined.
+    #[serde(rename = "evaltype")]
+    pub eval_type: Option<ActionEvalType>,
+    #[serde(rename = "opconditions")]
+    pub conditions: Option<Vec<ActionCondition>>,
+    #[serde(rename = "opmessage")]
+    pub message: Option<ActionMessage>,
+    #[serde(rename = "opmessage_grp")]
+    pub message_user_groups: Option<Vec<ActionUserGroupId>>,
+    #[serde(rename = "opmessage_usr")]
+    pub message_users: Option<Vec<ActionUserId>>,
+    #[serde(rename = "opcommand")]
+    pub command: Option<ActionCommand>,
+    /// Host ID `0` stands for the current host.
+    #[serde(rename = "opcommand_hst")]
+    pub command_hosts: Option<Vec<ZabbixHostId>>,
+    #[serde(rename = "opcommand_grp")]
+    pub command_groups: Option<Vec<ZabbixHostGroupId>>,
+    #[serde(rename = "opgroup")]
+    pub groups: Option<Vec<ZabbixHostGroupId>>,
+    #[serde(rename = "optemplate")]
+    pub templates: Option<Vec<ZabbixTemplateId>>,
+    #[serde(rename = "opinventory")]
+    pub inventory: Option<ActionInventory>,
+    #[serde(rename = "optag")]
+    pub tags: Option<Vec<ZabbixHostTag>>,
+}
+
+impl ActionOperation {
+    pub fn new(operation_type: ActionOperationType) -> Self {
+        Self {
+            operation_id: None,
+            operation_type,
+            esc_period: None,
+            esc_step_from: None,
+            esc_step_to: None,
+            eval_type: None,
+            conditions: None,
+            message: None,
+            message_user_groups: None,
+            message_users: None,
+            command: None,
+            command_hosts: None,
+            command_groups: None,
+            groups: None,
+            templates: None,
+            inventory: None,
+            tags: None,
+        }
+    }
+
+    /// Sends the message to the given users and user groups, see `to_user_group` and `to_user`.
+    pub fn send_message(message: ActionMessage) -> Self {
+        Self {
+            message: Some(message),
+            ..Self::new(ActionOperationType::SendMessage)
+        }
+    }
+
+    /// Runs the global script, see `on_current_host`, `on_host` and `on_group`.
+    pub fn remote_command(script_id: impl ToString) -> Self {
+        Self {
+            command: Some(ActionCommand {
+                script_id: script_id.to_string(),
+            }),
+            ..Self::new(ActionOperationType::RemoteCommand)
+        }
+    }
+
+    pub fn add_host() -> Self {
+        Self::new(ActionOperationType::AddHost)
+    }
+
+    pub fn remove_host() -> Self {
+        Self::new(ActionOperationType::RemoveHost)
+    }
+
+    pub fn enable_host() -> Self {
+        Self::new(ActionOperationType::EnableHost)
+    }
+
+    pub fn disable_host() -> Self {
+        Self::new(ActionOperationType::DisableHost)
+    }
+
+    pub fn add_to_groups(group_ids: &[String]) -> Self {
+        Self {
+            groups: Some(to_group_ids(group_ids)),
+            ..Self::new(ActionOperationType::AddToHostGroup)
+        }
+    }
+
+    pub fn remove_from_groups(group_ids: &[String]) -> Self {
+        Self {
+            groups: Some(to_group_ids(group_ids)),
+            ..Self::new(ActionOperationType::RemoveFromHostGroup)
+        }
+    }
+
+    pub fn link_templates(template_ids: &[String]) -> Self {
+        Self {
+            templates: Some(to_template_ids(template_ids)),
+            ..Self::new(ActionOperationType::LinkTemplate)
+        }
+    }
+
+    pub fn unlink_templates(template_ids: &[String]) -> Self {
+        Self {
+            templates: Some(to_template_ids(template_ids)),
+            ..Self::new(ActionOperationType::UnlinkTemplate)
+        }
+    }
+
+    pub fn set_inventory_mode(inventory_mode: InventoryMode) -> Self {
+        Self {
+            inventory: Some(ActionInventory { inventory_mode }),
+            ..Self::new(ActionOperationType::SetHostInventoryMode)
+        }
+    }
+
+    pub fn add_host_tags(tags: Vec<ZabbixHostTag>) -> Self {
+        Self {
+            tags: Some(tags),
+            ..Self::new(ActionOperationType::AddHostTags)
+        }
+    }
+
+    pub fn remove_host_tags(tags: Vec<ZabbixHostTag>) -> Self {
+        Self {
+            tags: Some(tags),
+            ..Self::new(ActionOperationType::RemoveHostTags)
+        }
+    }
+
+    /// Recovery operation.
+    pub fn notify_all_involved_on_recovery(message: ActionMessage) -> Self {
+        Self {
+            message: Some(message),
+            ..Self::new(ActionOperationType::NotifyAllInvolvedOnRecovery)
+        }
+    }
+
+    /// Update operation.
+    pub fn notify_all_involved_on_update(message: ActionMessage) -> Self {
+        Self {
+            message: Some(message),
+            ..Self::new(ActionOperationType::NotifyAllInvolvedOnUpdate)
+        }
+    }
+
+    /// Escalation steps, `to` of `0` repeats the operation infinitely.
+    pub fn steps(mut self, from: u32, to: u32) -> Self {
+        self.esc_step_from = Some(from);
+        self.esc_step_to = Some(to);
+        self
+    }
+
+    pub fn step_duration(mut self, esc_period: impl ToString) -> Self {
+        self.esc_period = Some(esc_period.to_string());
+        self
+    }
+
+    pub fn condition(mut self, condition: ActionCondition) -> Self {
+        self.conditions.get_or_insert_with(Vec::new).push(condition);
+        self
+    }
+
+    pub fn to_user_group(mut self, user_group_id: impl ToString) -> Self {
+        self.message_user_groups
+            .get_or_insert_with(Vec::new)
+            .push(ActionUserGroupId {
+                user_group_id: user_group_id.to_string(),
+            });
+        self
+    }
+
+    pub fn to_user(mut self, user_id: impl ToString) -> Self {
+        self.message_users
+            .get_or_insert_with(Vec::new)
+            .push(ActionUserId {
+                user_id: user_id.to_string(),
+            });
+        self
+    }
+
+    pub fn on_current_host(self) -> Self {
+        self.on_host("0")
+    }
+
+    pub fn on_host(mut self, host_id: impl ToString) -> Self {
+        self.command_hosts
+            .get_or_insert_with(Vec::new)
+            .push(ZabbixHostId {
+                host_id: host_id.to_string(),
+            });
+        self
+    }
+
+    pub fn on_group(mut self, group_id: impl ToString) -> Self {
+        self.command_groups
+            .get_or_insert_with(Vec::new)
+            .push(ZabbixHostGroupId {
+                group_id: group_id.to_string(),
+            });
+        self
+    }
+}
+
+fn to_group_ids(group_ids: &[String]) -> Vec<ZabbixHostGroupId> {
+    group_ids
+        .iter()
+        .map(|group_id| ZabbixHostGroupId {
+            group_id: group_id.to_string(),
+        })
+        .collect()
+}
+
+fn to_template_ids(template_ids: &[String]) -> Vec<ZabbixTemplateId> {
+    template_ids
+        .iter()
+        .map(|template_id| ZabbixTemplateId {
+            template_id: template_id.to_string(),
+        })
+        .collect()
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ActionOperationType {
+    #[serde(rename = "0")]
+    SendMessage,
+    /// Global script.
+    #[serde(rename = "1")]
+    RemoteCommand,
+    #[serde(rename = "2")]
+    AddHost,
+    #[serde(rename = "3")]
+    RemoveHost,
+    #[serde(rename = "4")]
+    AddToHostGroup,
+    #[serde(rename = "5")]
+    RemoveFromHostGroup,
+    #[serde(rename = "6")]
+    LinkTemplate,
+    #[serde(rename = "7")]
+    UnlinkTemplate,
+    #[serde(rename = "8")]
+    EnableHost,
+    #[serde(rename = "9")]
+    DisableHost,
+    #[serde(rename = "10")]
+    SetHostInventoryMode,
+    #[serde(rename = "11")]
+    NotifyAllInvolvedOnRecovery,
+    #[serde(rename = "12")]
+    NotifyAllInvolvedOnUpdate,
+    #[serde(rename = "13")]
+    AddHostTags,
+    #[serde(rename = "14")]
+    RemoveHostTags,
+}
+
+impl ActionOperationType {
+    /// Whether the type is allowed in recovery operations.
+    pub fn is_recovery(self) -> bool {
+        matches!(
+            self,
+            ActionOperationType::SendMessage
+                | ActionOperationType::RemoteCommand
+                | ActionOperationType::NotifyAllInvolvedOnRecovery
+        )
+    }
+
+    /// Whether the type is allowed in update operations.
+    pub fn is_update(self) -> bool {
+        matches!(
+            self,
+            ActionOperationType::SendMessage
+                | ActionOperationType::RemoteCommand
+                | ActionOperationType::NotifyAllInvolvedOnUpdate
+        )
+    }
+}
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/object#action-operation-message
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ActionMessage {
+    /// Use the message template of the media type.
+    #[serde(default, with = "flag")]
+    pub default_msg: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_non_empty")]
+    pub subject: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_non_empty")]
+    pub message: Option<String>,
+    /// Media type, `0` sends through all media types.
+    #[serde(rename = "mediatypeid")]
+    pub media_type_id: Option<String>,
+}
+
+impl ActionMessage {
+    /// Message from the media type template.
+    pub fn default_message() -> Self {
+        Self {
+            default_msg: Some(true),
+            subject: None,
+            message: None,
+            media_type_id: None,
+        }
+    }
+
+    pub fn custom(subject: impl ToString, message: impl ToString) -> Self {
+        Self {
+            default_msg: Some(false),
+            subject: Some(subject.to_string()),
+            message: Some(message.to_string()),
+            media_type_id: None,
+        }
+    }
+
+    pub fn media_type(mut self, media_type_id: impl ToString) -> Self {
+        self.media_type_id = Some(media_type_id.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ActionCommand {
+    #[serde(rename = "scriptid")]
+    pub script_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ActionInventory {
+    pub inventory_mode: InventoryMode,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ActionUserGroupId {
+    #[serde(rename = "usrgrpid")]
+    pub user_group_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ActionUserId {
+    #[serde(rename = "userid")]
+    pub user_id: String,
+}
+
+/// Zabbix returns empty strings for unused fields.
+fn deserialize_non_empty<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+
+    Ok(value.filter(|value| !value.is_empty()))
+}
+
+#[cfg(test)]
+mod action_tests {
+    use super::{
+        ActionConditionOperator, ActionConditionType, ActionEvalType, ActionOperationType,
+        ZabbixAction,
+    };
+    use crate::event::model::EventSource;
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"actionid":"3","name":"Report problems to Zabbix administrators","eventsource":"0","status":"1","esc_period":"1h","pause_suppressed":"1","pause_symptoms":"1","notify_if_canceled":"1",
+             "filter":{"evaltype":"3","formula":"A and B","conditions":[
+                {"conditiontype":"4","operator":"5","value":"3","value2":"","formulaid":"A"},
+                {"conditiontype":"26","operator":"0","value":"mysql","value2":"service","formulaid":"B"}],
+                "eval_formula":"A and B"},
+             "operations":[
+                {"operationid":"3","actionid":"3","operationtype":"0","esc_period":"0","esc_step_from":"1","esc_step_to":"1","evaltype":"0",
+                 "opconditions":[],"opmessage":{"default_msg":"1","subject":"","message":"","mediatypeid":"0"},
+                 "opmessage_grp":[{"usrgrpid":"7"}],"opmessage_usr":[]},
+                {"operationid":"4","actionid":"3","operationtype":"1","esc_period":"0","esc_step_from":"2","esc_step_to":"0","evaltype":"0",
+                 "opconditions":[{"conditiontype":"14","operator":"0","value":"0"}],
+                 "opcommand":{"scriptid":"3"},"opcommand_hst":[{"hostid":"0"}],"opcommand_grp":[]}],
+             "recovery_operations":[{"operationid":"7","actionid":"3","operationtype":"11","opmessage":{"default_msg":"0","subject":"Resolved","message":"Problem resolved","mediatypeid":"0"}}],
+             "update_operations":[{"operationid":"8","actionid":"3","operationtype":"12","opmessage":{"default_msg":"1","subject":"","message":"","mediatypeid":"0"}}]}
+            "#;
+
+        let action: ZabbixAction = serde_json::from_str(input).unwrap();
+
+        assert_eq!(action.event_source, EventSource::Trigger);
+        assert_eq!(action.pause_suppressed, Some(true));
+
+        let filter = action.filter.unwrap();
+        assert_eq!(filter.eval_type, ActionEvalType::Custom);
+        assert_eq!(filter.formula.as_deref(), Some("A and B"));
+        assert_eq!(
+            filter.conditions[0].condition_type,
+            ActionConditionType::TriggerSeverity
+        );
+        assert_eq!(
+            filter.conditions[0].operator,
+            ActionConditionOperator::GreaterOrEqual
+        );
+        assert_eq!(filter.conditions[0].value2, None);
+        assert_eq!(filter.conditions[1].value2.as_deref(), Some("service"));
+
+        let operations = action.operations.unwrap();
+        assert_eq!(
+            operations[0].operation_type,
+            ActionOperationType::SendMessage
+        );
+        assert_eq!(
+            operations[0].message_user_groups.clone().unwrap()[0].user_group_id,
+            "7"
+        );
+        assert_eq!(operations[1].esc_step_to, Some(0));
+        assert_eq!(operations[1].command.clone().unwrap().script_id, "3");
+
+        let recovery_operations = action.recovery_operations.unwrap();
+        let message = recovery_operations[0].message.clone().unwrap();
+        assert_eq!(message.default_msg, Some(false));
+        assert_eq!(message.subject.as_deref(), Some("Resolved"));
+
+        let update_operations = action.update_operations.unwrap();
+        assert_eq!(
+            update_operations[0].operation_type,
+            ActionOperationType::NotifyAllInvolvedOnUpdate
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::action::create::validate_operations;
+use crate::action::model::{ActionFilter, ActionOperation, ActionStatus};
+use crate::error::ZabbixApiError;
+use crate::flag;
+
+/// Filter and operations replace the existing ones when set.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UpdateActionRequest {
+    #[serde(rename = "actionid")]
+    pub action_id: String,
+    pub name: Option<String>,
+    pub status: Option<ActionStatus>,
+    pub esc_period: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(serialize_with = "flag::serialize")]
+    pub pause_symptoms: Option<bool>,
+    #[serde(serialize_with = "flag::serialize")]
+    pub pause_suppressed: Option<bool>,
+    #[serde(serialize_with = "flag::serialize")]
+    pub notify_if_canceled: Option<bool>,
+    pub filter: Option<ActionFilter>,
+    pub operations: Option<Vec<ActionOperation>>,
+    pub recovery_operations: Option<Vec<ActionOperation>>,
+    pub update_operations: Option<Vec<ActionOperation>>,
+}
+
+impl UpdateActionRequest {
+    pub fn builder(action_id: impl ToString) -> UpdateActionRequestBuilder {
+        UpdateActionRequestBuilder {
+            inner: UpdateActionRequest {
+                action_id: action_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateActionRequestBuilder {
+    inner: UpdateActionRequest,
+}
+
+impl UpdateActionRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn enable(mut self) -> Self {
+        self.inner.status = Some(ActionStatus::Enabled);
+        self
+    }
+
+    pub fn disable(mut self) -> Self {
+        self.inner.status = Some(ActionStatus::Disabled);
+        self
+    }
+
+    pub fn esc_period(mut self, esc_period: impl ToString) -> Self {
+        self.inner.esc_period = Some(esc_period.to_string());
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn pause_symptoms(mut self, pause: bool) -> Self {
+        self.inner.pause_symptoms = Some(pause);
+        self
+    }
+
+    pub fn pause_suppressed(mut self, pause: bool) -> Self {
+        self.inner.pause_suppressed = Some(pause);
+        self
+    }
+
+    pub fn notify_if_canceled(mut self, notify: bool) -> Self {
+        self.inner.notify_if_canceled = Some(notify);
+        self
+    }
+
+    pub fn filter(mut self, filter: ActionFilter) -> Self {
+        self.inner.filter = Some(filter);
+        self
+    }
+
+    pub fn operations(mut self, operations: Vec<ActionOperation>) -> Self {
+        self.inner.operations = Some(operations);
+        self
+    }
+
+    pub fn recovery_operations(mut self, operations: Vec<ActionOperation>) -> Self {
+        self.inner.recovery_operations = Some(operations);
+        self
+    }
+
+    pub fn update_operations(mut self, operations: Vec<ActionOperation>) -> Self {
+        self.inner.update_operations = Some(operations);
+        self
+    }
+
+    /// Checks operation types and the filter formula. Checks which depend on the
+    /// event source are left to Zabbix, as it is not known here.
+    pub fn build(self) -> Result<UpdateActionRequest, ZabbixApiError> {
+        let action = self.inner;
+
+        validate_operations(
+            action.name.as_deref().unwrap_or(&action.action_id),
+            action.filter.as_ref(),
+            action.recovery_operations.as_deref().unwrap_or_default(),
+            action.update_operations.as_deref().unwrap_or_default(),
+        )?;
+
+        Ok(action)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateActionResponse {
+    #[serde(rename = "actionids")]
+    pub action_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod update_tests {
+    use super::UpdateActionRequest;
+    use crate::action::model::{ActionCondition, ActionFilter, ActionMessage, ActionOperation};
+
+    #[test]
+    fn invalid_operations_are_rejected() {
+        assert!(UpdateActionRequest::builder("17")
+            .disable()
+            .recovery_operations(vec![ActionOperation::notify_all_involved_on_recovery(
+                ActionMessage::default_message()
+            )])
+            .build()
+            .is_ok());
+
+        assert!(UpdateActionRequest::builder("17")
+            .update_operations(vec![ActionOperation::add_host()])
+            .build()
+            .is_err());
+
+        assert!(UpdateActionRequest::builder("17")
+            .filter(ActionFilter::custom(
+                "",
+                vec![ActionCondition::problem_is_suppressed(false).formula_id("A")]
+            ))
+            .build()
+            .is_err());
+    }
+}
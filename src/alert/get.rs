@@ -0,0 +1,113 @@
+use std::time::SystemTime;
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::alert::model::{AlertStatus, AlertType};
+use crate::event::model::{EventObject, EventSource};
+use crate::timestamp::serialize_timestamp;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/alert/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetAlertsRequest<F: Serialize> {
+    pub output: Option<String>,
+    /// E.g. [`AlertFilter`].
+    pub filter: Option<F>,
+    #[serde(rename = "alertids")]
+    pub alert_ids: Option<Vec<String>>,
+    #[serde(rename = "actionids")]
+    pub action_ids: Option<Vec<String>>,
+    #[serde(rename = "eventids")]
+    pub event_ids: Option<Vec<String>>,
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "mediatypeids")]
+    pub media_type_ids: Option<Vec<String>>,
+    /// Return only alerts generated by the given triggers.
+    #[serde(rename = "objectids")]
+    pub object_ids: Option<Vec<String>>,
+    #[serde(rename = "userids")]
+    pub user_ids: Option<Vec<String>>,
+    #[serde(rename = "eventsource")]
+    pub event_source: Option<EventSource>,
+    #[serde(rename = "eventobject")]
+    pub event_object: Option<EventObject>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_from: Option<SystemTime>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_till: Option<SystemTime>,
+    #[serde(rename = "selectHosts")]
+    pub select_hosts: Option<String>,
+    #[serde(rename = "selectMediatypes")]
+    pub select_media_types: Option<String>,
+    #[serde(rename = "selectUsers")]
+    pub select_users: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<Vec<String>>,
+    #[serde(rename = "sortorder")]
+    pub sort_order: Option<String>,
+    pub limit: Option<u32>,
+}
+
+impl GetAlertsRequest<AlertFilter> {
+    /// Failed notifications between `time_from` and `time_till`, newest first,
+    /// with recipients and media types.
+    pub fn failed_messages(time_from: SystemTime, time_till: SystemTime) -> Self {
+        Self {
+            output: Some("extend".to_string()),
+            filter: Some(AlertFilter {
+                status: Some(vec![AlertStatus::Failed]),
+                alert_type: Some(AlertType::Message),
+            }),
+            time_from: Some(time_from),
+            time_till: Some(time_till),
+            select_media_types: Some("extend".to_string()),
+            select_users: Some("extend".to_string()),
+            sort_field: Some(vec!["clock".to_string()]),
+            sort_order: Some("DESC".to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Typed `filter` by status and alert type.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct AlertFilter {
+    pub status: Option<Vec<AlertStatus>>,
+    #[serde(rename = "alerttype")]
+    pub alert_type: Option<AlertType>,
+}
+
+#[cfg(test)]
+mod get_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::GetAlertsRequest;
+
+    #[test]
+    fn serialize_failed_messages_test() {
+        let since = UNIX_EPOCH + Duration::from_secs(1700000000);
+
+        let request = GetAlertsRequest::failed_messages(since, since + Duration::from_secs(3600));
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "output": "extend",
+                "filter": {"status": ["2"], "alerttype": "0"},
+                "time_from": 1700000000,
+                "time_till": 1700003600,
+                "selectMediatypes": "extend",
+                "selectUsers": "extend",
+                "sortfield": ["clock"],
+                "sortorder": "DESC"
+            })
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::de::deserialize_object_id;
+use crate::timestamp::deserialize_timestamp;
+
+/// Message or remote command sent by an action operation.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/alert/object
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixAlert {
+    #[serde(rename = "alertid")]
+    pub alert_id: String,
+    #[serde(rename = "actionid")]
+    pub action_id: String,
+    #[serde(rename = "eventid")]
+    pub event_id: String,
+    /// Problem event of a recovery or update alert.
+    #[serde(
+        rename = "p_eventid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub problem_event_id: Option<String>,
+    /// Problem update that generated the alert.
+    #[serde(
+        rename = "acknowledgeid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub acknowledge_id: Option<String>,
+    /// Recipient user, `None` for remote commands.
+    #[serde(rename = "userid", default, deserialize_with = "deserialize_object_id")]
+    pub user_id: Option<String>,
+    /// `None` for remote commands.
+    #[serde(
+        rename = "mediatypeid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub media_type_id: Option<String>,
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub clock: SystemTime,
+    #[serde(rename = "alerttype")]
+    pub alert_type: AlertType,
+    pub status: AlertStatus,
+    #[serde(rename = "sendto")]
+    pub send_to: Option<String>,
+    pub subject: Option<String>,
+    pub message: Option<String>,
+    /// Sending attempts made so far.
+    #[serde_as(as = "DisplayFromStr")]
+    pub retries: u32,
+    /// Error text of a failed alert, empty otherwise.
+    pub error: Option<String>,
+    /// Escalation step of the operation.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub esc_step: Option<u32>,
+    /// Returned with `selectHosts`.
+    pub hosts: Option<Vec<ZabbixAlertHost>>,
+    /// Returned with `selectMediatypes`.
+    #[serde(rename = "mediatypes")]
+    pub media_types: Option<Vec<ZabbixAlertMediaType>>,
+    /// Returned with `selectUsers`.
+    pub users: Option<Vec<ZabbixAlertUser>>,
+}
+
+impl ZabbixAlert {
+    /// Failed after all attempts, `error` holds the reason.
+    pub fn is_failed(&self) -> bool {
+        self.status == AlertStatus::Failed
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum AlertType {
+    #[serde(rename = "0")]
+    Message,
+    #[serde(rename = "1")]
+    RemoteCommand,
+}
+
+/// For remote commands `Sent` means executed and `NotSent` not executed.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum AlertStatus {
+    /// Not sent yet, or being retried.
+    #[serde(rename = "0")]
+    NotSent,
+    #[serde(rename = "1")]
+    Sent,
+    #[serde(rename = "2")]
+    Failed,
+    /// Created, not processed by the alert manager yet.
+    #[serde(rename = "3")]
+    New,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixAlertHost {
+    #[serde(rename = "hostid")]
+    pub host_id: String,
+    pub host: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixAlertMediaType {
+    #[serde(rename = "mediatypeid")]
+    pub media_type_id: String,
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixAlertUser {
+    #[serde(rename = "userid")]
+    pub user_id: String,
+    pub username: Option<String>,
+    pub name: Option<String>,
+    pub surname: Option<String>,
+}
+
+#[cfg(test)]
+mod alert_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{AlertStatus, AlertType, ZabbixAlert};
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"alertid":"1","actionid":"3","eventid":"21243","userid":"1","clock":"1362128008","mediatypeid":"1","sendto":"support@company.com","subject":"PROBLEM: Zabbix agent on Linux server is unreachable for 5 minutes","message":"Trigger: Zabbix agent on Linux server is unreachable for 5 minutes","status":"2","retries":"3","error":"Connection refused","esc_step":"1","alerttype":"0","p_eventid":"0","acknowledgeid":"0",
+             "mediatypes":[{"mediatypeid":"1","name":"Email"}],
+             "users":[{"userid":"1","username":"Admin"}]}
+            "#;
+
+        let alert: ZabbixAlert = serde_json::from_str(input).unwrap();
+
+        assert_eq!(alert.alert_type, AlertType::Message);
+        assert_eq!(alert.status, AlertStatus::Failed);
+        assert!(alert.is_failed());
+        assert_eq!(alert.retries, 3);
+        assert_eq!(alert.error.as_deref(), Some("Connection refused"));
+        assert_eq!(alert.clock, UNIX_EPOCH + Duration::from_secs(1362128008));
+        assert_eq!(alert.problem_event_id, None);
+        assert_eq!(alert.media_type_id.as_deref(), Some("1"));
+        assert_eq!(alert.media_types.unwrap()[0].name.as_deref(), Some("Email"));
+        assert_eq!(alert.users.unwrap()[0].username.as_deref(), Some("Admin"));
+    }
+
+    #[test]
+    fn deserialize_remote_command_test() {
+        let input = r#"
+            {"alertid":"7","actionid":"4","eventid":"21250","userid":"0","clock":"1362128100","mediatypeid":"0","sendto":"","subject":"","message":"systemctl restart nginx","status":"1","retries":"0","error":"","esc_step":"1","alerttype":"1","p_eventid":"0","acknowledgeid":"0"}
+            "#;
+
+        let alert: ZabbixAlert = serde_json::from_str(input).unwrap();
+
+        assert_eq!(alert.alert_type, AlertType::RemoteCommand);
+        assert_eq!(alert.status, AlertStatus::Sent);
+        assert_eq!(alert.user_id, None);
+        assert_eq!(alert.media_type_id, None);
+    }
+}
@@ -6,21 +6,166 @@ use log::info;
 use reqwest::blocking::Client;
 use serde::{de::DeserializeOwned, Serialize};
 
+#[cfg(feature = "action")]
+use crate::action::create::{CreateActionRequest, CreateActionResponse};
+#[cfg(feature = "action")]
+use crate::action::model::ZabbixAction;
+#[cfg(feature = "action")]
+use crate::action::update::{UpdateActionRequest, UpdateActionResponse};
+#[cfg(feature = "alert")]
+use crate::alert::model::ZabbixAlert;
 use crate::client::request::get_api_request;
+#[cfg(feature = "configuration")]
+use crate::configuration::compare::{ImportCompareResponse, ImportCompareResult};
+#[cfg(feature = "configuration")]
+use crate::configuration::export::ExportConfigurationRequest;
+#[cfg(feature = "configuration")]
+use crate::configuration::import::ImportConfigurationRequest;
+#[cfg(feature = "dashboard")]
+use crate::dashboard::create::{CreateDashboardRequest, CreateDashboardResponse};
+#[cfg(feature = "dashboard")]
+use crate::dashboard::model::ZabbixDashboard;
+#[cfg(feature = "dashboard")]
+use crate::dashboard::update::{UpdateDashboardRequest, UpdateDashboardResponse};
+#[cfg(feature = "discovery")]
+use crate::discoveryrule::copy::CopyDiscoveryRulesRequest;
+#[cfg(feature = "discovery")]
+use crate::discoveryrule::create::{CreateDiscoveryRuleRequest, CreateDiscoveryRuleResponse};
+#[cfg(feature = "discovery")]
+use crate::discoveryrule::model::ZabbixDiscoveryRule;
+#[cfg(feature = "discovery")]
+use crate::discoveryrule::update::{
+    DeleteDiscoveryRulesResponse, UpdateDiscoveryRuleRequest, UpdateDiscoveryRuleResponse,
+};
 use crate::error::ZabbixApiError;
+#[cfg(feature = "event")]
+use crate::event::acknowledge::{AcknowledgeEventsRequest, AcknowledgeEventsResponse};
+#[cfg(feature = "event")]
+use crate::event::model::ZabbixEvent;
+#[cfg(feature = "discovery")]
+use crate::graphprototype::create::{CreateGraphPrototypeRequest, CreateGraphPrototypeResponse};
+#[cfg(feature = "discovery")]
+use crate::graphprototype::model::ZabbixGraphPrototype;
+#[cfg(feature = "discovery")]
+use crate::graphprototype::update::{UpdateGraphPrototypeRequest, UpdateGraphPrototypeResponse};
+#[cfg(feature = "history")]
+use crate::history::get::GetHistoryRequest;
+#[cfg(feature = "history")]
+use crate::history::model::HistoryValueType;
+#[cfg(all(feature = "history", feature = "v7"))]
+use crate::history::push::{HistoryPushResponse, HistoryPushResult, HistoryPushValue};
 use crate::host::create::CreateHostRequest;
 use crate::host::create::CreateHostResponse;
 use crate::host::model::ZabbixHost;
 use crate::host::update::UpdateHostRequest;
 use crate::host::update::UpdateHostResponse;
 use crate::hostgroup::create::CreateHostGroupRequest;
+use crate::hostgroup::mass::{
+    MassAddHostGroupsRequest, MassRemoveHostGroupsRequest, MassUpdateHostGroupsRequest,
+};
 use crate::hostgroup::model::ZabbixHostGroup;
+#[cfg(feature = "v7")]
+use crate::hostgroup::propagate::PropagateHostGroupsRequest;
+use crate::hostgroup::update::{UpdateHostGroupRequest, UpdateHostGroupResponse};
+#[cfg(feature = "discovery")]
+use crate::hostprototype::create::{CreateHostPrototypeRequest, CreateHostPrototypeResponse};
+#[cfg(feature = "discovery")]
+use crate::hostprototype::model::ZabbixHostPrototype;
+#[cfg(feature = "discovery")]
+use crate::hostprototype::update::{UpdateHostPrototypeRequest, UpdateHostPrototypeResponse};
 use crate::item::create::CreateItemRequest;
 use crate::item::create::CreateItemResponse;
 use crate::item::model::ZabbixItem;
+use crate::item::update::{UpdateItemRequest, UpdateItemResponse};
+#[cfg(feature = "discovery")]
+use crate::itemprototype::create::{CreateItemPrototypeRequest, CreateItemPrototypeResponse};
+#[cfg(feature = "discovery")]
+use crate::itemprototype::model::ZabbixItemPrototype;
+#[cfg(feature = "discovery")]
+use crate::itemprototype::update::{
+    DeleteItemPrototypesResponse, UpdateItemPrototypeRequest, UpdateItemPrototypeResponse,
+};
+#[cfg(feature = "maintenance")]
+use crate::maintenance::create::{CreateMaintenanceRequest, CreateMaintenanceResponse};
+#[cfg(feature = "maintenance")]
+use crate::maintenance::model::ZabbixMaintenance;
+#[cfg(feature = "maintenance")]
+use crate::maintenance::update::{UpdateMaintenanceRequest, UpdateMaintenanceResponse};
+#[cfg(feature = "mediatype")]
+use crate::mediatype::create::{CreateMediaTypeRequest, CreateMediaTypeResponse};
+#[cfg(feature = "mediatype")]
+use crate::mediatype::model::ZabbixMediaType;
+#[cfg(feature = "mediatype")]
+use crate::mediatype::update::{UpdateMediaTypeRequest, UpdateMediaTypeResponse};
+#[cfg(feature = "event")]
+use crate::problem::model::ZabbixProblem;
+#[cfg(feature = "proxy")]
+use crate::proxy::create::{CreateProxyRequest, CreateProxyResponse};
+#[cfg(feature = "proxy")]
+use crate::proxy::model::ZabbixProxy;
+#[cfg(feature = "proxy")]
+use crate::proxy::update::{UpdateProxyRequest, UpdateProxyResponse};
+#[cfg(all(feature = "proxy", feature = "v7"))]
+use crate::proxygroup::create::{CreateProxyGroupRequest, CreateProxyGroupResponse};
+#[cfg(all(feature = "proxy", feature = "v7"))]
+use crate::proxygroup::model::ZabbixProxyGroup;
+#[cfg(all(feature = "proxy", feature = "v7"))]
+use crate::proxygroup::update::{UpdateProxyGroupRequest, UpdateProxyGroupResponse};
+#[cfg(feature = "script")]
+use crate::script::create::{CreateScriptRequest, CreateScriptResponse};
+#[cfg(feature = "script")]
+use crate::script::execute::{ExecuteScriptRequest, ScriptExecution};
+#[cfg(feature = "script")]
+use crate::script::get::{GetScriptsByEventsRequest, GetScriptsByHostsRequest, ScriptsByObject};
+#[cfg(feature = "script")]
+use crate::script::model::ZabbixScript;
+#[cfg(feature = "script")]
+use crate::script::update::{UpdateScriptRequest, UpdateScriptResponse};
+#[cfg(feature = "template")]
+use crate::template::create::{CreateTemplateRequest, CreateTemplateResponse};
+#[cfg(feature = "template")]
+use crate::template::link::{LinkTemplatesRequest, UnlinkTemplatesRequest};
+#[cfg(feature = "template")]
+use crate::template::mass::{
+    MassAddTemplatesRequest, MassRemoveTemplatesRequest, MassUpdateTemplatesRequest,
+};
+#[cfg(feature = "template")]
+use crate::template::model::ZabbixTemplate;
+#[cfg(feature = "template")]
+use crate::template::update::{UpdateTemplateRequest, UpdateTemplateResponse};
+#[cfg(all(feature = "template", feature = "v7"))]
+use crate::templategroup::create::{CreateTemplateGroupRequest, CreateTemplateGroupResponse};
+#[cfg(all(feature = "template", feature = "v7"))]
+use crate::templategroup::mass::{MassAddTemplateGroupsRequest, MassRemoveTemplateGroupsRequest};
+#[cfg(all(feature = "template", feature = "v7"))]
+use crate::templategroup::model::ZabbixTemplateGroup;
+#[cfg(all(feature = "template", feature = "v7"))]
+use crate::templategroup::propagate::PropagateTemplateGroupsRequest;
+#[cfg(all(feature = "template", feature = "v7"))]
+use crate::templategroup::update::{UpdateTemplateGroupRequest, UpdateTemplateGroupResponse};
+#[cfg(feature = "trend")]
+use crate::trend::get::GetTrendsRequest;
+#[cfg(feature = "trend")]
+use crate::trend::model::{TrendValue, ZabbixTrend};
 use crate::trigger::create::CreateTriggerRequest;
 use crate::trigger::create::CreateTriggerResponse;
+#[cfg(feature = "trigger")]
+use crate::trigger::create::ZabbixTriggerDependency;
+#[cfg(feature = "trigger")]
+use crate::trigger::dependency::AddTriggerDependencyRequest;
 use crate::trigger::model::ZabbixTrigger;
+#[cfg(feature = "trigger")]
+use crate::trigger::update::{UpdateTriggerRequest, UpdateTriggerResponse};
+#[cfg(feature = "discovery")]
+use crate::triggerprototype::create::{
+    CreateTriggerPrototypeRequest, CreateTriggerPrototypeResponse,
+};
+#[cfg(feature = "discovery")]
+use crate::triggerprototype::model::ZabbixTriggerPrototype;
+#[cfg(feature = "discovery")]
+use crate::triggerprototype::update::{
+    UpdateTriggerPrototypeRequest, UpdateTriggerPrototypeResponse,
+};
 #[cfg(feature = "user")]
 use crate::user::create::{CreateUserRequest, CreateUserResponse};
 #[cfg(feature = "user")]
@@ -305,18 +450,7 @@ pub trait ZabbixApiClient {
     /// ```rust
     /// use reqwest::blocking::Client;
     /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
-    /// use zabbix_api::trigger::model::ZabbixTrigger; // For the type of triggers
-    /// use serde::Serialize;
-    /// use std::collections::HashMap;
-    ///
-    /// #[derive(Serialize)]
-    /// struct GetTriggersParams {
-    ///     output: String,
-    ///     select_hosts: String, // Example: "extend"
-    ///     // Add other filters as needed, e.g. hostids, groupids, min_severity
-    ///     // filter: HashMap<String, serde_json::Value>,
-    ///     limit: Option<u32>,
-    /// }
+    /// use zabbix_api::trigger::get::GetTriggersRequest;
     ///
     /// let http_client = Client::new();
     /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
@@ -326,10 +460,18 @@ pub trait ZabbixApiClient {
     /// let client = ZabbixApiClientImpl::new(http_client, &url);
     /// let session = client.get_auth_session(&user, &password).unwrap();
     ///
-    /// let params = GetTriggersParams {
-    ///     output: "extend".to_string(),
-    ///     select_hosts: "extend".to_string(),
+    /// // Unacknowledged problems of average severity and above
+    /// let params = GetTriggersRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     select_hosts: Some("extend".to_string()),
+    ///     select_tags: Some("extend".to_string()),
+    ///     expand_description: true,
+    ///     only_true: true,
+    ///     min_severity: Some(3),
+    ///     with_last_event_unacknowledged: true,
+    ///     active: true,
     ///     limit: Some(10),
+    ///     ..Default::default()
     /// };
     ///
     /// match client.get_triggers(&session, &params) {
@@ -561,10 +703,9 @@ pub trait ZabbixApiClient {
     ///
     /// let host_id = "12".to_string();
     ///
-    /// let update_host_params = UpdateHostRequest {
-    ///     hostid: host_id.clone(),
-    ///     status: HostStatus::Disabled,
-    /// };
+    /// let update_host_params = UpdateHostRequest::builder(&host_id)
+    ///     .status(HostStatus::Disabled)
+    ///     .build();
     ///
     /// match client.update_host(&session, &update_host_params) {
     ///     Ok(id) => println!("Successfully disabled host with ID: {}", id),
@@ -627,6 +768,7 @@ pub trait ZabbixApiClient {
     /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
     /// use zabbix_api::item::create::CreateItemRequest;
     /// use zabbix_api::host::model::ZabbixHostTag; // For ZabbixItemTag if used
+    /// use zabbix_api::item::preprocessing::PreprocessingStep; // For preprocessing steps if used
     ///
     /// let http_client = Client::new();
     /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
@@ -648,7 +790,10 @@ pub trait ZabbixApiClient {
     ///     interface_id: "0".to_string(), // Use "0" if not specific interface, or a real one
     ///     delay: "30s".to_string(),
     ///     tags: vec![], // Example: vec![ZabbixHostTag { tag: "env".to_string(), value: "prod".to_string() }]
-    ///     // Add other fields as required by your item type
+    ///     preprocessing: vec![], // Example: vec![PreprocessingStep::ChangePerSecond.into()]
+    ///     // Add other fields as required by your item type,
+    ///     // or use a constructor like `CreateItemRequest::http_agent` instead
+    ///     ..Default::default()
     /// };
     ///
     /// match client.create_item(&session, &request) {
@@ -915,207 +1060,9637 @@ pub trait ZabbixApiClient {
         session: &str,
         request: &CreateUserRequest,
     ) -> Result<u32, ZabbixApiError>;
-}
-
-#[derive(Debug, Clone)]
-pub struct ZabbixApiClientImpl {
-    client: Client,
-    api_endpoint_url: String,
-}
-
-impl ZabbixApiClientImpl {
-    pub fn new(client: Client, api_endpoint_url: &str) -> ZabbixApiClientImpl {
-        ZabbixApiClientImpl {
-            client,
-            api_endpoint_url: api_endpoint_url.to_string(),
-        }
-    }
-}
-
-impl ZabbixApiClient for ZabbixApiClientImpl {
-    fn get_api_info(&self) -> Result<String, ZabbixApiError> {
-        let params = HashMap::<String, String>::new();
-
-        let api_request = get_api_request("apiinfo.version", params, None);
-
-        match send_post_request(&self.client, &self.api_endpoint_url, None, api_request) {
-            Ok(response_body) => {
-                let response = serde_json::from_str::<ZabbixApiResponse<String>>(&response_body)?;
-
-                match response.result {
-                    Some(api_version) => {
-                        info!("zabbix api version: '{api_version}'");
-                        Ok(api_version)
-                    }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
-
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
-                }
-            }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
-        }
-    }
-
-    fn get_auth_session(&self, login: &str, token: &str) -> Result<String, ZabbixApiError> {
-        info!("getting auth session for user '{login}'..");
-
-        let params = HashMap::from([
-            ("username".to_string(), login.to_string()),
-            ("password".to_string(), token.to_string()),
-        ]);
-
-        let api_request = get_api_request("user.login", params, None);
-
-        match send_post_request(&self.client, &self.api_endpoint_url, None, api_request) {
-            Ok(response_body) => {
-                let response = serde_json::from_str::<ZabbixApiResponse<String>>(&response_body)?;
-
-                match response.result {
-                    Some(session) => {
-                        info!("auth ok");
-                        Ok(session)
-                    }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
-
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
-                }
-            }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
-        }
-    }
 
-    fn raw_api_call<P: Serialize, R: DeserializeOwned>(
+    /// # update_host_group
+    ///
+    /// Updates a Zabbix host group.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::hostgroup::update::UpdateHostGroupRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real host group ID
+    /// let request = UpdateHostGroupRequest::rename("22", "Tenant A servers");
+    ///
+    /// match client.update_host_group(&session, &request) {
+    ///     Ok(group_id) => println!("Successfully renamed host group with ID: {}", group_id),
+    ///     Err(e) => eprintln!("Error updating host group: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "host")]
+    fn update_host_group(
         &self,
         session: &str,
-        method: &str,
-        params: &P,
-    ) -> Result<ZabbixApiResponse<R>, ZabbixApiError> {
-        info!("calling api method '{method}'..");
-
-        let api_request = get_api_request(method, params, Some(session.to_string()));
-
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
-
-                let response = serde_json::from_str::<ZabbixApiResponse<R>>(&response_body)?;
-
-                match response.result {
-                    Some(_) => {
-                        info!("api method '{method}' has been successfully called");
-                        Ok(response)
-                    }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
-
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
-                }
-            }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
-        }
-    }
+        request: &UpdateHostGroupRequest,
+    ) -> Result<u32, ZabbixApiError>;
 
-    /// # get_host_groups
+    /// # delete_host_groups
     ///
-    /// Implements `ZabbixApiClient::get_host_groups`.
+    /// Deletes Zabbix host groups. Returns IDs of the deleted host groups.
     ///
-    /// See the trait documentation for more details.
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let group_ids = vec!["22".to_string()];
+    ///
+    /// match client.delete_host_groups(&session, &group_ids) {
+    ///     Ok(ids) => println!("Successfully deleted host groups with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting host groups: {:?}", e),
+    /// }
+    /// ```
     #[cfg(feature = "host")]
-    fn get_host_groups<P: Serialize>(
+    fn delete_host_groups(
         &self,
         session: &str,
-        params: &P,
-    ) -> Result<Vec<ZabbixHostGroup>, ZabbixApiError> {
-        info!("getting host groups with params");
-
-        let api_request = get_api_request("hostgroup.get", params, Some(session.to_string()));
-
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
-
-                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixHostGroup>>>(
-                    &response_body,
-                )?;
-
-                match response.result {
-                    Some(results) => {
-                        info!("host groups found: {:?}", results);
-                        Ok(results)
-                    }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
-
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
-                }
-            }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
-        }
-    }
+        group_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
 
-    /// # get_hosts
+    /// # mass_add_host_groups
     ///
-    /// Implements `ZabbixApiClient::get_hosts`.
+    /// Adds hosts to the given host groups. Returns IDs of the updated host groups.
     ///
-    /// See the trait documentation for more details.
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/massadd
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::hostgroup::mass::MassAddHostGroupsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real host group and host IDs
+    /// let request = MassAddHostGroupsRequest::new(
+    ///     vec!["22".to_string(), "23".to_string()],
+    ///     vec!["10084".to_string()],
+    /// );
+    ///
+    /// match client.mass_add_host_groups(&session, &request) {
+    ///     Ok(ids) => println!("Hosts have been added to host groups: {:?}", ids),
+    ///     Err(e) => eprintln!("Error adding hosts to host groups: {:?}", e),
+    /// }
+    /// ```
     #[cfg(feature = "host")]
-    fn get_hosts<P: Serialize>(
+    fn mass_add_host_groups(
         &self,
         session: &str,
-        params: &P,
-    ) -> Result<Vec<ZabbixHost>, ZabbixApiError> {
-        info!("getting hosts with params");
-
-        let api_request = get_api_request("host.get", params, Some(session.to_string()));
+        request: &MassAddHostGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
+    /// # mass_remove_host_groups
+    ///
+    /// Removes hosts from the given host groups. Returns IDs of the updated host groups.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/massremove
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::hostgroup::mass::MassRemoveHostGroupsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real host group and host IDs
+    /// let request = MassRemoveHostGroupsRequest::new(
+    ///     vec!["23".to_string()],
+    ///     vec!["10084".to_string()],
+    /// );
+    ///
+    /// match client.mass_remove_host_groups(&session, &request) {
+    ///     Ok(ids) => println!("Hosts have been removed from host groups: {:?}", ids),
+    ///     Err(e) => eprintln!("Error removing hosts from host groups: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "host")]
+    fn mass_remove_host_groups(
+        &self,
+        session: &str,
+        request: &MassRemoveHostGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # mass_update_host_groups
+    ///
+    /// Replaces hosts of the given host groups. Returns IDs of the updated host groups.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/massupdate
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::hostgroup::mass::MassUpdateHostGroupsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // After the call host group "22" contains only host "10084"
+    /// let request = MassUpdateHostGroupsRequest::new(
+    ///     vec!["22".to_string()],
+    ///     vec!["10084".to_string()],
+    /// );
+    ///
+    /// match client.mass_update_host_groups(&session, &request) {
+    ///     Ok(ids) => println!("Host groups have been updated: {:?}", ids),
+    ///     Err(e) => eprintln!("Error updating host groups: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "host")]
+    fn mass_update_host_groups(
+        &self,
+        session: &str,
+        request: &MassUpdateHostGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # propagate_host_groups
+    ///
+    /// Propagates permissions and tag filters of host groups to their nested groups.
+    /// Returns IDs of the source host groups.
+    ///
+    /// Available since Zabbix 6.2.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/propagate
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::hostgroup::propagate::PropagateHostGroupsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Host group "22" is "Tenants", subgroups are "Tenants/A", "Tenants/B", etc.
+    /// let request = PropagateHostGroupsRequest::new(vec!["22".to_string()], true, true);
+    ///
+    /// match client.propagate_host_groups(&session, &request) {
+    ///     Ok(ids) => println!("Permissions have been propagated for groups: {:?}", ids),
+    ///     Err(e) => eprintln!("Error propagating host group permissions: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "host", feature = "v7"))]
+    fn propagate_host_groups(
+        &self,
+        session: &str,
+        request: &PropagateHostGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_template_groups
+    ///
+    /// Retrieves Zabbix template groups based on the provided parameters.
+    ///
+    /// Available since Zabbix 6.2.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::templategroup::get::{GetTemplateGroupsRequest, TemplateGroupFilter};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let params = GetTemplateGroupsRequest {
+    ///     output: Some("extend".to_string()),
+    ///     filter: Some(TemplateGroupFilter {
+    ///         name: Some(vec!["Templates/Applications".to_string()]),
+    ///     }),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_template_groups(&session, &params) {
+    ///     Ok(template_groups) => println!("Found template groups: {:?}", template_groups),
+    ///     Err(e) => eprintln!("Error getting template groups: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn get_template_groups<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixTemplateGroup>, ZabbixApiError>;
+
+    /// # create_template_group
+    ///
+    /// Creates a new Zabbix template group.
+    ///
+    /// Available since Zabbix 6.2.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::templategroup::create::CreateTemplateGroupRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = CreateTemplateGroupRequest {
+    ///     name: "Templates/Tenants".to_string(),
+    /// };
+    ///
+    /// match client.create_template_group(&session, &request) {
+    ///     Ok(group_id) => println!("Successfully created template group with ID: {}", group_id),
+    ///     Err(e) => eprintln!("Error creating template group: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn create_template_group(
+        &self,
+        session: &str,
+        request: &CreateTemplateGroupRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_template_group
+    ///
+    /// Updates a Zabbix template group.
+    ///
+    /// Available since Zabbix 6.2.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::templategroup::update::UpdateTemplateGroupRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real template group ID
+    /// let request = UpdateTemplateGroupRequest::rename("25", "Templates/Tenant A");
+    ///
+    /// match client.update_template_group(&session, &request) {
+    ///     Ok(group_id) => println!("Successfully renamed template group with ID: {}", group_id),
+    ///     Err(e) => eprintln!("Error updating template group: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn update_template_group(
+        &self,
+        session: &str,
+        request: &UpdateTemplateGroupRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_template_groups
+    ///
+    /// Deletes Zabbix template groups. Returns IDs of the deleted template groups.
+    ///
+    /// Available since Zabbix 6.2.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let group_ids = vec!["25".to_string()];
+    ///
+    /// match client.delete_template_groups(&session, &group_ids) {
+    ///     Ok(ids) => println!("Successfully deleted template groups with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting template groups: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn delete_template_groups(
+        &self,
+        session: &str,
+        group_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # mass_add_template_groups
+    ///
+    /// Adds templates to the given template groups. Returns IDs of the updated template groups.
+    ///
+    /// Available since Zabbix 6.2.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/massadd
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::templategroup::mass::MassAddTemplateGroupsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real template group and template IDs
+    /// let request = MassAddTemplateGroupsRequest::new(
+    ///     vec!["25".to_string()],
+    ///     vec!["10001".to_string()],
+    /// );
+    ///
+    /// match client.mass_add_template_groups(&session, &request) {
+    ///     Ok(ids) => println!("Templates have been added to template groups: {:?}", ids),
+    ///     Err(e) => eprintln!("Error adding templates to template groups: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn mass_add_template_groups(
+        &self,
+        session: &str,
+        request: &MassAddTemplateGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # mass_remove_template_groups
+    ///
+    /// Removes templates from the given template groups. Returns IDs of the updated template groups.
+    ///
+    /// Available since Zabbix 6.2.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/massremove
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::templategroup::mass::MassRemoveTemplateGroupsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real template group and template IDs
+    /// let request = MassRemoveTemplateGroupsRequest::new(
+    ///     vec!["25".to_string()],
+    ///     vec!["10001".to_string()],
+    /// );
+    ///
+    /// match client.mass_remove_template_groups(&session, &request) {
+    ///     Ok(ids) => println!("Templates have been removed from template groups: {:?}", ids),
+    ///     Err(e) => eprintln!("Error removing templates from template groups: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn mass_remove_template_groups(
+        &self,
+        session: &str,
+        request: &MassRemoveTemplateGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # propagate_template_groups
+    ///
+    /// Propagates permissions of template groups to their nested groups.
+    /// Returns IDs of the source template groups.
+    ///
+    /// Available since Zabbix 6.2.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/propagate
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::templategroup::propagate::PropagateTemplateGroupsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = PropagateTemplateGroupsRequest::new(vec!["25".to_string()]);
+    ///
+    /// match client.propagate_template_groups(&session, &request) {
+    ///     Ok(ids) => println!("Permissions have been propagated for groups: {:?}", ids),
+    ///     Err(e) => eprintln!("Error propagating template group permissions: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn propagate_template_groups(
+        &self,
+        session: &str,
+        request: &PropagateTemplateGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_templates
+    ///
+    /// Retrieves Zabbix templates based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::template::get::{GetTemplatesRequest, TemplateFilter};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let params = GetTemplatesRequest {
+    ///     output: Some("extend".to_string()),
+    ///     filter: Some(TemplateFilter {
+    ///         host: Some(vec!["Linux by Zabbix agent".to_string()]),
+    ///         ..Default::default()
+    ///     }),
+    ///     select_tags: Some("extend".to_string()),
+    ///     select_parent_templates: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_templates(&session, &params) {
+    ///     Ok(templates) => println!("Found templates: {:?}", templates),
+    ///     Err(e) => eprintln!("Error getting templates: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "template")]
+    fn get_templates<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixTemplate>, ZabbixApiError>;
+
+    /// # create_template
+    ///
+    /// Creates a new Zabbix template.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::template::create::CreateTemplateRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Template group ID for Zabbix 6.2+, host group ID before that
+    /// let request = CreateTemplateRequest::builder("tpl-nginx")
+    ///     .name("Nginx by HTTP")
+    ///     .group("12")
+    ///     .tag("class", "software")
+    ///     .template("10001") // links a parent template
+    ///     .build();
+    ///
+    /// match client.create_template(&session, &request) {
+    ///     Ok(template_id) => println!("Successfully created template with ID: {}", template_id),
+    ///     Err(e) => eprintln!("Error creating template: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "template")]
+    fn create_template(
+        &self,
+        session: &str,
+        request: &CreateTemplateRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_template
+    ///
+    /// Updates a Zabbix template.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::template::update::UpdateTemplateRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real template ID
+    /// let request = UpdateTemplateRequest::builder("10500")
+    ///     .description("Managed by provisioning")
+    ///     .templates_clear(vec!["10001".to_string()]) // unlinks and clears a parent template
+    ///     .build();
+    ///
+    /// match client.update_template(&session, &request) {
+    ///     Ok(template_id) => println!("Successfully updated template with ID: {}", template_id),
+    ///     Err(e) => eprintln!("Error updating template: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "template")]
+    fn update_template(
+        &self,
+        session: &str,
+        request: &UpdateTemplateRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_templates
+    ///
+    /// Deletes Zabbix templates. Returns IDs of the deleted templates.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let template_ids = vec!["10500".to_string()];
+    ///
+    /// match client.delete_templates(&session, &template_ids) {
+    ///     Ok(ids) => println!("Successfully deleted templates with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting templates: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "template")]
+    fn delete_templates(
+        &self,
+        session: &str,
+        template_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # mass_add_templates
+    ///
+    /// Adds groups, macros and parent templates to the given templates.
+    /// Returns IDs of the updated templates.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/massadd
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::template::mass::MassAddTemplatesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = MassAddTemplatesRequest::new(vec!["10500".to_string(), "10501".to_string()])
+    ///     .groups(vec!["12".to_string()])
+    ///     .templates_link(vec!["10001".to_string()]);
+    ///
+    /// match client.mass_add_templates(&session, &request) {
+    ///     Ok(ids) => println!("Templates have been updated: {:?}", ids),
+    ///     Err(e) => eprintln!("Error updating templates: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "template")]
+    fn mass_add_templates(
+        &self,
+        session: &str,
+        request: &MassAddTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # mass_remove_templates
+    ///
+    /// Removes groups, macros and parent templates from the given templates.
+    /// Returns IDs of the updated templates.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/massremove
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::template::mass::MassRemoveTemplatesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = MassRemoveTemplatesRequest::new(vec!["10500".to_string()])
+    ///     .macros(vec!["{$NGINX.PORT}".to_string()])
+    ///     .templates_link(vec!["10001".to_string()]);
+    ///
+    /// match client.mass_remove_templates(&session, &request) {
+    ///     Ok(ids) => println!("Templates have been updated: {:?}", ids),
+    ///     Err(e) => eprintln!("Error updating templates: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "template")]
+    fn mass_remove_templates(
+        &self,
+        session: &str,
+        request: &MassRemoveTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # mass_update_templates
+    ///
+    /// Replaces groups, macros and parent templates of the given templates.
+    /// Returns IDs of the updated templates.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/massupdate
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::template::mass::MassUpdateTemplatesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = MassUpdateTemplatesRequest::new(vec!["10500".to_string()])
+    ///     .groups(vec!["12".to_string()]);
+    ///
+    /// match client.mass_update_templates(&session, &request) {
+    ///     Ok(ids) => println!("Templates have been updated: {:?}", ids),
+    ///     Err(e) => eprintln!("Error updating templates: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "template")]
+    fn mass_update_templates(
+        &self,
+        session: &str,
+        request: &MassUpdateTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # link_templates
+    ///
+    /// Links templates to hosts. Returns IDs of the updated hosts.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/host/massadd
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::template::link::LinkTemplatesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = LinkTemplatesRequest::new(
+    ///     vec!["10500".to_string()],
+    ///     vec!["10084".to_string(), "10085".to_string()],
+    /// );
+    ///
+    /// match client.link_templates(&session, &request) {
+    ///     Ok(ids) => println!("Templates have been linked to hosts: {:?}", ids),
+    ///     Err(e) => eprintln!("Error linking templates: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "template")]
+    fn link_templates(
+        &self,
+        session: &str,
+        request: &LinkTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # unlink_templates
+    ///
+    /// Unlinks templates from hosts. Returns IDs of the updated hosts.
+    ///
+    /// Use `UnlinkTemplatesRequest::clear` to also delete entities inherited from the templates.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/host/massremove
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::template::link::UnlinkTemplatesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = UnlinkTemplatesRequest::clear(
+    ///     vec!["10500".to_string()],
+    ///     vec!["10084".to_string()],
+    /// );
+    ///
+    /// match client.unlink_templates(&session, &request) {
+    ///     Ok(ids) => println!("Templates have been unlinked from hosts: {:?}", ids),
+    ///     Err(e) => eprintln!("Error unlinking templates: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "template")]
+    fn unlink_templates(
+        &self,
+        session: &str,
+        request: &UnlinkTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # export_configuration
+    ///
+    /// Exports configuration data (templates, hosts, groups, etc.) as a serialized string
+    /// in the requested format.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/configuration/export
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::configuration::export::ExportConfigurationRequest;
+    /// use zabbix_api::configuration::model::ConfigurationFormat;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = ExportConfigurationRequest::templates(
+    ///     ConfigurationFormat::Yaml,
+    ///     vec!["10001".to_string()],
+    /// );
+    ///
+    /// match client.export_configuration(&session, &request) {
+    ///     Ok(source) => println!("{}", source),
+    ///     Err(e) => eprintln!("Error exporting configuration: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "configuration")]
+    fn export_configuration(
+        &self,
+        session: &str,
+        request: &ExportConfigurationRequest,
+    ) -> Result<String, ZabbixApiError>;
+
+    /// # import_configuration
+    ///
+    /// Imports configuration data according to the given rules.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/configuration/import
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::configuration::import::{ImportConfigurationRequest, ImportRules};
+    /// use zabbix_api::configuration::model::ConfigurationFormat;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let source = std::fs::read_to_string("templates/nginx.yaml").unwrap();
+    ///
+    /// let request = ImportConfigurationRequest::new(
+    ///     ConfigurationFormat::Yaml,
+    ///     source,
+    ///     ImportRules::templates_sync(),
+    /// );
+    ///
+    /// match client.import_configuration(&session, &request) {
+    ///     Ok(_) => println!("Configuration has been imported"),
+    ///     Err(e) => eprintln!("Error importing configuration: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "configuration")]
+    fn import_configuration(
+        &self,
+        session: &str,
+        request: &ImportConfigurationRequest,
+    ) -> Result<bool, ZabbixApiError>;
+
+    /// # compare_configuration_import
+    ///
+    /// Compares configuration data with the server state and returns the changes
+    /// `configuration.import` would make with the same parameters. Nothing is changed on the server.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/configuration/importcompare
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::configuration::import::{ImportConfigurationRequest, ImportRules};
+    /// use zabbix_api::configuration::model::ConfigurationFormat;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let source = std::fs::read_to_string("templates/nginx.yaml").unwrap();
+    ///
+    /// let request = ImportConfigurationRequest::new(
+    ///     ConfigurationFormat::Yaml,
+    ///     source,
+    ///     ImportRules::templates_sync(),
+    /// );
+    ///
+    /// match client.compare_configuration_import(&session, &request) {
+    ///     Ok(changes) if changes.is_empty() => println!("Nothing to import"),
+    ///     Ok(changes) => println!("Import would change: {:?}", changes.templates),
+    ///     Err(e) => eprintln!("Error comparing configuration: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "configuration")]
+    fn compare_configuration_import(
+        &self,
+        session: &str,
+        request: &ImportConfigurationRequest,
+    ) -> Result<ImportCompareResult, ZabbixApiError>;
+
+    /// # update_item
+    ///
+    /// Updates a Zabbix item.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/item/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::item::update::UpdateItemRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real item ID
+    /// let request = UpdateItemRequest::builder("23296")
+    ///     .delay("5m")
+    ///     .history("14d")
+    ///     .disable()
+    ///     .build();
+    ///
+    /// match client.update_item(&session, &request) {
+    ///     Ok(item_id) => println!("Successfully updated item with ID: {}", item_id),
+    ///     Err(e) => eprintln!("Error updating item: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "item")]
+    fn update_item(
+        &self,
+        session: &str,
+        request: &UpdateItemRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_items
+    ///
+    /// Deletes Zabbix items. Returns IDs of the deleted items.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/item/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let item_ids = vec!["23296".to_string()];
+    ///
+    /// match client.delete_items(&session, &item_ids) {
+    ///     Ok(ids) => println!("Successfully deleted items with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting items: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "item")]
+    fn delete_items(
+        &self,
+        session: &str,
+        item_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_discovery_rules
+    ///
+    /// Retrieves low-level discovery rules based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::discoveryrule::get::GetDiscoveryRulesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real host ID
+    /// let params = GetDiscoveryRulesRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     host_ids: Some(vec!["10084".to_string()]),
+    ///     select_filter: Some("extend".to_string()),
+    ///     select_lld_macro_paths: Some("extend".to_string()),
+    ///     select_overrides: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_discovery_rules(&session, &params) {
+    ///     Ok(rules) => println!("Found discovery rules: {:?}", rules),
+    ///     Err(e) => eprintln!("Error getting discovery rules: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn get_discovery_rules<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixDiscoveryRule>, ZabbixApiError>;
+
+    /// # create_discovery_rule
+    ///
+    /// Creates a low-level discovery rule.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::discoveryrule::create::CreateDiscoveryRuleRequest;
+    /// use zabbix_api::discoveryrule::model::{LldFilter, LldFilterCondition};
+    /// use zabbix_api::item::model::ItemType;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real host and master item IDs
+    /// let request = CreateDiscoveryRuleRequest::builder(
+    ///     "10084",
+    ///     "Services discovery",
+    ///     "services.discovery",
+    ///     ItemType::Dependent,
+    /// )
+    /// .master_item_id("23296")
+    /// .macro_path("{#SERVICE}", "$.name")
+    /// .filter(LldFilter::and_or(vec![LldFilterCondition::not_matches("{#SERVICE}", "^test")]))
+    /// .lifetime("7d")
+    /// .build();
+    ///
+    /// match client.create_discovery_rule(&session, &request) {
+    ///     Ok(rule_id) => println!("Successfully created discovery rule with ID: {}", rule_id),
+    ///     Err(e) => eprintln!("Error creating discovery rule: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn create_discovery_rule(
+        &self,
+        session: &str,
+        request: &CreateDiscoveryRuleRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_discovery_rule
+    ///
+    /// Updates a low-level discovery rule.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::discoveryrule::update::UpdateDiscoveryRuleRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real discovery rule ID
+    /// let request = UpdateDiscoveryRuleRequest::builder("27425")
+    ///     .delay("30m")
+    ///     .lifetime("1d")
+    ///     .build();
+    ///
+    /// match client.update_discovery_rule(&session, &request) {
+    ///     Ok(rule_id) => println!("Successfully updated discovery rule with ID: {}", rule_id),
+    ///     Err(e) => eprintln!("Error updating discovery rule: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn update_discovery_rule(
+        &self,
+        session: &str,
+        request: &UpdateDiscoveryRuleRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_discovery_rules
+    ///
+    /// Deletes low-level discovery rules with their prototypes and discovered entities.
+    /// Returns IDs of the deleted rules.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let rule_ids = vec!["27425".to_string()];
+    ///
+    /// match client.delete_discovery_rules(&session, &rule_ids) {
+    ///     Ok(ids) => println!("Successfully deleted discovery rules with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting discovery rules: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn delete_discovery_rules(
+        &self,
+        session: &str,
+        rule_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # copy_discovery_rules
+    ///
+    /// Copies low-level discovery rules with all their prototypes to the given hosts.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/copy
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::discoveryrule::copy::CopyDiscoveryRulesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = CopyDiscoveryRulesRequest::new(
+    ///     vec!["27425".to_string()],
+    ///     vec!["10085".to_string(), "10086".to_string()],
+    /// );
+    ///
+    /// match client.copy_discovery_rules(&session, &request) {
+    ///     Ok(_) => println!("Discovery rules have been copied"),
+    ///     Err(e) => eprintln!("Error copying discovery rules: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn copy_discovery_rules(
+        &self,
+        session: &str,
+        request: &CopyDiscoveryRulesRequest,
+    ) -> Result<bool, ZabbixApiError>;
+
+    /// # get_item_prototypes
+    ///
+    /// Retrieves item prototypes based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::itemprototype::get::GetItemPrototypesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real discovery rule ID
+    /// let params = GetItemPrototypesRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     discovery_ids: Some(vec!["27425".to_string()]),
+    ///     select_preprocessing: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_item_prototypes(&session, &params) {
+    ///     Ok(prototypes) => println!("Found item prototypes: {:?}", prototypes),
+    ///     Err(e) => eprintln!("Error getting item prototypes: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn get_item_prototypes<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixItemPrototype>, ZabbixApiError>;
+
+    /// # create_item_prototype
+    ///
+    /// Creates an item prototype on a low-level discovery rule.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::item::create::CreateItemRequest;
+    /// use zabbix_api::item::model::ItemValueType;
+    /// use zabbix_api::itemprototype::create::CreateItemPrototypeRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real host, master item and discovery rule IDs
+    /// let item = CreateItemRequest::dependent(
+    ///     "10084",
+    ///     "Service {#SERVICE} state",
+    ///     "service.state[{#SERVICE}]",
+    ///     ItemValueType::Unsigned,
+    ///     "23296",
+    /// )
+    /// .build()
+    /// .unwrap();
+    ///
+    /// let request = CreateItemPrototypeRequest::new("27425", item);
+    ///
+    /// match client.create_item_prototype(&session, &request) {
+    ///     Ok(item_id) => println!("Successfully created item prototype with ID: {}", item_id),
+    ///     Err(e) => eprintln!("Error creating item prototype: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn create_item_prototype(
+        &self,
+        session: &str,
+        request: &CreateItemPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_item_prototype
+    ///
+    /// Updates an item prototype.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::item::update::UpdateItemRequest;
+    /// use zabbix_api::itemprototype::update::UpdateItemPrototypeRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real item prototype ID
+    /// let item = UpdateItemRequest::builder("27428").delay("5m").build();
+    ///
+    /// let request = UpdateItemPrototypeRequest::new(item).discover(false);
+    ///
+    /// match client.update_item_prototype(&session, &request) {
+    ///     Ok(item_id) => println!("Successfully updated item prototype with ID: {}", item_id),
+    ///     Err(e) => eprintln!("Error updating item prototype: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn update_item_prototype(
+        &self,
+        session: &str,
+        request: &UpdateItemPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_item_prototypes
+    ///
+    /// Deletes item prototypes with the items discovered from them.
+    /// Returns IDs of the deleted prototypes.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let prototype_ids = vec!["27428".to_string()];
+    ///
+    /// match client.delete_item_prototypes(&session, &prototype_ids) {
+    ///     Ok(ids) => println!("Successfully deleted item prototypes with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting item prototypes: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn delete_item_prototypes(
+        &self,
+        session: &str,
+        prototype_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_trigger_prototypes
+    ///
+    /// Retrieves trigger prototypes based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::triggerprototype::get::GetTriggerPrototypesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real discovery rule ID
+    /// let params = GetTriggerPrototypesRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     discovery_ids: Some(vec!["27425".to_string()]),
+    ///     select_tags: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_trigger_prototypes(&session, &params) {
+    ///     Ok(prototypes) => println!("Found trigger prototypes: {:?}", prototypes),
+    ///     Err(e) => eprintln!("Error getting trigger prototypes: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn get_trigger_prototypes<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixTriggerPrototype>, ZabbixApiError>;
+
+    /// # create_trigger_prototype
+    ///
+    /// Creates a trigger prototype. The expression must refer to at least one item prototype.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::trigger::create::CreateTriggerRequest;
+    /// use zabbix_api::triggerprototype::create::CreateTriggerPrototypeRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let trigger = CreateTriggerRequest {
+    ///     description: "Free disk space is low on {#FSNAME}".to_string(),
+    ///     expression: "last(/Zabbix server/vfs.fs.size[{#FSNAME},pfree])<10".to_string(),
+    ///     priority: 3,
+    ///     recovery_mode: None,
+    ///     recovery_expression: None,
+    ///     url: None,
+    ///     event_name: None,
+    ///     dependencies: vec![],
+    ///     tags: vec![],
+    /// };
+    ///
+    /// let request = CreateTriggerPrototypeRequest::new(trigger);
+    ///
+    /// match client.create_trigger_prototype(&session, &request) {
+    ///     Ok(trigger_id) => println!("Successfully created trigger prototype with ID: {}", trigger_id),
+    ///     Err(e) => eprintln!("Error creating trigger prototype: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn create_trigger_prototype(
+        &self,
+        session: &str,
+        request: &CreateTriggerPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_trigger_prototype
+    ///
+    /// Updates a trigger prototype.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::triggerprototype::update::UpdateTriggerPrototypeRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real trigger prototype ID
+    /// let request = UpdateTriggerPrototypeRequest::builder("13521")
+    ///     .priority(4)
+    ///     .disable()
+    ///     .build();
+    ///
+    /// match client.update_trigger_prototype(&session, &request) {
+    ///     Ok(trigger_id) => println!("Successfully updated trigger prototype with ID: {}", trigger_id),
+    ///     Err(e) => eprintln!("Error updating trigger prototype: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn update_trigger_prototype(
+        &self,
+        session: &str,
+        request: &UpdateTriggerPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_trigger_prototypes
+    ///
+    /// Deletes trigger prototypes with the triggers discovered from them.
+    /// Returns IDs of the deleted prototypes.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let prototype_ids = vec!["13521".to_string()];
+    ///
+    /// match client.delete_trigger_prototypes(&session, &prototype_ids) {
+    ///     Ok(ids) => println!("Successfully deleted trigger prototypes with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting trigger prototypes: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn delete_trigger_prototypes(
+        &self,
+        session: &str,
+        prototype_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_graph_prototypes
+    ///
+    /// Retrieves graph prototypes based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphprototype/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::graphprototype::get::GetGraphPrototypesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real discovery rule ID
+    /// let params = GetGraphPrototypesRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     discovery_ids: Some(vec!["27425".to_string()]),
+    ///     select_graph_items: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_graph_prototypes(&session, &params) {
+    ///     Ok(prototypes) => println!("Found graph prototypes: {:?}", prototypes),
+    ///     Err(e) => eprintln!("Error getting graph prototypes: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn get_graph_prototypes<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixGraphPrototype>, ZabbixApiError>;
+
+    /// # create_graph_prototype
+    ///
+    /// Creates a graph prototype.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphprototype/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::graphprototype::create::CreateGraphPrototypeRequest;
+    /// use zabbix_api::graphprototype::model::GraphItem;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real item prototype IDs
+    /// let request = CreateGraphPrototypeRequest::builder("Disk space usage {#FSNAME}")
+    ///     .item(GraphItem::new("27428", "00AA00"))
+    ///     .item(GraphItem::new("27429", "AA0000"))
+    ///     .build();
+    ///
+    /// match client.create_graph_prototype(&session, &request) {
+    ///     Ok(graph_id) => println!("Successfully created graph prototype with ID: {}", graph_id),
+    ///     Err(e) => eprintln!("Error creating graph prototype: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn create_graph_prototype(
+        &self,
+        session: &str,
+        request: &CreateGraphPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_graph_prototype
+    ///
+    /// Updates a graph prototype.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphprototype/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::graphprototype::update::UpdateGraphPrototypeRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real graph prototype ID
+    /// let request = UpdateGraphPrototypeRequest::builder("3287")
+    ///     .size(1200, 300)
+    ///     .build();
+    ///
+    /// match client.update_graph_prototype(&session, &request) {
+    ///     Ok(graph_id) => println!("Successfully updated graph prototype with ID: {}", graph_id),
+    ///     Err(e) => eprintln!("Error updating graph prototype: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn update_graph_prototype(
+        &self,
+        session: &str,
+        request: &UpdateGraphPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_graph_prototypes
+    ///
+    /// Deletes graph prototypes with the graphs discovered from them.
+    /// Returns IDs of the deleted prototypes.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphprototype/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let prototype_ids = vec!["3287".to_string()];
+    ///
+    /// match client.delete_graph_prototypes(&session, &prototype_ids) {
+    ///     Ok(ids) => println!("Successfully deleted graph prototypes with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting graph prototypes: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn delete_graph_prototypes(
+        &self,
+        session: &str,
+        prototype_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_host_prototypes
+    ///
+    /// Retrieves host prototypes based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::hostprototype::get::GetHostPrototypesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real discovery rule ID
+    /// let params = GetHostPrototypesRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     discovery_ids: Some(vec!["27425".to_string()]),
+    ///     select_group_links: Some("extend".to_string()),
+    ///     select_group_prototypes: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_host_prototypes(&session, &params) {
+    ///     Ok(prototypes) => println!("Found host prototypes: {:?}", prototypes),
+    ///     Err(e) => eprintln!("Error getting host prototypes: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn get_host_prototypes<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixHostPrototype>, ZabbixApiError>;
+
+    /// # create_host_prototype
+    ///
+    /// Creates a host prototype on a low-level discovery rule of a host.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::hostprototype::create::CreateHostPrototypeRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real discovery rule and host group IDs
+    /// let request = CreateHostPrototypeRequest::builder("23542", "{#VM.NAME}")
+    ///     .name("VM {#VM.NAME}")
+    ///     .group("2")
+    ///     .group_prototype("Cluster {#CLUSTER.NAME}")
+    ///     .build();
+    ///
+    /// match client.create_host_prototype(&session, &request) {
+    ///     Ok(host_id) => println!("Successfully created host prototype with ID: {}", host_id),
+    ///     Err(e) => eprintln!("Error creating host prototype: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn create_host_prototype(
+        &self,
+        session: &str,
+        request: &CreateHostPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_host_prototype
+    ///
+    /// Updates a host prototype.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::hostprototype::update::UpdateHostPrototypeRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real host prototype ID
+    /// let request = UpdateHostPrototypeRequest::builder("10092")
+    ///     .discover(false)
+    ///     .build();
+    ///
+    /// match client.update_host_prototype(&session, &request) {
+    ///     Ok(host_id) => println!("Successfully updated host prototype with ID: {}", host_id),
+    ///     Err(e) => eprintln!("Error updating host prototype: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn update_host_prototype(
+        &self,
+        session: &str,
+        request: &UpdateHostPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_host_prototypes
+    ///
+    /// Deletes host prototypes with the hosts discovered from them.
+    /// Returns IDs of the deleted prototypes.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let prototype_ids = vec!["10092".to_string()];
+    ///
+    /// match client.delete_host_prototypes(&session, &prototype_ids) {
+    ///     Ok(ids) => println!("Successfully deleted host prototypes with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting host prototypes: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "discovery")]
+    fn delete_host_prototypes(
+        &self,
+        session: &str,
+        prototype_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # update_trigger
+    ///
+    /// Updates a Zabbix trigger.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trigger/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::trigger::update::UpdateTriggerRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real trigger IDs
+    /// let request = UpdateTriggerRequest::builder("14062")
+    ///     .priority(2)
+    ///     .depends_on("14063")
+    ///     .build();
+    ///
+    /// match client.update_trigger(&session, &request) {
+    ///     Ok(trigger_id) => println!("Successfully updated trigger with ID: {}", trigger_id),
+    ///     Err(e) => eprintln!("Error updating trigger: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "trigger")]
+    fn update_trigger(
+        &self,
+        session: &str,
+        request: &UpdateTriggerRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_triggers
+    ///
+    /// Deletes Zabbix triggers. Returns IDs of the deleted triggers.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trigger/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let trigger_ids = vec!["14062".to_string()];
+    ///
+    /// match client.delete_triggers(&session, &trigger_ids) {
+    ///     Ok(ids) => println!("Successfully deleted triggers with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting triggers: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "trigger")]
+    fn delete_triggers(
+        &self,
+        session: &str,
+        trigger_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # enable_triggers
+    ///
+    /// Enables triggers in one request. Returns IDs of the updated triggers.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trigger/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let trigger_ids = vec!["14062".to_string(), "14063".to_string()];
+    ///
+    /// match client.enable_triggers(&session, &trigger_ids) {
+    ///     Ok(ids) => println!("Successfully enabled triggers with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error enabling triggers: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "trigger")]
+    fn enable_triggers(
+        &self,
+        session: &str,
+        trigger_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # disable_triggers
+    ///
+    /// Disables triggers in one request. Returns IDs of the updated triggers.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trigger/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let trigger_ids = vec!["14062".to_string(), "14063".to_string()];
+    ///
+    /// match client.disable_triggers(&session, &trigger_ids) {
+    ///     Ok(ids) => println!("Successfully disabled triggers with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error disabling triggers: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "trigger")]
+    fn disable_triggers(
+        &self,
+        session: &str,
+        trigger_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # add_trigger_dependencies
+    ///
+    /// Adds dependencies to triggers. Returns IDs of the dependent triggers.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trigger/adddependencies
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::trigger::dependency::AddTriggerDependencyRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Trigger 14062 won't raise problems while trigger 14063 is in the problem state
+    /// let dependencies = vec![AddTriggerDependencyRequest::new("14062", "14063")];
+    ///
+    /// match client.add_trigger_dependencies(&session, &dependencies) {
+    ///     Ok(ids) => println!("Successfully added dependencies to triggers: {:?}", ids),
+    ///     Err(e) => eprintln!("Error adding trigger dependencies: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "trigger")]
+    fn add_trigger_dependencies(
+        &self,
+        session: &str,
+        dependencies: &[AddTriggerDependencyRequest],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # delete_trigger_dependencies
+    ///
+    /// Removes all dependencies of the given triggers. Returns IDs of the affected triggers.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trigger/deletedependencies
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let trigger_ids = vec!["14062".to_string()];
+    ///
+    /// match client.delete_trigger_dependencies(&session, &trigger_ids) {
+    ///     Ok(ids) => println!("Successfully removed dependencies of triggers: {:?}", ids),
+    ///     Err(e) => eprintln!("Error removing trigger dependencies: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "trigger")]
+    fn delete_trigger_dependencies(
+        &self,
+        session: &str,
+        trigger_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_problems
+    ///
+    /// Retrieves problems based on the provided parameters.
+    /// Only unresolved problems are returned unless `recent` is set.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/problem/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::event::model::{EventSeverity, EventTagFilter};
+    /// use zabbix_api::problem::get::GetProblemsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let params = GetProblemsRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     severities: Some(vec![EventSeverity::High, EventSeverity::Disaster]),
+    ///     acknowledged: Some(false),
+    ///     tags: Some(vec![EventTagFilter::equals("service", "mysql")]),
+    ///     select_tags: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_problems(&session, &params) {
+    ///     Ok(problems) => println!("Found problems: {:?}", problems),
+    ///     Err(e) => eprintln!("Error getting problems: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "event")]
+    fn get_problems<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixProblem>, ZabbixApiError>;
+
+    /// # get_events
+    ///
+    /// Retrieves events based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/event/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use std::time::{Duration, SystemTime};
+    /// use zabbix_api::event::get::GetEventsRequest;
+    /// use zabbix_api::event::model::EventSource;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let params = GetEventsRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     source: Some(EventSource::Trigger),
+    ///     time_from: Some(SystemTime::now() - Duration::from_secs(3600)),
+    ///     select_acknowledges: Some("extend".to_string()),
+    ///     sort_field: Some(vec!["clock".to_string()]),
+    ///     sort_order: Some("DESC".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_events(&session, &params) {
+    ///     Ok(events) => println!("Found events: {:?}", events),
+    ///     Err(e) => eprintln!("Error getting events: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "event")]
+    fn get_events<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixEvent>, ZabbixApiError>;
+
+    /// # acknowledge_events
+    ///
+    /// Updates problem events: closes, acknowledges, adds a message, changes severity,
+    /// suppresses and so on. Returns IDs of the updated events.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/event/acknowledge
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use std::time::{Duration, SystemTime};
+    /// use zabbix_api::event::acknowledge::AcknowledgeEventsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real problem event IDs
+    /// let request = AcknowledgeEventsRequest::builder(vec!["20427".to_string()])
+    ///     .acknowledge()
+    ///     .message("Investigating")
+    ///     .suppress(Some(SystemTime::now() + Duration::from_secs(3600)))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// match client.acknowledge_events(&session, &request) {
+    ///     Ok(ids) => println!("Updated events: {:?}", ids),
+    ///     Err(e) => eprintln!("Error acknowledging events: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "event")]
+    fn acknowledge_events(
+        &self,
+        session: &str,
+        request: &AcknowledgeEventsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_history
+    ///
+    /// Retrieves history values. The value type of the request decides the record type,
+    /// e.g. `GetHistoryRequest::<FloatHistory>` returns `f64` values.
+    ///
+    /// For long ranges see `HistoryWindows`, which fetches history window by window.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/history/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use std::time::{Duration, SystemTime};
+    /// use zabbix_api::history::get::GetHistoryRequest;
+    /// use zabbix_api::history::model::FloatHistory;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real item ID
+    /// let request = GetHistoryRequest::<FloatHistory> {
+    ///     output: Some("extend".to_string()),
+    ///     item_ids: Some(vec!["23296".to_string()]),
+    ///     time_from: Some(SystemTime::now() - Duration::from_secs(3600)),
+    ///     sort_field: Some("clock".to_string()),
+    ///     sort_order: Some("DESC".to_string()),
+    ///     limit: Some(10),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_history(&session, &request) {
+    ///     Ok(records) => println!("Found history records: {:?}", records),
+    ///     Err(e) => eprintln!("Error getting history: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "history")]
+    fn get_history<V: HistoryValueType>(
+        &self,
+        session: &str,
+        request: &GetHistoryRequest<V>,
+    ) -> Result<Vec<V::Record>, ZabbixApiError>;
+
+    /// # push_history
+    ///
+    /// Sends values to trapper and HTTP agent items. Returns the result for each value
+    /// in the order of the given values. Available since Zabbix 7.0.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/history/push
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use std::time::SystemTime;
+    /// use zabbix_api::history::push::HistoryPushValue;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real trapper item ID, host and key
+    /// let values = vec![
+    ///     HistoryPushValue::for_item("10600", 0.5).at(SystemTime::now()),
+    ///     HistoryPushValue::for_host_key("Linux server", "backup.status", "ok"),
+    /// ];
+    ///
+    /// match client.push_history(&session, &values) {
+    ///     Ok(results) => {
+    ///         for result in results.iter().filter(|result| !result.is_success()) {
+    ///             eprintln!("Value rejected: {:?}", result.error);
+    ///         }
+    ///     }
+    ///     Err(e) => eprintln!("Error pushing history: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "history", feature = "v7"))]
+    fn push_history(
+        &self,
+        session: &str,
+        values: &[HistoryPushValue],
+    ) -> Result<Vec<HistoryPushResult>, ZabbixApiError>;
+
+    /// # get_trends
+    ///
+    /// Retrieves hourly trends of numeric items. Use `f64` for float items and `u64`
+    /// for unsigned items.
+    ///
+    /// Trends can be merged into days or weeks with `downsample_trends`.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trend/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use std::time::{Duration, SystemTime};
+    /// use zabbix_api::trend::downsample::{downsample_trends, TrendBucket};
+    /// use zabbix_api::trend::get::GetTrendsRequest;
+    /// use zabbix_api::trend::model::ZabbixTrend;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real float item ID
+    /// let request = GetTrendsRequest {
+    ///     output: Some("extend".to_string()),
+    ///     item_ids: Some(vec!["23715".to_string()]),
+    ///     time_from: Some(SystemTime::now() - Duration::from_secs(30 * 24 * 3600)),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_trends::<f64>(&session, &request) {
+    ///     Ok(trends) => {
+    ///         let daily: Vec<ZabbixTrend<f64>> = downsample_trends(&trends, TrendBucket::Day);
+    ///         println!("Daily trends: {:?}", daily)
+    ///     }
+    ///     Err(e) => eprintln!("Error getting trends: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "trend")]
+    fn get_trends<T: TrendValue>(
+        &self,
+        session: &str,
+        request: &GetTrendsRequest,
+    ) -> Result<Vec<ZabbixTrend<T>>, ZabbixApiError>;
+
+    /// # get_maintenances
+    ///
+    /// Retrieves maintenances based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/maintenance/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::maintenance::get::GetMaintenancesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real host ID
+    /// let params = GetMaintenancesRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     host_ids: Some(vec!["10084".to_string()]),
+    ///     select_time_periods: Some("extend".to_string()),
+    ///     select_tags: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_maintenances(&session, &params) {
+    ///     Ok(maintenances) => println!("Found maintenances: {:?}", maintenances),
+    ///     Err(e) => eprintln!("Error getting maintenances: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "maintenance")]
+    fn get_maintenances<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixMaintenance>, ZabbixApiError>;
+
+    /// # create_maintenance
+    ///
+    /// Creates a maintenance.
+    ///
+    /// `CreateMaintenanceRequest::for_hosts` covers the common case of putting hosts into
+    /// maintenance for some time, starting now.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/maintenance/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use std::time::Duration;
+    /// use zabbix_api::maintenance::create::CreateMaintenanceRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real host IDs
+    /// let host_ids = vec!["10084".to_string()];
+    ///
+    /// let request =
+    ///     CreateMaintenanceRequest::for_hosts("Deploy", &host_ids, Duration::from_secs(30 * 60))
+    ///         .unwrap();
+    ///
+    /// match client.create_maintenance(&session, &request) {
+    ///     Ok(maintenance_id) => println!("Successfully created maintenance with ID: {}", maintenance_id),
+    ///     Err(e) => eprintln!("Error creating maintenance: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "maintenance")]
+    fn create_maintenance(
+        &self,
+        session: &str,
+        request: &CreateMaintenanceRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_maintenance
+    ///
+    /// Updates a maintenance. Hosts, groups, time periods and tags replace the existing ones.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/maintenance/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use std::time::Duration;
+    /// use zabbix_api::maintenance::model::{Month, TimePeriod};
+    /// use zabbix_api::maintenance::update::UpdateMaintenanceRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real maintenance ID
+    /// let request = UpdateMaintenanceRequest::builder("3")
+    ///     .time_periods(vec![TimePeriod::MonthlyByDay {
+    ///         months: Month::January | Month::July,
+    ///         day: 1,
+    ///         start_time: Duration::from_secs(2 * 3600),
+    ///         period: Duration::from_secs(3600),
+    ///     }])
+    ///     .build();
+    ///
+    /// match client.update_maintenance(&session, &request) {
+    ///     Ok(maintenance_id) => println!("Successfully updated maintenance with ID: {}", maintenance_id),
+    ///     Err(e) => eprintln!("Error updating maintenance: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "maintenance")]
+    fn update_maintenance(
+        &self,
+        session: &str,
+        request: &UpdateMaintenanceRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_maintenances
+    ///
+    /// Deletes maintenances. Returns IDs of the deleted maintenances.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/maintenance/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let maintenance_ids = vec!["3".to_string()];
+    ///
+    /// match client.delete_maintenances(&session, &maintenance_ids) {
+    ///     Ok(ids) => println!("Successfully deleted maintenances with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting maintenances: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "maintenance")]
+    fn delete_maintenances(
+        &self,
+        session: &str,
+        maintenance_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_actions
+    ///
+    /// Retrieves actions based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::action::get::GetActionsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let params = GetActionsRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     select_filter: Some("extend".to_string()),
+    ///     select_operations: Some("extend".to_string()),
+    ///     select_recovery_operations: Some("extend".to_string()),
+    ///     select_update_operations: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_actions(&session, &params) {
+    ///     Ok(actions) => println!("Found actions: {:?}", actions),
+    ///     Err(e) => eprintln!("Error getting actions: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "action")]
+    fn get_actions<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixAction>, ZabbixApiError>;
+
+    /// # create_action
+    ///
+    /// Creates an action.
+    ///
+    /// An existing action retrieved with its filter and operations can be copied with
+    /// `ZabbixAction::copy_as`.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::action::create::CreateActionRequest;
+    /// use zabbix_api::action::model::{
+    ///     ActionCondition, ActionConditionOperator, ActionFilter, ActionMessage, ActionOperation,
+    /// };
+    /// use zabbix_api::event::model::{EventSeverity, EventSource};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real host group and user group IDs
+    /// let request = CreateActionRequest::builder("Notify on-call", EventSource::Trigger)
+    ///     .esc_period("15m")
+    ///     .filter(ActionFilter::and_or(vec![
+    ///         ActionCondition::host_group(ActionConditionOperator::Equals, "2"),
+    ///         ActionCondition::trigger_severity(
+    ///             ActionConditionOperator::GreaterOrEqual,
+    ///             EventSeverity::High,
+    ///         ),
+    ///     ]))
+    ///     .operation(
+    ///         ActionOperation::send_message(ActionMessage::default_message())
+    ///             .to_user_group("7")
+    ///             .steps(1, 0),
+    ///     )
+    ///     .recovery_operation(ActionOperation::notify_all_involved_on_recovery(
+    ///         ActionMessage::default_message(),
+    ///     ))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// match client.create_action(&session, &request) {
+    ///     Ok(action_id) => println!("Successfully created action with ID: {}", action_id),
+    ///     Err(e) => eprintln!("Error creating action: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "action")]
+    fn create_action(
+        &self,
+        session: &str,
+        request: &CreateActionRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_action
+    ///
+    /// Updates an action. Filter and operations replace the existing ones when set.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::action::update::UpdateActionRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real action ID
+    /// let request = UpdateActionRequest::builder("17").disable().build().unwrap();
+    ///
+    /// match client.update_action(&session, &request) {
+    ///     Ok(action_id) => println!("Successfully updated action with ID: {}", action_id),
+    ///     Err(e) => eprintln!("Error updating action: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "action")]
+    fn update_action(
+        &self,
+        session: &str,
+        request: &UpdateActionRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_actions
+    ///
+    /// Deletes actions. Returns IDs of the deleted actions.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/action/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let action_ids = vec!["17".to_string()];
+    ///
+    /// match client.delete_actions(&session, &action_ids) {
+    ///     Ok(ids) => println!("Successfully deleted actions with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting actions: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "action")]
+    fn delete_actions(
+        &self,
+        session: &str,
+        action_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_media_types
+    ///
+    /// Retrieves media types based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/mediatype/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::mediatype::get::GetMediaTypesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let params = GetMediaTypesRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     select_message_templates: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_media_types(&session, &params) {
+    ///     Ok(media_types) => println!("Found media types: {:?}", media_types),
+    ///     Err(e) => eprintln!("Error getting media types: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "mediatype")]
+    fn get_media_types<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixMediaType>, ZabbixApiError>;
+
+    /// # create_media_type
+    ///
+    /// Creates a media type.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/mediatype/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::mediatype::create::CreateMediaTypeRequest;
+    /// use zabbix_api::mediatype::model::{MediaTypeDetails, WebhookSettings};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let webhook = WebhookSettings::new("return JSON.parse(value).to;")
+    ///     .parameter("to", "{ALERT.SENDTO}")
+    ///     .parameter("message", "{ALERT.MESSAGE}")
+    ///     .timeout("10s");
+    ///
+    /// let request = CreateMediaTypeRequest::builder("Ops webhook", MediaTypeDetails::Webhook(webhook))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// match client.create_media_type(&session, &request) {
+    ///     Ok(media_type_id) => println!("Successfully created media type with ID: {}", media_type_id),
+    ///     Err(e) => eprintln!("Error creating media type: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "mediatype")]
+    fn create_media_type(
+        &self,
+        session: &str,
+        request: &CreateMediaTypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_media_type
+    ///
+    /// Updates a media type.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/mediatype/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::mediatype::model::MediaTypeStatus;
+    /// use zabbix_api::mediatype::update::UpdateMediaTypeRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real media type ID
+    /// let request = UpdateMediaTypeRequest::builder("33")
+    ///     .status(MediaTypeStatus::Disabled)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// match client.update_media_type(&session, &request) {
+    ///     Ok(media_type_id) => println!("Successfully updated media type with ID: {}", media_type_id),
+    ///     Err(e) => eprintln!("Error updating media type: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "mediatype")]
+    fn update_media_type(
+        &self,
+        session: &str,
+        request: &UpdateMediaTypeRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_media_types
+    ///
+    /// Deletes media types. Returns IDs of the deleted media types.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/mediatype/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let media_type_ids = vec!["33".to_string()];
+    ///
+    /// match client.delete_media_types(&session, &media_type_ids) {
+    ///     Ok(ids) => println!("Successfully deleted media types with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting media types: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "mediatype")]
+    fn delete_media_types(
+        &self,
+        session: &str,
+        media_type_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_alerts
+    ///
+    /// Retrieves alerts, i.e. messages and remote commands sent by actions.
+    ///
+    /// `GetAlertsRequest::failed_messages` returns failed notifications of a time
+    /// range.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/alert/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use zabbix_api::alert::get::GetAlertsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let now = SystemTime::now();
+    /// let params = GetAlertsRequest::failed_messages(now - Duration::from_secs(86400), now);
+    ///
+    /// match client.get_alerts(&session, &params) {
+    ///     Ok(alerts) => {
+    ///         for alert in alerts {
+    ///             println!("{:?} to {:?}: {:?}", alert.subject, alert.send_to, alert.error);
+    ///         }
+    ///     }
+    ///     Err(e) => eprintln!("Error getting alerts: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "alert")]
+    fn get_alerts<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixAlert>, ZabbixApiError>;
+
+    /// # get_scripts
+    ///
+    /// Retrieves scripts based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::script::get::GetScriptsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real host ID
+    /// let params = GetScriptsRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     host_ids: Some(vec!["10084".to_string()]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_scripts(&session, &params) {
+    ///     Ok(scripts) => println!("Found scripts: {:?}", scripts),
+    ///     Err(e) => eprintln!("Error getting scripts: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "script")]
+    fn get_scripts<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixScript>, ZabbixApiError>;
+
+    /// # create_script
+    ///
+    /// Creates a script.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::script::create::CreateScriptRequest;
+    /// use zabbix_api::script::model::{CustomScript, ScriptDetails, ScriptExecuteOn, ScriptScope};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = CreateScriptRequest::builder(
+    ///     "Disk usage",
+    ///     ScriptDetails::Script(CustomScript::new("df -h", ScriptExecuteOn::Agent)),
+    ///     ScriptScope::ManualHostAction,
+    /// )
+    /// .menu_path("Diagnostics")
+    /// .build()
+    /// .unwrap();
+    ///
+    /// match client.create_script(&session, &request) {
+    ///     Ok(script_id) => println!("Successfully created script with ID: {}", script_id),
+    ///     Err(e) => eprintln!("Error creating script: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "script")]
+    fn create_script(
+        &self,
+        session: &str,
+        request: &CreateScriptRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_script
+    ///
+    /// Updates a script.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::script::update::UpdateScriptRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real script ID
+    /// let request = UpdateScriptRequest::builder("4")
+    ///     .menu_path("Diagnostics/Disk")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// match client.update_script(&session, &request) {
+    ///     Ok(script_id) => println!("Successfully updated script with ID: {}", script_id),
+    ///     Err(e) => eprintln!("Error updating script: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "script")]
+    fn update_script(
+        &self,
+        session: &str,
+        request: &UpdateScriptRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_scripts
+    ///
+    /// Deletes scripts. Returns IDs of the deleted scripts.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let script_ids = vec!["4".to_string()];
+    ///
+    /// match client.delete_scripts(&session, &script_ids) {
+    ///     Ok(ids) => println!("Successfully deleted scripts with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting scripts: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "script")]
+    fn delete_scripts(
+        &self,
+        session: &str,
+        script_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # execute_script
+    ///
+    /// Runs a script on a host or on the host of an event and returns its output.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/execute
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::script::execute::ExecuteScriptRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real script and host IDs
+    /// let request = ExecuteScriptRequest::on_host("1", "10084");
+    ///
+    /// match client.execute_script(&session, &request) {
+    ///     Ok(execution) => println!("Script output: {}", execution.value),
+    ///     Err(e) => eprintln!("Error executing script: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "script")]
+    fn execute_script(
+        &self,
+        session: &str,
+        request: &ExecuteScriptRequest,
+    ) -> Result<ScriptExecution, ZabbixApiError>;
+
+    /// # get_scripts_by_hosts
+    ///
+    /// Returns scripts available on the hosts by host ID, with macros resolved.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/getscriptsbyhosts
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::script::get::GetScriptsByHostsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real host ID
+    /// let request = GetScriptsByHostsRequest::new(&["10084".to_string()]);
+    ///
+    /// match client.get_scripts_by_hosts(&session, &request) {
+    ///     Ok(scripts) => println!("Found scripts: {:?}", scripts),
+    ///     Err(e) => eprintln!("Error getting scripts: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "script")]
+    fn get_scripts_by_hosts(
+        &self,
+        session: &str,
+        request: &GetScriptsByHostsRequest,
+    ) -> Result<HashMap<String, Vec<ZabbixScript>>, ZabbixApiError>;
+
+    /// # get_scripts_by_events
+    ///
+    /// Returns scripts available for the events by event ID, with macros resolved.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/getscriptsbyevents
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::script::get::GetScriptsByEventsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real event ID
+    /// let request = GetScriptsByEventsRequest::new(&["1245463".to_string()]);
+    ///
+    /// match client.get_scripts_by_events(&session, &request) {
+    ///     Ok(scripts) => println!("Found scripts: {:?}", scripts),
+    ///     Err(e) => eprintln!("Error getting scripts: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "script")]
+    fn get_scripts_by_events(
+        &self,
+        session: &str,
+        request: &GetScriptsByEventsRequest,
+    ) -> Result<HashMap<String, Vec<ZabbixScript>>, ZabbixApiError>;
+
+    /// # get_proxies
+    ///
+    /// Retrieves proxies based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxy/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::proxy::get::GetProxiesRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let params = GetProxiesRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     select_hosts: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_proxies(&session, &params) {
+    ///     Ok(proxies) => println!("Found proxies: {:?}", proxies),
+    ///     Err(e) => eprintln!("Error getting proxies: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "proxy")]
+    fn get_proxies<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixProxy>, ZabbixApiError>;
+
+    /// # create_proxy
+    ///
+    /// Creates a proxy.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxy/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::proxy::create::CreateProxyRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = CreateProxyRequest::active("Proxy Riga")
+    ///     .allowed_addresses("10.0.0.5")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// match client.create_proxy(&session, &request) {
+    ///     Ok(proxy_id) => println!("Successfully created proxy with ID: {}", proxy_id),
+    ///     Err(e) => eprintln!("Error creating proxy: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "proxy")]
+    fn create_proxy(
+        &self,
+        session: &str,
+        request: &CreateProxyRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_proxy
+    ///
+    /// Updates a proxy. Hosts replace the hosts of the proxy when set.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxy/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::proxy::update::UpdateProxyRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real proxy ID
+    /// let request = UpdateProxyRequest::builder("10451")
+    ///     .passive("proxy.example.com", "10051")
+    ///     .build();
+    ///
+    /// match client.update_proxy(&session, &request) {
+    ///     Ok(proxy_id) => println!("Successfully updated proxy with ID: {}", proxy_id),
+    ///     Err(e) => eprintln!("Error updating proxy: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "proxy")]
+    fn update_proxy(
+        &self,
+        session: &str,
+        request: &UpdateProxyRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_proxies
+    ///
+    /// Deletes proxies. Returns IDs of the deleted proxies.
+    ///
+    /// Proxies still monitoring hosts cannot be deleted.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxy/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let proxy_ids = vec!["10451".to_string()];
+    ///
+    /// match client.delete_proxies(&session, &proxy_ids) {
+    ///     Ok(ids) => println!("Successfully deleted proxies with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting proxies: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "proxy")]
+    fn delete_proxies(
+        &self,
+        session: &str,
+        proxy_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_proxy_groups
+    ///
+    /// Retrieves proxy groups based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxygroup/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::proxygroup::get::GetProxyGroupsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let params = GetProxyGroupsRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     select_proxies: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_proxy_groups(&session, &params) {
+    ///     Ok(proxy_groups) => println!("Found proxy groups: {:?}", proxy_groups),
+    ///     Err(e) => eprintln!("Error getting proxy groups: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "proxy", feature = "v7"))]
+    fn get_proxy_groups<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixProxyGroup>, ZabbixApiError>;
+
+    /// # create_proxy_group
+    ///
+    /// Creates a proxy group.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxygroup/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::proxygroup::create::CreateProxyGroupRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let request = CreateProxyGroupRequest::builder("Riga")
+    ///     .failover_delay("1m")
+    ///     .min_online(2)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// match client.create_proxy_group(&session, &request) {
+    ///     Ok(proxy_group_id) => println!("Successfully created proxy group with ID: {}", proxy_group_id),
+    ///     Err(e) => eprintln!("Error creating proxy group: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "proxy", feature = "v7"))]
+    fn create_proxy_group(
+        &self,
+        session: &str,
+        request: &CreateProxyGroupRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_proxy_group
+    ///
+    /// Updates a proxy group.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxygroup/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::proxygroup::update::UpdateProxyGroupRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real proxy group ID
+    /// let request = UpdateProxyGroupRequest::builder("1").min_online(1).build();
+    ///
+    /// match client.update_proxy_group(&session, &request) {
+    ///     Ok(proxy_group_id) => println!("Successfully updated proxy group with ID: {}", proxy_group_id),
+    ///     Err(e) => eprintln!("Error updating proxy group: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "proxy", feature = "v7"))]
+    fn update_proxy_group(
+        &self,
+        session: &str,
+        request: &UpdateProxyGroupRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_proxy_groups
+    ///
+    /// Deletes proxy groups. Returns IDs of the deleted proxy groups.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxygroup/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let proxy_group_ids = vec!["1".to_string()];
+    ///
+    /// match client.delete_proxy_groups(&session, &proxy_group_ids) {
+    ///     Ok(ids) => println!("Successfully deleted proxy groups with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting proxy groups: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(all(feature = "proxy", feature = "v7"))]
+    fn delete_proxy_groups(
+        &self,
+        session: &str,
+        proxy_group_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+
+    /// # get_dashboards
+    ///
+    /// Retrieves dashboards based on the provided parameters.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/dashboard/get
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::dashboard::get::GetDashboardsRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let params = GetDashboardsRequest::<()> {
+    ///     output: Some("extend".to_string()),
+    ///     select_pages: Some("extend".to_string()),
+    ///     select_users: Some("extend".to_string()),
+    ///     select_user_groups: Some("extend".to_string()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// match client.get_dashboards(&session, &params) {
+    ///     Ok(dashboards) => println!("Found dashboards: {:?}", dashboards),
+    ///     Err(e) => eprintln!("Error getting dashboards: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "dashboard")]
+    fn get_dashboards<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixDashboard>, ZabbixApiError>;
+
+    /// # create_dashboard
+    ///
+    /// Creates a dashboard.
+    ///
+    /// `DashboardPage::flow` places widgets onto the grid in the given order.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/dashboard/create
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::dashboard::create::CreateDashboardRequest;
+    /// use zabbix_api::dashboard::model::{DashboardPage, DashboardPermission};
+    /// use zabbix_api::dashboard::widget::DashboardWidget;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with real host group, item and user group IDs
+    /// let page = DashboardPage::flow(
+    ///     "Overview",
+    ///     vec![
+    ///         DashboardWidget::problems()
+    ///             .name("Team A problems")
+    ///             .host_groups(&["2".to_string()]),
+    ///         DashboardWidget::item_value("42237").name("CPU load"),
+    ///         DashboardWidget::clock(),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// let request = CreateDashboardRequest::builder("Team A")
+    ///     .page(page)
+    ///     .share_with_user_group("7", DashboardPermission::ReadOnly)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// match client.create_dashboard(&session, &request) {
+    ///     Ok(dashboard_id) => println!("Successfully created dashboard with ID: {}", dashboard_id),
+    ///     Err(e) => eprintln!("Error creating dashboard: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "dashboard")]
+    fn create_dashboard(
+        &self,
+        session: &str,
+        request: &CreateDashboardRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # update_dashboard
+    ///
+    /// Updates a dashboard. Pages, users and user groups replace the existing ones when set.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/dashboard/update
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    /// use zabbix_api::dashboard::update::UpdateDashboardRequest;
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// // Replace with a real dashboard ID
+    /// let request = UpdateDashboardRequest::builder("5")
+    ///     .name("Team A (old)")
+    ///     .unshare()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// match client.update_dashboard(&session, &request) {
+    ///     Ok(dashboard_id) => println!("Successfully updated dashboard with ID: {}", dashboard_id),
+    ///     Err(e) => eprintln!("Error updating dashboard: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "dashboard")]
+    fn update_dashboard(
+        &self,
+        session: &str,
+        request: &UpdateDashboardRequest,
+    ) -> Result<u32, ZabbixApiError>;
+
+    /// # delete_dashboards
+    ///
+    /// Deletes dashboards. Returns IDs of the deleted dashboards.
+    ///
+    /// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/dashboard/delete
+    ///
+    /// **Example:**
+    ///
+    /// ```rust
+    /// use reqwest::blocking::Client;
+    /// use zabbix_api::client::client::{ZabbixApiClient, ZabbixApiClientImpl};
+    ///
+    /// let http_client = Client::new();
+    /// let url = std::env::var("ZABBIX_API_URL").expect("ZABBIX_API_URL not set");
+    /// let user = std::env::var("ZABBIX_API_USER").expect("ZABBIX_API_USER not set");
+    /// let password = std::env::var("ZABBIX_API_PASSWORD").expect("ZABBIX_API_PASSWORD not set");
+    ///
+    /// let client = ZabbixApiClientImpl::new(http_client, &url);
+    /// let session = client.get_auth_session(&user, &password).unwrap();
+    ///
+    /// let dashboard_ids = vec!["5".to_string()];
+    ///
+    /// match client.delete_dashboards(&session, &dashboard_ids) {
+    ///     Ok(ids) => println!("Successfully deleted dashboards with IDs: {:?}", ids),
+    ///     Err(e) => eprintln!("Error deleting dashboards: {:?}", e),
+    /// }
+    /// ```
+    #[cfg(feature = "dashboard")]
+    fn delete_dashboards(
+        &self,
+        session: &str,
+        dashboard_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct ZabbixApiClientImpl {
+    client: Client,
+    api_endpoint_url: String,
+}
+
+impl ZabbixApiClientImpl {
+    pub fn new(client: Client, api_endpoint_url: &str) -> ZabbixApiClientImpl {
+        ZabbixApiClientImpl {
+            client,
+            api_endpoint_url: api_endpoint_url.to_string(),
+        }
+    }
+}
+
+impl ZabbixApiClient for ZabbixApiClientImpl {
+    fn get_api_info(&self) -> Result<String, ZabbixApiError> {
+        let params = HashMap::<String, String>::new();
+
+        let api_request = get_api_request("apiinfo.version", params, None);
+
+        match send_post_request(&self.client, &self.api_endpoint_url, None, api_request) {
+            Ok(response_body) => {
+                let response = serde_json::from_str::<ZabbixApiResponse<String>>(&response_body)?;
+
+                match response.result {
+                    Some(api_version) => {
+                        info!("zabbix api version: '{api_version}'");
+                        Ok(api_version)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    fn get_auth_session(&self, login: &str, token: &str) -> Result<String, ZabbixApiError> {
+        info!("getting auth session for user '{login}'..");
+
+        let params = HashMap::from([
+            ("username".to_string(), login.to_string()),
+            ("password".to_string(), token.to_string()),
+        ]);
+
+        let api_request = get_api_request("user.login", params, None);
+
+        match send_post_request(&self.client, &self.api_endpoint_url, None, api_request) {
+            Ok(response_body) => {
+                let response = serde_json::from_str::<ZabbixApiResponse<String>>(&response_body)?;
+
+                match response.result {
+                    Some(session) => {
+                        info!("auth ok");
+                        Ok(session)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    fn raw_api_call<P: Serialize, R: DeserializeOwned>(
+        &self,
+        session: &str,
+        method: &str,
+        params: &P,
+    ) -> Result<ZabbixApiResponse<R>, ZabbixApiError> {
+        info!("calling api method '{method}'..");
+
+        let api_request = get_api_request(method, params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<R>>(&response_body)?;
+
+                match response.result {
+                    Some(_) => {
+                        info!("api method '{method}' has been successfully called");
+                        Ok(response)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_host_groups
+    ///
+    /// Implements `ZabbixApiClient::get_host_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn get_host_groups<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixHostGroup>, ZabbixApiError> {
+        info!("getting host groups with params");
+
+        let api_request = get_api_request("hostgroup.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixHostGroup>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("host groups found: {:?}", results);
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_hosts
+    ///
+    /// Implements `ZabbixApiClient::get_hosts`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn get_hosts<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixHost>, ZabbixApiError> {
+        info!("getting hosts with params");
+
+        let api_request = get_api_request("host.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixHost>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("hosts found: {:?}", results);
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_items
+    ///
+    /// Implements `ZabbixApiClient::get_items`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "item")]
+    fn get_items<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixItem>, ZabbixApiError> {
+        info!("getting items with params");
+
+        let api_request = get_api_request("item.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixItem>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("hosts found: {:?}", results);
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_triggers
+    ///
+    /// Implements `ZabbixApiClient::get_triggers`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "trigger")]
+    fn get_triggers<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixTrigger>, ZabbixApiError> {
+        info!("getting triggers..");
+
+        let api_request = get_api_request("trigger.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixTrigger>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("hosts found: {:?}", results);
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_webscenarios
+    ///
+    /// Implements `ZabbixApiClient::get_webscenarios`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "webscenario")]
+    fn get_webscenarios<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixWebScenario>, ZabbixApiError> {
+        info!("getting web-scenarios..");
+
+        let api_request = get_api_request("httptest.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixWebScenario>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("hosts found: {:?}", results);
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_host_group
+    ///
+    /// Implements `ZabbixApiClient::create_host_group`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn create_host_group(
+        &self,
+        session: &str,
+        request: &CreateHostGroupRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        use crate::hostgroup::create::CreateHostGroupResponse;
+
+        info!("creating host group '{}'..", request.name);
+
+        let api_request = get_api_request("hostgroup.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateHostGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("host group '{}' has been created", request.name);
+
+                        match result.group_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_host
+    ///
+    /// Implements `ZabbixApiClient::create_host`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn create_host(
+        &self,
+        session: &str,
+        request: &CreateHostRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating host '{}'..", request.host);
+
+        let api_request = get_api_request("host.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<CreateHostResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("host '{}' has been created", request.host);
+
+                        match result.host_ids.first() {
+                            Some(host_id) => {
+                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
+                            }
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_host
+    ///
+    /// Implements `ZabbixApiClient::update_host`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn update_host(
+        &self,
+        session: &str,
+        request: &UpdateHostRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating host '{:?}'..", &serde_json::to_string(request));
+
+        let api_request = get_api_request("host.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<UpdateHostResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("host '{}' has been updated", request.hostid);
+
+                        match result.host_ids.first() {
+                            Some(host_id) => {
+                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
+                            }
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_host
+    ///
+    /// Implements `ZabbixApiClient::delete_host`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn delete_hosts(
+        &self,
+        session: &str,
+        host_ids: &Vec<String>,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting hosts '{:?}'..", &serde_json::to_string(host_ids));
+
+        let api_request = get_api_request("host.delete", host_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<UpdateHostResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("hosts '{:?}' have been deleted", result.host_ids);
+
+                        Ok(result.host_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_item
+    ///
+    /// Implements `ZabbixApiClient::create_item`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "item")]
+    fn create_item(
+        &self,
+        session: &str,
+        request: &CreateItemRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!(
+            "creating item with key '{}' for host id {}..",
+            request.key_, request.host_id
+        );
+
+        let api_request = get_api_request("item.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<CreateItemResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("item '{}' has been created", request.key_);
+
+                        match result.item_ids.first() {
+                            Some(host_id) => {
+                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
+                            }
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_trigger
+    ///
+    /// Implements `ZabbixApiClient::create_trigger`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "trigger")]
+    fn create_trigger(
+        &self,
+        session: &str,
+        request: &CreateTriggerRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!(
+            "creating trigger '{}' with expression '{}'..",
+            request.description, request.expression
+        );
+
+        let api_request = get_api_request("trigger.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateTriggerResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("trigger '{}' has been created", request.description);
+
+                        match result.trigger_ids.first() {
+                            Some(host_id) => {
+                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
+                            }
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_webscenario
+    ///
+    /// Implements `ZabbixApiClient::create_webscenario`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "webscenario")]
+    fn create_webscenario(
+        &self,
+        session: &str,
+        request: &CreateWebScenarioRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!(
+            "creating web-scenario '{}' for host id '{}'..",
+            request.name, request.host_id
+        );
+
+        let api_request = get_api_request("httptest.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateWebScenarioResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("web-scenario '{}' has been created", request.name);
+
+                        match result.http_test_ids.first() {
+                            Some(host_id) => {
+                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
+                            }
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    #[cfg(feature = "user")]
+    fn get_users<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixUser>, ZabbixApiError> {
+        info!("getting users..");
+
+        let api_request = get_api_request("user.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(&session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixUser>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("users found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_user_group
+    ///
+    /// Implements `ZabbixApiClient::create_user_group`.
+    ///
+    /// API: https://www.zabbix.com/documentation/current/en/manual/api/reference/usergroup/create
+    #[cfg(feature = "user")]
+    fn create_user_group(
+        &self,
+        session: &str,
+        request: &CreateUserGroupRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating user group '{}'..", request.name);
+
+        let api_request = get_api_request("usergroup.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateUserGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("user group '{}' has been created", request.name);
+
+                        match result.user_group_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    #[cfg(feature = "user")]
+    fn get_user_groups<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixUserGroup>, ZabbixApiError> {
+        info!("getting user groups..");
+
+        let api_request = get_api_request("usergroup.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixUserGroup>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("user groups found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    #[cfg(feature = "user")]
+    fn create_user(
+        &self,
+        session: &str,
+        request: &CreateUserRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating user '{}'..", request.username);
+
+        let params = [request];
+        let api_request = get_api_request("user.create", &params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<CreateUserResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("user '{}' has been created", request.username);
+
+                        match result.user_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_host_group
+    ///
+    /// Implements `ZabbixApiClient::update_host_group`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn update_host_group(
+        &self,
+        session: &str,
+        request: &UpdateHostGroupRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating host group '{}'..", request.group_id);
+
+        let api_request = get_api_request("hostgroup.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateHostGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("host group '{}' has been updated", request.group_id);
+
+                        match result.group_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_host_groups
+    ///
+    /// Implements `ZabbixApiClient::delete_host_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn delete_host_groups(
+        &self,
+        session: &str,
+        group_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting host groups {:?}..", group_ids);
+
+        let api_request = get_api_request("hostgroup.delete", group_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateHostGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("host groups {:?} have been deleted", result.group_ids);
+
+                        Ok(result.group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # mass_add_host_groups
+    ///
+    /// Implements `ZabbixApiClient::mass_add_host_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn mass_add_host_groups(
+        &self,
+        session: &str,
+        request: &MassAddHostGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("adding hosts to host groups..");
+
+        let api_request = get_api_request("hostgroup.massadd", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateHostGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "hosts have been added to host groups {:?}",
+                            result.group_ids
+                        );
+
+                        Ok(result.group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # mass_remove_host_groups
+    ///
+    /// Implements `ZabbixApiClient::mass_remove_host_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn mass_remove_host_groups(
+        &self,
+        session: &str,
+        request: &MassRemoveHostGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("removing hosts from host groups {:?}..", request.group_ids);
+
+        let api_request =
+            get_api_request("hostgroup.massremove", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateHostGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "hosts have been removed from host groups {:?}",
+                            result.group_ids
+                        );
+
+                        Ok(result.group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # mass_update_host_groups
+    ///
+    /// Implements `ZabbixApiClient::mass_update_host_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "host")]
+    fn mass_update_host_groups(
+        &self,
+        session: &str,
+        request: &MassUpdateHostGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("replacing hosts of host groups..");
+
+        let api_request =
+            get_api_request("hostgroup.massupdate", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateHostGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("host groups {:?} have been updated", result.group_ids);
+
+                        Ok(result.group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # propagate_host_groups
+    ///
+    /// Implements `ZabbixApiClient::propagate_host_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "host", feature = "v7"))]
+    fn propagate_host_groups(
+        &self,
+        session: &str,
+        request: &PropagateHostGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("propagating host group permissions..");
+
+        let api_request =
+            get_api_request("hostgroup.propagate", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateHostGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "permissions have been propagated for host groups {:?}",
+                            result.group_ids
+                        );
+
+                        Ok(result.group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_template_groups
+    ///
+    /// Implements `ZabbixApiClient::get_template_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn get_template_groups<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixTemplateGroup>, ZabbixApiError> {
+        info!("getting template groups..");
+
+        let api_request = get_api_request("templategroup.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixTemplateGroup>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("template groups found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_template_group
+    ///
+    /// Implements `ZabbixApiClient::create_template_group`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn create_template_group(
+        &self,
+        session: &str,
+        request: &CreateTemplateGroupRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating template group '{}'..", request.name);
+
+        let api_request =
+            get_api_request("templategroup.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<CreateTemplateGroupResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("template group '{}' has been created", request.name);
+
+                        match result.group_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_template_group
+    ///
+    /// Implements `ZabbixApiClient::update_template_group`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn update_template_group(
+        &self,
+        session: &str,
+        request: &UpdateTemplateGroupRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating template group '{}'..", request.group_id);
+
+        let api_request =
+            get_api_request("templategroup.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateTemplateGroupResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("template group '{}' has been updated", request.group_id);
+
+                        match result.group_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_template_groups
+    ///
+    /// Implements `ZabbixApiClient::delete_template_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn delete_template_groups(
+        &self,
+        session: &str,
+        group_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting template groups {:?}..", group_ids);
+
+        let api_request =
+            get_api_request("templategroup.delete", group_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateTemplateGroupResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("template groups {:?} have been deleted", result.group_ids);
+
+                        Ok(result.group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # mass_add_template_groups
+    ///
+    /// Implements `ZabbixApiClient::mass_add_template_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn mass_add_template_groups(
+        &self,
+        session: &str,
+        request: &MassAddTemplateGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("adding templates to template groups..");
+
+        let api_request =
+            get_api_request("templategroup.massadd", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateTemplateGroupResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "templates have been added to template groups {:?}",
+                            result.group_ids
+                        );
+
+                        Ok(result.group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # mass_remove_template_groups
+    ///
+    /// Implements `ZabbixApiClient::mass_remove_template_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn mass_remove_template_groups(
+        &self,
+        session: &str,
+        request: &MassRemoveTemplateGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!(
+            "removing templates from template groups {:?}..",
+            request.group_ids
+        );
+
+        let api_request = get_api_request(
+            "templategroup.massremove",
+            request,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateTemplateGroupResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "templates have been removed from template groups {:?}",
+                            result.group_ids
+                        );
+
+                        Ok(result.group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # propagate_template_groups
+    ///
+    /// Implements `ZabbixApiClient::propagate_template_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "template", feature = "v7"))]
+    fn propagate_template_groups(
+        &self,
+        session: &str,
+        request: &PropagateTemplateGroupsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("propagating template group permissions..");
+
+        let api_request = get_api_request(
+            "templategroup.propagate",
+            request,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateTemplateGroupResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "permissions have been propagated for template groups {:?}",
+                            result.group_ids
+                        );
+
+                        Ok(result.group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_templates
+    ///
+    /// Implements `ZabbixApiClient::get_templates`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "template")]
+    fn get_templates<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixTemplate>, ZabbixApiError> {
+        info!("getting templates..");
+
+        let api_request = get_api_request("template.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixTemplate>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("templates found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_template
+    ///
+    /// Implements `ZabbixApiClient::create_template`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "template")]
+    fn create_template(
+        &self,
+        session: &str,
+        request: &CreateTemplateRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating template '{}'..", request.host);
+
+        let api_request = get_api_request("template.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateTemplateResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("template '{}' has been created", request.host);
+
+                        match result.template_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_template
+    ///
+    /// Implements `ZabbixApiClient::update_template`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "template")]
+    fn update_template(
+        &self,
+        session: &str,
+        request: &UpdateTemplateRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating template '{}'..", request.template_id);
+
+        let api_request = get_api_request("template.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTemplateResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("template '{}' has been updated", request.template_id);
+
+                        match result.template_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_templates
+    ///
+    /// Implements `ZabbixApiClient::delete_templates`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "template")]
+    fn delete_templates(
+        &self,
+        session: &str,
+        template_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting templates {:?}..", template_ids);
+
+        let api_request =
+            get_api_request("template.delete", template_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTemplateResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("templates {:?} have been deleted", result.template_ids);
+
+                        Ok(result.template_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # mass_add_templates
+    ///
+    /// Implements `ZabbixApiClient::mass_add_templates`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "template")]
+    fn mass_add_templates(
+        &self,
+        session: &str,
+        request: &MassAddTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("adding objects to templates..");
+
+        let api_request = get_api_request("template.massadd", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTemplateResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "objects have been added to templates {:?}",
+                            result.template_ids
+                        );
+
+                        Ok(result.template_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # mass_remove_templates
+    ///
+    /// Implements `ZabbixApiClient::mass_remove_templates`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "template")]
+    fn mass_remove_templates(
+        &self,
+        session: &str,
+        request: &MassRemoveTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!(
+            "removing objects from templates {:?}..",
+            request.template_ids
+        );
+
+        let api_request =
+            get_api_request("template.massremove", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTemplateResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "objects have been removed from templates {:?}",
+                            result.template_ids
+                        );
+
+                        Ok(result.template_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # mass_update_templates
+    ///
+    /// Implements `ZabbixApiClient::mass_update_templates`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "template")]
+    fn mass_update_templates(
+        &self,
+        session: &str,
+        request: &MassUpdateTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("replacing objects of templates..");
+
+        let api_request =
+            get_api_request("template.massupdate", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTemplateResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("templates {:?} have been updated", result.template_ids);
+
+                        Ok(result.template_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # link_templates
+    ///
+    /// Implements `ZabbixApiClient::link_templates`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "template")]
+    fn link_templates(
+        &self,
+        session: &str,
+        request: &LinkTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("linking templates to hosts..");
+
+        let api_request = get_api_request("host.massadd", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<UpdateHostResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("templates have been linked to hosts {:?}", result.host_ids);
+
+                        Ok(result.host_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # unlink_templates
+    ///
+    /// Implements `ZabbixApiClient::unlink_templates`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "template")]
+    fn unlink_templates(
+        &self,
+        session: &str,
+        request: &UnlinkTemplatesRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("unlinking templates from hosts {:?}..", request.host_ids);
+
+        let api_request = get_api_request("host.massremove", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<UpdateHostResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "templates have been unlinked from hosts {:?}",
+                            result.host_ids
+                        );
+
+                        Ok(result.host_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # export_configuration
+    ///
+    /// Implements `ZabbixApiClient::export_configuration`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "configuration")]
+    fn export_configuration(
+        &self,
+        session: &str,
+        request: &ExportConfigurationRequest,
+    ) -> Result<String, ZabbixApiError> {
+        info!("exporting configuration..");
+
+        let api_request =
+            get_api_request("configuration.export", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<String>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("configuration has been exported");
+                        Ok(result)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # import_configuration
+    ///
+    /// Implements `ZabbixApiClient::import_configuration`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "configuration")]
+    fn import_configuration(
+        &self,
+        session: &str,
+        request: &ImportConfigurationRequest,
+    ) -> Result<bool, ZabbixApiError> {
+        info!("importing configuration..");
+
+        let api_request =
+            get_api_request("configuration.import", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<bool>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("configuration has been imported");
+                        Ok(result)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # compare_configuration_import
+    ///
+    /// Implements `ZabbixApiClient::compare_configuration_import`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "configuration")]
+    fn compare_configuration_import(
+        &self,
+        session: &str,
+        request: &ImportConfigurationRequest,
+    ) -> Result<ImportCompareResult, ZabbixApiError> {
+        info!("comparing configuration with the server state..");
+
+        let api_request = get_api_request(
+            "configuration.importcompare",
+            request,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<ImportCompareResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("import changes: {:?}", result);
+                        Ok(result.into())
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_item
+    ///
+    /// Implements `ZabbixApiClient::update_item`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "item")]
+    fn update_item(
+        &self,
+        session: &str,
+        request: &UpdateItemRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating item '{}'..", request.item_id);
+
+        let api_request = get_api_request("item.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<UpdateItemResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("item '{}' has been updated", request.item_id);
+
+                        match result.item_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_items
+    ///
+    /// Implements `ZabbixApiClient::delete_items`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "item")]
+    fn delete_items(
+        &self,
+        session: &str,
+        item_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting items {:?}..", item_ids);
+
+        let api_request = get_api_request("item.delete", item_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<UpdateItemResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("items {:?} have been deleted", result.item_ids);
+
+                        Ok(result.item_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_discovery_rules
+    ///
+    /// Implements `ZabbixApiClient::get_discovery_rules`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn get_discovery_rules<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixDiscoveryRule>, ZabbixApiError> {
+        info!("getting discovery rules..");
+
+        let api_request = get_api_request("discoveryrule.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixDiscoveryRule>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("discovery rules found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_discovery_rule
+    ///
+    /// Implements `ZabbixApiClient::create_discovery_rule`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn create_discovery_rule(
+        &self,
+        session: &str,
+        request: &CreateDiscoveryRuleRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating discovery rule '{}'..", request.key_);
+
+        let api_request =
+            get_api_request("discoveryrule.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<CreateDiscoveryRuleResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("discovery rule '{}' has been created", request.key_);
+
+                        match result.item_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_discovery_rule
+    ///
+    /// Implements `ZabbixApiClient::update_discovery_rule`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn update_discovery_rule(
+        &self,
+        session: &str,
+        request: &UpdateDiscoveryRuleRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating discovery rule '{}'..", request.item_id);
+
+        let api_request =
+            get_api_request("discoveryrule.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateDiscoveryRuleResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("discovery rule '{}' has been updated", request.item_id);
+
+                        match result.item_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_discovery_rules
+    ///
+    /// Implements `ZabbixApiClient::delete_discovery_rules`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn delete_discovery_rules(
+        &self,
+        session: &str,
+        rule_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting discovery rules {:?}..", rule_ids);
+
+        let api_request =
+            get_api_request("discoveryrule.delete", rule_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<DeleteDiscoveryRulesResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("discovery rules {:?} have been deleted", result.rule_ids);
+
+                        Ok(result.rule_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # copy_discovery_rules
+    ///
+    /// Implements `ZabbixApiClient::copy_discovery_rules`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn copy_discovery_rules(
+        &self,
+        session: &str,
+        request: &CopyDiscoveryRulesRequest,
+    ) -> Result<bool, ZabbixApiError> {
+        info!(
+            "copying discovery rules {:?} to hosts {:?}..",
+            request.discovery_ids, request.host_ids
+        );
+
+        let api_request = get_api_request("discoveryrule.copy", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<bool>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("discovery rules have been copied");
+                        Ok(result)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_item_prototypes
+    ///
+    /// Implements `ZabbixApiClient::get_item_prototypes`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn get_item_prototypes<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixItemPrototype>, ZabbixApiError> {
+        info!("getting item prototypes..");
+
+        let api_request = get_api_request("itemprototype.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixItemPrototype>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("item prototypes found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_item_prototype
+    ///
+    /// Implements `ZabbixApiClient::create_item_prototype`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn create_item_prototype(
+        &self,
+        session: &str,
+        request: &CreateItemPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating item prototype '{}'..", request.item.key_);
+
+        let api_request =
+            get_api_request("itemprototype.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<CreateItemPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("item prototype '{}' has been created", request.item.key_);
+
+                        match result.item_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_item_prototype
+    ///
+    /// Implements `ZabbixApiClient::update_item_prototype`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn update_item_prototype(
+        &self,
+        session: &str,
+        request: &UpdateItemPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating item prototype '{}'..", request.item.item_id);
+
+        let api_request =
+            get_api_request("itemprototype.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateItemPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("item prototype '{}' has been updated", request.item.item_id);
+
+                        match result.item_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_item_prototypes
+    ///
+    /// Implements `ZabbixApiClient::delete_item_prototypes`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn delete_item_prototypes(
+        &self,
+        session: &str,
+        prototype_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting item prototypes {:?}..", prototype_ids);
+
+        let api_request = get_api_request(
+            "itemprototype.delete",
+            prototype_ids,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<DeleteItemPrototypesResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!(
+                            "item prototypes {:?} have been deleted",
+                            result.prototype_ids
+                        );
+
+                        Ok(result.prototype_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_trigger_prototypes
+    ///
+    /// Implements `ZabbixApiClient::get_trigger_prototypes`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn get_trigger_prototypes<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixTriggerPrototype>, ZabbixApiError> {
+        info!("getting trigger prototypes..");
+
+        let api_request =
+            get_api_request("triggerprototype.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<Vec<ZabbixTriggerPrototype>>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("trigger prototypes found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_trigger_prototype
+    ///
+    /// Implements `ZabbixApiClient::create_trigger_prototype`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn create_trigger_prototype(
+        &self,
+        session: &str,
+        request: &CreateTriggerPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!(
+            "creating trigger prototype '{}'..",
+            request.trigger.description
+        );
+
+        let api_request = get_api_request(
+            "triggerprototype.create",
+            request,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<CreateTriggerPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "trigger prototype '{}' has been created",
+                            request.trigger.description
+                        );
+
+                        match result.trigger_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_trigger_prototype
+    ///
+    /// Implements `ZabbixApiClient::update_trigger_prototype`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn update_trigger_prototype(
+        &self,
+        session: &str,
+        request: &UpdateTriggerPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating trigger prototype '{}'..", request.trigger_id);
+
+        let api_request = get_api_request(
+            "triggerprototype.update",
+            request,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateTriggerPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!(
+                            "trigger prototype '{}' has been updated",
+                            request.trigger_id
+                        );
+
+                        match result.trigger_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_trigger_prototypes
+    ///
+    /// Implements `ZabbixApiClient::delete_trigger_prototypes`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn delete_trigger_prototypes(
+        &self,
+        session: &str,
+        prototype_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting trigger prototypes {:?}..", prototype_ids);
+
+        let api_request = get_api_request(
+            "triggerprototype.delete",
+            prototype_ids,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateTriggerPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!(
+                            "trigger prototypes {:?} have been deleted",
+                            result.trigger_ids
+                        );
+
+                        Ok(result.trigger_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_graph_prototypes
+    ///
+    /// Implements `ZabbixApiClient::get_graph_prototypes`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn get_graph_prototypes<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixGraphPrototype>, ZabbixApiError> {
+        info!("getting graph prototypes..");
+
+        let api_request = get_api_request("graphprototype.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixGraphPrototype>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("graph prototypes found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_graph_prototype
+    ///
+    /// Implements `ZabbixApiClient::create_graph_prototype`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn create_graph_prototype(
+        &self,
+        session: &str,
+        request: &CreateGraphPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating graph prototype '{}'..", request.name);
+
+        let api_request =
+            get_api_request("graphprototype.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<CreateGraphPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("graph prototype '{}' has been created", request.name);
+
+                        match result.graph_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_graph_prototype
+    ///
+    /// Implements `ZabbixApiClient::update_graph_prototype`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn update_graph_prototype(
+        &self,
+        session: &str,
+        request: &UpdateGraphPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating graph prototype '{}'..", request.graph_id);
+
+        let api_request =
+            get_api_request("graphprototype.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateGraphPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("graph prototype '{}' has been updated", request.graph_id);
+
+                        match result.graph_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_graph_prototypes
+    ///
+    /// Implements `ZabbixApiClient::delete_graph_prototypes`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn delete_graph_prototypes(
+        &self,
+        session: &str,
+        prototype_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting graph prototypes {:?}..", prototype_ids);
+
+        let api_request = get_api_request(
+            "graphprototype.delete",
+            prototype_ids,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateGraphPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("graph prototypes {:?} have been deleted", result.graph_ids);
+
+                        Ok(result.graph_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_host_prototypes
+    ///
+    /// Implements `ZabbixApiClient::get_host_prototypes`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn get_host_prototypes<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixHostPrototype>, ZabbixApiError> {
+        info!("getting host prototypes..");
+
+        let api_request = get_api_request("hostprototype.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixHostPrototype>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("host prototypes found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_host_prototype
+    ///
+    /// Implements `ZabbixApiClient::create_host_prototype`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn create_host_prototype(
+        &self,
+        session: &str,
+        request: &CreateHostPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating host prototype '{}'..", request.host);
+
+        let api_request =
+            get_api_request("hostprototype.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<CreateHostPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("host prototype '{}' has been created", request.host);
+
+                        match result.host_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_host_prototype
+    ///
+    /// Implements `ZabbixApiClient::update_host_prototype`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn update_host_prototype(
+        &self,
+        session: &str,
+        request: &UpdateHostPrototypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating host prototype '{}'..", request.host_id);
+
+        let api_request =
+            get_api_request("hostprototype.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateHostPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("host prototype '{}' has been updated", request.host_id);
+
+                        match result.host_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_host_prototypes
+    ///
+    /// Implements `ZabbixApiClient::delete_host_prototypes`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "discovery")]
+    fn delete_host_prototypes(
+        &self,
+        session: &str,
+        prototype_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting host prototypes {:?}..", prototype_ids);
+
+        let api_request = get_api_request(
+            "hostprototype.delete",
+            prototype_ids,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<
+                    ZabbixApiResponse<UpdateHostPrototypeResponse>,
+                >(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("host prototypes {:?} have been deleted", result.host_ids);
+
+                        Ok(result.host_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_trigger
+    ///
+    /// Implements `ZabbixApiClient::update_trigger`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "trigger")]
+    fn update_trigger(
+        &self,
+        session: &str,
+        request: &UpdateTriggerRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating trigger '{}'..", request.trigger_id);
+
+        let api_request = get_api_request("trigger.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTriggerResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("trigger '{}' has been updated", request.trigger_id);
+
+                        match result.trigger_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_triggers
+    ///
+    /// Implements `ZabbixApiClient::delete_triggers`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "trigger")]
+    fn delete_triggers(
+        &self,
+        session: &str,
+        trigger_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting triggers {:?}..", trigger_ids);
+
+        let api_request = get_api_request("trigger.delete", trigger_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTriggerResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("triggers {:?} have been deleted", result.trigger_ids);
+
+                        Ok(result.trigger_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # enable_triggers
+    ///
+    /// Implements `ZabbixApiClient::enable_triggers`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "trigger")]
+    fn enable_triggers(
+        &self,
+        session: &str,
+        trigger_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        let requests: Vec<UpdateTriggerRequest> = trigger_ids
+            .iter()
+            .map(|trigger_id| UpdateTriggerRequest::builder(trigger_id).enable().build())
+            .collect();
+
+        info!("enabling triggers {:?}..", trigger_ids);
+
+        let api_request = get_api_request("trigger.update", &requests, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTriggerResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("triggers {:?} have been enabled", result.trigger_ids);
+
+                        Ok(result.trigger_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # disable_triggers
+    ///
+    /// Implements `ZabbixApiClient::disable_triggers`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "trigger")]
+    fn disable_triggers(
+        &self,
+        session: &str,
+        trigger_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        let requests: Vec<UpdateTriggerRequest> = trigger_ids
+            .iter()
+            .map(|trigger_id| UpdateTriggerRequest::builder(trigger_id).disable().build())
+            .collect();
+
+        info!("disabling triggers {:?}..", trigger_ids);
+
+        let api_request = get_api_request("trigger.update", &requests, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTriggerResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("triggers {:?} have been disabled", result.trigger_ids);
+
+                        Ok(result.trigger_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # add_trigger_dependencies
+    ///
+    /// Implements `ZabbixApiClient::add_trigger_dependencies`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "trigger")]
+    fn add_trigger_dependencies(
+        &self,
+        session: &str,
+        dependencies: &[AddTriggerDependencyRequest],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("adding trigger dependencies {:?}..", dependencies);
+
+        let api_request = get_api_request(
+            "trigger.adddependencies",
+            dependencies,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTriggerResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!(
+                            "dependencies have been added to triggers {:?}",
+                            result.trigger_ids
+                        );
+
+                        Ok(result.trigger_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_trigger_dependencies
+    ///
+    /// Implements `ZabbixApiClient::delete_trigger_dependencies`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "trigger")]
+    fn delete_trigger_dependencies(
+        &self,
+        session: &str,
+        trigger_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        let triggers: Vec<ZabbixTriggerDependency> = trigger_ids
+            .iter()
+            .map(ZabbixTriggerDependency::new)
+            .collect();
+
+        info!("removing dependencies of triggers {:?}..", trigger_ids);
+
+        let api_request = get_api_request(
+            "trigger.deletedependencies",
+            &triggers,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateTriggerResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!(
+                            "dependencies of triggers {:?} have been removed",
+                            result.trigger_ids
+                        );
+
+                        Ok(result.trigger_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_problems
+    ///
+    /// Implements `ZabbixApiClient::get_problems`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "event")]
+    fn get_problems<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixProblem>, ZabbixApiError> {
+        info!("getting problems..");
+
+        let api_request = get_api_request("problem.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixProblem>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("problems found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_events
+    ///
+    /// Implements `ZabbixApiClient::get_events`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "event")]
+    fn get_events<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixEvent>, ZabbixApiError> {
+        info!("getting events..");
+
+        let api_request = get_api_request("event.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixEvent>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("events found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # acknowledge_events
+    ///
+    /// Implements `ZabbixApiClient::acknowledge_events`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "event")]
+    fn acknowledge_events(
+        &self,
+        session: &str,
+        request: &AcknowledgeEventsRequest,
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("acknowledging events {:?}..", request.event_ids);
+
+        let api_request = get_api_request("event.acknowledge", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<AcknowledgeEventsResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("events {:?} have been updated", result.event_ids);
+
+                        Ok(result.event_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_history
+    ///
+    /// Implements `ZabbixApiClient::get_history`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "history")]
+    fn get_history<V: HistoryValueType>(
+        &self,
+        session: &str,
+        request: &GetHistoryRequest<V>,
+    ) -> Result<Vec<V::Record>, ZabbixApiError> {
+        info!("getting history..");
+
+        let api_request = get_api_request("history.get", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<V::Record>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("history records found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # push_history
+    ///
+    /// Implements `ZabbixApiClient::push_history`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "history", feature = "v7"))]
+    fn push_history(
+        &self,
+        session: &str,
+        values: &[HistoryPushValue],
+    ) -> Result<Vec<HistoryPushResult>, ZabbixApiError> {
+        info!("pushing {} history values..", values.len());
+
+        let api_request = get_api_request("history.push", values, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<HistoryPushResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("history values have been pushed");
+                        Ok(result.data)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_trends
+    ///
+    /// Implements `ZabbixApiClient::get_trends`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "trend")]
+    fn get_trends<T: TrendValue>(
+        &self,
+        session: &str,
+        request: &GetTrendsRequest,
+    ) -> Result<Vec<ZabbixTrend<T>>, ZabbixApiError> {
+        info!("getting trends..");
+
+        let api_request = get_api_request("trend.get", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixTrend<T>>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("trends found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_maintenances
+    ///
+    /// Implements `ZabbixApiClient::get_maintenances`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "maintenance")]
+    fn get_maintenances<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixMaintenance>, ZabbixApiError> {
+        info!("getting maintenances..");
+
+        let api_request = get_api_request("maintenance.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixMaintenance>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("maintenances found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_maintenance
+    ///
+    /// Implements `ZabbixApiClient::create_maintenance`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "maintenance")]
+    fn create_maintenance(
+        &self,
+        session: &str,
+        request: &CreateMaintenanceRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating maintenance '{}'..", request.name);
+
+        let api_request = get_api_request("maintenance.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateMaintenanceResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("maintenance '{}' has been created", request.name);
+
+                        match result.maintenance_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_maintenance
+    ///
+    /// Implements `ZabbixApiClient::update_maintenance`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "maintenance")]
+    fn update_maintenance(
+        &self,
+        session: &str,
+        request: &UpdateMaintenanceRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating maintenance '{}'..", request.maintenance_id);
+
+        let api_request = get_api_request("maintenance.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateMaintenanceResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("maintenance '{}' has been updated", request.maintenance_id);
+
+                        match result.maintenance_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_maintenances
+    ///
+    /// Implements `ZabbixApiClient::delete_maintenances`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "maintenance")]
+    fn delete_maintenances(
+        &self,
+        session: &str,
+        maintenance_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting maintenances {:?}..", maintenance_ids);
+
+        let api_request = get_api_request(
+            "maintenance.delete",
+            maintenance_ids,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateMaintenanceResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!(
+                            "maintenances {:?} have been deleted",
+                            result.maintenance_ids
+                        );
+
+                        Ok(result.maintenance_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_actions
+    ///
+    /// Implements `ZabbixApiClient::get_actions`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "action")]
+    fn get_actions<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixAction>, ZabbixApiError> {
+        info!("getting actions..");
+
+        let api_request = get_api_request("action.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixAction>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("actions found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_action
+    ///
+    /// Implements `ZabbixApiClient::create_action`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "action")]
+    fn create_action(
+        &self,
+        session: &str,
+        request: &CreateActionRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating action '{}'..", request.name);
+
+        let api_request = get_api_request("action.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateActionResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("action '{}' has been created", request.name);
+
+                        match result.action_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_action
+    ///
+    /// Implements `ZabbixApiClient::update_action`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "action")]
+    fn update_action(
+        &self,
+        session: &str,
+        request: &UpdateActionRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating action '{}'..", request.action_id);
+
+        let api_request = get_api_request("action.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateActionResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("action '{}' has been updated", request.action_id);
+
+                        match result.action_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_actions
+    ///
+    /// Implements `ZabbixApiClient::delete_actions`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "action")]
+    fn delete_actions(
+        &self,
+        session: &str,
+        action_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting actions {:?}..", action_ids);
+
+        let api_request = get_api_request("action.delete", action_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateActionResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("actions {:?} have been deleted", result.action_ids);
+
+                        Ok(result.action_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_media_types
+    ///
+    /// Implements `ZabbixApiClient::get_media_types`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "mediatype")]
+    fn get_media_types<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixMediaType>, ZabbixApiError> {
+        info!("getting media types..");
+
+        let api_request = get_api_request("mediatype.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixMediaType>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("media types found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_media_type
+    ///
+    /// Implements `ZabbixApiClient::create_media_type`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "mediatype")]
+    fn create_media_type(
+        &self,
+        session: &str,
+        request: &CreateMediaTypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating media type '{}'..", request.name);
+
+        let api_request = get_api_request("mediatype.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateMediaTypeResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("media type '{}' has been created", request.name);
+
+                        match result.media_type_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_media_type
+    ///
+    /// Implements `ZabbixApiClient::update_media_type`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "mediatype")]
+    fn update_media_type(
+        &self,
+        session: &str,
+        request: &UpdateMediaTypeRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating media type '{}'..", request.media_type_id);
+
+        let api_request = get_api_request("mediatype.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateMediaTypeResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("media type '{}' has been updated", request.media_type_id);
+
+                        match result.media_type_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_media_types
+    ///
+    /// Implements `ZabbixApiClient::delete_media_types`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "mediatype")]
+    fn delete_media_types(
+        &self,
+        session: &str,
+        media_type_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting media types {:?}..", media_type_ids);
+
+        let api_request = get_api_request(
+            "mediatype.delete",
+            media_type_ids,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateMediaTypeResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("media types {:?} have been deleted", result.media_type_ids);
+
+                        Ok(result.media_type_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_alerts
+    ///
+    /// Implements `ZabbixApiClient::get_alerts`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "alert")]
+    fn get_alerts<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixAlert>, ZabbixApiError> {
+        info!("getting alerts..");
+
+        let api_request = get_api_request("alert.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixAlert>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("alerts found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_scripts
+    ///
+    /// Implements `ZabbixApiClient::get_scripts`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "script")]
+    fn get_scripts<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixScript>, ZabbixApiError> {
+        info!("getting scripts..");
+
+        let api_request = get_api_request("script.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixScript>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("scripts found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_script
+    ///
+    /// Implements `ZabbixApiClient::create_script`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "script")]
+    fn create_script(
+        &self,
+        session: &str,
+        request: &CreateScriptRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating script '{}'..", request.name);
+
+        let api_request = get_api_request("script.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateScriptResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("script '{}' has been created", request.name);
+
+                        match result.script_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_script
+    ///
+    /// Implements `ZabbixApiClient::update_script`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "script")]
+    fn update_script(
+        &self,
+        session: &str,
+        request: &UpdateScriptRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating script '{}'..", request.script_id);
+
+        let api_request = get_api_request("script.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateScriptResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("script '{}' has been updated", request.script_id);
+
+                        match result.script_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_scripts
+    ///
+    /// Implements `ZabbixApiClient::delete_scripts`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "script")]
+    fn delete_scripts(
+        &self,
+        session: &str,
+        script_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting scripts {:?}..", script_ids);
+
+        let api_request = get_api_request("script.delete", script_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateScriptResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("scripts {:?} have been deleted", result.script_ids);
+
+                        Ok(result.script_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # execute_script
+    ///
+    /// Implements `ZabbixApiClient::execute_script`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "script")]
+    fn execute_script(
+        &self,
+        session: &str,
+        request: &ExecuteScriptRequest,
+    ) -> Result<ScriptExecution, ZabbixApiError> {
+        info!("executing script '{}'..", request.script_id);
+
+        let api_request = get_api_request("script.execute", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<ScriptExecution>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("script '{}' has been executed", request.script_id);
+                        Ok(result)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_scripts_by_hosts
+    ///
+    /// Implements `ZabbixApiClient::get_scripts_by_hosts`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "script")]
+    fn get_scripts_by_hosts(
+        &self,
+        session: &str,
+        request: &GetScriptsByHostsRequest,
+    ) -> Result<HashMap<String, Vec<ZabbixScript>>, ZabbixApiError> {
+        info!("getting scripts of hosts {:?}..", request.host_ids);
+
+        let api_request = get_api_request(
+            "script.getscriptsbyhosts",
+            request,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<ScriptsByObject>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("scripts of hosts {:?} found", request.host_ids);
+                        Ok(result.into_map())
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_scripts_by_events
+    ///
+    /// Implements `ZabbixApiClient::get_scripts_by_events`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "script")]
+    fn get_scripts_by_events(
+        &self,
+        session: &str,
+        request: &GetScriptsByEventsRequest,
+    ) -> Result<HashMap<String, Vec<ZabbixScript>>, ZabbixApiError> {
+        info!("getting scripts of events {:?}..", request.event_ids);
+
+        let api_request = get_api_request(
+            "script.getscriptsbyevents",
+            request,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<ScriptsByObject>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("scripts of events {:?} found", request.event_ids);
+                        Ok(result.into_map())
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_proxies
+    ///
+    /// Implements `ZabbixApiClient::get_proxies`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "proxy")]
+    fn get_proxies<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixProxy>, ZabbixApiError> {
+        info!("getting proxies..");
+
+        let api_request = get_api_request("proxy.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixProxy>>>(&response_body)?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("proxies found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_proxy
+    ///
+    /// Implements `ZabbixApiClient::create_proxy`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "proxy")]
+    fn create_proxy(
+        &self,
+        session: &str,
+        request: &CreateProxyRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating proxy '{}'..", request.name);
+
+        let api_request = get_api_request("proxy.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<CreateProxyResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("proxy '{}' has been created", request.name);
+
+                        match result.proxy_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_proxy
+    ///
+    /// Implements `ZabbixApiClient::update_proxy`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "proxy")]
+    fn update_proxy(
+        &self,
+        session: &str,
+        request: &UpdateProxyRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating proxy '{}'..", request.proxy_id);
+
+        let api_request = get_api_request("proxy.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<UpdateProxyResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("proxy '{}' has been updated", request.proxy_id);
+
+                        match result.proxy_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_proxies
+    ///
+    /// Implements `ZabbixApiClient::delete_proxies`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "proxy")]
+    fn delete_proxies(
+        &self,
+        session: &str,
+        proxy_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting proxies {:?}..", proxy_ids);
+
+        let api_request = get_api_request("proxy.delete", proxy_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response =
+                    serde_json::from_str::<ZabbixApiResponse<UpdateProxyResponse>>(&response_body)?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("proxies {:?} have been deleted", result.proxy_ids);
+
+                        Ok(result.proxy_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_proxy_groups
+    ///
+    /// Implements `ZabbixApiClient::get_proxy_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "proxy", feature = "v7"))]
+    fn get_proxy_groups<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixProxyGroup>, ZabbixApiError> {
+        info!("getting proxy groups..");
+
+        let api_request = get_api_request("proxygroup.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixProxyGroup>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("proxy groups found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_proxy_group
+    ///
+    /// Implements `ZabbixApiClient::create_proxy_group`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "proxy", feature = "v7"))]
+    fn create_proxy_group(
+        &self,
+        session: &str,
+        request: &CreateProxyGroupRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating proxy group '{}'..", request.name);
+
+        let api_request = get_api_request("proxygroup.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateProxyGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("proxy group '{}' has been created", request.name);
+
+                        match result.proxy_group_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_proxy_group
+    ///
+    /// Implements `ZabbixApiClient::update_proxy_group`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "proxy", feature = "v7"))]
+    fn update_proxy_group(
+        &self,
+        session: &str,
+        request: &UpdateProxyGroupRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating proxy group '{}'..", request.proxy_group_id);
+
+        let api_request = get_api_request("proxygroup.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateProxyGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("proxy group '{}' has been updated", request.proxy_group_id);
+
+                        match result.proxy_group_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_proxy_groups
+    ///
+    /// Implements `ZabbixApiClient::delete_proxy_groups`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(all(feature = "proxy", feature = "v7"))]
+    fn delete_proxy_groups(
+        &self,
+        session: &str,
+        proxy_group_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting proxy groups {:?}..", proxy_group_ids);
+
+        let api_request = get_api_request(
+            "proxygroup.delete",
+            proxy_group_ids,
+            Some(session.to_string()),
+        );
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateProxyGroupResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!(
+                            "proxy groups {:?} have been deleted",
+                            result.proxy_group_ids
+                        );
+
+                        Ok(result.proxy_group_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # get_dashboards
+    ///
+    /// Implements `ZabbixApiClient::get_dashboards`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "dashboard")]
+    fn get_dashboards<P: Serialize>(
+        &self,
+        session: &str,
+        params: &P,
+    ) -> Result<Vec<ZabbixDashboard>, ZabbixApiError> {
+        info!("getting dashboards..");
+
+        let api_request = get_api_request("dashboard.get", params, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
             api_request,
         ) {
             Ok(response_body) => {
@@ -1123,1469 +10698,3459 @@ impl ZabbixApiClient for ZabbixApiClientImpl {
                 debug!("{response_body}");
                 debug!("[/response body]");
 
-                let response =
-                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixHost>>>(&response_body)?;
+                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixDashboard>>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(results) => {
+                        info!("dashboards found: {:?}", results.len());
+                        Ok(results)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # create_dashboard
+    ///
+    /// Implements `ZabbixApiClient::create_dashboard`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "dashboard")]
+    fn create_dashboard(
+        &self,
+        session: &str,
+        request: &CreateDashboardRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("creating dashboard '{}'..", request.name);
+
+        let api_request = get_api_request("dashboard.create", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<CreateDashboardResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("dashboard '{}' has been created", request.name);
+
+                        match result.dashboard_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # update_dashboard
+    ///
+    /// Implements `ZabbixApiClient::update_dashboard`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "dashboard")]
+    fn update_dashboard(
+        &self,
+        session: &str,
+        request: &UpdateDashboardRequest,
+    ) -> Result<u32, ZabbixApiError> {
+        info!("updating dashboard '{}'..", request.dashboard_id);
+
+        let api_request = get_api_request("dashboard.update", request, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateDashboardResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        info!("dashboard '{}' has been updated", request.dashboard_id);
+
+                        match result.dashboard_ids.first() {
+                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
+                            None => {
+                                error!("unexpected error, server returned empty id list");
+                                Err(ZabbixApiError::Error)
+                            }
+                        }
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// # delete_dashboards
+    ///
+    /// Implements `ZabbixApiClient::delete_dashboards`.
+    ///
+    /// See the trait documentation for more details.
+    #[cfg(feature = "dashboard")]
+    fn delete_dashboards(
+        &self,
+        session: &str,
+        dashboard_ids: &[String],
+    ) -> Result<Vec<String>, ZabbixApiError> {
+        info!("deleting dashboards {:?}..", dashboard_ids);
+
+        let api_request =
+            get_api_request("dashboard.delete", dashboard_ids, Some(session.to_string()));
+
+        match send_post_request(
+            &self.client,
+            &self.api_endpoint_url,
+            Some(session),
+            api_request,
+        ) {
+            Ok(response_body) => {
+                debug!("[response body]");
+                debug!("{response_body}");
+                debug!("[/response body]");
+
+                let response = serde_json::from_str::<ZabbixApiResponse<UpdateDashboardResponse>>(
+                    &response_body,
+                )?;
+
+                match response.result {
+                    Some(result) => {
+                        debug!("dashboards {:?} have been deleted", result.dashboard_ids);
+
+                        Ok(result.dashboard_ids)
+                    }
+                    None => match response.error {
+                        Some(error) => {
+                            error!("{:?}", error);
+
+                            Err(ZabbixApiError::ApiCallError { zabbix: error })
+                        }
+                        None => Err(ZabbixApiError::BadRequestError),
+                    },
+                }
+            }
+            Err(e) => {
+                error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "user"))]
+mod user_tests {
+    use log::{error, info};
+    use serde::Serialize;
+    use std::error::Error as StdError;
+
+    use super::ZabbixApiClient;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::user::create::{CreateUserRequest, UserGroupId};
+    use crate::usergroup::get::{GetUserGroupsRequest, UserGroupFilter};
+    use crate::usergroup::model::CreateUserGroupRequest;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
+
+    #[test]
+    fn get_users_test() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+            test_env.get_session();
+
+            #[derive(Serialize)]
+            struct UserFilterParams {
+                output: String,
+                filter: UserFilter,
+            }
+
+            #[derive(Serialize)]
+            struct UserFilter {
+                alias: Vec<String>,
+            }
+
+            let api_user_alias = test_env.integration_tests_config.zabbix_api_user.clone();
+
+            let params = UserFilterParams {
+                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
+                filter: UserFilter {
+                    alias: vec![api_user_alias.clone()],
+                },
+            };
+
+            match test_env.client.get_users(&test_env.session, &params) {
+                Ok(users) => {
+                    assert!(!users.is_empty(), "Expected to find at least one user");
+                    let found_user = users.iter().find(|u| u.alias == api_user_alias);
+                    assert!(
+                        found_user.is_some(),
+                        "Expected to find user with alias '{}'",
+                        api_user_alias
+                    );
+                    if let Some(user) = found_user {
+                        info!("Successfully fetched user: {:?}", user);
+                    }
+                }
+                Err(e) => {
+                    error!("get_users test failed: {}", e);
+                    if let Some(source) = e.source() {
+                        error!("Caused by: {}", source);
+                    }
+                    panic!("get_users test failed");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn get_user_groups_test() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+            test_env.get_session();
+
+            let user_group_name = format!("test_get_ug_{}", get_random_string());
+            let create_request = CreateUserGroupRequest {
+                name: user_group_name.clone(),
+                ..Default::default()
+            };
+
+            let user_group_id = test_env
+                .client
+                .create_user_group(&test_env.session, &create_request)
+                .expect("Failed to create user group for get_user_groups_test");
+            info!(
+                "Created user group '{}' with ID '{}'",
+                user_group_name, user_group_id
+            );
+
+            let get_request = GetUserGroupsRequest {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                filter: Some(UserGroupFilter {
+                    name: Some(vec![user_group_name.clone()]),
+                }),
+                select_users: Some("extend".to_string()),
+                select_rights: Some("extend".to_string()),
+                ..Default::default()
+            };
+
+            match test_env
+                .client
+                .get_user_groups(&test_env.session, &get_request)
+            {
+                Ok(user_groups) => {
+                    assert!(
+                        !user_groups.is_empty(),
+                        "Expected to find at least one user group"
+                    );
+                    let found_group = user_groups.iter().find(|ug| ug.name == user_group_name);
+                    assert!(
+                        found_group.is_some(),
+                        "Expected to find user group with name '{}'",
+                        user_group_name
+                    );
+                    if let Some(group) = found_group {
+                        info!("Successfully fetched user group: {:?}", group);
+                        assert_eq!(group.usrgrp_id, user_group_id.to_string());
+                    }
+                }
+                Err(e) => {
+                    error!("get_user_groups test failed: {}", e);
+                    if let Some(source) = e.source() {
+                        error!("Caused by: {}", source);
+                    }
+                    panic!("get_user_groups test failed");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn create_user_test() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+            test_env.get_session();
+
+            let user_group_name = format!("test_ug_for_user_create_{}", get_random_string());
+            let create_ug_request = CreateUserGroupRequest {
+                name: user_group_name.clone(),
+                ..Default::default()
+            };
+
+            let user_group_id = test_env
+                .client
+                .create_user_group(&test_env.session, &create_ug_request)
+                .expect("Failed to create user group for create_user_test");
+            info!(
+                "Created user group '{}' with ID '{}' for create_user_test",
+                user_group_name, user_group_id
+            );
+
+            let user_alias = format!("test_user_{}", get_random_string());
+            let user_passwd = get_random_string();
+            let role_id = "3"; // Default "User role" ID
+
+            let create_user_req = CreateUserRequest {
+                username: user_alias.clone(),
+                passwd: user_passwd,
+                roleid: role_id.to_string(),
+                usrgrps: vec![UserGroupId {
+                    usrgrpid: user_group_id.to_string(),
+                }],
+                name: Some("Test".to_string()),
+                surname: Some("User".to_string()),
+                ..Default::default()
+            };
+
+            match test_env
+                .client
+                .create_user(&test_env.session, &create_user_req)
+            {
+                Ok(user_id) => {
+                    assert!(user_id > 0, "Expected a valid user ID to be returned");
+                    info!(
+                        "Successfully created user '{}' with ID '{}'",
+                        user_alias, user_id
+                    );
+                }
+                Err(e) => {
+                    error!("create_user test failed: {}", e);
+                    if let Some(source) = e.source() {
+                        error!("Caused by: {}", source);
+                    }
+                    panic!("create_user test failed");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use log::{error, info};
+    use reqwest::blocking::Client;
+    use serde::Serialize;
+
+    use crate::client::client::ZabbixApiClient;
+    use crate::host::create::TlsConfig;
+    use crate::host::get::GetHostsRequest;
+    use crate::host::model::ZabbixHost;
+    use crate::host::update::UpdateHostRequest;
+    use crate::hostgroup::get::GetHostGroupsRequest;
+    use crate::item::create::CreateItemRequest;
+    use crate::item::get::GetItemsRequestById;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::{are_integration_tests_enabled, get_integration_tests_config};
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_hex_string;
+    use crate::tests::strings::get_random_string;
+    use crate::trigger::create::CreateTriggerRequest;
+    use crate::trigger::get::GetTriggerByIdRequest;
+    use crate::usergroup::model::{CreateUserGroupRequest, UserGroupPermission, UserGroupUser};
+    use crate::webscenario::create::CreateWebScenarioRequest;
+    use crate::webscenario::get::GetWebScenarioByIdRequest;
+    use crate::webscenario::model::ZabbixWebScenarioStep;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
+
+    use super::ZabbixApiClientImpl;
+
+    #[test]
+    fn get_api_info() {
+        if are_integration_tests_enabled() {
+            let test_env = TestEnvBuilder::build();
+
+            match test_env.client.get_api_info() {
+                Ok(result) => {
+                    assert!(!result.is_empty())
+                }
+                Err(e) => {
+                    error!("error: {}", e);
+                    panic!("unexpected error")
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn session_should_be_returned() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let http_client = Client::new();
+
+            let tests_config = get_integration_tests_config();
+
+            let client = ZabbixApiClientImpl::new(http_client, &tests_config.zabbix_api_url);
+
+            match client.get_auth_session(
+                &tests_config.zabbix_api_user,
+                &tests_config.zabbix_api_password,
+            ) {
+                Ok(session) => assert!(session.len() > 0),
+                Err(e) => {
+                    error!("error: {}", e);
+                    panic!("unexpected error")
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn raw_api_call_test() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+            test_env.get_session();
+
+            #[derive(Serialize)]
+            struct Params {
+                pub filter: Filter,
+            }
+
+            #[derive(Serialize)]
+            struct Filter {
+                pub host: Vec<String>,
+            }
+
+            let params = Params {
+                filter: Filter {
+                    host: vec!["Zabbix server".to_string()],
+                },
+            };
+
+            match test_env.client.raw_api_call::<Params, Vec<ZabbixHost>>(
+                &test_env.session,
+                "host.get",
+                &params,
+            ) {
+                Ok(response) => {
+                    let results = response.result.unwrap();
+                    info!("{:?}", results.first().unwrap());
+                    assert_eq!(1, results.len())
+                }
+                Err(e) => {
+                    error!("api call error: {}", e);
+                    panic!("unexpected api call error")
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn get_host_groups_test() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let group_name2 = get_random_string();
+            let group_name3 = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host_group(&group_name2)
+                .create_host_group(&group_name3);
+
+            #[derive(Serialize)]
+            struct Filter {
+                pub name: Vec<String>,
+            }
+
+            let request = GetHostGroupsRequest {
+                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
+                filter: Filter {
+                    name: vec![group_name2.to_string()],
+                },
+            };
+
+            match test_env.client.get_host_groups(&test_env.session, &request) {
+                Ok(host_groups) => {
+                    assert_eq!(host_groups.len(), 1);
+
+                    let host_group = host_groups.first().unwrap();
+
+                    assert_eq!(&host_group.name, &group_name2)
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host group get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn get_hosts_test() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name1 = get_random_string();
+            let host_name2 = get_random_string();
+            let host_name3 = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name1, None)
+                .create_host(&host_name2, None)
+                .create_host(&host_name3, None);
+
+            #[derive(Serialize)]
+            struct Filter {
+                pub host: Vec<String>,
+            }
+
+            let request = GetHostsRequest {
+                filter: Filter {
+                    host: vec![host_name2.to_string()],
+                },
+            };
+
+            match test_env.client.get_hosts(&test_env.session, &request) {
+                Ok(hosts) => {
+                    assert_eq!(hosts.len(), 1);
+
+                    let host = hosts.first().unwrap();
+
+                    assert_eq!(&host.host, &host_name2)
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn delete_hosts_test() {
+        init_logging();
+
+        #[derive(Serialize)]
+        struct HostFilter {
+            pub host: Vec<String>,
+        }
+
+        if are_integration_tests_enabled() {
+            use crate::host::get::GetHostsByIdsRequest;
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name1 = get_random_string();
+            let host_name2 = get_random_string();
+            let host_name3 = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name1, None)
+                .create_host(&host_name2, None)
+                .create_host(&host_name3, None);
+
+            match test_env.client.get_hosts(
+                &test_env.session,
+                &GetHostsRequest {
+                    filter: HostFilter {
+                        host: vec![host_name1, host_name2, host_name3],
+                    },
+                },
+            ) {
+                Ok(hosts) => {
+                    let host_ids = hosts
+                        .iter()
+                        .map(|host| host.host_id.clone())
+                        .collect::<Vec<String>>();
+
+                    test_env.get_session().delete_hosts(&host_ids);
+
+                    match test_env.client.get_hosts(
+                        &test_env.session,
+                        &GetHostsByIdsRequest { hostids: host_ids },
+                    ) {
+                        Ok(hosts) => {
+                            assert!(hosts.is_empty());
+                        }
+                        Err(e) => {
+                            if let Some(inner_source) = e.source() {
+                                println!("Caused by: {}", inner_source);
+                            }
+
+                            error!("host get error: {}", e);
+                            panic!("{}", e)
+                        }
+                    }
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn get_items_test() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name1 = get_random_string();
+            let host_name2 = get_random_string();
+            let host_name3 = get_random_string();
+            let item_name = get_random_string();
+            let item_key = format!("test{}", get_random_string());
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name1, None)
+                .create_host(&host_name2, None)
+                .create_host(&host_name3, None)
+                .create_item(&item_name, &item_key);
+
+            #[derive(Serialize)]
+            struct Search {
+                pub key_: String,
+            }
+
+            let request = GetItemsRequestById {
+                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
+                with_triggers: false,
+                host_ids: test_env.latest_host_id.to_string(),
+                search: Search {
+                    key_: item_key.to_string(),
+                },
+                sort_field: "name".to_string(),
+            };
+
+            match test_env.client.get_items(&test_env.session, &request) {
+                Ok(items) => {
+                    assert_eq!(items.len(), 1);
+
+                    let item = items.first().unwrap();
+
+                    assert_eq!(&item.key_, &item_key)
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn get_triggers_test() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let item_name = get_random_string();
+            let item_key = get_random_string();
+            let trigger_description = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_item(&item_name, &item_key)
+                .create_trigger(
+                    &trigger_description,
+                    &format!("last(/{host_name}/{item_key})=0"),
+                );
+
+            let request = GetTriggerByIdRequest {
+                trigger_ids: test_env.latest_trigger_id.to_string(),
+                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
+                select_functions: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
+            };
+
+            match test_env.client.get_triggers(&test_env.session, &request) {
+                Ok(results) => {
+                    assert_eq!(results.len(), 1);
+                    let result = results.first().unwrap();
+
+                    assert_eq!(&result.description, &trigger_description)
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn get_webscenarios_test() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let item_name = get_random_string();
+            let item_key = get_random_string();
+            let trigger_description = get_random_string();
+            let webscenario_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_item(&item_name, &item_key)
+                .create_trigger(
+                    &trigger_description,
+                    &format!("last(/{host_name}/{item_key})=0"),
+                )
+                .create_web_scenario(&webscenario_name);
+
+            let request = GetWebScenarioByIdRequest {
+                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
+                select_steps: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
+                httptest_ids: test_env.latest_webscenario_id.to_string(),
+            };
+
+            match test_env
+                .client
+                .get_webscenarios(&test_env.session, &request)
+            {
+                Ok(results) => {
+                    assert_eq!(results.len(), 1);
+                    let result = results.first().unwrap();
+
+                    assert_eq!(&result.name, &webscenario_name)
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn create_host_group_and_host() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None);
+
+            assert!(test_env.latest_host_group_id > 0);
+            assert!(test_env.latest_host_id > 0);
+        }
+    }
+
+    #[test]
+    fn create_host_with_cert() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(
+                    &host_name,
+                    Some(TlsConfig::new_cert(
+                        "CN=some issuer".to_string(),
+                        "CN=some subject".to_string(),
+                    )),
+                );
+
+            assert!(test_env.latest_host_group_id > 0);
+            assert!(test_env.latest_host_id > 0);
+        }
+    }
+
+    #[test]
+    fn create_host_with_psk() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(
+                    &host_name,
+                    Some(TlsConfig::new_psk(
+                        get_random_string(),
+                        get_random_hex_string(),
+                    )),
+                );
+
+            assert!(test_env.latest_host_group_id > 0);
+            assert!(test_env.latest_host_id > 0);
+        }
+    }
+
+    #[test]
+    fn create_and_update_host() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None);
+
+            assert!(test_env.latest_host_group_id > 0);
+            assert!(test_env.latest_host_id > 0);
+
+            let host_id = test_env.latest_host_id.to_string();
+            test_env
+                .get_session()
+                .update_host(UpdateHostRequest::disable_host(host_id));
+        }
+    }
+
+    #[test]
+    fn create_item() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None);
+
+            let item_key = get_random_string();
+            let item_name = get_random_string();
+
+            let request = CreateItemRequest {
+                key_: item_key,
+                name: item_name,
+                host_id: test_env.latest_host_id.to_string(),
+                r#type: 7,
+                value_type: 4,
+                interface_id: "0".to_string(),
+                tags: vec![],
+                delay: "30s".to_string(),
+                preprocessing: vec![],
+                ..Default::default()
+            };
+
+            match test_env.client.create_item(&test_env.session, &request) {
+                Ok(item_id) => {
+                    assert!(item_id > 0);
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("item create error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn create_trigger() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+
+            let item_name = get_random_string();
+            let item_key = format!("key{}", get_random_string());
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_item(&item_name, &item_key);
+
+            let trigger_description = get_random_string();
+
+            let expression = format!("last(/{host_name}/{item_key})=0");
+
+            let request = CreateTriggerRequest {
+                description: trigger_description,
+                expression: expression.to_string(),
+                priority: 4,
+                recovery_mode: Some(0),
+                recovery_expression: None,
+                url: None,
+                event_name: None,
+                dependencies: vec![],
+                tags: vec![],
+            };
+
+            match test_env.client.create_trigger(&test_env.session, &request) {
+                Ok(trigger_id) => assert!(trigger_id > 0),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("trigger create error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn create_web_scenario() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None);
+
+            let web_scenario_name = get_random_string();
+
+            let step = ZabbixWebScenarioStep {
+                name: "Check github.com page".to_string(),
+                url: "https://github.com".to_string(),
+                status_codes: "200".to_string(),
+                no: "0".to_string(),
+            };
+
+            let request = CreateWebScenarioRequest {
+                name: web_scenario_name,
+                host_id: test_env.latest_host_id.to_string(),
+                steps: vec![step],
+            };
+
+            match test_env
+                .client
+                .create_webscenario(&test_env.session, &request)
+            {
+                Ok(web_scenario_id) => {
+                    assert!(web_scenario_id > 0);
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("web-scenario create error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn create_user_group() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+            test_env.get_session();
+
+            let group_name = get_random_string();
+            let user_group_name = format!("user_group_{}", get_random_string());
+
+            // Create a host group to assign permissions to
+            test_env.create_host_group(&group_name);
+            let host_group_id = test_env.latest_host_group_id.to_string();
+
+            // A dummy user ID (replace with a real one if needed for more thorough testing)
+            // For this test, Zabbix might not validate the user ID existence strictly for group creation.
+            let user_id = "1"; // Assuming user with ID '1' (Admin) exists or is not strictly checked
+
+            let request = CreateUserGroupRequest {
+                name: user_group_name.clone(),
+                gui_access: Some(0),   // System default
+                users_status: Some(0), // Enabled
+                hostgroup_rights: Some(vec![UserGroupPermission {
+                    id: host_group_id,
+                    permission: 2, // Read-only
+                }]),
+                users: Some(vec![UserGroupUser {
+                    user_id: user_id.to_string(),
+                }]),
+                ..Default::default()
+            };
+
+            match test_env
+                .client
+                .create_user_group(&test_env.session, &request)
+            {
+                Ok(user_group_id) => {
+                    assert!(user_group_id > 0);
+                    info!(
+                        "Successfully created user group '{}' with ID '{}'",
+                        user_group_name, user_group_id
+                    );
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+                    error!("user group create error: {}", e);
+                    panic!("{}", e);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "host"))]
+mod hostgroup_tests {
+    use std::error::Error;
+
+    use log::error;
+    use serde::Serialize;
+
+    use super::ZabbixApiClient;
+    use crate::hostgroup::get::GetHostGroupsRequest;
+    use crate::hostgroup::mass::{MassAddHostGroupsRequest, MassRemoveHostGroupsRequest};
+    use crate::hostgroup::update::UpdateHostGroupRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
+
+    #[derive(Serialize)]
+    struct Filter {
+        pub name: Vec<String>,
+    }
+
+    #[test]
+    fn update_and_delete_host_group() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let new_group_name = get_random_string();
+
+            test_env.get_session().create_host_group(&group_name);
+
+            let group_id = test_env.latest_host_group_id.to_string();
+
+            let request = UpdateHostGroupRequest::rename(&group_id, &new_group_name);
+
+            match test_env
+                .client
+                .update_host_group(&test_env.session, &request)
+            {
+                Ok(updated_group_id) => {
+                    assert_eq!(updated_group_id, test_env.latest_host_group_id);
+
+                    let request = GetHostGroupsRequest {
+                        output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
+                        filter: Filter {
+                            name: vec![new_group_name.to_string()],
+                        },
+                    };
+
+                    let host_groups = test_env
+                        .client
+                        .get_host_groups(&test_env.session, &request)
+                        .unwrap();
+
+                    assert_eq!(host_groups.len(), 1);
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host group update error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            let group_ids = vec![group_id];
+
+            match test_env
+                .client
+                .delete_host_groups(&test_env.session, &group_ids)
+            {
+                Ok(deleted_group_ids) => assert_eq!(deleted_group_ids, group_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host group delete error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mass_add_and_remove_host_groups() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let extra_group_name = get_random_string();
+            let host_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_host_group(&extra_group_name);
+
+            let extra_group_id = test_env.latest_host_group_id.to_string();
+            let host_id = test_env.latest_host_id.to_string();
+
+            let request =
+                MassAddHostGroupsRequest::new(vec![extra_group_id.clone()], vec![host_id.clone()]);
+
+            match test_env
+                .client
+                .mass_add_host_groups(&test_env.session, &request)
+            {
+                Ok(group_ids) => assert_eq!(group_ids, vec![extra_group_id.clone()]),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host group massadd error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            let request =
+                MassRemoveHostGroupsRequest::new(vec![extra_group_id.clone()], vec![host_id]);
+
+            match test_env
+                .client
+                .mass_remove_host_groups(&test_env.session, &request)
+            {
+                Ok(group_ids) => assert_eq!(group_ids, vec![extra_group_id]),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("host group massremove error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "template", feature = "v7"))]
+mod templategroup_tests {
+    use std::error::Error;
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::templategroup::get::{GetTemplateGroupsRequest, TemplateGroupFilter};
+    use crate::templategroup::update::UpdateTemplateGroupRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
+
+    #[test]
+    fn create_and_get_template_group() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+
+            test_env.get_session().create_template_group(&group_name);
+
+            let request = GetTemplateGroupsRequest {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                filter: Some(TemplateGroupFilter {
+                    name: Some(vec![group_name.clone()]),
+                }),
+                ..Default::default()
+            };
+
+            match test_env
+                .client
+                .get_template_groups(&test_env.session, &request)
+            {
+                Ok(template_groups) => {
+                    assert_eq!(template_groups.len(), 1);
+
+                    let template_group = template_groups.first().unwrap();
+
+                    assert_eq!(&template_group.name, &group_name);
+                    assert_eq!(
+                        template_group.group_id,
+                        test_env.latest_template_group_id.to_string()
+                    );
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("template group get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn update_and_delete_template_group() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+
+            test_env.get_session().create_template_group(&group_name);
+
+            let group_id = test_env.latest_template_group_id.to_string();
+
+            let request = UpdateTemplateGroupRequest::rename(&group_id, get_random_string());
+
+            match test_env
+                .client
+                .update_template_group(&test_env.session, &request)
+            {
+                Ok(updated_group_id) => {
+                    assert_eq!(updated_group_id, test_env.latest_template_group_id)
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("template group update error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            let group_ids = vec![group_id];
+
+            match test_env
+                .client
+                .delete_template_groups(&test_env.session, &group_ids)
+            {
+                Ok(deleted_group_ids) => assert_eq!(deleted_group_ids, group_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("template group delete error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "template"))]
+mod template_tests {
+    use std::error::Error;
 
-                match response.result {
-                    Some(results) => {
-                        info!("hosts found: {:?}", results);
-                        Ok(results)
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::template::get::{GetTemplatesRequest, TemplateFilter};
+    use crate::template::link::{LinkTemplatesRequest, UnlinkTemplatesRequest};
+    use crate::template::update::UpdateTemplateRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
+
+    #[test]
+    fn create_and_get_template() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let parent_template_name = get_random_string();
+            let template_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_template_owner_group(&group_name)
+                .create_template(&parent_template_name, None);
+
+            let parent_template_id = test_env.latest_template_id.to_string();
+
+            test_env.create_template(&template_name, Some(&parent_template_id));
+
+            let request = GetTemplatesRequest {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                filter: Some(TemplateFilter {
+                    host: Some(vec![template_name.clone()]),
+                    ..Default::default()
+                }),
+                select_tags: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_parent_templates: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
+            };
+
+            match test_env.client.get_templates(&test_env.session, &request) {
+                Ok(templates) => {
+                    assert_eq!(templates.len(), 1);
+
+                    let template = templates.first().unwrap();
+
+                    assert_eq!(&template.host, &template_name);
+
+                    let parent_templates = template.parent_templates.clone().unwrap();
+                    assert_eq!(parent_templates.len(), 1);
+                    assert_eq!(parent_templates[0].template_id, parent_template_id);
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("template get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn update_and_delete_template() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let template_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_template_owner_group(&group_name)
+                .create_template(&template_name, None);
+
+            let template_id = test_env.latest_template_id.to_string();
+
+            let request = UpdateTemplateRequest::builder(&template_id)
+                .description("updated")
+                .build();
+
+            match test_env.client.update_template(&test_env.session, &request) {
+                Ok(updated_template_id) => {
+                    assert_eq!(updated_template_id, test_env.latest_template_id)
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("template update error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            let template_ids = vec![template_id];
+
+            match test_env
+                .client
+                .delete_templates(&test_env.session, &template_ids)
+            {
+                Ok(deleted_template_ids) => assert_eq!(deleted_template_ids, template_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("template delete error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn link_and_unlink_template() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let host_group_name = get_random_string();
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let template_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_host_group(&host_group_name)
+                .create_host(&host_name, None)
+                .create_template_owner_group(&group_name)
+                .create_template(&template_name, None);
+
+            let host_ids = vec![test_env.latest_host_id.to_string()];
+            let template_ids = vec![test_env.latest_template_id.to_string()];
+
+            let request = LinkTemplatesRequest::new(template_ids.clone(), host_ids.clone());
+
+            match test_env.client.link_templates(&test_env.session, &request) {
+                Ok(linked_host_ids) => assert_eq!(linked_host_ids, host_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("template link error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            let request = UnlinkTemplatesRequest::clear(template_ids, host_ids.clone());
+
+            match test_env
+                .client
+                .unlink_templates(&test_env.session, &request)
+            {
+                Ok(unlinked_host_ids) => assert_eq!(unlinked_host_ids, host_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("template unlink error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "configuration"))]
+mod configuration_tests {
+    use std::error::Error;
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::configuration::export::ExportConfigurationRequest;
+    use crate::configuration::import::{ImportConfigurationRequest, ImportRules};
+    use crate::configuration::model::ConfigurationFormat;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+
+    #[test]
+    fn export_compare_and_import_template() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let template_name = get_random_string();
+
+            test_env
+                .get_session()
+                .create_template_owner_group(&group_name)
+                .create_template(&template_name, None);
+
+            let request = ExportConfigurationRequest::templates(
+                ConfigurationFormat::Yaml,
+                vec![test_env.latest_template_id.to_string()],
+            );
+
+            let source = match test_env
+                .client
+                .export_configuration(&test_env.session, &request)
+            {
+                Ok(source) => {
+                    assert!(source.contains(&template_name));
+                    source
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("configuration export error: {}", e);
+                    panic!("{}", e)
+                }
+            };
+
+            let request = ImportConfigurationRequest::new(
+                ConfigurationFormat::Yaml,
+                source,
+                ImportRules::templates_sync(),
+            );
+
+            match test_env
+                .client
+                .compare_configuration_import(&test_env.session, &request)
+            {
+                Ok(changes) => assert!(changes.is_empty()),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("configuration import compare error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            match test_env
+                .client
+                .import_configuration(&test_env.session, &request)
+            {
+                Ok(imported) => assert!(imported),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("configuration import error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "item"))]
+mod item_tests {
+    use std::error::Error;
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::item::create::CreateItemRequest;
+    use crate::item::get::GetItemsRequest;
+    use crate::item::model::{ItemStatus, ItemType, ItemValueType};
+    use crate::item::preprocessing::{
+        ItemPreprocessing, PreprocessingErrorHandler, PreprocessingStep,
+    };
+    use crate::item::update::UpdateItemRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
+
+    #[test]
+    fn update_and_delete_item() {
+        init_logging();
+
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let item_name = get_random_string();
+            let item_key = format!("key{}", get_random_string());
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_item(&item_name, &item_key);
+
+            let item_id = test_env.latest_item_id.to_string();
+
+            let preprocessing = vec![
+                ItemPreprocessing::new(PreprocessingStep::Trim {
+                    chars: " ".to_string(),
+                }),
+                ItemPreprocessing::new(PreprocessingStep::DiscardUnchangedWithHeartbeat {
+                    heartbeat: "1h".to_string(),
+                }),
+                ItemPreprocessing::new(PreprocessingStep::JavaScript {
+                    script: "return value.toUpperCase();".to_string(),
+                })
+                .on_fail(PreprocessingErrorHandler::SetError("bad value".to_string())),
+            ];
+
+            let request = UpdateItemRequest::builder(&item_id)
+                .units("ms")
+                .disable()
+                .preprocessing(preprocessing.clone())
+                .build();
+
+            match test_env.client.update_item(&test_env.session, &request) {
+                Ok(updated_item_id) => assert_eq!(updated_item_id, test_env.latest_item_id),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("item update error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            let request = GetItemsRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                item_ids: Some(vec![item_id.clone()]),
+                select_tags: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_preprocessing: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
+            };
+
+            match test_env.client.get_items(&test_env.session, &request) {
+                Ok(items) => {
+                    assert_eq!(items.len(), 1);
+
+                    let item = items.first().unwrap();
+
+                    assert_eq!(item.item_id, item_id);
+                    assert_eq!(item.status, Some(ItemStatus::Disabled));
+                    assert_eq!(item.units.as_deref(), Some("ms"));
+                    assert_eq!(item.preprocessing, Some(preprocessing));
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("item get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            let item_ids = vec![item_id];
+
+            match test_env.client.delete_items(&test_env.session, &item_ids) {
+                Ok(deleted_item_ids) => assert_eq!(deleted_item_ids, item_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("item delete error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
         }
     }
+    #[test]
+    fn create_trapper_and_dependent_items() {
+        init_logging();
 
-    /// # get_items
-    ///
-    /// Implements `ZabbixApiClient::get_items`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "item")]
-    fn get_items<P: Serialize>(
-        &self,
-        session: &str,
-        params: &P,
-    ) -> Result<Vec<ZabbixItem>, ZabbixApiError> {
-        info!("getting items with params");
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
 
-        let api_request = get_api_request("item.get", params, Some(session.to_string()));
+            let group_name = get_random_string();
+            let host_name = get_random_string();
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None);
 
-                let response =
-                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixItem>>>(&response_body)?;
+            let host_id = test_env.latest_host_id.to_string();
 
-                match response.result {
-                    Some(results) => {
-                        info!("hosts found: {:?}", results);
-                        Ok(results)
+            let request = CreateItemRequest::trapper(
+                &host_id,
+                "Backup report",
+                "backup.report",
+                ItemValueType::Text,
+            )
+            .build()
+            .unwrap();
+
+            let master_item_id = match test_env.client.create_item(&test_env.session, &request) {
+                Ok(item_id) => item_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("item create error: {}", e);
+                    panic!("{}", e)
+                }
+            };
+
+            let request = CreateItemRequest::dependent(
+                &host_id,
+                "Backup size",
+                "backup.size",
+                ItemValueType::Unsigned,
+                &master_item_id,
+            )
+            .units("B")
+            .preprocessing_step(PreprocessingStep::JsonPath {
+                path: "$.size".to_string(),
+            })
+            .build()
+            .unwrap();
+
+            let item_id = match test_env.client.create_item(&test_env.session, &request) {
+                Ok(item_id) => item_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("item create error: {}", e);
+                    panic!("{}", e)
+                }
+            };
+
+            let request = GetItemsRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                item_ids: Some(vec![item_id]),
+                ..Default::default()
+            };
+
+            match test_env.client.get_items(&test_env.session, &request) {
+                Ok(items) => {
+                    let item = items.first().unwrap();
+
+                    assert_eq!(item.r#type, Some(ItemType::Dependent));
+                    assert_eq!(item.master_item_id, Some(master_item_id));
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("item get error: {}", e);
+                    panic!("{}", e)
                 }
-            }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
             }
         }
     }
+}
 
-    /// # get_triggers
-    ///
-    /// Implements `ZabbixApiClient::get_triggers`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "trigger")]
-    fn get_triggers<P: Serialize>(
-        &self,
-        session: &str,
-        params: &P,
-    ) -> Result<Vec<ZabbixTrigger>, ZabbixApiError> {
-        info!("getting triggers..");
+#[cfg(all(test, feature = "discovery"))]
+mod discoveryrule_tests {
+    use std::error::Error;
 
-        let api_request = get_api_request("trigger.get", params, Some(session.to_string()));
+    use log::error;
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+    use super::ZabbixApiClient;
+    use crate::discoveryrule::copy::CopyDiscoveryRulesRequest;
+    use crate::discoveryrule::get::GetDiscoveryRulesRequest;
+    use crate::discoveryrule::model::{
+        LldFilterOperator, LldOverride, LldOverrideObject, LldOverrideOperation,
+        LldOverrideOperator,
+    };
+    use crate::discoveryrule::update::UpdateDiscoveryRuleRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
-                let response =
-                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixTrigger>>>(&response_body)?;
+    #[test]
+    fn create_update_and_get_discovery_rule() {
+        init_logging();
 
-                match response.result {
-                    Some(results) => {
-                        info!("hosts found: {:?}", results);
-                        Ok(results)
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
+
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let rule_key = format!("lld{}", get_random_string());
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_discovery_rule(&rule_key);
+
+            let rule_id = test_env.latest_discovery_rule_id.to_string();
+
+            let override_rule = LldOverride::new("Don't discover test services", 1).operation(
+                LldOverrideOperation::new(
+                    LldOverrideObject::ItemPrototype,
+                    LldOverrideOperator::Contains,
+                    "test",
+                )
+                .discover(false),
+            );
+
+            let request = UpdateDiscoveryRuleRequest::builder(&rule_id)
+                .lifetime("1d")
+                .overrides(vec![override_rule])
+                .build();
+
+            match test_env
+                .client
+                .update_discovery_rule(&test_env.session, &request)
+            {
+                Ok(updated_rule_id) => {
+                    assert_eq!(updated_rule_id, test_env.latest_discovery_rule_id)
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("discovery rule update error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
+
+            let request = GetDiscoveryRulesRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                item_ids: Some(vec![rule_id]),
+                select_filter: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_lld_macro_paths: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_overrides: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
+            };
+
+            match test_env
+                .client
+                .get_discovery_rules(&test_env.session, &request)
+            {
+                Ok(rules) => {
+                    assert_eq!(rules.len(), 1);
+
+                    let rule = rules.first().unwrap();
+
+                    assert_eq!(rule.key_, rule_key);
+                    assert_eq!(rule.lifetime.as_deref(), Some("1d"));
+
+                    let filter = rule.filter.clone().unwrap();
+                    assert_eq!(filter.conditions[0].operator, LldFilterOperator::Matches);
+
+                    let lld_macro_paths = rule.lld_macro_paths.clone().unwrap();
+                    assert_eq!(lld_macro_paths[0].lld_macro, "{#NAME}");
+
+                    let overrides = rule.overrides.clone().unwrap();
+                    assert_eq!(overrides[0].operations.len(), 1);
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("discovery rule get error: {}", e);
+                    panic!("{}", e)
+                }
             }
         }
     }
 
-    /// # get_webscenarios
-    ///
-    /// Implements `ZabbixApiClient::get_webscenarios`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "webscenario")]
-    fn get_webscenarios<P: Serialize>(
-        &self,
-        session: &str,
-        params: &P,
-    ) -> Result<Vec<ZabbixWebScenario>, ZabbixApiError> {
-        info!("getting web-scenarios..");
+    #[test]
+    fn copy_and_delete_discovery_rule() {
+        init_logging();
 
-        let api_request = get_api_request("httptest.get", params, Some(session.to_string()));
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let target_host_name = get_random_string();
+            let rule_key = format!("lld{}", get_random_string());
 
-                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixWebScenario>>>(
-                    &response_body,
-                )?;
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&target_host_name, None);
 
-                match response.result {
-                    Some(results) => {
-                        info!("hosts found: {:?}", results);
-                        Ok(results)
+            let target_host_id = test_env.latest_host_id.to_string();
+
+            test_env
+                .create_host(&host_name, None)
+                .create_discovery_rule(&rule_key);
+
+            let rule_ids = vec![test_env.latest_discovery_rule_id.to_string()];
+
+            let request = CopyDiscoveryRulesRequest::new(rule_ids.clone(), vec![target_host_id]);
+
+            match test_env
+                .client
+                .copy_discovery_rules(&test_env.session, &request)
+            {
+                Ok(copied) => assert!(copied),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("discovery rule copy error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            match test_env
+                .client
+                .delete_discovery_rules(&test_env.session, &rule_ids)
+            {
+                Ok(deleted_rule_ids) => assert_eq!(deleted_rule_ids, rule_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("discovery rule delete error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
         }
     }
+}
 
-    /// # create_host_group
-    ///
-    /// Implements `ZabbixApiClient::create_host_group`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "host")]
-    fn create_host_group(
-        &self,
-        session: &str,
-        request: &CreateHostGroupRequest,
-    ) -> Result<u32, ZabbixApiError> {
-        use crate::hostgroup::create::CreateHostGroupResponse;
+#[cfg(all(test, feature = "discovery"))]
+mod prototype_tests {
+    use std::error::Error;
 
-        info!("creating host group '{}'..", request.name);
+    use log::error;
 
-        let api_request = get_api_request("hostgroup.create", request, Some(session.to_string()));
+    use super::ZabbixApiClient;
+    use crate::discoveryrule::model::PrototypeDiscover;
+    use crate::graphprototype::create::CreateGraphPrototypeRequest;
+    use crate::graphprototype::get::GetGraphPrototypesRequest;
+    use crate::graphprototype::model::GraphItem;
+    use crate::hostprototype::create::CreateHostPrototypeRequest;
+    use crate::hostprototype::get::GetHostPrototypesRequest;
+    use crate::item::update::UpdateItemRequest;
+    use crate::itemprototype::get::GetItemPrototypesRequest;
+    use crate::itemprototype::update::UpdateItemPrototypeRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::trigger::create::CreateTriggerRequest;
+    use crate::triggerprototype::create::CreateTriggerPrototypeRequest;
+    use crate::triggerprototype::get::GetTriggerPrototypesRequest;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+    #[test]
+    fn create_update_and_delete_item_prototype() {
+        init_logging();
 
-                let response = serde_json::from_str::<ZabbixApiResponse<CreateHostGroupResponse>>(
-                    &response_body,
-                )?;
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
 
-                match response.result {
-                    Some(result) => {
-                        info!("host group '{}' has been created", request.name);
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let rule_key = format!("lld{}", get_random_string());
+            let item_key = format!("value{}", get_random_string());
 
-                        match result.group_ids.first() {
-                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
-                            None => {
-                                error!("unexpected error, server returned empty id list");
-                                Err(ZabbixApiError::Error)
-                            }
-                        }
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_discovery_rule(&rule_key)
+                .create_item_prototype(&item_key);
+
+            let prototype_id = test_env.latest_item_prototype_id.to_string();
+
+            let request = UpdateItemPrototypeRequest::new(
+                UpdateItemRequest::builder(&prototype_id).units("B").build(),
+            )
+            .discover(false);
+
+            match test_env
+                .client
+                .update_item_prototype(&test_env.session, &request)
+            {
+                Ok(updated_id) => assert_eq!(updated_id, test_env.latest_item_prototype_id),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("item prototype update error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
-        }
-    }
 
-    /// # create_host
-    ///
-    /// Implements `ZabbixApiClient::create_host`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "host")]
-    fn create_host(
-        &self,
-        session: &str,
-        request: &CreateHostRequest,
-    ) -> Result<u32, ZabbixApiError> {
-        info!("creating host '{}'..", request.host);
+            let request = GetItemPrototypesRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                discovery_ids: Some(vec![test_env.latest_discovery_rule_id.to_string()]),
+                select_discovery_rule: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
+            };
 
-        let api_request = get_api_request("host.create", request, Some(session.to_string()));
+            match test_env
+                .client
+                .get_item_prototypes(&test_env.session, &request)
+            {
+                Ok(prototypes) => {
+                    assert_eq!(prototypes.len(), 1);
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+                    let prototype = prototypes.first().unwrap();
 
-                let response =
-                    serde_json::from_str::<ZabbixApiResponse<CreateHostResponse>>(&response_body)?;
+                    assert_eq!(prototype.item.key_, format!("{item_key}[{{#NAME}}]"));
+                    assert_eq!(prototype.item.units.as_deref(), Some("B"));
+                    assert_eq!(prototype.discover, Some(PrototypeDiscover::DontDiscover));
+                    assert_eq!(prototype.discovery_rule.as_ref().unwrap().key_, rule_key);
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-                match response.result {
-                    Some(result) => {
-                        info!("host '{}' has been created", request.host);
+                    error!("item prototype get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
 
-                        match result.host_ids.first() {
-                            Some(host_id) => {
-                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
-                            }
-                            None => {
-                                error!("unexpected error, server returned empty id list");
-                                Err(ZabbixApiError::Error)
-                            }
-                        }
+            match test_env
+                .client
+                .delete_item_prototypes(&test_env.session, std::slice::from_ref(&prototype_id))
+            {
+                Ok(deleted_ids) => assert_eq!(deleted_ids, vec![prototype_id]),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("item prototype delete error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
         }
     }
 
-    /// # update_host
-    ///
-    /// Implements `ZabbixApiClient::update_host`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "host")]
-    fn update_host(
-        &self,
-        session: &str,
-        request: &UpdateHostRequest,
-    ) -> Result<u32, ZabbixApiError> {
-        info!("updating host '{:?}'..", &serde_json::to_string(request));
-
-        let api_request = get_api_request("host.update", request, Some(session.to_string()));
+    #[test]
+    fn create_trigger_and_graph_prototypes() {
+        init_logging();
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
 
-                let response =
-                    serde_json::from_str::<ZabbixApiResponse<UpdateHostResponse>>(&response_body)?;
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let rule_key = format!("lld{}", get_random_string());
+            let item_key = format!("value{}", get_random_string());
 
-                match response.result {
-                    Some(result) => {
-                        info!("host '{}' has been updated", request.hostid);
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_discovery_rule(&rule_key)
+                .create_item_prototype(&item_key);
+
+            let trigger = CreateTriggerRequest {
+                description: "Value of {#NAME} is too high".to_string(),
+                expression: format!("last(/{host_name}/{item_key}[{{#NAME}}])>100"),
+                priority: 2,
+                recovery_mode: None,
+                recovery_expression: None,
+                url: None,
+                event_name: None,
+                dependencies: vec![],
+                tags: vec![],
+            };
 
-                        match result.host_ids.first() {
-                            Some(host_id) => {
-                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
-                            }
-                            None => {
-                                error!("unexpected error, server returned empty id list");
-                                Err(ZabbixApiError::Error)
-                            }
-                        }
-                    }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
+            let request = CreateTriggerPrototypeRequest::new(trigger);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+            if let Err(e) = test_env
+                .client
+                .create_trigger_prototype(&test_env.session, &request)
+            {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
                 }
+
+                error!("trigger prototype create error: {}", e);
+                panic!("{}", e)
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
+
+            let request = CreateGraphPrototypeRequest::builder("Value of {#NAME}")
+                .item(GraphItem::new(test_env.latest_item_prototype_id, "00AA00"))
+                .build();
+
+            if let Err(e) = test_env
+                .client
+                .create_graph_prototype(&test_env.session, &request)
+            {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("graph prototype create error: {}", e);
+                panic!("{}", e)
             }
-        }
-    }
 
-    /// # delete_host
-    ///
-    /// Implements `ZabbixApiClient::delete_host`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "host")]
-    fn delete_hosts(
-        &self,
-        session: &str,
-        host_ids: &Vec<String>,
-    ) -> Result<Vec<String>, ZabbixApiError> {
-        info!("deleting hosts '{:?}'..", &serde_json::to_string(host_ids));
+            let discovery_ids = vec![test_env.latest_discovery_rule_id.to_string()];
 
-        let api_request = get_api_request("host.delete", host_ids, Some(session.to_string()));
+            let request = GetTriggerPrototypesRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                discovery_ids: Some(discovery_ids.clone()),
+                ..Default::default()
+            };
+
+            match test_env
+                .client
+                .get_trigger_prototypes(&test_env.session, &request)
+            {
+                Ok(prototypes) => {
+                    assert_eq!(prototypes.len(), 1);
+                    assert_eq!(prototypes[0].trigger.priority, 2);
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+                    error!("trigger prototype get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
 
-                let response =
-                    serde_json::from_str::<ZabbixApiResponse<UpdateHostResponse>>(&response_body)?;
+            let request = GetGraphPrototypesRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                discovery_ids: Some(discovery_ids),
+                select_graph_items: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
+            };
 
-                match response.result {
-                    Some(result) => {
-                        debug!("hosts '{:?}' have been deleted", result.host_ids);
+            match test_env
+                .client
+                .get_graph_prototypes(&test_env.session, &request)
+            {
+                Ok(prototypes) => {
+                    assert_eq!(prototypes.len(), 1);
 
-                        Ok(result.host_ids)
+                    let graph_items = prototypes[0].graph_items.clone().unwrap();
+                    assert_eq!(
+                        graph_items[0].item_id,
+                        test_env.latest_item_prototype_id.to_string()
+                    );
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("graph prototype get error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
         }
     }
 
-    /// # create_item
-    ///
-    /// Implements `ZabbixApiClient::create_item`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "item")]
-    fn create_item(
-        &self,
-        session: &str,
-        request: &CreateItemRequest,
-    ) -> Result<u32, ZabbixApiError> {
-        info!(
-            "creating item with key '{}' for host id {}..",
-            request.key_, request.host_id
-        );
+    #[test]
+    fn create_and_delete_host_prototype() {
+        init_logging();
 
-        let api_request = get_api_request("item.create", request, Some(session.to_string()));
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let rule_key = format!("lld{}", get_random_string());
 
-                let response =
-                    serde_json::from_str::<ZabbixApiResponse<CreateItemResponse>>(&response_body)?;
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_discovery_rule(&rule_key);
 
-                match response.result {
-                    Some(result) => {
-                        info!("item '{}' has been created", request.key_);
+            let request =
+                CreateHostPrototypeRequest::builder(test_env.latest_discovery_rule_id, "{#NAME}")
+                    .name("Discovered {#NAME}")
+                    .group(test_env.latest_host_group_id)
+                    .group_prototype("Group {#NAME}")
+                    .tag("source", &host_name)
+                    .build();
 
-                        match result.item_ids.first() {
-                            Some(host_id) => {
-                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
-                            }
-                            None => {
-                                error!("unexpected error, server returned empty id list");
-                                Err(ZabbixApiError::Error)
-                            }
-                        }
+            let host_prototype_id = match test_env
+                .client
+                .create_host_prototype(&test_env.session, &request)
+            {
+                Ok(host_prototype_id) => host_prototype_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("host prototype create error: {}", e);
+                    panic!("{}", e)
                 }
-            }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
-        }
-    }
+            };
 
-    /// # create_trigger
-    ///
-    /// Implements `ZabbixApiClient::create_trigger`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "trigger")]
-    fn create_trigger(
-        &self,
-        session: &str,
-        request: &CreateTriggerRequest,
-    ) -> Result<u32, ZabbixApiError> {
-        info!(
-            "creating trigger '{}' with expression '{}'..",
-            request.description, request.expression
-        );
+            let request = GetHostPrototypesRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                host_ids: Some(vec![host_prototype_id.clone()]),
+                select_group_links: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_group_prototypes: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
+            };
 
-        let api_request = get_api_request("trigger.create", request, Some(session.to_string()));
+            match test_env
+                .client
+                .get_host_prototypes(&test_env.session, &request)
+            {
+                Ok(prototypes) => {
+                    assert_eq!(prototypes.len(), 1);
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+                    let prototype = prototypes.first().unwrap();
 
-                let response = serde_json::from_str::<ZabbixApiResponse<CreateTriggerResponse>>(
-                    &response_body,
-                )?;
+                    assert_eq!(prototype.host, "{#NAME}");
+                    assert_eq!(
+                        prototype.group_links.clone().unwrap()[0].group_id,
+                        test_env.latest_host_group_id.to_string()
+                    );
+                    assert_eq!(
+                        prototype.group_prototypes.clone().unwrap()[0].name,
+                        "Group {#NAME}"
+                    );
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-                match response.result {
-                    Some(result) => {
-                        info!("trigger '{}' has been created", request.description);
+                    error!("host prototype get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
 
-                        match result.trigger_ids.first() {
-                            Some(host_id) => {
-                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
-                            }
-                            None => {
-                                error!("unexpected error, server returned empty id list");
-                                Err(ZabbixApiError::Error)
-                            }
-                        }
+            match test_env
+                .client
+                .delete_host_prototypes(&test_env.session, std::slice::from_ref(&host_prototype_id))
+            {
+                Ok(deleted_ids) => assert_eq!(deleted_ids, vec![host_prototype_id]),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("host prototype delete error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
         }
     }
+}
 
-    /// # create_webscenario
-    ///
-    /// Implements `ZabbixApiClient::create_webscenario`.
-    ///
-    /// See the trait documentation for more details.
-    #[cfg(feature = "webscenario")]
-    fn create_webscenario(
-        &self,
-        session: &str,
-        request: &CreateWebScenarioRequest,
-    ) -> Result<u32, ZabbixApiError> {
-        info!(
-            "creating web-scenario '{}' for host id '{}'..",
-            request.name, request.host_id
-        );
+#[cfg(all(test, feature = "trigger"))]
+mod trigger_tests {
+    use std::error::Error;
 
-        let api_request = get_api_request("httptest.create", request, Some(session.to_string()));
+    use log::error;
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+    use super::ZabbixApiClient;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::trigger::dependency::AddTriggerDependencyRequest;
+    use crate::trigger::expression::{ItemRef, TriggerExpression};
+    use crate::trigger::get::GetTriggersRequest;
+    use crate::trigger::model::{TriggerStatus, TriggerValue, ZabbixTriggerTag};
+    use crate::trigger::update::UpdateTriggerRequest;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
-                let response = serde_json::from_str::<ZabbixApiResponse<CreateWebScenarioResponse>>(
-                    &response_body,
-                )?;
+    #[test]
+    fn update_disable_and_delete_triggers() {
+        init_logging();
 
-                match response.result {
-                    Some(result) => {
-                        info!("web-scenario '{}' has been created", request.name);
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
 
-                        match result.http_test_ids.first() {
-                            Some(host_id) => {
-                                host_id.parse::<u32>().map_err(|_| ZabbixApiError::Error)
-                            }
-                            None => {
-                                error!("unexpected error, server returned empty id list");
-                                Err(ZabbixApiError::Error)
-                            }
-                        }
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let item_name = get_random_string();
+            let item_key = format!("key{}", get_random_string());
+
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_item(&item_name, &item_key)
+                .create_trigger(
+                    &get_random_string(),
+                    &format!("last(/{host_name}/{item_key})=0"),
+                );
+
+            let trigger_ids = vec![test_env.latest_trigger_id.to_string()];
+
+            let request = UpdateTriggerRequest::builder(test_env.latest_trigger_id)
+                .priority(2)
+                .comments("website decommissioned")
+                .build();
+
+            match test_env.client.update_trigger(&test_env.session, &request) {
+                Ok(trigger_id) => assert_eq!(trigger_id, test_env.latest_trigger_id),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
 
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+                    error!("trigger update error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
-        }
-    }
 
-    #[cfg(feature = "user")]
-    fn get_users<P: Serialize>(
-        &self,
-        session: &str,
-        params: &P,
-    ) -> Result<Vec<ZabbixUser>, ZabbixApiError> {
-        info!("getting users..");
+            match test_env
+                .client
+                .disable_triggers(&test_env.session, &trigger_ids)
+            {
+                Ok(disabled_ids) => assert_eq!(disabled_ids, trigger_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-        let api_request = get_api_request("user.get", params, Some(session.to_string()));
+                    error!("trigger disable error: {}", e);
+                    panic!("{}", e)
+                }
+            }
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(&session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+            match test_env
+                .client
+                .enable_triggers(&test_env.session, &trigger_ids)
+            {
+                Ok(enabled_ids) => assert_eq!(enabled_ids, trigger_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-                let response =
-                    serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixUser>>>(&response_body)?;
+                    error!("trigger enable error: {}", e);
+                    panic!("{}", e)
+                }
+            }
 
-                match response.result {
-                    Some(results) => {
-                        info!("users found: {:?}", results.len());
-                        Ok(results)
+            match test_env
+                .client
+                .delete_triggers(&test_env.session, &trigger_ids)
+            {
+                Ok(deleted_ids) => assert_eq!(deleted_ids, trigger_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+
+                    error!("trigger delete error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
         }
     }
 
-    /// # create_user_group
-    ///
-    /// Implements `ZabbixApiClient::create_user_group`.
-    ///
-    /// API: https://www.zabbix.com/documentation/current/en/manual/api/reference/usergroup/create
-    #[cfg(feature = "user")]
-    fn create_user_group(
-        &self,
-        session: &str,
-        request: &CreateUserGroupRequest,
-    ) -> Result<u32, ZabbixApiError> {
-        info!("creating user group '{}'..", request.name);
+    #[test]
+    fn add_and_delete_trigger_dependencies() {
+        init_logging();
 
-        let api_request = get_api_request("usergroup.create", request, Some(session.to_string()));
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let item_name = get_random_string();
+            let item_key = format!("key{}", get_random_string());
 
-                let response = serde_json::from_str::<ZabbixApiResponse<CreateUserGroupResponse>>(
-                    &response_body,
-                )?;
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_item(&item_name, &item_key)
+                .create_trigger(
+                    &get_random_string(),
+                    &format!("last(/{host_name}/{item_key})=0"),
+                );
 
-                match response.result {
-                    Some(result) => {
-                        info!("user group '{}' has been created", request.name);
+            let master_trigger_id = test_env.latest_trigger_id.to_string();
 
-                        match result.user_group_ids.first() {
-                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
-                            None => {
-                                error!("unexpected error, server returned empty id list");
-                                Err(ZabbixApiError::Error)
-                            }
-                        }
-                    }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
-                }
-            }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
-        }
-    }
+            test_env.create_trigger(
+                &get_random_string(),
+                &format!("last(/{host_name}/{item_key})>100"),
+            );
 
-    #[cfg(feature = "user")]
-    fn get_user_groups<P: Serialize>(
-        &self,
-        session: &str,
-        params: &P,
-    ) -> Result<Vec<ZabbixUserGroup>, ZabbixApiError> {
-        info!("getting user groups..");
+            let trigger_ids = vec![test_env.latest_trigger_id.to_string()];
 
-        let api_request = get_api_request("usergroup.get", params, Some(session.to_string()));
+            let dependencies = vec![AddTriggerDependencyRequest::new(
+                test_env.latest_trigger_id,
+                &master_trigger_id,
+            )];
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+            match test_env
+                .client
+                .add_trigger_dependencies(&test_env.session, &dependencies)
+            {
+                Ok(ids) => assert_eq!(ids, trigger_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-                let response = serde_json::from_str::<ZabbixApiResponse<Vec<ZabbixUserGroup>>>(
-                    &response_body,
-                )?;
+                    error!("trigger dependencies add error: {}", e);
+                    panic!("{}", e)
+                }
+            }
 
-                match response.result {
-                    Some(results) => {
-                        info!("user groups found: {:?}", results.len());
-                        Ok(results)
+            match test_env
+                .client
+                .delete_trigger_dependencies(&test_env.session, &trigger_ids)
+            {
+                Ok(ids) => assert_eq!(ids, trigger_ids),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+
+                    error!("trigger dependencies delete error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
         }
     }
 
-    #[cfg(feature = "user")]
-    fn create_user(
-        &self,
-        session: &str,
-        request: &CreateUserRequest,
-    ) -> Result<u32, ZabbixApiError> {
-        info!("creating user '{}'..", request.username);
+    #[test]
+    fn get_triggers_with_selected_objects() {
+        init_logging();
 
-        let params = [request];
-        let api_request = get_api_request("user.create", &params, Some(session.to_string()));
+        if are_integration_tests_enabled() {
+            let mut test_env = TestEnvBuilder::build();
 
-        match send_post_request(
-            &self.client,
-            &self.api_endpoint_url,
-            Some(session),
-            api_request,
-        ) {
-            Ok(response_body) => {
-                debug!("[response body]");
-                debug!("{response_body}");
-                debug!("[/response body]");
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let item_name = get_random_string();
+            let item_key = format!("key{}", get_random_string());
+            let expression = TriggerExpression::last(ItemRef::new(&host_name, &item_key))
+                .equals(0)
+                .to_string();
 
-                let response =
-                    serde_json::from_str::<ZabbixApiResponse<CreateUserResponse>>(&response_body)?;
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_item(&item_name, &item_key)
+                .create_trigger(&get_random_string(), &expression);
+
+            let request = UpdateTriggerRequest::builder(test_env.latest_trigger_id)
+                .tags(vec![ZabbixTriggerTag {
+                    tag: "scope".to_string(),
+                    value: "availability".to_string(),
+                }])
+                .build();
+
+            if let Err(e) = test_env.client.update_trigger(&test_env.session, &request) {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
 
-                match response.result {
-                    Some(result) => {
-                        info!("user '{}' has been created", request.username);
+                error!("trigger update error: {}", e);
+                panic!("{}", e)
+            }
 
-                        match result.user_ids.first() {
-                            Some(id) => id.parse::<u32>().map_err(|_| ZabbixApiError::Error),
-                            None => {
-                                error!("unexpected error, server returned empty id list");
-                                Err(ZabbixApiError::Error)
-                            }
-                        }
+            let request = GetTriggersRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                trigger_ids: Some(vec![test_env.latest_trigger_id.to_string()]),
+                select_functions: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_hosts: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_items: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_tags: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_dependencies: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                expand_expression: true,
+                ..Default::default()
+            };
+
+            match test_env.client.get_triggers(&test_env.session, &request) {
+                Ok(triggers) => {
+                    assert_eq!(triggers.len(), 1);
+
+                    let trigger = triggers.first().unwrap();
+
+                    assert_eq!(trigger.expression, expression);
+                    assert_eq!(trigger.status, Some(TriggerStatus::Enabled));
+                    assert_eq!(trigger.value, Some(TriggerValue::Ok));
+                    assert_eq!(trigger.functions.clone().unwrap()[0].function, "last");
+                    assert_eq!(trigger.hosts.clone().unwrap()[0].host, host_name);
+                    assert_eq!(trigger.items.clone().unwrap()[0].key_, item_key);
+                    assert_eq!(trigger.tags.clone().unwrap()[0].tag, "scope");
+                    assert!(trigger.dependencies.clone().unwrap().is_empty());
+
+                    let parsed = trigger.parse_expression().unwrap();
+                    assert_eq!(parsed.item_refs()[0].full_key(), item_key);
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    None => match response.error {
-                        Some(error) => {
-                            error!("{:?}", error);
-                            Err(ZabbixApiError::ApiCallError { zabbix: error })
-                        }
-                        None => Err(ZabbixApiError::BadRequestError),
-                    },
+
+                    error!("triggers get error: {}", e);
+                    panic!("{}", e)
                 }
             }
-            Err(e) => {
-                error!("{}", e);
-                Err(e)
-            }
         }
     }
 }
 
-#[cfg(all(test, feature = "user"))]
-mod user_tests {
-    use log::{error, info};
-    use serde::Serialize;
-    use std::error::Error as StdError;
+#[cfg(all(test, feature = "event"))]
+mod event_tests {
+    use std::error::Error;
+    use std::time::{Duration, SystemTime};
+
+    use log::error;
 
     use super::ZabbixApiClient;
+    use crate::event::get::GetEventsRequest;
+    use crate::event::model::{EventSeverity, EventSource};
+    use crate::problem::get::GetProblemsRequest;
     use crate::tests::builder::TestEnvBuilder;
     use crate::tests::integration::are_integration_tests_enabled;
     use crate::tests::logging::init_logging;
     use crate::tests::strings::get_random_string;
-    use crate::user::create::{CreateUserRequest, UserGroupId};
-    use crate::usergroup::get::{GetUserGroupsRequest, UserGroupFilter};
-    use crate::usergroup::model::CreateUserGroupRequest;
     use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
     #[test]
-    fn get_users_test() {
+    fn get_problems_of_new_host() {
         init_logging();
 
         if are_integration_tests_enabled() {
             let mut test_env = TestEnvBuilder::build();
-            test_env.get_session();
-
-            #[derive(Serialize)]
-            struct UserFilterParams {
-                output: String,
-                filter: UserFilter,
-            }
 
-            #[derive(Serialize)]
-            struct UserFilter {
-                alias: Vec<String>,
-            }
+            let group_name = get_random_string();
+            let host_name = get_random_string();
 
-            let api_user_alias = test_env.integration_tests_config.zabbix_api_user.clone();
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None);
 
-            let params = UserFilterParams {
-                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
-                filter: UserFilter {
-                    alias: vec![api_user_alias.clone()],
-                },
+            let request = GetProblemsRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                host_ids: Some(vec![test_env.latest_host_id.to_string()]),
+                severities: Some(vec![EventSeverity::Warning, EventSeverity::Disaster]),
+                recent: Some(true),
+                select_acknowledges: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_tags: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_suppression_data: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
             };
 
-            match test_env.client.get_users(&test_env.session, &params) {
-                Ok(users) => {
-                    assert!(!users.is_empty(), "Expected to find at least one user");
-                    let found_user = users.iter().find(|u| u.alias == api_user_alias);
-                    assert!(
-                        found_user.is_some(),
-                        "Expected to find user with alias '{}'",
-                        api_user_alias
-                    );
-                    if let Some(user) = found_user {
-                        info!("Successfully fetched user: {:?}", user);
-                    }
-                }
+            match test_env.client.get_problems(&test_env.session, &request) {
+                Ok(problems) => assert!(problems.is_empty()),
                 Err(e) => {
-                    error!("get_users test failed: {}", e);
-                    if let Some(source) = e.source() {
-                        error!("Caused by: {}", source);
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    panic!("get_users test failed");
+
+                    error!("problems get error: {}", e);
+                    panic!("{}", e)
                 }
             }
         }
     }
 
     #[test]
-    fn get_user_groups_test() {
+    fn get_recent_events() {
         init_logging();
 
         if are_integration_tests_enabled() {
             let mut test_env = TestEnvBuilder::build();
-            test_env.get_session();
-
-            let user_group_name = format!("test_get_ug_{}", get_random_string());
-            let create_request = CreateUserGroupRequest {
-                name: user_group_name.clone(),
-                ..Default::default()
-            };
 
-            let user_group_id = test_env
-                .client
-                .create_user_group(&test_env.session, &create_request)
-                .expect("Failed to create user group for get_user_groups_test");
-            info!(
-                "Created user group '{}' with ID '{}'",
-                user_group_name, user_group_id
-            );
+            test_env.get_session();
 
-            let get_request = GetUserGroupsRequest {
+            let request = GetEventsRequest::<()> {
                 output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
-                filter: Some(UserGroupFilter {
-                    name: Some(vec![user_group_name.clone()]),
-                }),
-                select_users: Some("extend".to_string()),
-                select_rights: Some("extend".to_string()),
+                source: Some(EventSource::Trigger),
+                time_from: Some(SystemTime::now() - Duration::from_secs(3600)),
+                select_tags: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                limit: Some(10),
                 ..Default::default()
             };
 
-            match test_env
-                .client
-                .get_user_groups(&test_env.session, &get_request)
-            {
-                Ok(user_groups) => {
-                    assert!(
-                        !user_groups.is_empty(),
-                        "Expected to find at least one user group"
-                    );
-                    let found_group = user_groups.iter().find(|ug| ug.name == user_group_name);
-                    assert!(
-                        found_group.is_some(),
-                        "Expected to find user group with name '{}'",
-                        user_group_name
-                    );
-                    if let Some(group) = found_group {
-                        info!("Successfully fetched user group: {:?}", group);
-                        assert_eq!(group.usrgrp_id, user_group_id.to_string());
-                    }
-                }
+            match test_env.client.get_events(&test_env.session, &request) {
+                Ok(events) => assert!(events.len() <= 10),
                 Err(e) => {
-                    error!("get_user_groups test failed: {}", e);
-                    if let Some(source) = e.source() {
-                        error!("Caused by: {}", source);
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
-                    panic!("get_user_groups test failed");
+
+                    error!("events get error: {}", e);
+                    panic!("{}", e)
                 }
             }
         }
     }
+}
+
+#[cfg(all(test, feature = "event"))]
+mod event_acknowledge_tests {
+    use log::info;
+
+    use super::ZabbixApiClient;
+    use crate::error::ZabbixApiError;
+    use crate::event::acknowledge::AcknowledgeEventsRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
 
     #[test]
-    fn create_user_test() {
+    fn acknowledge_unknown_event_returns_api_error() {
         init_logging();
 
         if are_integration_tests_enabled() {
             let mut test_env = TestEnvBuilder::build();
-            test_env.get_session();
-
-            let user_group_name = format!("test_ug_for_user_create_{}", get_random_string());
-            let create_ug_request = CreateUserGroupRequest {
-                name: user_group_name.clone(),
-                ..Default::default()
-            };
-
-            let user_group_id = test_env
-                .client
-                .create_user_group(&test_env.session, &create_ug_request)
-                .expect("Failed to create user group for create_user_test");
-            info!(
-                "Created user group '{}' with ID '{}' for create_user_test",
-                user_group_name, user_group_id
-            );
 
-            let user_alias = format!("test_user_{}", get_random_string());
-            let user_passwd = get_random_string();
-            let role_id = "3"; // Default "User role" ID
+            test_env.get_session();
 
-            let create_user_req = CreateUserRequest {
-                username: user_alias.clone(),
-                passwd: user_passwd,
-                roleid: role_id.to_string(),
-                usrgrps: vec![UserGroupId {
-                    usrgrpid: user_group_id.to_string(),
-                }],
-                name: Some("Test".to_string()),
-                surname: Some("User".to_string()),
-                ..Default::default()
-            };
+            let request = AcknowledgeEventsRequest::builder(vec!["999999999".to_string()])
+                .acknowledge()
+                .message("test")
+                .build()
+                .unwrap();
 
             match test_env
                 .client
-                .create_user(&test_env.session, &create_user_req)
+                .acknowledge_events(&test_env.session, &request)
             {
-                Ok(user_id) => {
-                    assert!(user_id > 0, "Expected a valid user ID to be returned");
-                    info!(
-                        "Successfully created user '{}' with ID '{}'",
-                        user_alias, user_id
-                    );
-                }
-                Err(e) => {
-                    error!("create_user test failed: {}", e);
-                    if let Some(source) = e.source() {
-                        error!("Caused by: {}", source);
-                    }
-                    panic!("create_user test failed");
-                }
+                Ok(ids) => panic!("unexpected result: {:?}", ids),
+                Err(ZabbixApiError::ApiCallError { zabbix }) => info!("{}", zabbix),
+                Err(e) => panic!("unexpected error: {}", e),
             }
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
+#[cfg(all(test, feature = "history"))]
+mod history_tests {
     use std::error::Error;
+    use std::time::{Duration, SystemTime};
 
-    use log::{error, info};
-    use reqwest::blocking::Client;
-    use serde::Serialize;
+    use log::error;
 
-    use crate::client::client::ZabbixApiClient;
-    use crate::host::create::TlsConfig;
-    use crate::host::get::GetHostsRequest;
-    use crate::host::model::ZabbixHost;
-    use crate::host::update::UpdateHostRequest;
-    use crate::hostgroup::get::GetHostGroupsRequest;
-    use crate::item::create::CreateItemRequest;
-    use crate::item::get::GetItemsRequestById;
+    use crate::history::get::GetHistoryRequest;
+    use crate::history::model::FloatHistory;
+    use crate::history::window::HistoryWindows;
     use crate::tests::builder::TestEnvBuilder;
-    use crate::tests::integration::{are_integration_tests_enabled, get_integration_tests_config};
+    use crate::tests::integration::are_integration_tests_enabled;
     use crate::tests::logging::init_logging;
-    use crate::tests::strings::get_random_hex_string;
     use crate::tests::strings::get_random_string;
-    use crate::trigger::create::CreateTriggerRequest;
-    use crate::trigger::get::GetTriggerByIdRequest;
-    use crate::usergroup::model::{CreateUserGroupRequest, UserGroupPermission, UserGroupUser};
-    use crate::webscenario::create::CreateWebScenarioRequest;
-    use crate::webscenario::get::GetWebScenarioByIdRequest;
-    use crate::webscenario::model::ZabbixWebScenarioStep;
     use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
-    use super::ZabbixApiClientImpl;
-
-    #[test]
-    fn get_api_info() {
-        if are_integration_tests_enabled() {
-            let test_env = TestEnvBuilder::build();
-
-            match test_env.client.get_api_info() {
-                Ok(result) => {
-                    assert!(!result.is_empty())
-                }
-                Err(e) => {
-                    error!("error: {}", e);
-                    panic!("unexpected error")
-                }
-            }
-        }
-    }
-
     #[test]
-    fn session_should_be_returned() {
+    fn get_history_of_new_item_in_windows() {
         init_logging();
 
         if are_integration_tests_enabled() {
-            let http_client = Client::new();
+            let mut test_env = TestEnvBuilder::build();
 
-            let tests_config = get_integration_tests_config();
+            let group_name = get_random_string();
+            let host_name = get_random_string();
+            let item_name = get_random_string();
+            let item_key = format!("key{}", get_random_string());
 
-            let client = ZabbixApiClientImpl::new(http_client, &tests_config.zabbix_api_url);
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None)
+                .create_item(&item_name, &item_key);
 
-            match client.get_auth_session(
-                &tests_config.zabbix_api_user,
-                &tests_config.zabbix_api_password,
-            ) {
-                Ok(session) => assert!(session.len() > 0),
-                Err(e) => {
-                    error!("error: {}", e);
-                    panic!("unexpected error")
+            let request = GetHistoryRequest::<FloatHistory> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                item_ids: Some(vec![test_env.latest_item_id.to_string()]),
+                time_from: Some(SystemTime::now() - Duration::from_secs(3 * 3600)),
+                ..Default::default()
+            };
+
+            let windows = HistoryWindows::new(
+                &test_env.client,
+                &test_env.session,
+                request,
+                Duration::from_secs(3600),
+            )
+            .unwrap();
+
+            let mut window_count = 0;
+
+            for result in windows {
+                match result {
+                    Ok(records) => assert!(records.is_empty()),
+                    Err(e) => {
+                        if let Some(inner_source) = e.source() {
+                            println!("Caused by: {}", inner_source);
+                        }
+
+                        error!("history get error: {}", e);
+                        panic!("{}", e)
+                    }
                 }
+
+                window_count += 1;
             }
+
+            assert!(window_count >= 3);
         }
     }
+}
+
+#[cfg(all(test, feature = "history", feature = "v7"))]
+mod history_push_tests {
+    use std::error::Error;
+    use std::time::SystemTime;
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::history::push::HistoryPushValue;
+    use crate::item::create::CreateItemRequest;
+    use crate::item::model::ItemValueType;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
 
     #[test]
-    fn raw_api_call_test() {
+    fn push_values_to_trapper_item() {
         init_logging();
 
         if are_integration_tests_enabled() {
             let mut test_env = TestEnvBuilder::build();
-            test_env.get_session();
 
-            #[derive(Serialize)]
-            struct Params {
-                pub filter: Filter,
-            }
+            let group_name = get_random_string();
+            let host_name = get_random_string();
 
-            #[derive(Serialize)]
-            struct Filter {
-                pub host: Vec<String>,
-            }
+            test_env
+                .get_session()
+                .create_host_group(&group_name)
+                .create_host(&host_name, None);
 
-            let params = Params {
-                filter: Filter {
-                    host: vec!["Zabbix server".to_string()],
-                },
+            let request = CreateItemRequest::trapper(
+                test_env.latest_host_id,
+                "Queue size",
+                "queue.size",
+                ItemValueType::Unsigned,
+            )
+            .build()
+            .unwrap();
+
+            let item_id = match test_env.client.create_item(&test_env.session, &request) {
+                Ok(item_id) => item_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("item create error: {}", e);
+                    panic!("{}", e)
+                }
             };
 
-            match test_env.client.raw_api_call::<Params, Vec<ZabbixHost>>(
-                &test_env.session,
-                "host.get",
-                &params,
-            ) {
-                Ok(response) => {
-                    let results = response.result.unwrap();
-                    info!("{:?}", results.first().unwrap());
-                    assert_eq!(1, results.len())
+            let values = vec![
+                HistoryPushValue::for_item(&item_id, 15).at(SystemTime::now()),
+                HistoryPushValue::for_host_key(&host_name, "queue.size", 20),
+                HistoryPushValue::for_host_key(&host_name, "unknown.key", 1),
+            ];
+
+            match test_env.client.push_history(&test_env.session, &values) {
+                Ok(results) => {
+                    assert_eq!(results.len(), 3);
+                    assert!(results[0].is_success());
+                    assert_eq!(results[1].item_id.as_deref(), Some(item_id.as_str()));
+                    assert!(!results[2].is_success());
                 }
                 Err(e) => {
-                    error!("api call error: {}", e);
-                    panic!("unexpected api call error")
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("history push error: {}", e);
+                    panic!("{}", e)
                 }
             }
         }
     }
+}
+
+#[cfg(all(test, feature = "trend"))]
+mod trend_tests {
+    use std::error::Error;
+    use std::time::{Duration, SystemTime};
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::trend::get::GetTrendsRequest;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
     #[test]
-    fn get_host_groups_test() {
+    fn get_trends_of_new_item() {
         init_logging();
 
         if are_integration_tests_enabled() {
             let mut test_env = TestEnvBuilder::build();
 
             let group_name = get_random_string();
-            let group_name2 = get_random_string();
-            let group_name3 = get_random_string();
+            let host_name = get_random_string();
+            let item_name = get_random_string();
+            let item_key = format!("key{}", get_random_string());
 
             test_env
                 .get_session()
                 .create_host_group(&group_name)
-                .create_host_group(&group_name2)
-                .create_host_group(&group_name3);
-
-            #[derive(Serialize)]
-            struct Filter {
-                pub name: Vec<String>,
-            }
+                .create_host(&host_name, None)
+                .create_item(&item_name, &item_key);
 
-            let request = GetHostGroupsRequest {
-                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
-                filter: Filter {
-                    name: vec![group_name2.to_string()],
-                },
+            let request = GetTrendsRequest {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                item_ids: Some(vec![test_env.latest_item_id.to_string()]),
+                time_from: Some(SystemTime::now() - Duration::from_secs(7 * 24 * 3600)),
+                ..Default::default()
             };
 
-            match test_env.client.get_host_groups(&test_env.session, &request) {
-                Ok(host_groups) => {
-                    assert_eq!(host_groups.len(), 1);
-
-                    let host_group = host_groups.first().unwrap();
-
-                    assert_eq!(&host_group.name, &group_name2)
-                }
+            match test_env
+                .client
+                .get_trends::<f64>(&test_env.session, &request)
+            {
+                Ok(trends) => assert!(trends.is_empty()),
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
 
-                    error!("host group get error: {}", e);
+                    error!("trends get error: {}", e);
                     panic!("{}", e)
                 }
             }
         }
     }
+}
+
+#[cfg(all(test, feature = "maintenance"))]
+mod maintenance_tests {
+    use std::error::Error;
+    use std::time::Duration;
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::maintenance::create::CreateMaintenanceRequest;
+    use crate::maintenance::get::GetMaintenancesRequest;
+    use crate::maintenance::model::{TimePeriod, Weekday};
+    use crate::maintenance::update::UpdateMaintenanceRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
     #[test]
-    fn get_hosts_test() {
+    fn create_update_get_and_delete_maintenance() {
         init_logging();
 
         if are_integration_tests_enabled() {
             let mut test_env = TestEnvBuilder::build();
 
             let group_name = get_random_string();
-            let host_name1 = get_random_string();
-            let host_name2 = get_random_string();
-            let host_name3 = get_random_string();
+            let host_name = get_random_string();
+            let maintenance_name = get_random_string();
 
             test_env
                 .get_session()
                 .create_host_group(&group_name)
-                .create_host(&host_name1, None)
-                .create_host(&host_name2, None)
-                .create_host(&host_name3, None);
+                .create_host(&host_name, None);
 
-            #[derive(Serialize)]
-            struct Filter {
-                pub host: Vec<String>,
+            let host_ids = vec![test_env.latest_host_id.to_string()];
+
+            let request = CreateMaintenanceRequest::for_hosts(
+                &maintenance_name,
+                &host_ids,
+                Duration::from_secs(30 * 60),
+            )
+            .unwrap();
+
+            let maintenance_id = match test_env
+                .client
+                .create_maintenance(&test_env.session, &request)
+            {
+                Ok(maintenance_id) => maintenance_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("maintenance create error: {}", e);
+                    panic!("{}", e)
+                }
+            };
+
+            let request = UpdateMaintenanceRequest::builder(&maintenance_id)
+                .description("deploy")
+                .time_periods(vec![TimePeriod::Weekly {
+                    every: 1,
+                    days: Weekday::Saturday | Weekday::Sunday,
+                    start_time: Duration::from_secs(3600),
+                    period: Duration::from_secs(3600),
+                }])
+                .build();
+
+            if let Err(e) = test_env
+                .client
+                .update_maintenance(&test_env.session, &request)
+            {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("maintenance update error: {}", e);
+                panic!("{}", e)
             }
 
-            let request = GetHostsRequest {
-                filter: Filter {
-                    host: vec![host_name2.to_string()],
-                },
+            let request = GetMaintenancesRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                maintenance_ids: Some(vec![maintenance_id.clone()]),
+                select_hosts: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_time_periods: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
             };
 
-            match test_env.client.get_hosts(&test_env.session, &request) {
-                Ok(hosts) => {
-                    assert_eq!(hosts.len(), 1);
+            match test_env
+                .client
+                .get_maintenances(&test_env.session, &request)
+            {
+                Ok(maintenances) => {
+                    assert_eq!(maintenances.len(), 1);
 
-                    let host = hosts.first().unwrap();
+                    let maintenance = maintenances.first().unwrap();
 
-                    assert_eq!(&host.host, &host_name2)
+                    assert_eq!(maintenance.name, maintenance_name);
+                    assert_eq!(maintenance.description, "deploy");
+                    assert_eq!(maintenance.hosts.clone().unwrap()[0].host, host_name);
+
+                    let periods = maintenance.time_periods.clone().unwrap();
+                    assert!(matches!(periods[0], TimePeriod::Weekly { .. }));
                 }
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
 
-                    error!("host get error: {}", e);
+                    error!("maintenance get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            match test_env
+                .client
+                .delete_maintenances(&test_env.session, std::slice::from_ref(&maintenance_id))
+            {
+                Ok(deleted_ids) => assert_eq!(deleted_ids, vec![maintenance_id]),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("maintenance delete error: {}", e);
                     panic!("{}", e)
                 }
             }
         }
     }
+}
+
+#[cfg(all(test, feature = "action"))]
+mod action_tests {
+    use std::error::Error;
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::action::create::CreateActionRequest;
+    use crate::action::get::GetActionsRequest;
+    use crate::action::model::{
+        ActionCondition, ActionConditionOperator, ActionFilter, ActionMessage, ActionOperation,
+        ActionOperationType, ActionStatus,
+    };
+    use crate::action::update::UpdateActionRequest;
+    use crate::event::model::{EventSeverity, EventSource};
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
+
+    /// Default "Zabbix administrators" user group.
+    const ADMINISTRATORS_GROUP_ID: &str = "7";
 
     #[test]
-    fn delete_hosts_test() {
+    fn create_copy_update_and_delete_action() {
         init_logging();
 
-        #[derive(Serialize)]
-        struct HostFilter {
-            pub host: Vec<String>,
-        }
-
         if are_integration_tests_enabled() {
-            use crate::host::get::GetHostsByIdsRequest;
             let mut test_env = TestEnvBuilder::build();
 
             let group_name = get_random_string();
-            let host_name1 = get_random_string();
-            let host_name2 = get_random_string();
-            let host_name3 = get_random_string();
+            let action_name = get_random_string();
+
+            test_env.get_session().create_host_group(&group_name);
+
+            let request = CreateActionRequest::builder(&action_name, EventSource::Trigger)
+                .esc_period("30m")
+                .filter(ActionFilter::custom(
+                    "A and B",
+                    vec![
+                        ActionCondition::host_group(
+                            ActionConditionOperator::Equals,
+                            test_env.latest_host_group_id,
+                        )
+                        .formula_id("A"),
+                        ActionCondition::trigger_severity(
+                            ActionConditionOperator::GreaterOrEqual,
+                            EventSeverity::Average,
+                        )
+                        .formula_id("B"),
+                    ],
+                ))
+                .operation(
+                    ActionOperation::send_message(ActionMessage::default_message())
+                        .to_user_group(ADMINISTRATORS_GROUP_ID)
+                        .steps(1, 2),
+                )
+                .recovery_operation(ActionOperation::notify_all_involved_on_recovery(
+                    ActionMessage::custom("Resolved", "{EVENT.NAME} has been resolved"),
+                ))
+                .update_operation(ActionOperation::notify_all_involved_on_update(
+                    ActionMessage::default_message(),
+                ))
+                .build()
+                .unwrap();
+
+            let action_id = match test_env.client.create_action(&test_env.session, &request) {
+                Ok(action_id) => action_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-            test_env
-                .get_session()
-                .create_host_group(&group_name)
-                .create_host(&host_name1, None)
-                .create_host(&host_name2, None)
-                .create_host(&host_name3, None);
+                    error!("action create error: {}", e);
+                    panic!("{}", e)
+                }
+            };
 
-            match test_env.client.get_hosts(
-                &test_env.session,
-                &GetHostsRequest {
-                    filter: HostFilter {
-                        host: vec![host_name1, host_name2, host_name3],
-                    },
-                },
-            ) {
-                Ok(hosts) => {
-                    let host_ids = hosts
-                        .iter()
-                        .map(|host| host.host_id.clone())
-                        .collect::<Vec<String>>();
+            let request = GetActionsRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                action_ids: Some(vec![action_id.clone()]),
+                select_filter: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_operations: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_recovery_operations: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_update_operations: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
+            };
 
-                    test_env.get_session().delete_hosts(&host_ids);
+            let action = match test_env.client.get_actions(&test_env.session, &request) {
+                Ok(actions) => {
+                    assert_eq!(actions.len(), 1);
+                    actions.first().unwrap().clone()
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-                    match test_env.client.get_hosts(
-                        &test_env.session,
-                        &GetHostsByIdsRequest { hostids: host_ids },
-                    ) {
-                        Ok(hosts) => {
-                            assert!(hosts.is_empty());
-                        }
-                        Err(e) => {
-                            if let Some(inner_source) = e.source() {
-                                println!("Caused by: {}", inner_source);
-                            }
+                    error!("action get error: {}", e);
+                    panic!("{}", e)
+                }
+            };
+
+            assert_eq!(action.filter.clone().unwrap().conditions.len(), 2);
+            assert_eq!(
+                action.recovery_operations.clone().unwrap()[0].operation_type,
+                ActionOperationType::NotifyAllInvolvedOnRecovery
+            );
+
+            let copy = action.copy_as(get_random_string()).unwrap();
+
+            let copy_id = match test_env.client.create_action(&test_env.session, &copy) {
+                Ok(action_id) => action_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("action copy error: {}", e);
+                    panic!("{}", e)
+                }
+            };
+
+            let request = UpdateActionRequest::builder(&copy_id)
+                .disable()
+                .build()
+                .unwrap();
+
+            if let Err(e) = test_env.client.update_action(&test_env.session, &request) {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("action update error: {}", e);
+                panic!("{}", e)
+            }
+
+            let request = GetActionsRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                action_ids: Some(vec![copy_id.clone()]),
+                ..Default::default()
+            };
 
-                            error!("host get error: {}", e);
-                            panic!("{}", e)
-                        }
+            match test_env.client.get_actions(&test_env.session, &request) {
+                Ok(actions) => assert_eq!(actions[0].status, ActionStatus::Disabled),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
                     }
+
+                    error!("action get error: {}", e);
+                    panic!("{}", e)
                 }
+            }
+
+            let action_ids = vec![action_id, copy_id];
+
+            match test_env
+                .client
+                .delete_actions(&test_env.session, &action_ids)
+            {
+                Ok(deleted_ids) => assert_eq!(deleted_ids, action_ids),
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
 
-                    error!("host get error: {}", e);
+                    error!("action delete error: {}", e);
                     panic!("{}", e)
                 }
             }
         }
     }
+}
 
-    #[test]
-    fn get_items_test() {
-        init_logging();
+#[cfg(all(test, feature = "mediatype"))]
+mod mediatype_tests {
+    use std::error::Error;
 
-        if are_integration_tests_enabled() {
-            let mut test_env = TestEnvBuilder::build();
+    use log::error;
 
-            let group_name = get_random_string();
-            let host_name1 = get_random_string();
-            let host_name2 = get_random_string();
-            let host_name3 = get_random_string();
-            let item_name = get_random_string();
-            let item_key = format!("test{}", get_random_string());
+    use super::ZabbixApiClient;
+    use crate::mediatype::create::CreateMediaTypeRequest;
+    use crate::mediatype::get::GetMediaTypesRequest;
+    use crate::mediatype::model::{MediaTypeDetails, MediaTypeStatus, WebhookSettings};
+    use crate::mediatype::update::UpdateMediaTypeRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
-            test_env
-                .get_session()
-                .create_host_group(&group_name)
-                .create_host(&host_name1, None)
-                .create_host(&host_name2, None)
-                .create_host(&host_name3, None)
-                .create_item(&item_name, &item_key);
+    #[test]
+    fn create_update_and_delete_webhook_media_type() {
+        init_logging();
 
-            #[derive(Serialize)]
-            struct Search {
-                pub key_: String,
-            }
+        if are_integration_tests_enabled() {
+            let test_env = TestEnvBuilder::build();
 
-            let request = GetItemsRequestById {
-                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
-                with_triggers: false,
-                host_ids: test_env.latest_host_id.to_string(),
-                search: Search {
-                    key_: item_key.to_string(),
-                },
-                sort_field: "name".to_string(),
-            };
+            let name = get_random_string();
 
-            match test_env.client.get_items(&test_env.session, &request) {
-                Ok(items) => {
-                    assert_eq!(items.len(), 1);
+            let webhook = WebhookSettings::new("return 'OK';")
+                .parameter("to", "{ALERT.SENDTO}")
+                .parameter("message", "{ALERT.MESSAGE}")
+                .timeout("15s")
+                .process_tags(true);
 
-                    let item = items.first().unwrap();
+            let request =
+                CreateMediaTypeRequest::builder(&name, MediaTypeDetails::Webhook(webhook))
+                    .attempts(2, "5s")
+                    .build()
+                    .unwrap();
 
-                    assert_eq!(&item.key_, &item_key)
-                }
+            let media_type_id = match test_env
+                .client
+                .create_media_type(&test_env.session, &request)
+            {
+                Ok(media_type_id) => media_type_id.to_string(),
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
 
-                    error!("host get error: {}", e);
+                    error!("media type create error: {}", e);
                     panic!("{}", e)
                 }
-            }
-        }
-    }
-
-    #[test]
-    fn get_triggers_test() {
-        init_logging();
+            };
 
-        if are_integration_tests_enabled() {
-            let mut test_env = TestEnvBuilder::build();
+            let request = UpdateMediaTypeRequest::builder(&media_type_id)
+                .status(MediaTypeStatus::Disabled)
+                .build()
+                .unwrap();
 
-            let group_name = get_random_string();
-            let host_name = get_random_string();
-            let item_name = get_random_string();
-            let item_key = get_random_string();
-            let trigger_description = get_random_string();
+            if let Err(e) = test_env
+                .client
+                .update_media_type(&test_env.session, &request)
+            {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
 
-            test_env
-                .get_session()
-                .create_host_group(&group_name)
-                .create_host(&host_name, None)
-                .create_item(&item_name, &item_key)
-                .create_trigger(
-                    &trigger_description,
-                    &format!("last(/{host_name}/{item_key})=0"),
-                );
+                error!("media type update error: {}", e);
+                panic!("{}", e)
+            }
 
-            let request = GetTriggerByIdRequest {
-                trigger_ids: test_env.latest_trigger_id.to_string(),
-                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
-                select_functions: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
+            let request = GetMediaTypesRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                media_type_ids: Some(vec![media_type_id.clone()]),
+                ..Default::default()
             };
 
-            match test_env.client.get_triggers(&test_env.session, &request) {
-                Ok(results) => {
-                    assert_eq!(results.len(), 1);
-                    let result = results.first().unwrap();
+            match test_env.client.get_media_types(&test_env.session, &request) {
+                Ok(media_types) => {
+                    assert_eq!(media_types.len(), 1);
 
-                    assert_eq!(&result.description, &trigger_description)
+                    let media_type = media_types.first().unwrap();
+
+                    assert_eq!(media_type.name, name);
+                    assert_eq!(media_type.status, MediaTypeStatus::Disabled);
+
+                    match &media_type.details {
+                        MediaTypeDetails::Webhook(webhook) => {
+                            assert_eq!(webhook.process_tags, Some(true));
+                            assert_eq!(webhook.parameters.as_ref().unwrap().len(), 2);
+                        }
+                        other => panic!("unexpected media type: {:?}", other),
+                    }
                 }
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
 
-                    error!("host get error: {}", e);
+                    error!("media type get error: {}", e);
                     panic!("{}", e)
                 }
             }
-        }
-    }
-
-    #[test]
-    fn get_webscenarios_test() {
-        init_logging();
-
-        if are_integration_tests_enabled() {
-            let mut test_env = TestEnvBuilder::build();
-
-            let group_name = get_random_string();
-            let host_name = get_random_string();
-            let item_name = get_random_string();
-            let item_key = get_random_string();
-            let trigger_description = get_random_string();
-            let webscenario_name = get_random_string();
-
-            test_env
-                .get_session()
-                .create_host_group(&group_name)
-                .create_host(&host_name, None)
-                .create_item(&item_name, &item_key)
-                .create_trigger(
-                    &trigger_description,
-                    &format!("last(/{host_name}/{item_key})=0"),
-                )
-                .create_web_scenario(&webscenario_name);
-
-            let request = GetWebScenarioByIdRequest {
-                output: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
-                select_steps: ZABBIX_EXTEND_PROPERTY_VALUE.to_string(),
-                httptest_ids: test_env.latest_webscenario_id.to_string(),
-            };
 
             match test_env
                 .client
-                .get_webscenarios(&test_env.session, &request)
+                .delete_media_types(&test_env.session, std::slice::from_ref(&media_type_id))
             {
-                Ok(results) => {
-                    assert_eq!(results.len(), 1);
-                    let result = results.first().unwrap();
-
-                    assert_eq!(&result.name, &webscenario_name)
-                }
+                Ok(deleted_ids) => assert_eq!(deleted_ids, vec![media_type_id]),
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
 
-                    error!("host get error: {}", e);
+                    error!("media type delete error: {}", e);
                     panic!("{}", e)
                 }
             }
         }
     }
+}
+
+#[cfg(all(test, feature = "alert"))]
+mod alert_tests {
+    use std::error::Error;
+    use std::time::{Duration, SystemTime};
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::alert::get::GetAlertsRequest;
+    use crate::alert::model::AlertStatus;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
 
     #[test]
-    fn create_host_group_and_host() {
+    fn get_failed_messages() {
         init_logging();
 
         if are_integration_tests_enabled() {
-            let mut test_env = TestEnvBuilder::build();
+            let test_env = TestEnvBuilder::build();
 
-            let group_name = get_random_string();
-            let host_name = get_random_string();
+            let now = SystemTime::now();
+            let request =
+                GetAlertsRequest::failed_messages(now - Duration::from_secs(7 * 86400), now);
 
-            test_env
-                .get_session()
-                .create_host_group(&group_name)
-                .create_host(&host_name, None);
+            match test_env.client.get_alerts(&test_env.session, &request) {
+                Ok(alerts) => {
+                    assert!(alerts
+                        .iter()
+                        .all(|alert| alert.status == AlertStatus::Failed));
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-            assert!(test_env.latest_host_group_id > 0);
-            assert!(test_env.latest_host_id > 0);
+                    error!("alert get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
         }
     }
+}
+
+#[cfg(all(test, feature = "script"))]
+mod script_tests {
+    use std::error::Error;
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::script::create::CreateScriptRequest;
+    use crate::script::get::{GetScriptsByHostsRequest, GetScriptsRequest};
+    use crate::script::model::{CustomScript, ScriptDetails, ScriptExecuteOn, ScriptScope};
+    use crate::script::update::UpdateScriptRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
     #[test]
-    fn create_host_with_cert() {
+    fn create_update_and_delete_script() {
         init_logging();
 
         if are_integration_tests_enabled() {
@@ -2593,76 +14158,137 @@ mod tests {
 
             let group_name = get_random_string();
             let host_name = get_random_string();
+            let script_name = get_random_string();
 
             test_env
                 .get_session()
                 .create_host_group(&group_name)
-                .create_host(
-                    &host_name,
-                    Some(TlsConfig::new_cert(
-                        "CN=some issuer".to_string(),
-                        "CN=some subject".to_string(),
-                    )),
-                );
+                .create_host(&host_name, None);
 
-            assert!(test_env.latest_host_group_id > 0);
-            assert!(test_env.latest_host_id > 0);
-        }
-    }
+            let request = CreateScriptRequest::builder(
+                &script_name,
+                ScriptDetails::Script(CustomScript::new(
+                    "echo {HOST.HOST}",
+                    ScriptExecuteOn::Agent,
+                )),
+                ScriptScope::ManualHostAction,
+            )
+            .menu_path("Tests")
+            .host_group(test_env.latest_host_group_id)
+            .build()
+            .unwrap();
+
+            let script_id = match test_env.client.create_script(&test_env.session, &request) {
+                Ok(script_id) => script_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-    #[test]
-    fn create_host_with_psk() {
-        init_logging();
+                    error!("script create error: {}", e);
+                    panic!("{}", e)
+                }
+            };
 
-        if are_integration_tests_enabled() {
-            let mut test_env = TestEnvBuilder::build();
+            let host_id = test_env.latest_host_id.to_string();
+            let request = GetScriptsByHostsRequest::new(std::slice::from_ref(&host_id));
 
-            let group_name = get_random_string();
-            let host_name = get_random_string();
+            match test_env
+                .client
+                .get_scripts_by_hosts(&test_env.session, &request)
+            {
+                Ok(scripts) => {
+                    let script = scripts[&host_id]
+                        .iter()
+                        .find(|script| script.script_id == script_id)
+                        .unwrap();
 
-            test_env
-                .get_session()
-                .create_host_group(&group_name)
-                .create_host(
-                    &host_name,
-                    Some(TlsConfig::new_psk(
-                        get_random_string(),
-                        get_random_hex_string(),
-                    )),
-                );
+                    assert_eq!(script.details.command(), format!("echo {}", host_name));
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-            assert!(test_env.latest_host_group_id > 0);
-            assert!(test_env.latest_host_id > 0);
-        }
-    }
+                    error!("scripts by hosts error: {}", e);
+                    panic!("{}", e)
+                }
+            }
 
-    #[test]
-    fn create_and_update_host() {
-        init_logging();
+            let request = UpdateScriptRequest::builder(&script_id)
+                .menu_path("Tests/Updated")
+                .build()
+                .unwrap();
+
+            if let Err(e) = test_env.client.update_script(&test_env.session, &request) {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("script update error: {}", e);
+                panic!("{}", e)
+            }
 
-        if are_integration_tests_enabled() {
-            let mut test_env = TestEnvBuilder::build();
+            let request = GetScriptsRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                script_ids: Some(vec![script_id.clone()]),
+                ..Default::default()
+            };
 
-            let group_name = get_random_string();
-            let host_name = get_random_string();
+            match test_env.client.get_scripts(&test_env.session, &request) {
+                Ok(scripts) => {
+                    assert_eq!(scripts.len(), 1);
+                    assert_eq!(scripts[0].menu_path.as_deref(), Some("Tests/Updated"));
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-            test_env
-                .get_session()
-                .create_host_group(&group_name)
-                .create_host(&host_name, None);
+                    error!("script get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
 
-            assert!(test_env.latest_host_group_id > 0);
-            assert!(test_env.latest_host_id > 0);
+            match test_env
+                .client
+                .delete_scripts(&test_env.session, std::slice::from_ref(&script_id))
+            {
+                Ok(deleted_ids) => assert_eq!(deleted_ids, vec![script_id]),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-            let host_id = test_env.latest_host_id.to_string();
-            test_env
-                .get_session()
-                .update_host(UpdateHostRequest::disable_host(host_id));
+                    error!("script delete error: {}", e);
+                    panic!("{}", e)
+                }
+            }
         }
     }
+}
+
+#[cfg(all(test, feature = "proxy"))]
+mod proxy_tests {
+    use std::error::Error;
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::host::model::HostMonitoring;
+    use crate::host::update::UpdateHostRequest;
+    use crate::proxy::create::CreateProxyRequest;
+    use crate::proxy::get::GetProxiesRequest;
+    use crate::proxy::model::ProxyMode;
+    use crate::proxy::update::UpdateProxyRequest;
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
     #[test]
-    fn create_item() {
+    fn create_update_and_delete_proxy() {
         init_logging();
 
         if are_integration_tests_enabled() {
@@ -2670,190 +14296,338 @@ mod tests {
 
             let group_name = get_random_string();
             let host_name = get_random_string();
+            let proxy_name = get_random_string();
 
             test_env
                 .get_session()
                 .create_host_group(&group_name)
                 .create_host(&host_name, None);
 
-            let item_key = get_random_string();
-            let item_name = get_random_string();
+            let host_id = test_env.latest_host_id.to_string();
 
-            let request = CreateItemRequest {
-                key_: item_key,
-                name: item_name,
-                host_id: test_env.latest_host_id.to_string(),
-                r#type: 7,
-                value_type: 4,
-                interface_id: "0".to_string(),
-                tags: vec![],
-                delay: "30s".to_string(),
-            };
+            let request = CreateProxyRequest::active(&proxy_name)
+                .allowed_addresses("127.0.0.1")
+                .host(&host_id)
+                .build()
+                .unwrap();
 
-            match test_env.client.create_item(&test_env.session, &request) {
-                Ok(item_id) => {
-                    assert!(item_id > 0);
-                }
+            let proxy_id = match test_env.client.create_proxy(&test_env.session, &request) {
+                Ok(proxy_id) => proxy_id.to_string(),
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
 
-                    error!("item create error: {}", e);
+                    error!("proxy create error: {}", e);
                     panic!("{}", e)
                 }
+            };
+
+            let request = UpdateProxyRequest::builder(&proxy_id)
+                .passive("127.0.0.1", "10051")
+                .build();
+
+            if let Err(e) = test_env.client.update_proxy(&test_env.session, &request) {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("proxy update error: {}", e);
+                panic!("{}", e)
             }
-        }
-    }
 
-    #[test]
-    fn create_trigger() {
-        init_logging();
+            let request = GetProxiesRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                proxy_ids: Some(vec![proxy_id.clone()]),
+                select_hosts: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
+            };
 
-        if are_integration_tests_enabled() {
-            let mut test_env = TestEnvBuilder::build();
+            match test_env.client.get_proxies(&test_env.session, &request) {
+                Ok(proxies) => {
+                    assert_eq!(proxies.len(), 1);
 
-            let group_name = get_random_string();
-            let host_name = get_random_string();
+                    let proxy = proxies.first().unwrap();
 
-            let item_name = get_random_string();
-            let item_key = format!("key{}", get_random_string());
+                    assert_eq!(proxy.name, proxy_name);
+                    assert_eq!(proxy.mode, ProxyMode::Passive);
+                    assert_eq!(proxy.hosts.as_ref().unwrap()[0].host_id, host_id);
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-            test_env
-                .get_session()
-                .create_host_group(&group_name)
-                .create_host(&host_name, None)
-                .create_item(&item_name, &item_key);
+                    error!("proxy get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
 
-            let trigger_description = get_random_string();
+            let request = UpdateHostRequest::builder(&host_id)
+                .monitored_by(HostMonitoring::Server)
+                .build();
 
-            let expression = format!("last(/{host_name}/{item_key})=0");
+            if let Err(e) = test_env.client.update_host(&test_env.session, &request) {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
 
-            let request = CreateTriggerRequest {
-                description: trigger_description,
-                expression: expression.to_string(),
-                priority: 4,
-                recovery_mode: Some(0),
-                recovery_expression: None,
-                url: None,
-                event_name: None,
-                dependencies: vec![],
-                tags: vec![],
-            };
+                error!("host update error: {}", e);
+                panic!("{}", e)
+            }
 
-            match test_env.client.create_trigger(&test_env.session, &request) {
-                Ok(trigger_id) => assert!(trigger_id > 0),
+            match test_env
+                .client
+                .delete_proxies(&test_env.session, std::slice::from_ref(&proxy_id))
+            {
+                Ok(deleted_ids) => assert_eq!(deleted_ids, vec![proxy_id]),
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
 
-                    error!("trigger create error: {}", e);
+                    error!("proxy delete error: {}", e);
                     panic!("{}", e)
                 }
             }
         }
     }
 
+    #[cfg(feature = "v7")]
     #[test]
-    fn create_web_scenario() {
+    fn create_proxy_in_group() {
+        use crate::proxygroup::create::CreateProxyGroupRequest;
+        use crate::proxygroup::get::GetProxyGroupsRequest;
+
         init_logging();
 
         if are_integration_tests_enabled() {
-            let mut test_env = TestEnvBuilder::build();
+            let test_env = TestEnvBuilder::build();
 
-            let group_name = get_random_string();
-            let host_name = get_random_string();
+            let request = CreateProxyGroupRequest::builder(get_random_string())
+                .failover_delay("1m")
+                .min_online(1)
+                .build()
+                .unwrap();
 
-            test_env
-                .get_session()
-                .create_host_group(&group_name)
-                .create_host(&host_name, None);
+            let proxy_group_id = match test_env
+                .client
+                .create_proxy_group(&test_env.session, &request)
+            {
+                Ok(proxy_group_id) => proxy_group_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-            let web_scenario_name = get_random_string();
+                    error!("proxy group create error: {}", e);
+                    panic!("{}", e)
+                }
+            };
 
-            let step = ZabbixWebScenarioStep {
-                name: "Check github.com page".to_string(),
-                url: "https://github.com".to_string(),
-                status_codes: "200".to_string(),
-                no: "0".to_string(),
+            let request = CreateProxyRequest::active(get_random_string())
+                .proxy_group(&proxy_group_id, "127.0.0.1", "10051")
+                .build()
+                .unwrap();
+
+            let proxy_id = match test_env.client.create_proxy(&test_env.session, &request) {
+                Ok(proxy_id) => proxy_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("proxy create error: {}", e);
+                    panic!("{}", e)
+                }
             };
 
-            let request = CreateWebScenarioRequest {
-                name: web_scenario_name,
-                host_id: test_env.latest_host_id.to_string(),
-                steps: vec![step],
+            let request = GetProxyGroupsRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                proxy_group_ids: Some(vec![proxy_group_id.clone()]),
+                select_proxies: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                ..Default::default()
             };
 
             match test_env
                 .client
-                .create_webscenario(&test_env.session, &request)
+                .get_proxy_groups(&test_env.session, &request)
             {
-                Ok(web_scenario_id) => {
-                    assert!(web_scenario_id > 0);
+                Ok(proxy_groups) => {
+                    assert_eq!(proxy_groups.len(), 1);
+                    assert_eq!(
+                        proxy_groups[0].proxies.as_ref().unwrap()[0].proxy_id,
+                        proxy_id
+                    );
                 }
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
 
-                    error!("web-scenario create error: {}", e);
+                    error!("proxy group get error: {}", e);
+                    panic!("{}", e)
+                }
+            }
+
+            if let Err(e) = test_env
+                .client
+                .delete_proxies(&test_env.session, &[proxy_id])
+            {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("proxy delete error: {}", e);
+                panic!("{}", e)
+            }
+
+            match test_env
+                .client
+                .delete_proxy_groups(&test_env.session, std::slice::from_ref(&proxy_group_id))
+            {
+                Ok(deleted_ids) => assert_eq!(deleted_ids, vec![proxy_group_id]),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("proxy group delete error: {}", e);
                     panic!("{}", e)
                 }
             }
         }
     }
+}
+
+#[cfg(all(test, feature = "dashboard"))]
+mod dashboard_tests {
+    use std::error::Error;
+
+    use log::error;
+
+    use super::ZabbixApiClient;
+    use crate::dashboard::create::CreateDashboardRequest;
+    use crate::dashboard::get::GetDashboardsRequest;
+    use crate::dashboard::model::{DashboardPage, DashboardPermission};
+    use crate::dashboard::update::UpdateDashboardRequest;
+    use crate::dashboard::widget::{DashboardWidget, WidgetType};
+    use crate::tests::builder::TestEnvBuilder;
+    use crate::tests::integration::are_integration_tests_enabled;
+    use crate::tests::logging::init_logging;
+    use crate::tests::strings::get_random_string;
+    use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
+
+    /// Default "Zabbix administrators" user group.
+    const ADMINISTRATORS_GROUP_ID: &str = "7";
 
     #[test]
-    fn create_user_group() {
+    fn create_update_and_delete_dashboard() {
         init_logging();
 
         if are_integration_tests_enabled() {
             let mut test_env = TestEnvBuilder::build();
-            test_env.get_session();
 
             let group_name = get_random_string();
-            let user_group_name = format!("user_group_{}", get_random_string());
-
-            // Create a host group to assign permissions to
-            test_env.create_host_group(&group_name);
-            let host_group_id = test_env.latest_host_group_id.to_string();
+            let dashboard_name = get_random_string();
+
+            test_env.get_session().create_host_group(&group_name);
+
+            let page = DashboardPage::flow(
+                "Overview",
+                vec![
+                    DashboardWidget::problems()
+                        .name("Problems")
+                        .host_groups(&[test_env.latest_host_group_id.to_string()]),
+                    DashboardWidget::clock(),
+                    DashboardWidget::url("https://www.zabbix.com"),
+                ],
+            )
+            .unwrap();
+
+            let request = CreateDashboardRequest::builder(&dashboard_name)
+                .display_period(60)
+                .page(page)
+                .share_with_user_group(ADMINISTRATORS_GROUP_ID, DashboardPermission::ReadOnly)
+                .build()
+                .unwrap();
+
+            let dashboard_id = match test_env
+                .client
+                .create_dashboard(&test_env.session, &request)
+            {
+                Ok(dashboard_id) => dashboard_id.to_string(),
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
 
-            // A dummy user ID (replace with a real one if needed for more thorough testing)
-            // For this test, Zabbix might not validate the user ID existence strictly for group creation.
-            let user_id = "1"; // Assuming user with ID '1' (Admin) exists or is not strictly checked
+                    error!("dashboard create error: {}", e);
+                    panic!("{}", e)
+                }
+            };
 
-            let request = CreateUserGroupRequest {
-                name: user_group_name.clone(),
-                gui_access: Some(0),   // System default
-                users_status: Some(0), // Enabled
-                hostgroup_rights: Some(vec![UserGroupPermission {
-                    id: host_group_id,
-                    permission: 2, // Read-only
-                }]),
-                users: Some(vec![UserGroupUser {
-                    user_id: user_id.to_string(),
-                }]),
+            let request = GetDashboardsRequest::<()> {
+                output: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                dashboard_ids: Some(vec![dashboard_id.clone()]),
+                select_pages: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
+                select_user_groups: Some(ZABBIX_EXTEND_PROPERTY_VALUE.to_string()),
                 ..Default::default()
             };
 
-            match test_env
+            let dashboard = match test_env.client.get_dashboards(&test_env.session, &request) {
+                Ok(dashboards) => {
+                    assert_eq!(dashboards.len(), 1);
+                    dashboards.first().unwrap().clone()
+                }
+                Err(e) => {
+                    if let Some(inner_source) = e.source() {
+                        println!("Caused by: {}", inner_source);
+                    }
+
+                    error!("dashboard get error: {}", e);
+                    panic!("{}", e)
+                }
+            };
+
+            let mut pages = dashboard.pages.unwrap();
+
+            assert_eq!(pages[0].widgets.len(), 3);
+            assert_eq!(pages[0].widgets[0].widget_type, WidgetType::Problems);
+            assert_eq!(dashboard.user_groups.unwrap().len(), 1);
+
+            pages[0].widgets.pop();
+
+            let request = UpdateDashboardRequest::builder(&dashboard_id)
+                .pages(pages)
+                .unshare()
+                .build()
+                .unwrap();
+
+            if let Err(e) = test_env
                 .client
-                .create_user_group(&test_env.session, &request)
+                .update_dashboard(&test_env.session, &request)
             {
-                Ok(user_group_id) => {
-                    assert!(user_group_id > 0);
-                    info!(
-                        "Successfully created user group '{}' with ID '{}'",
-                        user_group_name, user_group_id
-                    );
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
                 }
+
+                error!("dashboard update error: {}", e);
+                panic!("{}", e)
+            }
+
+            match test_env
+                .client
+                .delete_dashboards(&test_env.session, std::slice::from_ref(&dashboard_id))
+            {
+                Ok(deleted_ids) => assert_eq!(deleted_ids, vec![dashboard_id]),
                 Err(e) => {
                     if let Some(inner_source) = e.source() {
                         println!("Caused by: {}", inner_source);
                     }
-                    error!("user group create error: {}", e);
-                    panic!("{}", e);
+
+                    error!("dashboard delete error: {}", e);
+                    panic!("{}", e)
                 }
             }
         }
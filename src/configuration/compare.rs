@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Changes `configuration.importcompare` found between the source and the server state.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/configuration/importcompare
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct ImportCompareResult {
+    #[cfg(feature = "v7")]
+    #[serde(default)]
+    pub host_groups: Option<ImportCompareChanges>,
+    #[cfg(feature = "v7")]
+    #[serde(default)]
+    pub template_groups: Option<ImportCompareChanges>,
+    #[cfg(feature = "v6")]
+    #[serde(default)]
+    pub groups: Option<ImportCompareChanges>,
+    #[serde(default)]
+    pub templates: Option<ImportCompareChanges>,
+}
+
+impl ImportCompareResult {
+    /// Returns `true` if the import wouldn't change anything.
+    pub fn is_empty(&self) -> bool {
+        #[cfg(feature = "v7")]
+        let groups = [&self.host_groups, &self.template_groups];
+        #[cfg(feature = "v6")]
+        let groups = [&self.groups];
+
+        groups
+            .into_iter()
+            .chain([&self.templates])
+            .flatten()
+            .all(|changes| changes.is_empty())
+    }
+}
+
+/// Objects of one kind that would be added, removed or updated.
+/// Objects are represented the same way as in the import source.
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct ImportCompareChanges {
+    #[serde(default)]
+    pub added: Vec<Value>,
+    #[serde(default)]
+    pub removed: Vec<Value>,
+    #[serde(default)]
+    pub updated: Vec<ImportCompareUpdate>,
+}
+
+impl ImportCompareChanges {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.updated.is_empty()
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ImportCompareUpdate {
+    pub before: Value,
+    pub after: Value,
+    /// Changes of the nested entities keyed by kind, e.g. `items`, `triggers`,
+    /// `discovery_rules`, `dashboards`, `httptests`, `valuemaps`.
+    #[serde(flatten)]
+    pub changes: BTreeMap<String, ImportCompareChanges>,
+}
+
+/// Zabbix returns an empty array instead of an object when nothing would change.
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub(crate) enum ImportCompareResponse {
+    NoChanges([(); 0]),
+    Changes(Box<ImportCompareResult>),
+}
+
+impl From<ImportCompareResponse> for ImportCompareResult {
+    fn from(response: ImportCompareResponse) -> Self {
+        match response {
+            ImportCompareResponse::NoChanges(_) => ImportCompareResult::default(),
+            ImportCompareResponse::Changes(result) => *result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod compare_tests {
+    use super::{ImportCompareResponse, ImportCompareResult};
+
+    #[test]
+    fn deserialize_no_changes_test() {
+        let response = serde_json::from_str::<ImportCompareResponse>("[]").unwrap();
+        let result = ImportCompareResult::from(response);
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn deserialize_changes_test() {
+        let json = r#"{
+            "templates": {
+                "updated": [
+                    {
+                        "before": {"uuid": "a1", "template": "tpl", "name": "tpl"},
+                        "after": {"uuid": "a1", "template": "tpl", "name": "tpl"},
+                        "items": {
+                            "added": [{"uuid": "b2", "name": "CPU load", "key": "system.cpu.load"}]
+                        },
+                        "triggers": {
+                            "removed": [{"uuid": "c3", "name": "High CPU"}]
+                        }
+                    }
+                ]
+            }
+        }"#;
+
+        let response = serde_json::from_str::<ImportCompareResponse>(json).unwrap();
+        let result = ImportCompareResult::from(response);
+
+        assert!(!result.is_empty());
+
+        let templates = result.templates.unwrap();
+        assert!(templates.added.is_empty());
+        assert_eq!(templates.updated.len(), 1);
+
+        let update = &templates.updated[0];
+        assert_eq!(update.before["template"], "tpl");
+        assert_eq!(update.changes["items"].added[0]["key"], "system.cpu.load");
+        assert_eq!(update.changes["triggers"].removed.len(), 1);
+    }
+}
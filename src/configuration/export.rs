@@ -0,0 +1,91 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::configuration::model::ConfigurationFormat;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/configuration/export
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct ExportConfigurationRequest {
+    pub format: ConfigurationFormat,
+    /// Make the output human-readable by adding indentation.
+    pub prettyprint: Option<bool>,
+    pub options: ExportOptions,
+}
+
+impl ExportConfigurationRequest {
+    pub fn new(format: ConfigurationFormat, options: ExportOptions) -> Self {
+        Self {
+            format,
+            prettyprint: None,
+            options,
+        }
+    }
+
+    /// Exports the given templates.
+    pub fn templates(format: ConfigurationFormat, template_ids: Vec<String>) -> Self {
+        Self::new(
+            format,
+            ExportOptions {
+                templates: Some(template_ids),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Exports the given hosts.
+    pub fn hosts(format: ConfigurationFormat, host_ids: Vec<String>) -> Self {
+        Self::new(
+            format,
+            ExportOptions {
+                hosts: Some(host_ids),
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn pretty(mut self) -> Self {
+        self.prettyprint = Some(true);
+        self
+    }
+}
+
+/// IDs of the objects to export.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct ExportOptions {
+    #[cfg(feature = "v7")]
+    pub host_groups: Option<Vec<String>>,
+    #[cfg(feature = "v7")]
+    pub template_groups: Option<Vec<String>>,
+    #[cfg(feature = "v6")]
+    pub groups: Option<Vec<String>>,
+    pub hosts: Option<Vec<String>>,
+    pub images: Option<Vec<String>>,
+    pub maps: Option<Vec<String>>,
+    #[serde(rename = "mediaTypes")]
+    pub media_types: Option<Vec<String>>,
+    pub templates: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod export_tests {
+    use super::ExportConfigurationRequest;
+    use crate::configuration::model::ConfigurationFormat;
+
+    #[test]
+    fn serialization_test() {
+        let request = ExportConfigurationRequest::templates(
+            ConfigurationFormat::Json,
+            vec!["10001".to_string()],
+        )
+        .pretty();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["format"], "json");
+        assert_eq!(json["prettyprint"], true);
+        assert_eq!(json["options"]["templates"][0], "10001");
+        assert!(json["options"].get("hosts").is_none());
+    }
+}
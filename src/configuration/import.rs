@@ -0,0 +1,160 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::configuration::model::ConfigurationFormat;
+
+/// Parameters of `configuration.import` and `configuration.importcompare`.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/configuration/import
+#[derive(Serialize, Debug)]
+pub struct ImportConfigurationRequest {
+    pub format: ConfigurationFormat,
+    /// Serialized configuration data, e.g. the output of `configuration.export`.
+    pub source: String,
+    pub rules: ImportRules,
+}
+
+impl ImportConfigurationRequest {
+    pub fn new(format: ConfigurationFormat, source: impl ToString, rules: ImportRules) -> Self {
+        Self {
+            format,
+            source: source.to_string(),
+            rules,
+        }
+    }
+}
+
+/// Rules on how new and existing objects of each kind should be imported.
+/// Object kinds without a rule are not imported.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct ImportRules {
+    #[serde(rename = "discoveryRules")]
+    pub discovery_rules: Option<ImportRule>,
+    pub graphs: Option<ImportRule>,
+    #[cfg(feature = "v7")]
+    pub host_groups: Option<ImportRule>,
+    #[cfg(feature = "v7")]
+    pub template_groups: Option<ImportRule>,
+    #[cfg(feature = "v6")]
+    pub groups: Option<ImportRule>,
+    pub hosts: Option<ImportRule>,
+    pub httptests: Option<ImportRule>,
+    pub images: Option<ImportRule>,
+    pub items: Option<ImportRule>,
+    pub maps: Option<ImportRule>,
+    #[serde(rename = "mediaTypes")]
+    pub media_types: Option<ImportRule>,
+    /// Supports `createMissing` and `deleteMissing` only.
+    #[serde(rename = "templateLinkage")]
+    pub template_linkage: Option<ImportRule>,
+    pub templates: Option<ImportRule>,
+    #[serde(rename = "templateDashboards")]
+    pub template_dashboards: Option<ImportRule>,
+    pub triggers: Option<ImportRule>,
+    #[serde(rename = "valueMaps")]
+    pub value_maps: Option<ImportRule>,
+}
+
+impl ImportRules {
+    /// Rules which make templates on the server match the imported source:
+    /// missing objects are created, existing ones updated and
+    /// template entities absent from the source are deleted.
+    pub fn templates_sync() -> Self {
+        let mut rules = Self {
+            discovery_rules: Some(ImportRule::sync()),
+            graphs: Some(ImportRule::sync()),
+            httptests: Some(ImportRule::sync()),
+            items: Some(ImportRule::sync()),
+            template_linkage: Some(ImportRule {
+                create_missing: Some(true),
+                update_existing: None,
+                delete_missing: Some(true),
+            }),
+            templates: Some(ImportRule::create_update()),
+            template_dashboards: Some(ImportRule::sync()),
+            triggers: Some(ImportRule::sync()),
+            value_maps: Some(ImportRule::sync()),
+            ..Default::default()
+        };
+
+        #[cfg(feature = "v7")]
+        {
+            rules.template_groups = Some(ImportRule::create());
+        }
+
+        #[cfg(feature = "v6")]
+        {
+            rules.groups = Some(ImportRule::create());
+        }
+
+        rules
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ImportRule {
+    #[serde(rename = "createMissing")]
+    pub create_missing: Option<bool>,
+    #[serde(rename = "updateExisting")]
+    pub update_existing: Option<bool>,
+    #[serde(rename = "deleteMissing")]
+    pub delete_missing: Option<bool>,
+}
+
+impl ImportRule {
+    /// Create new objects only.
+    pub fn create() -> Self {
+        Self {
+            create_missing: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Create new objects and update existing ones.
+    pub fn create_update() -> Self {
+        Self {
+            create_missing: Some(true),
+            update_existing: Some(true),
+            delete_missing: None,
+        }
+    }
+
+    /// Create new objects, update existing ones and delete objects absent from the source.
+    pub fn sync() -> Self {
+        Self {
+            create_missing: Some(true),
+            update_existing: Some(true),
+            delete_missing: Some(true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod import_tests {
+    use super::{ImportConfigurationRequest, ImportRules};
+    use crate::configuration::model::ConfigurationFormat;
+
+    #[test]
+    fn templates_sync_rules_serialization_test() {
+        let request = ImportConfigurationRequest::new(
+            ConfigurationFormat::Yaml,
+            "zabbix_export: {}",
+            ImportRules::templates_sync(),
+        );
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["format"], "yaml");
+        assert_eq!(json["rules"]["items"]["createMissing"], true);
+        assert_eq!(json["rules"]["items"]["updateExisting"], true);
+        assert_eq!(json["rules"]["items"]["deleteMissing"], true);
+        assert_eq!(json["rules"]["templates"]["updateExisting"], true);
+        assert!(json["rules"]["templates"].get("deleteMissing").is_none());
+        assert!(json["rules"]["templateLinkage"]
+            .get("updateExisting")
+            .is_none());
+        assert!(json["rules"].get("hosts").is_none());
+    }
+}
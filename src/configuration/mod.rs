@@ -0,0 +1,4 @@
+pub mod compare;
+pub mod export;
+pub mod import;
+pub mod model;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Format of the configuration data used by export and import.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug, Default)]
+pub enum ConfigurationFormat {
+    #[default]
+    #[serde(rename = "yaml")]
+    Yaml,
+    #[serde(rename = "xml")]
+    Xml,
+    #[serde(rename = "json")]
+    Json,
+}
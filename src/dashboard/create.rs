@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::dashboard::layout::check_widget;
+use crate::dashboard::model::{
+    DashboardPage, DashboardPermission, DashboardUser, DashboardUserGroup,
+};
+use crate::error::ZabbixApiError;
+use crate::flag;
+
+/// Slideshow periods supported by Zabbix, in seconds.
+const DISPLAY_PERIODS: [u32; 7] = [10, 30, 60, 120, 600, 1800, 3600];
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/dashboard/create
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateDashboardRequest {
+    pub name: String,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub display_period: Option<u32>,
+    #[serde(with = "flag")]
+    pub auto_start: Option<bool>,
+    #[serde(with = "flag")]
+    pub private: Option<bool>,
+    pub pages: Vec<DashboardPage>,
+    pub users: Vec<DashboardUser>,
+    #[serde(rename = "userGroups")]
+    pub user_groups: Vec<DashboardUserGroup>,
+}
+
+impl CreateDashboardRequest {
+    pub fn builder(name: impl ToString) -> CreateDashboardRequestBuilder {
+        CreateDashboardRequestBuilder {
+            inner: CreateDashboardRequest {
+                name: name.to_string(),
+                display_period: None,
+                auto_start: None,
+                private: None,
+                pages: vec![],
+                users: vec![],
+                user_groups: vec![],
+            },
+        }
+    }
+}
+
+pub struct CreateDashboardRequestBuilder {
+    inner: CreateDashboardRequest,
+}
+
+impl CreateDashboardRequestBuilder {
+    pub fn page(mut self, page: DashboardPage) -> Self {
+        self.inner.pages.push(page);
+        self
+    }
+
+    /// Seconds each page is shown in slideshow mode.
+    pub fn display_period(mut self, seconds: u32) -> Self {
+        self.inner.display_period = Some(seconds);
+        self
+    }
+
+    pub fn auto_start(mut self, auto_start: bool) -> Self {
+        self.inner.auto_start = Some(auto_start);
+        self
+    }
+
+    /// Visible to all users, otherwise only to the owner and shared users.
+    pub fn public(mut self) -> Self {
+        self.inner.private = Some(false);
+        self
+    }
+
+    pub fn share_with_user(
+        mut self,
+        user_id: impl ToString,
+        permission: DashboardPermission,
+    ) -> Self {
+        self.inner
+            .users
+            .push(DashboardUser::new(user_id, permission));
+        self
+    }
+
+    pub fn share_with_user_group(
+        mut self,
+        user_group_id: impl ToString,
+        permission: DashboardPermission,
+    ) -> Self {
+        self.inner
+            .user_groups
+            .push(DashboardUserGroup::new(user_group_id, permission));
+        self
+    }
+
+    /// Checks pages, widget positions and display periods.
+    pub fn build(self) -> Result<CreateDashboardRequest, ZabbixApiError> {
+        let dashboard = self.inner;
+
+        if dashboard.pages.is_empty() {
+            return Err(ZabbixApiError::InvalidRequestError(format!(
+                "dashboard '{}': at least one page is required",
+                dashboard.name
+            )));
+        }
+
+        validate_dashboard(&dashboard.name, dashboard.display_period, &dashboard.pages)?;
+
+        Ok(dashboard)
+    }
+}
+
+pub(crate) fn validate_dashboard(
+    name: &str,
+    display_period: Option<u32>,
+    pages: &[DashboardPage],
+) -> Result<(), ZabbixApiError> {
+    let invalid = |message: String| {
+        Err(ZabbixApiError::InvalidRequestError(format!(
+            "dashboard '{name}': {message}"
+        )))
+    };
+
+    if display_period.is_some_and(|period| !DISPLAY_PERIODS.contains(&period)) {
+        return invalid(format!("display period must be one of {DISPLAY_PERIODS:?}"));
+    }
+
+    for (page_index, page) in pages.iter().enumerate() {
+        let page_name = page
+            .name
+            .clone()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| format!("#{}", page_index + 1));
+
+        if page
+            .display_period
+            .is_some_and(|period| period != 0 && !DISPLAY_PERIODS.contains(&period))
+        {
+            return invalid(format!(
+                "page '{page_name}': display period must be 0 or one of {DISPLAY_PERIODS:?}"
+            ));
+        }
+
+        for (index, widget) in page.widgets.iter().enumerate() {
+            if let Err(message) = check_widget(widget) {
+                return invalid(format!(
+                    "page '{page_name}': widget #{} {message}",
+                    index + 1
+                ));
+            }
+
+            let overlapping = page.widgets[..index].iter().position(|other| {
+                widget.x < other.x + other.width
+                    && other.x < widget.x + widget.width
+                    && widget.y < other.y + other.height
+                    && other.y < widget.y + widget.height
+            });
+
+            if let Some(other) = overlapping {
+                return invalid(format!(
+                    "page '{page_name}': widget #{} overlaps widget #{}",
+                    index + 1,
+                    other + 1
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateDashboardResponse {
+    #[serde(rename = "dashboardids")]
+    pub dashboard_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateDashboardRequest;
+    use crate::dashboard::layout::{GRID_COLUMNS, WIDGET_MIN_HEIGHT};
+    use crate::dashboard::model::{DashboardPage, DashboardPermission};
+    use crate::dashboard::widget::{DashboardWidget, WidgetType};
+
+    #[test]
+    fn serialize_test() {
+        let page = DashboardPage::flow(
+            "Overview",
+            vec![
+                DashboardWidget::clock(),
+                DashboardWidget::item_value("42237"),
+            ],
+        )
+        .unwrap();
+
+        let request = CreateDashboardRequest::builder("Team A")
+            .display_period(60)
+            .page(page)
+            .share_with_user_group("7", DashboardPermission::ReadOnly)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["display_period"], "60");
+        assert_eq!(
+            json["userGroups"],
+            serde_json::json!([{"usrgrpid": "7", "permission": "2"}])
+        );
+        assert_eq!(json["users"], serde_json::json!([]));
+
+        let widgets = &json["pages"][0]["widgets"];
+
+        assert_eq!(widgets[0]["type"], "clock");
+        assert_eq!(widgets[1]["type"], "item");
+        assert_eq!(widgets[1]["x"], (GRID_COLUMNS / 6).to_string());
+        assert_eq!(widgets[1]["fields"][0]["type"], "4");
+    }
+
+    #[test]
+    fn invalid_requests_are_rejected() {
+        assert!(CreateDashboardRequest::builder("No pages").build().is_err());
+
+        assert!(CreateDashboardRequest::builder("Period")
+            .display_period(45)
+            .page(DashboardPage::new("Overview", vec![]))
+            .build()
+            .is_err());
+
+        assert!(CreateDashboardRequest::builder("Outside")
+            .page(DashboardPage::new(
+                "Overview",
+                vec![DashboardWidget::new(WidgetType::Clock, 4, 4).at(GRID_COLUMNS - 2, 0)]
+            ))
+            .build()
+            .is_err());
+
+        assert!(CreateDashboardRequest::builder("Overlap")
+            .page(DashboardPage::new(
+                "Overview",
+                vec![
+                    DashboardWidget::new(WidgetType::Clock, 4, 4),
+                    DashboardWidget::new(WidgetType::Clock, 4, 4).at(2, 2)
+                ]
+            ))
+            .build()
+            .is_err());
+
+        assert!(CreateDashboardRequest::builder("Height")
+            .page(DashboardPage::new(
+                "Overview",
+                vec![DashboardWidget::new(
+                    WidgetType::Clock,
+                    4,
+                    WIDGET_MIN_HEIGHT - 1
+                )]
+            ))
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,21 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/dashboard/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetDashboardsRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "dashboardids")]
+    pub dashboard_ids: Option<Vec<String>>,
+    #[serde(rename = "selectPages")]
+    pub select_pages: Option<String>,
+    #[serde(rename = "selectUsers")]
+    pub select_users: Option<String>,
+    #[serde(rename = "selectUserGroups")]
+    pub select_user_groups: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,245 @@
+use crate::dashboard::widget::DashboardWidget;
+use crate::error::ZabbixApiError;
+
+/// Columns of the dashboard grid.
+#[cfg(feature = "v7")]
+pub const GRID_COLUMNS: u32 = 72;
+/// Columns of the dashboard grid.
+#[cfg(feature = "v6")]
+pub const GRID_COLUMNS: u32 = 24;
+
+/// Rows of the dashboard grid, widgets start at row 63 at the latest.
+#[cfg(feature = "v7")]
+pub const GRID_ROWS: u32 = 64;
+/// Rows of the dashboard grid, widgets start at row 62 at the latest.
+#[cfg(feature = "v6")]
+pub const GRID_ROWS: u32 = 64;
+
+/// Lowest widget, in rows.
+#[cfg(feature = "v7")]
+pub const WIDGET_MIN_HEIGHT: u32 = 1;
+/// Lowest widget, in rows.
+#[cfg(feature = "v6")]
+pub const WIDGET_MIN_HEIGHT: u32 = 2;
+
+/// Highest widget, in rows.
+#[cfg(feature = "v7")]
+pub const WIDGET_MAX_HEIGHT: u32 = 64;
+/// Highest widget, in rows.
+#[cfg(feature = "v6")]
+pub const WIDGET_MAX_HEIGHT: u32 = 32;
+
+/// Places widgets left to right, starting a new row when a widget does not fit.
+///
+/// A row is as high as its highest widget. Widgets wider than the grid are
+/// narrowed to the grid width, widgets below the grid or with an unsupported
+/// height are rejected.
+#[derive(Debug, Clone)]
+pub struct GridLayout {
+    columns: u32,
+    x: u32,
+    y: u32,
+    row_height: u32,
+}
+
+impl Default for GridLayout {
+    fn default() -> Self {
+        Self::new(GRID_COLUMNS)
+    }
+}
+
+impl GridLayout {
+    /// Layout on the first `columns` columns of the grid.
+    pub fn new(columns: u32) -> Self {
+        Self {
+            columns: columns.clamp(1, GRID_COLUMNS),
+            x: 0,
+            y: 0,
+            row_height: 0,
+        }
+    }
+
+    pub fn place(&mut self, widget: DashboardWidget) -> Result<DashboardWidget, ZabbixApiError> {
+        let width = widget.width.clamp(1, self.columns);
+        let height = widget.height;
+
+        let (x, y, row_height) = if self.x + width > self.columns {
+            (0, self.y + self.row_height, 0)
+        } else {
+            (self.x, self.y, self.row_height)
+        };
+
+        let widget = widget.at(x, y).size(width, height);
+
+        check_widget(&widget).map_err(|message| {
+            ZabbixApiError::InvalidRequestError(format!(
+                "{} widget {message}",
+                widget.widget_type.as_str()
+            ))
+        })?;
+
+        self.x = x + width;
+        self.y = y;
+        self.row_height = row_height.max(height);
+
+        Ok(widget)
+    }
+
+    /// Following widgets start at the beginning of the next row.
+    pub fn new_row(&mut self) {
+        self.y += self.row_height;
+        self.x = 0;
+        self.row_height = 0;
+    }
+
+    /// Rows taken by the placed widgets.
+    pub fn height(&self) -> u32 {
+        self.y + self.row_height
+    }
+
+    pub fn flow(
+        mut self,
+        widgets: Vec<DashboardWidget>,
+    ) -> Result<Vec<DashboardWidget>, ZabbixApiError> {
+        widgets
+            .into_iter()
+            .map(|widget| self.place(widget))
+            .collect()
+    }
+}
+
+/// Checks the widget size and position against the grid of the Zabbix version.
+pub(crate) fn check_widget(widget: &DashboardWidget) -> Result<(), String> {
+    if widget.width == 0 {
+        return Err("is empty".to_string());
+    }
+
+    if !(WIDGET_MIN_HEIGHT..=WIDGET_MAX_HEIGHT).contains(&widget.height) {
+        return Err(format!(
+            "must be {WIDGET_MIN_HEIGHT} to {WIDGET_MAX_HEIGHT} rows high"
+        ));
+    }
+
+    if widget.x + widget.width > GRID_COLUMNS || widget.y + widget.height > GRID_ROWS {
+        return Err(format!("is outside of the {GRID_COLUMNS}x{GRID_ROWS} grid"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod layout_tests {
+    use super::{GridLayout, GRID_COLUMNS, GRID_ROWS, WIDGET_MAX_HEIGHT, WIDGET_MIN_HEIGHT};
+    use crate::dashboard::widget::{DashboardWidget, WidgetType};
+
+    #[test]
+    fn widgets_flow_onto_rows() {
+        let half = GRID_COLUMNS / 2;
+        let third = GRID_COLUMNS / 3;
+
+        let widgets = GridLayout::default()
+            .flow(vec![
+                DashboardWidget::new(WidgetType::Clock, half, 3),
+                DashboardWidget::new(WidgetType::Clock, half, 5),
+                DashboardWidget::new(WidgetType::Clock, third, 2),
+                DashboardWidget::new(WidgetType::Clock, GRID_COLUMNS + 10, 4),
+            ])
+            .unwrap();
+
+        let positions: Vec<(u32, u32, u32)> = widgets
+            .iter()
+            .map(|widget| (widget.x, widget.y, widget.width))
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                (0, 0, half),
+                (half, 0, half),
+                (0, 5, third),
+                (0, 7, GRID_COLUMNS)
+            ]
+        );
+    }
+
+    #[test]
+    fn new_row_starts_below_highest_widget() {
+        let mut layout = GridLayout::new(GRID_COLUMNS / 2);
+
+        layout
+            .place(DashboardWidget::new(WidgetType::Clock, 2, 4))
+            .unwrap();
+        layout.new_row();
+
+        let widget = layout
+            .place(DashboardWidget::new(WidgetType::Clock, 2, 2))
+            .unwrap();
+
+        assert_eq!((widget.x, widget.y), (0, 4));
+        assert_eq!(layout.height(), 6);
+    }
+
+    #[test]
+    fn placements_outside_of_version_limits_are_rejected() {
+        let mut layout = GridLayout::default();
+
+        assert!(layout
+            .place(DashboardWidget::new(
+                WidgetType::Clock,
+                2,
+                WIDGET_MIN_HEIGHT - 1
+            ))
+            .is_err());
+        assert!(layout
+            .place(DashboardWidget::new(
+                WidgetType::Clock,
+                2,
+                WIDGET_MAX_HEIGHT + 1
+            ))
+            .is_err());
+
+        let mut layout = GridLayout::default();
+
+        while layout.height() + WIDGET_MAX_HEIGHT <= GRID_ROWS {
+            layout
+                .place(DashboardWidget::new(
+                    WidgetType::Clock,
+                    GRID_COLUMNS,
+                    WIDGET_MAX_HEIGHT,
+                ))
+                .unwrap();
+        }
+
+        assert!(layout
+            .place(DashboardWidget::new(
+                WidgetType::Clock,
+                GRID_COLUMNS,
+                WIDGET_MAX_HEIGHT
+            ))
+            .is_err());
+    }
+
+    #[test]
+    fn rejected_placement_keeps_layout() {
+        let mut layout = GridLayout::default();
+
+        layout
+            .place(DashboardWidget::new(WidgetType::Clock, GRID_COLUMNS - 2, 4))
+            .unwrap();
+
+        assert!(layout
+            .place(DashboardWidget::new(
+                WidgetType::Clock,
+                4,
+                WIDGET_MAX_HEIGHT + 1
+            ))
+            .is_err());
+
+        let widget = layout
+            .place(DashboardWidget::new(WidgetType::Clock, 2, 4))
+            .unwrap();
+
+        assert_eq!((widget.x, widget.y), (GRID_COLUMNS - 2, 0));
+        assert_eq!(layout.height(), 4);
+    }
+}
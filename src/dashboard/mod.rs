@@ -0,0 +1,6 @@
+pub mod create;
+pub mod get;
+pub mod layout;
+pub mod model;
+pub mod update;
+pub mod widget;
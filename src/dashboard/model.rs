@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::dashboard::layout::GridLayout;
+use crate::dashboard::widget::DashboardWidget;
+use crate::error::ZabbixApiError;
+use crate::flag;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/dashboard/object
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixDashboard {
+    #[serde(rename = "dashboardid")]
+    pub dashboard_id: String,
+    pub name: String,
+    /// Owner of the dashboard.
+    #[serde(rename = "userid")]
+    pub user_id: Option<String>,
+    /// Private dashboards are visible only to the owner and shared users.
+    #[serde(default, with = "flag")]
+    pub private: Option<bool>,
+    /// Seconds each page is shown in slideshow mode.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub display_period: Option<u32>,
+    /// Starts the slideshow when the dashboard is opened.
+    #[serde(default, with = "flag")]
+    pub auto_start: Option<bool>,
+    /// Returned with `selectPages`.
+    pub pages: Option<Vec<DashboardPage>>,
+    /// Returned with `selectUsers`.
+    pub users: Option<Vec<DashboardUser>>,
+    /// Returned with `selectUserGroups`.
+    #[serde(rename = "userGroups")]
+    pub user_groups: Option<Vec<DashboardUserGroup>>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct DashboardPage {
+    /// Keeps the existing page when a dashboard is updated.
+    #[serde(rename = "dashboard_pageid")]
+    pub dashboard_page_id: Option<String>,
+    pub name: Option<String>,
+    /// Seconds the page is shown in slideshow mode, `0` for the dashboard default.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub display_period: Option<u32>,
+    #[serde(default)]
+    pub widgets: Vec<DashboardWidget>,
+}
+
+impl DashboardPage {
+    /// Page with widgets at their set positions.
+    pub fn new(name: impl ToString, widgets: Vec<DashboardWidget>) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            widgets,
+            ..Default::default()
+        }
+    }
+
+    /// Page with widgets placed by `GridLayout`, in the given order.
+    pub fn flow(
+        name: impl ToString,
+        widgets: Vec<DashboardWidget>,
+    ) -> Result<Self, ZabbixApiError> {
+        Ok(Self::new(name, GridLayout::default().flow(widgets)?))
+    }
+
+    pub fn display_period(mut self, seconds: u32) -> Self {
+        self.display_period = Some(seconds);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum DashboardPermission {
+    #[serde(rename = "2")]
+    ReadOnly,
+    #[serde(rename = "3")]
+    ReadWrite,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct DashboardUser {
+    #[serde(rename = "userid")]
+    pub user_id: String,
+    pub permission: DashboardPermission,
+}
+
+impl DashboardUser {
+    pub fn new(user_id: impl ToString, permission: DashboardPermission) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            permission,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct DashboardUserGroup {
+    #[serde(rename = "usrgrpid")]
+    pub user_group_id: String,
+    pub permission: DashboardPermission,
+}
+
+impl DashboardUserGroup {
+    pub fn new(user_group_id: impl ToString, permission: DashboardPermission) -> Self {
+        Self {
+            user_group_id: user_group_id.to_string(),
+            permission,
+        }
+    }
+}
+
+#[cfg(test)]
+mod dashboard_tests {
+    use super::{DashboardPermission, ZabbixDashboard};
+    use crate::dashboard::widget::WidgetType;
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"dashboardid":"1","name":"Global view","userid":"1","private":"0","display_period":"30","auto_start":"1",
+             "users":[{"userid":"3","permission":"2"}],
+             "userGroups":[{"usrgrpid":"7","permission":"3"}],
+             "pages":[{"dashboard_pageid":"1","name":"","display_period":"0","widgets":[
+                {"widgetid":"1","type":"systeminfo","name":"","x":"0","y":"0","width":"48","height":"5","view_mode":"0","fields":[]}
+             ]}]}
+            "#;
+
+        let dashboard: ZabbixDashboard = serde_json::from_str(input).unwrap();
+
+        assert_eq!(dashboard.private, Some(false));
+        assert_eq!(dashboard.auto_start, Some(true));
+        assert_eq!(dashboard.display_period, Some(30));
+        assert_eq!(
+            dashboard.users.unwrap()[0].permission,
+            DashboardPermission::ReadOnly
+        );
+        assert_eq!(
+            dashboard.user_groups.unwrap()[0].permission,
+            DashboardPermission::ReadWrite
+        );
+
+        let pages = dashboard.pages.unwrap();
+
+        assert_eq!(
+            pages[0].widgets[0].widget_type,
+            WidgetType::SystemInformation
+        );
+        assert_eq!(pages[0].widgets[0].width, 48);
+    }
+}
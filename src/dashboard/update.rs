@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::dashboard::create::validate_dashboard;
+use crate::dashboard::model::{
+    DashboardPage, DashboardPermission, DashboardUser, DashboardUserGroup,
+};
+use crate::error::ZabbixApiError;
+use crate::flag;
+
+/// Pages, users and user groups replace the existing ones when set. Pages and
+/// widgets with an ID are updated, others are created.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/dashboard/update
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UpdateDashboardRequest {
+    #[serde(rename = "dashboardid")]
+    pub dashboard_id: String,
+    pub name: Option<String>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub display_period: Option<u32>,
+    #[serde(with = "flag")]
+    pub auto_start: Option<bool>,
+    #[serde(with = "flag")]
+    pub private: Option<bool>,
+    pub pages: Option<Vec<DashboardPage>>,
+    pub users: Option<Vec<DashboardUser>>,
+    #[serde(rename = "userGroups")]
+    pub user_groups: Option<Vec<DashboardUserGroup>>,
+}
+
+impl UpdateDashboardRequest {
+    pub fn builder(dashboard_id: impl ToString) -> UpdateDashboardRequestBuilder {
+        UpdateDashboardRequestBuilder {
+            inner: UpdateDashboardRequest {
+                dashboard_id: dashboard_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateDashboardRequestBuilder {
+    inner: UpdateDashboardRequest,
+}
+
+impl UpdateDashboardRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn display_period(mut self, seconds: u32) -> Self {
+        self.inner.display_period = Some(seconds);
+        self
+    }
+
+    pub fn auto_start(mut self, auto_start: bool) -> Self {
+        self.inner.auto_start = Some(auto_start);
+        self
+    }
+
+    pub fn private(mut self, private: bool) -> Self {
+        self.inner.private = Some(private);
+        self
+    }
+
+    pub fn pages(mut self, pages: Vec<DashboardPage>) -> Self {
+        self.inner.pages = Some(pages);
+        self
+    }
+
+    pub fn share_with_user(
+        mut self,
+        user_id: impl ToString,
+        permission: DashboardPermission,
+    ) -> Self {
+        self.inner
+            .users
+            .get_or_insert_with(Vec::new)
+            .push(DashboardUser::new(user_id, permission));
+        self
+    }
+
+    pub fn share_with_user_group(
+        mut self,
+        user_group_id: impl ToString,
+        permission: DashboardPermission,
+    ) -> Self {
+        self.inner
+            .user_groups
+            .get_or_insert_with(Vec::new)
+            .push(DashboardUserGroup::new(user_group_id, permission));
+        self
+    }
+
+    /// Removes sharing with all users and user groups.
+    pub fn unshare(mut self) -> Self {
+        self.inner.users = Some(vec![]);
+        self.inner.user_groups = Some(vec![]);
+        self
+    }
+
+    /// Checks pages, widget positions and display periods when they are set.
+    pub fn build(self) -> Result<UpdateDashboardRequest, ZabbixApiError> {
+        let dashboard = self.inner;
+        let name = dashboard.name.as_deref().unwrap_or(&dashboard.dashboard_id);
+
+        validate_dashboard(
+            name,
+            dashboard.display_period,
+            dashboard.pages.as_deref().unwrap_or_default(),
+        )?;
+
+        Ok(dashboard)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateDashboardResponse {
+    #[serde(rename = "dashboardids")]
+    pub dashboard_ids: Vec<String>,
+}
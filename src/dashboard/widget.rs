@@ -0,0 +1,399 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::dashboard::layout::GRID_COLUMNS;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/dashboard/object#dashboard-widget
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct DashboardWidget {
+    /// Keeps the existing widget when a dashboard is updated.
+    #[serde(rename = "widgetid")]
+    pub widget_id: Option<String>,
+    #[serde(rename = "type")]
+    pub widget_type: WidgetType,
+    pub name: Option<String>,
+    /// Column of the top left corner, from 0.
+    #[serde_as(as = "DisplayFromStr")]
+    pub x: u32,
+    /// Row of the top left corner, from 0.
+    #[serde_as(as = "DisplayFromStr")]
+    pub y: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub width: u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u32,
+    pub view_mode: Option<WidgetViewMode>,
+    #[serde(default)]
+    pub fields: Vec<WidgetField>,
+}
+
+impl DashboardWidget {
+    /// Widget at the top left corner, see `GridLayout` to place it.
+    pub fn new(widget_type: WidgetType, width: u32, height: u32) -> Self {
+        Self {
+            widget_id: None,
+            widget_type,
+            name: None,
+            x: 0,
+            y: 0,
+            width,
+            height,
+            view_mode: None,
+            fields: vec![],
+        }
+    }
+
+    /// Classic graph, half of the dashboard wide.
+    pub fn graph(graph_id: impl ToString) -> Self {
+        Self::new(WidgetType::Graph, GRID_COLUMNS / 2, 5)
+            .field(WidgetField::integer("source_type", 0))
+            .fields(WidgetField::list(
+                "graphid",
+                vec![WidgetFieldValue::Graph(graph_id.to_string())],
+            ))
+    }
+
+    /// Latest value of an item.
+    pub fn item_value(item_id: impl ToString) -> Self {
+        Self::new(WidgetType::ItemValue, GRID_COLUMNS / 6, 3).fields(WidgetField::list(
+            "itemid",
+            vec![WidgetFieldValue::Item(item_id.to_string())],
+        ))
+    }
+
+    pub fn problems() -> Self {
+        Self::new(WidgetType::Problems, GRID_COLUMNS / 2, 5)
+    }
+
+    pub fn clock() -> Self {
+        Self::new(WidgetType::Clock, GRID_COLUMNS / 6, 3)
+    }
+
+    pub fn url(url: impl ToString) -> Self {
+        Self::new(WidgetType::Url, GRID_COLUMNS / 3, 5).field(WidgetField::string("url", url))
+    }
+
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn at(mut self, x: u32, y: u32) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn hide_header(mut self) -> Self {
+        self.view_mode = Some(WidgetViewMode::HiddenHeader);
+        self
+    }
+
+    /// Refresh interval in seconds, `0` disables refresh.
+    pub fn refresh_interval(self, seconds: i64) -> Self {
+        self.field(WidgetField::integer("rf_rate", seconds))
+    }
+
+    /// Limits the widget to the host groups.
+    pub fn host_groups(self, group_ids: &[String]) -> Self {
+        self.fields(WidgetField::list(
+            "groupids",
+            group_ids
+                .iter()
+                .map(|group_id| WidgetFieldValue::HostGroup(group_id.to_string()))
+                .collect(),
+        ))
+    }
+
+    /// Limits the widget to the hosts.
+    pub fn hosts(self, host_ids: &[String]) -> Self {
+        self.fields(WidgetField::list(
+            "hostids",
+            host_ids
+                .iter()
+                .map(|host_id| WidgetFieldValue::Host(host_id.to_string()))
+                .collect(),
+        ))
+    }
+
+    pub fn field(mut self, field: WidgetField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<WidgetField>) -> Self {
+        self.fields.extend(fields);
+        self
+    }
+}
+
+/// Widget type, unknown types are kept as `Other`.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug)]
+#[serde(from = "String", into = "String")]
+pub enum WidgetType {
+    ActionLog,
+    Clock,
+    Discovery,
+    FavoriteGraphs,
+    FavoriteMaps,
+    Gauge,
+    Geomap,
+    /// Classic graph.
+    Graph,
+    GraphPrototype,
+    HostAvailability,
+    ItemHistory,
+    ItemValue,
+    Map,
+    MapNavigationTree,
+    PieChart,
+    ProblemHosts,
+    Problems,
+    ProblemsBySeverity,
+    SlaReport,
+    SvgGraph,
+    SystemInformation,
+    TopHosts,
+    TriggerOverview,
+    Url,
+    WebMonitoring,
+    Other(String),
+}
+
+impl WidgetType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            WidgetType::ActionLog => "actionlog",
+            WidgetType::Clock => "clock",
+            WidgetType::Discovery => "discovery",
+            WidgetType::FavoriteGraphs => "favgraphs",
+            WidgetType::FavoriteMaps => "favmaps",
+            WidgetType::Gauge => "gauge",
+            WidgetType::Geomap => "geomap",
+            WidgetType::Graph => "graph",
+            WidgetType::GraphPrototype => "graphprototype",
+            WidgetType::HostAvailability => "hostavail",
+            WidgetType::ItemHistory => "itemhistory",
+            WidgetType::ItemValue => "item",
+            WidgetType::Map => "map",
+            WidgetType::MapNavigationTree => "navtree",
+            WidgetType::PieChart => "piechart",
+            WidgetType::ProblemHosts => "problemhosts",
+            WidgetType::Problems => "problems",
+            WidgetType::ProblemsBySeverity => "problemsbysv",
+            WidgetType::SlaReport => "slareport",
+            WidgetType::SvgGraph => "svggraph",
+            WidgetType::SystemInformation => "systeminfo",
+            WidgetType::TopHosts => "tophosts",
+            WidgetType::TriggerOverview => "trigover",
+            WidgetType::Url => "url",
+            WidgetType::WebMonitoring => "web",
+            WidgetType::Other(widget_type) => widget_type,
+        }
+    }
+}
+
+impl From<String> for WidgetType {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "actionlog" => WidgetType::ActionLog,
+            "clock" => WidgetType::Clock,
+            "discovery" => WidgetType::Discovery,
+            "favgraphs" => WidgetType::FavoriteGraphs,
+            "favmaps" => WidgetType::FavoriteMaps,
+            "gauge" => WidgetType::Gauge,
+            "geomap" => WidgetType::Geomap,
+            "graph" => WidgetType::Graph,
+            "graphprototype" => WidgetType::GraphPrototype,
+            "hostavail" => WidgetType::HostAvailability,
+            "itemhistory" => WidgetType::ItemHistory,
+            "item" => WidgetType::ItemValue,
+            "map" => WidgetType::Map,
+            "navtree" => WidgetType::MapNavigationTree,
+            "piechart" => WidgetType::PieChart,
+            "problemhosts" => WidgetType::ProblemHosts,
+            "problems" => WidgetType::Problems,
+            "problemsbysv" => WidgetType::ProblemsBySeverity,
+            "slareport" => WidgetType::SlaReport,
+            "svggraph" => WidgetType::SvgGraph,
+            "systeminfo" => WidgetType::SystemInformation,
+            "tophosts" => WidgetType::TopHosts,
+            "trigover" => WidgetType::TriggerOverview,
+            "url" => WidgetType::Url,
+            "web" => WidgetType::WebMonitoring,
+            _ => WidgetType::Other(value),
+        }
+    }
+}
+
+impl From<WidgetType> for String {
+    fn from(value: WidgetType) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum WidgetViewMode {
+    #[serde(rename = "0")]
+    Default,
+    #[serde(rename = "1")]
+    HiddenHeader,
+}
+
+/// Widget parameter, the value type is serialized as the field `type`.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct WidgetField {
+    pub name: String,
+    #[serde(flatten)]
+    pub value: WidgetFieldValue,
+}
+
+impl WidgetField {
+    pub fn new(name: impl ToString, value: WidgetFieldValue) -> Self {
+        Self {
+            name: name.to_string(),
+            value,
+        }
+    }
+
+    pub fn integer(name: impl ToString, value: i64) -> Self {
+        Self::new(name, WidgetFieldValue::Integer(value))
+    }
+
+    pub fn string(name: impl ToString, value: impl ToString) -> Self {
+        Self::new(name, WidgetFieldValue::String(value.to_string()))
+    }
+
+    /// Multiple values of one parameter, e.g. host groups of a problems widget,
+    /// named `name.0`, `name.1`, ..
+    #[cfg(feature = "v7")]
+    pub fn list(name: &str, values: Vec<WidgetFieldValue>) -> Vec<Self> {
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| Self::new(format!("{name}.{index}"), value))
+            .collect()
+    }
+
+    /// Multiple values of one parameter, e.g. host groups of a problems widget,
+    /// each named `name`.
+    #[cfg(feature = "v6")]
+    pub fn list(name: &str, values: Vec<WidgetFieldValue>) -> Vec<Self> {
+        values
+            .into_iter()
+            .map(|value| Self::new(name, value))
+            .collect()
+    }
+}
+
+/// Value of a widget field by type, IDs refer to the objects of the type.
+#[serde_as]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(tag = "type", content = "value")]
+pub enum WidgetFieldValue {
+    #[serde(rename = "0")]
+    Integer(#[serde_as(as = "DisplayFromStr")] i64),
+    #[serde(rename = "1")]
+    String(String),
+    #[serde(rename = "2")]
+    HostGroup(String),
+    #[serde(rename = "3")]
+    Host(String),
+    #[serde(rename = "4")]
+    Item(String),
+    #[serde(rename = "5")]
+    ItemPrototype(String),
+    #[serde(rename = "6")]
+    Graph(String),
+    #[serde(rename = "7")]
+    GraphPrototype(String),
+    #[serde(rename = "8")]
+    Map(String),
+    #[serde(rename = "9")]
+    Service(String),
+    #[serde(rename = "10")]
+    Sla(String),
+    #[serde(rename = "11")]
+    User(String),
+    #[serde(rename = "12")]
+    Action(String),
+    #[serde(rename = "13")]
+    MediaType(String),
+}
+
+#[cfg(test)]
+mod widget_tests {
+    use super::{DashboardWidget, WidgetField, WidgetFieldValue, WidgetType};
+    use crate::dashboard::layout::GRID_COLUMNS;
+
+    #[test]
+    fn serialize_test() {
+        let widget = DashboardWidget::problems()
+            .name("Team problems")
+            .at(0, 5)
+            .host_groups(&["2".to_string(), "4".to_string()])
+            .refresh_interval(60);
+
+        let json = serde_json::to_value(&widget).unwrap();
+
+        #[cfg(feature = "v7")]
+        let names = ["groupids.0", "groupids.1"];
+        #[cfg(feature = "v6")]
+        let names = ["groupids", "groupids"];
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "type": "problems",
+                "name": "Team problems",
+                "x": "0",
+                "y": "5",
+                "width": (GRID_COLUMNS / 2).to_string(),
+                "height": "5",
+                "fields": [
+                    {"name": names[0], "type": "2", "value": "2"},
+                    {"name": names[1], "type": "2", "value": "4"},
+                    {"name": "rf_rate", "type": "0", "value": "60"}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"widgetid":"9","type":"svggraph","name":"","x":"0","y":"0","width":"36","height":"5","view_mode":"0",
+             "fields":[{"type":"0","name":"rf_rate","value":"30"},{"type":"1","name":"ds.0.hosts.0","value":"Zabbix server"},{"type":"4","name":"itemid.0","value":"42237"}]}
+            "#;
+
+        let widget: DashboardWidget = serde_json::from_str(input).unwrap();
+
+        assert_eq!(widget.widget_type, WidgetType::SvgGraph);
+        assert_eq!(widget.fields[0], WidgetField::integer("rf_rate", 30));
+        assert_eq!(
+            widget.fields[1],
+            WidgetField::string("ds.0.hosts.0", "Zabbix server")
+        );
+        assert_eq!(
+            widget.fields[2].value,
+            WidgetFieldValue::Item("42237".to_string())
+        );
+
+        let input = r#"{"type":"honeycomb","x":"0","y":"0","width":"12","height":"4"}"#;
+
+        let widget: DashboardWidget = serde_json::from_str(input).unwrap();
+
+        assert_eq!(
+            widget.widget_type,
+            WidgetType::Other("honeycomb".to_string())
+        );
+    }
+}
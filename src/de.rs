@@ -0,0 +1,28 @@
+//! Deserializers for values which Zabbix returns as strings.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+/// Object ID where `0` stands for no object.
+pub(crate) fn deserialize_object_id<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let id = Option::<String>::deserialize(deserializer)?;
+
+    Ok(id.filter(|id| id != "0"))
+}
+
+/// Value parsed from its string form, e.g. a history or trend value.
+pub(crate) fn deserialize_value<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    String::deserialize(deserializer)?
+        .parse::<T>()
+        .map_err(serde::de::Error::custom)
+}
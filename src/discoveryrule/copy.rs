@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+/// Copies discovery rules with all their prototypes to other hosts.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/copy
+#[derive(Serialize, Debug)]
+pub struct CopyDiscoveryRulesRequest {
+    #[serde(rename = "discoveryids")]
+    pub discovery_ids: Vec<String>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Vec<String>,
+}
+
+impl CopyDiscoveryRulesRequest {
+    pub fn new(discovery_ids: Vec<String>, host_ids: Vec<String>) -> Self {
+        Self {
+            discovery_ids,
+            host_ids,
+        }
+    }
+}
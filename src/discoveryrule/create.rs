@@ -0,0 +1,251 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::item::model::ItemType;
+use crate::item::preprocessing::ItemPreprocessing;
+
+#[cfg(feature = "v7")]
+use crate::discoveryrule::model::LldLifetimeType;
+use crate::discoveryrule::model::{LldFilter, LldMacroPath, LldOverride};
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug)]
+pub struct CreateDiscoveryRuleRequest {
+    pub name: String,
+    pub key_: String,
+    #[serde(rename = "hostid")]
+    pub host_id: String,
+    pub r#type: ItemType,
+    #[serde(rename = "interfaceid")]
+    pub interface_id: Option<String>,
+    pub delay: Option<String>,
+    pub description: Option<String>,
+    pub lifetime: Option<String>,
+    #[cfg(feature = "v7")]
+    pub lifetime_type: Option<LldLifetimeType>,
+    #[cfg(feature = "v7")]
+    pub enabled_lifetime: Option<String>,
+    #[cfg(feature = "v7")]
+    pub enabled_lifetime_type: Option<LldLifetimeType>,
+    #[serde(rename = "master_itemid")]
+    pub master_item_id: Option<String>,
+    /// Script of script rules, formula of calculated rules.
+    pub params: Option<String>,
+    pub url: Option<String>,
+    pub snmp_oid: Option<String>,
+    pub trapper_hosts: Option<String>,
+    pub timeout: Option<String>,
+    pub filter: Option<LldFilter>,
+    pub lld_macro_paths: Option<Vec<LldMacroPath>>,
+    pub preprocessing: Option<Vec<ItemPreprocessing>>,
+    pub overrides: Option<Vec<LldOverride>>,
+}
+
+impl CreateDiscoveryRuleRequest {
+    pub fn builder(
+        host_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        rule_type: ItemType,
+    ) -> CreateDiscoveryRuleRequestBuilder {
+        CreateDiscoveryRuleRequestBuilder::new(host_id, name, key, rule_type)
+    }
+}
+
+pub struct CreateDiscoveryRuleRequestBuilder {
+    inner: CreateDiscoveryRuleRequest,
+}
+
+impl CreateDiscoveryRuleRequestBuilder {
+    pub fn new(
+        host_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        rule_type: ItemType,
+    ) -> Self {
+        Self {
+            inner: CreateDiscoveryRuleRequest {
+                name: name.to_string(),
+                key_: key.to_string(),
+                host_id: host_id.to_string(),
+                r#type: rule_type,
+                interface_id: None,
+                delay: None,
+                description: None,
+                lifetime: None,
+                #[cfg(feature = "v7")]
+                lifetime_type: None,
+                #[cfg(feature = "v7")]
+                enabled_lifetime: None,
+                #[cfg(feature = "v7")]
+                enabled_lifetime_type: None,
+                master_item_id: None,
+                params: None,
+                url: None,
+                snmp_oid: None,
+                trapper_hosts: None,
+                timeout: None,
+                filter: None,
+                lld_macro_paths: None,
+                preprocessing: None,
+                overrides: None,
+            },
+        }
+    }
+
+    pub fn interface_id(mut self, interface_id: impl ToString) -> Self {
+        self.inner.interface_id = Some(interface_id.to_string());
+        self
+    }
+
+    pub fn delay(mut self, delay: impl ToString) -> Self {
+        self.inner.delay = Some(delay.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    /// Entities no longer discovered are deleted after `lifetime`, e.g. `7d`.
+    pub fn lifetime(mut self, lifetime: impl ToString) -> Self {
+        self.inner.lifetime = Some(lifetime.to_string());
+        #[cfg(feature = "v7")]
+        {
+            self.inner.lifetime_type = Some(LldLifetimeType::After);
+        }
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn lifetime_type(mut self, lifetime_type: LldLifetimeType) -> Self {
+        self.inner.lifetime_type = Some(lifetime_type);
+        self
+    }
+
+    /// Entities no longer discovered are disabled after `enabled_lifetime`, e.g. `1d`.
+    #[cfg(feature = "v7")]
+    pub fn enabled_lifetime(mut self, enabled_lifetime: impl ToString) -> Self {
+        self.inner.enabled_lifetime = Some(enabled_lifetime.to_string());
+        self.inner.enabled_lifetime_type = Some(LldLifetimeType::After);
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn enabled_lifetime_type(mut self, enabled_lifetime_type: LldLifetimeType) -> Self {
+        self.inner.enabled_lifetime_type = Some(enabled_lifetime_type);
+        self
+    }
+
+    pub fn master_item_id(mut self, master_item_id: impl ToString) -> Self {
+        self.inner.master_item_id = Some(master_item_id.to_string());
+        self
+    }
+
+    pub fn params(mut self, params: impl ToString) -> Self {
+        self.inner.params = Some(params.to_string());
+        self
+    }
+
+    pub fn url(mut self, url: impl ToString) -> Self {
+        self.inner.url = Some(url.to_string());
+        self
+    }
+
+    pub fn snmp_oid(mut self, snmp_oid: impl ToString) -> Self {
+        self.inner.snmp_oid = Some(snmp_oid.to_string());
+        self
+    }
+
+    pub fn trapper_hosts(mut self, trapper_hosts: impl ToString) -> Self {
+        self.inner.trapper_hosts = Some(trapper_hosts.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: impl ToString) -> Self {
+        self.inner.timeout = Some(timeout.to_string());
+        self
+    }
+
+    pub fn filter(mut self, filter: LldFilter) -> Self {
+        self.inner.filter = Some(filter);
+        self
+    }
+
+    /// Maps an LLD macro to a JSONPath, e.g. `{#FSNAME}` to `$.fsname`.
+    pub fn macro_path(mut self, lld_macro: impl ToString, path: impl ToString) -> Self {
+        self.inner
+            .lld_macro_paths
+            .get_or_insert_with(Vec::new)
+            .push(LldMacroPath::new(lld_macro, path));
+        self
+    }
+
+    pub fn preprocessing_step(mut self, step: impl Into<ItemPreprocessing>) -> Self {
+        self.inner
+            .preprocessing
+            .get_or_insert_with(Vec::new)
+            .push(step.into());
+        self
+    }
+
+    pub fn override_rule(mut self, override_rule: LldOverride) -> Self {
+        self.inner
+            .overrides
+            .get_or_insert_with(Vec::new)
+            .push(override_rule);
+        self
+    }
+
+    pub fn build(self) -> CreateDiscoveryRuleRequest {
+        self.inner
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/create
+#[derive(Deserialize, Debug)]
+pub struct CreateDiscoveryRuleResponse {
+    #[serde(rename = "itemids")]
+    pub item_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateDiscoveryRuleRequest;
+    use crate::discoveryrule::model::{LldFilter, LldFilterCondition};
+    use crate::item::model::ItemType;
+
+    #[test]
+    fn builder_serialization_test() {
+        let request = CreateDiscoveryRuleRequest::builder(
+            "10084",
+            "Services discovery",
+            "services.discovery",
+            ItemType::Dependent,
+        )
+        .master_item_id("23296")
+        .macro_path("{#SERVICE}", "$.name")
+        .filter(LldFilter::and_or(vec![LldFilterCondition::not_matches(
+            "{#SERVICE}",
+            "^test",
+        )]))
+        .lifetime("3d")
+        .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["type"], "18");
+        assert_eq!(json["master_itemid"], "23296");
+        assert_eq!(json["lifetime"], "3d");
+        assert_eq!(json["lld_macro_paths"][0]["lld_macro"], "{#SERVICE}");
+        assert_eq!(json["filter"]["evaltype"], "0");
+        assert_eq!(json["filter"]["conditions"][0]["operator"], "9");
+        assert!(json["filter"].get("formula").is_none());
+        assert!(json.get("delay").is_none());
+
+        #[cfg(feature = "v7")]
+        assert_eq!(json["lifetime_type"], "0");
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetDiscoveryRulesRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "itemids")]
+    pub item_ids: Option<Vec<String>>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "templateids")]
+    pub template_ids: Option<Vec<String>>,
+    /// Return only discovery rules that belong to templates.
+    pub templated: Option<bool>,
+    #[serde(rename = "selectFilter")]
+    pub select_filter: Option<String>,
+    #[serde(rename = "selectLLDMacroPaths")]
+    pub select_lld_macro_paths: Option<String>,
+    #[serde(rename = "selectPreprocessing")]
+    pub select_preprocessing: Option<String>,
+    #[serde(rename = "selectOverrides")]
+    pub select_overrides: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct DiscoveryRuleFilter {
+    pub key_: Option<Vec<String>>,
+    pub name: Option<Vec<String>>,
+}
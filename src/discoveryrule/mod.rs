@@ -0,0 +1,5 @@
+pub mod copy;
+pub mod create;
+pub mod get;
+pub mod model;
+pub mod update;
@@ -0,0 +1,559 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::host::create::InventoryMode;
+use crate::host::model::ZabbixHostTag;
+use crate::item::model::{ItemState, ItemStatus, ItemType};
+use crate::item::preprocessing::ItemPreprocessing;
+use crate::template::model::ZabbixTemplateId;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/object
+///
+/// Properties are optional because they are returned only if requested in `output`.
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixDiscoveryRule {
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+
+    #[serde(rename = "hostid")]
+    pub host_id: String,
+
+    pub name: String,
+
+    pub key_: String,
+
+    pub r#type: Option<ItemType>,
+
+    pub status: Option<ItemStatus>,
+
+    /// Whether the rule is supported. Read-only.
+    pub state: Option<ItemState>,
+
+    /// Error text if there are problems updating the rule. Read-only.
+    pub error: Option<String>,
+
+    pub description: Option<String>,
+
+    pub delay: Option<String>,
+
+    #[serde(rename = "interfaceid")]
+    pub interface_id: Option<String>,
+
+    #[serde(rename = "master_itemid")]
+    pub master_item_id: Option<String>,
+
+    /// Time period after which entities no longer discovered are deleted.
+    pub lifetime: Option<String>,
+
+    #[cfg(feature = "v7")]
+    pub lifetime_type: Option<LldLifetimeType>,
+
+    /// Time period after which entities no longer discovered are disabled.
+    #[cfg(feature = "v7")]
+    pub enabled_lifetime: Option<String>,
+
+    #[cfg(feature = "v7")]
+    pub enabled_lifetime_type: Option<LldLifetimeType>,
+
+    #[serde(rename = "templateid")]
+    pub template_id: Option<String>,
+
+    pub uuid: Option<String>,
+
+    /// Returned with `selectFilter`.
+    pub filter: Option<LldFilter>,
+
+    /// Returned with `selectLLDMacroPaths`.
+    pub lld_macro_paths: Option<Vec<LldMacroPath>>,
+
+    /// Returned with `selectPreprocessing`.
+    pub preprocessing: Option<Vec<ItemPreprocessing>>,
+
+    /// Returned with `selectOverrides`.
+    pub overrides: Option<Vec<LldOverride>>,
+}
+
+/// Whether entities are discovered from a prototype.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum PrototypeDiscover {
+    #[serde(rename = "0")]
+    Discover,
+    #[serde(rename = "1")]
+    DontDiscover,
+}
+
+impl From<bool> for PrototypeDiscover {
+    fn from(discover: bool) -> Self {
+        if discover {
+            PrototypeDiscover::Discover
+        } else {
+            PrototypeDiscover::DontDiscover
+        }
+    }
+}
+
+/// When entities which are no longer discovered are deleted or disabled.
+#[cfg(feature = "v7")]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum LldLifetimeType {
+    /// After the period set in `lifetime` (`enabled_lifetime`).
+    #[serde(rename = "0")]
+    After,
+    #[serde(rename = "1")]
+    Never,
+    #[serde(rename = "2")]
+    Immediately,
+}
+
+/// Maps an LLD macro to a JSONPath in the discovery data, e.g. `{#FSNAME}` to `$.fsname`.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/object#lld-macro-path
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldMacroPath {
+    pub lld_macro: String,
+    pub path: String,
+}
+
+impl LldMacroPath {
+    pub fn new(lld_macro: impl ToString, path: impl ToString) -> Self {
+        Self {
+            lld_macro: lld_macro.to_string(),
+            path: path.to_string(),
+        }
+    }
+}
+
+/// Filter of discovery rules and LLD overrides.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/object#lld-rule-filter
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldFilter {
+    #[serde(rename = "evaltype")]
+    pub eval_type: LldEvalType,
+    /// Custom expression like `A and (B or C)`, required for `LldEvalType::Custom`.
+    pub formula: Option<String>,
+    #[serde(default)]
+    pub conditions: Vec<LldFilterCondition>,
+    /// Generated expression used to evaluate the conditions. Read-only.
+    #[serde(skip_serializing)]
+    pub eval_formula: Option<String>,
+}
+
+impl LldFilter {
+    pub fn new(eval_type: LldEvalType, conditions: Vec<LldFilterCondition>) -> Self {
+        Self {
+            eval_type,
+            formula: None,
+            conditions,
+            eval_formula: None,
+        }
+    }
+
+    /// Conditions with the same macro are combined with `or`, different macros with `and`.
+    pub fn and_or(conditions: Vec<LldFilterCondition>) -> Self {
+        Self::new(LldEvalType::AndOr, conditions)
+    }
+
+    /// Conditions are combined by `formula` referring to the condition formula IDs.
+    pub fn custom(formula: impl ToString, conditions: Vec<LldFilterCondition>) -> Self {
+        Self {
+            formula: Some(formula.to_string()),
+            ..Self::new(LldEvalType::Custom, conditions)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum LldEvalType {
+    #[serde(rename = "0")]
+    AndOr,
+    #[serde(rename = "1")]
+    And,
+    #[serde(rename = "2")]
+    Or,
+    #[serde(rename = "3")]
+    Custom,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldFilterCondition {
+    /// LLD macro to check, e.g. `{#FSTYPE}`.
+    pub r#macro: String,
+    #[serde(default)]
+    pub value: String,
+    pub operator: LldFilterOperator,
+    /// ID referenced from a custom filter formula, e.g. `A`.
+    #[serde(rename = "formulaid")]
+    pub formula_id: Option<String>,
+}
+
+impl LldFilterCondition {
+    pub fn new(
+        lld_macro: impl ToString,
+        operator: LldFilterOperator,
+        value: impl ToString,
+    ) -> Self {
+        Self {
+            r#macro: lld_macro.to_string(),
+            value: value.to_string(),
+            operator,
+            formula_id: None,
+        }
+    }
+
+    pub fn matches(lld_macro: impl ToString, regex: impl ToString) -> Self {
+        Self::new(lld_macro, LldFilterOperator::Matches, regex)
+    }
+
+    pub fn not_matches(lld_macro: impl ToString, regex: impl ToString) -> Self {
+        Self::new(lld_macro, LldFilterOperator::NotMatches, regex)
+    }
+
+    pub fn exists(lld_macro: impl ToString) -> Self {
+        Self::new(lld_macro, LldFilterOperator::Exists, "")
+    }
+
+    pub fn not_exists(lld_macro: impl ToString) -> Self {
+        Self::new(lld_macro, LldFilterOperator::NotExists, "")
+    }
+
+    pub fn formula_id(mut self, formula_id: impl ToString) -> Self {
+        self.formula_id = Some(formula_id.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum LldFilterOperator {
+    #[serde(rename = "8")]
+    Matches,
+    #[serde(rename = "9")]
+    NotMatches,
+    #[serde(rename = "12")]
+    Exists,
+    #[serde(rename = "13")]
+    NotExists,
+}
+
+/// Changes applied to prototypes whose discovered entities match the override filter.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/object#lld-rule-overrides
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldOverride {
+    pub name: String,
+    /// Order in which overrides are processed, starting from 1.
+    #[serde_as(as = "DisplayFromStr")]
+    pub step: u32,
+    /// Stop processing next overrides when this one matches.
+    pub stop: Option<LldOverrideStop>,
+    pub filter: Option<LldFilter>,
+    #[serde(default)]
+    pub operations: Vec<LldOverrideOperation>,
+}
+
+impl LldOverride {
+    pub fn new(name: impl ToString, step: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            step,
+            stop: None,
+            filter: None,
+            operations: vec![],
+        }
+    }
+
+    pub fn stop(mut self) -> Self {
+        self.stop = Some(LldOverrideStop::Stop);
+        self
+    }
+
+    pub fn filter(mut self, filter: LldFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn operation(mut self, operation: LldOverrideOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum LldOverrideStop {
+    #[serde(rename = "0")]
+    Continue,
+    #[serde(rename = "1")]
+    Stop,
+}
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/object#lld-override-operation
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldOverrideOperation {
+    #[serde(rename = "operationobject")]
+    pub operation_object: LldOverrideObject,
+    /// How `value` is matched against the prototype name (key for item prototypes).
+    pub operator: Option<LldOverrideOperator>,
+    pub value: Option<String>,
+    #[serde(rename = "opstatus")]
+    pub status: Option<LldOpStatus>,
+    #[serde(rename = "opdiscover")]
+    pub discover: Option<LldOpDiscover>,
+    #[serde(rename = "opperiod")]
+    pub period: Option<LldOpPeriod>,
+    #[serde(rename = "ophistory")]
+    pub history: Option<LldOpHistory>,
+    #[serde(rename = "optrends")]
+    pub trends: Option<LldOpTrends>,
+    #[serde(rename = "opseverity")]
+    pub severity: Option<LldOpSeverity>,
+    #[serde(rename = "optag")]
+    pub tags: Option<Vec<ZabbixHostTag>>,
+    #[serde(rename = "optemplate")]
+    pub templates: Option<Vec<ZabbixTemplateId>>,
+    #[serde(rename = "opinventory")]
+    pub inventory: Option<LldOpInventory>,
+}
+
+impl LldOverrideOperation {
+    /// Operation on prototypes of the given kind matching `operator` and `value`.
+    pub fn new(
+        operation_object: LldOverrideObject,
+        operator: LldOverrideOperator,
+        value: impl ToString,
+    ) -> Self {
+        Self {
+            operation_object,
+            operator: Some(operator),
+            value: Some(value.to_string()),
+            status: None,
+            discover: None,
+            period: None,
+            history: None,
+            trends: None,
+            severity: None,
+            tags: None,
+            templates: None,
+            inventory: None,
+        }
+    }
+
+    /// Discovered entities are created enabled or disabled.
+    pub fn status(mut self, enabled: bool) -> Self {
+        self.status = Some(LldOpStatus {
+            status: if enabled { "0" } else { "1" }.to_string(),
+        });
+        self
+    }
+
+    /// Whether entities are discovered at all.
+    pub fn discover(mut self, discover: bool) -> Self {
+        self.discover = Some(LldOpDiscover {
+            discover: PrototypeDiscover::from(discover),
+        });
+        self
+    }
+
+    /// Item prototypes only.
+    pub fn period(mut self, delay: impl ToString) -> Self {
+        self.period = Some(LldOpPeriod {
+            delay: delay.to_string(),
+        });
+        self
+    }
+
+    /// Item prototypes only.
+    pub fn history(mut self, history: impl ToString) -> Self {
+        self.history = Some(LldOpHistory {
+            history: history.to_string(),
+        });
+        self
+    }
+
+    /// Item prototypes only.
+    pub fn trends(mut self, trends: impl ToString) -> Self {
+        self.trends = Some(LldOpTrends {
+            trends: trends.to_string(),
+        });
+        self
+    }
+
+    /// Trigger prototypes only. Severity from `0` (not classified) to `5` (disaster).
+    pub fn severity(mut self, severity: u8) -> Self {
+        self.severity = Some(LldOpSeverity { severity });
+        self
+    }
+
+    pub fn tag(mut self, tag: impl ToString, value: impl ToString) -> Self {
+        self.tags.get_or_insert_with(Vec::new).push(ZabbixHostTag {
+            tag: tag.to_string(),
+            value: value.to_string(),
+        });
+        self
+    }
+
+    /// Host prototypes only.
+    pub fn template(mut self, template_id: impl ToString) -> Self {
+        self.templates
+            .get_or_insert_with(Vec::new)
+            .push(ZabbixTemplateId {
+                template_id: template_id.to_string(),
+            });
+        self
+    }
+
+    /// Host prototypes only.
+    pub fn inventory(mut self, inventory_mode: InventoryMode) -> Self {
+        self.inventory = Some(LldOpInventory { inventory_mode });
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum LldOverrideObject {
+    #[serde(rename = "0")]
+    ItemPrototype,
+    #[serde(rename = "1")]
+    TriggerPrototype,
+    #[serde(rename = "2")]
+    GraphPrototype,
+    #[serde(rename = "3")]
+    HostPrototype,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum LldOverrideOperator {
+    #[serde(rename = "0")]
+    Equals,
+    #[serde(rename = "1")]
+    NotEquals,
+    #[serde(rename = "2")]
+    Contains,
+    #[serde(rename = "3")]
+    NotContains,
+    #[serde(rename = "8")]
+    Matches,
+    #[serde(rename = "9")]
+    NotMatches,
+}
+
+/// `0` - create enabled, `1` - create disabled.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldOpStatus {
+    pub status: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldOpDiscover {
+    pub discover: PrototypeDiscover,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldOpPeriod {
+    pub delay: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldOpHistory {
+    pub history: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldOpTrends {
+    pub trends: String,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldOpSeverity {
+    #[serde_as(as = "DisplayFromStr")]
+    pub severity: u8,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct LldOpInventory {
+    pub inventory_mode: InventoryMode,
+}
+
+#[cfg(test)]
+mod discoveryrule_tests {
+    use super::{
+        LldFilter, LldFilterCondition, LldFilterOperator, LldOverride, LldOverrideObject,
+        LldOverrideOperation, LldOverrideOperator, PrototypeDiscover, ZabbixDiscoveryRule,
+    };
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"itemid":"27425","type":"0","snmp_oid":"","hostid":"10084","name":"Mounted filesystem discovery","key_":"vfs.fs.discovery","delay":"1h","status":"0","trapper_hosts":"","templateid":"0","valuemapid":"0","params":"","ipmi_sensor":"","authtype":"0","username":"","password":"","publickey":"","privatekey":"","flags":"1","interfaceid":"1","description":"","lifetime":"7d","lifetime_type":"0","enabled_lifetime_type":"2","enabled_lifetime":"0","state":"0","error":"","uuid":"",
+             "filter":{"evaltype":"1","formula":"","conditions":[{"macro":"{#FSTYPE}","value":"ext4","operator":"8","formulaid":"A"}],"eval_formula":"A"},
+             "lld_macro_paths":[{"lld_macro":"{#FSNAME}","path":"$.fsname"}],
+             "preprocessing":[],
+             "overrides":[{"name":"Discover only root","step":"1","stop":"1","filter":{"evaltype":"0","formula":"","conditions":[{"macro":"{#FSNAME}","value":"^/$","operator":"9","formulaid":"A"}],"eval_formula":"A"},"operations":[{"operationobject":"0","operator":"2","value":"vfs.fs.size","opdiscover":{"discover":"1"}}]}]}
+            "#;
+
+        let rule: ZabbixDiscoveryRule = serde_json::from_str(input).unwrap();
+
+        let filter = rule.filter.unwrap();
+        assert_eq!(filter.conditions[0].operator, LldFilterOperator::Matches);
+        assert_eq!(filter.eval_formula.as_deref(), Some("A"));
+
+        assert_eq!(rule.lld_macro_paths.unwrap()[0].path, "$.fsname");
+
+        let overrides = rule.overrides.unwrap();
+        assert_eq!(overrides[0].step, 1);
+        assert_eq!(
+            overrides[0].operations[0].operator,
+            Some(LldOverrideOperator::Contains)
+        );
+        assert_eq!(
+            overrides[0].operations[0]
+                .discover
+                .as_ref()
+                .unwrap()
+                .discover,
+            PrototypeDiscover::DontDiscover
+        );
+    }
+
+    #[test]
+    fn override_serialization_test() {
+        let value = LldOverride::new("Skip tmpfs", 1)
+            .stop()
+            .filter(LldFilter::custom(
+                "A and B",
+                vec![
+                    LldFilterCondition::matches("{#FSTYPE}", "tmpfs").formula_id("A"),
+                    LldFilterCondition::exists("{#FSNAME}").formula_id("B"),
+                ],
+            ))
+            .operation(
+                LldOverrideOperation::new(
+                    LldOverrideObject::TriggerPrototype,
+                    LldOverrideOperator::Matches,
+                    ".*",
+                )
+                .discover(false)
+                .severity(4)
+                .tag("scope", "capacity"),
+            );
+
+        let json = serde_json::to_value(&value).unwrap();
+
+        assert_eq!(json["step"], "1");
+        assert_eq!(json["stop"], "1");
+        assert_eq!(json["filter"]["evaltype"], "3");
+        assert_eq!(json["filter"]["formula"], "A and B");
+        assert_eq!(json["filter"]["conditions"][1]["operator"], "12");
+        assert!(json["filter"].get("eval_formula").is_none());
+        assert_eq!(json["operations"][0]["operationobject"], "1");
+        assert_eq!(json["operations"][0]["opdiscover"]["discover"], "1");
+        assert_eq!(json["operations"][0]["opseverity"]["severity"], "4");
+        assert_eq!(json["operations"][0]["optag"][0]["tag"], "scope");
+    }
+}
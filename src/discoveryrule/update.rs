@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[cfg(feature = "v7")]
+use crate::discoveryrule::model::LldLifetimeType;
+use crate::discoveryrule::model::{LldFilter, LldMacroPath, LldOverride};
+use crate::item::model::ItemStatus;
+use crate::item::preprocessing::ItemPreprocessing;
+
+/// Properties left as `None` are not changed. Collections replace the existing values.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateDiscoveryRuleRequest {
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+    pub name: Option<String>,
+    pub key_: Option<String>,
+    pub status: Option<ItemStatus>,
+    pub delay: Option<String>,
+    pub description: Option<String>,
+    pub lifetime: Option<String>,
+    #[cfg(feature = "v7")]
+    pub lifetime_type: Option<LldLifetimeType>,
+    #[cfg(feature = "v7")]
+    pub enabled_lifetime: Option<String>,
+    #[cfg(feature = "v7")]
+    pub enabled_lifetime_type: Option<LldLifetimeType>,
+    #[serde(rename = "master_itemid")]
+    pub master_item_id: Option<String>,
+    pub params: Option<String>,
+    pub url: Option<String>,
+    pub filter: Option<LldFilter>,
+    pub lld_macro_paths: Option<Vec<LldMacroPath>>,
+    pub preprocessing: Option<Vec<ItemPreprocessing>>,
+    pub overrides: Option<Vec<LldOverride>>,
+}
+
+impl UpdateDiscoveryRuleRequest {
+    pub fn builder(item_id: impl ToString) -> UpdateDiscoveryRuleRequestBuilder {
+        UpdateDiscoveryRuleRequestBuilder {
+            inner: UpdateDiscoveryRuleRequest {
+                item_id: item_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateDiscoveryRuleRequestBuilder {
+    inner: UpdateDiscoveryRuleRequest,
+}
+
+impl UpdateDiscoveryRuleRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn key(mut self, key: impl ToString) -> Self {
+        self.inner.key_ = Some(key.to_string());
+        self
+    }
+
+    pub fn enable(mut self) -> Self {
+        self.inner.status = Some(ItemStatus::Enabled);
+        self
+    }
+
+    pub fn disable(mut self) -> Self {
+        self.inner.status = Some(ItemStatus::Disabled);
+        self
+    }
+
+    pub fn delay(mut self, delay: impl ToString) -> Self {
+        self.inner.delay = Some(delay.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn lifetime(mut self, lifetime: impl ToString) -> Self {
+        self.inner.lifetime = Some(lifetime.to_string());
+        #[cfg(feature = "v7")]
+        {
+            self.inner.lifetime_type = Some(LldLifetimeType::After);
+        }
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn lifetime_type(mut self, lifetime_type: LldLifetimeType) -> Self {
+        self.inner.lifetime_type = Some(lifetime_type);
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn enabled_lifetime(mut self, enabled_lifetime: impl ToString) -> Self {
+        self.inner.enabled_lifetime = Some(enabled_lifetime.to_string());
+        self.inner.enabled_lifetime_type = Some(LldLifetimeType::After);
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn enabled_lifetime_type(mut self, enabled_lifetime_type: LldLifetimeType) -> Self {
+        self.inner.enabled_lifetime_type = Some(enabled_lifetime_type);
+        self
+    }
+
+    pub fn master_item_id(mut self, master_item_id: impl ToString) -> Self {
+        self.inner.master_item_id = Some(master_item_id.to_string());
+        self
+    }
+
+    pub fn params(mut self, params: impl ToString) -> Self {
+        self.inner.params = Some(params.to_string());
+        self
+    }
+
+    pub fn url(mut self, url: impl ToString) -> Self {
+        self.inner.url = Some(url.to_string());
+        self
+    }
+
+    pub fn filter(mut self, filter: LldFilter) -> Self {
+        self.inner.filter = Some(filter);
+        self
+    }
+
+    pub fn lld_macro_paths(mut self, lld_macro_paths: Vec<LldMacroPath>) -> Self {
+        self.inner.lld_macro_paths = Some(lld_macro_paths);
+        self
+    }
+
+    pub fn preprocessing(mut self, steps: Vec<ItemPreprocessing>) -> Self {
+        self.inner.preprocessing = Some(steps);
+        self
+    }
+
+    pub fn overrides(mut self, overrides: Vec<LldOverride>) -> Self {
+        self.inner.overrides = Some(overrides);
+        self
+    }
+
+    pub fn build(self) -> UpdateDiscoveryRuleRequest {
+        self.inner
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/update
+#[derive(Deserialize, Debug)]
+pub struct UpdateDiscoveryRuleResponse {
+    #[serde(rename = "itemids")]
+    pub item_ids: Vec<String>,
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/discoveryrule/delete
+#[derive(Deserialize, Debug)]
+pub struct DeleteDiscoveryRulesResponse {
+    #[serde(rename = "ruleids")]
+    pub rule_ids: Vec<String>,
+}
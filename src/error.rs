@@ -21,6 +21,10 @@ pub enum ZabbixApiError {
     #[error("zabbix api bad request error")]
     BadRequestError,
 
+    /// Request was rejected locally, before calling Zabbix API.
+    #[error("invalid request: {0}")]
+    InvalidRequestError(String),
+
     #[error("zabbix api error")]
     Error,
 }
@@ -0,0 +1,371 @@
+use std::fmt::{Display, Formatter};
+use std::ops::BitOr;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::error::ZabbixApiError;
+use crate::event::model::EventSeverity;
+use crate::timestamp::serialize_timestamp;
+
+/// Single update operation of `event.acknowledge`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum AcknowledgeAction {
+    Close,
+    Acknowledge,
+    AddMessage,
+    ChangeSeverity,
+    Unacknowledge,
+    Suppress,
+    Unsuppress,
+    #[cfg(feature = "v7")]
+    ChangeRankToCause,
+    #[cfg(feature = "v7")]
+    ChangeRankToSymptom,
+}
+
+impl AcknowledgeAction {
+    pub fn bit(self) -> u32 {
+        match self {
+            AcknowledgeAction::Close => 1,
+            AcknowledgeAction::Acknowledge => 2,
+            AcknowledgeAction::AddMessage => 4,
+            AcknowledgeAction::ChangeSeverity => 8,
+            AcknowledgeAction::Unacknowledge => 16,
+            AcknowledgeAction::Suppress => 32,
+            AcknowledgeAction::Unsuppress => 64,
+            #[cfg(feature = "v7")]
+            AcknowledgeAction::ChangeRankToCause => 128,
+            #[cfg(feature = "v7")]
+            AcknowledgeAction::ChangeRankToSymptom => 256,
+        }
+    }
+}
+
+/// Set of actions, sent to Zabbix as a bitmask.
+#[derive(Serialize, PartialEq, Eq, Clone, Copy, Debug, Default)]
+#[serde(transparent)]
+pub struct AcknowledgeActions(u32);
+
+impl AcknowledgeActions {
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn contains(self, action: AcknowledgeAction) -> bool {
+        self.0 & action.bit() != 0
+    }
+
+    pub fn insert(&mut self, action: AcknowledgeAction) {
+        self.0 |= action.bit();
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl From<AcknowledgeAction> for AcknowledgeActions {
+    fn from(action: AcknowledgeAction) -> Self {
+        Self(action.bit())
+    }
+}
+
+impl BitOr for AcknowledgeAction {
+    type Output = AcknowledgeActions;
+
+    fn bitor(self, rhs: Self) -> AcknowledgeActions {
+        AcknowledgeActions::from(self) | rhs
+    }
+}
+
+impl BitOr<AcknowledgeAction> for AcknowledgeActions {
+    type Output = AcknowledgeActions;
+
+    fn bitor(mut self, rhs: AcknowledgeAction) -> AcknowledgeActions {
+        self.insert(rhs);
+        self
+    }
+}
+
+impl Display for AcknowledgeActions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for AcknowledgeActions {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value.parse::<u32>().map(Self)
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/event/acknowledge
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct AcknowledgeEventsRequest {
+    #[serde(rename = "eventids")]
+    pub event_ids: Vec<String>,
+    pub action: AcknowledgeActions,
+    pub message: Option<String>,
+    pub severity: Option<EventSeverity>,
+    /// Suppress until the given time, `UNIX_EPOCH` suppresses indefinitely.
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub suppress_until: Option<SystemTime>,
+    /// Cause event for `ChangeRankToSymptom`.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "cause_eventid")]
+    pub cause_event_id: Option<String>,
+}
+
+impl AcknowledgeEventsRequest {
+    pub fn builder(event_ids: Vec<String>) -> AcknowledgeEventsRequestBuilder {
+        AcknowledgeEventsRequestBuilder {
+            inner: AcknowledgeEventsRequest {
+                event_ids,
+                action: AcknowledgeActions::empty(),
+                message: None,
+                severity: None,
+                suppress_until: None,
+                #[cfg(feature = "v7")]
+                cause_event_id: None,
+            },
+        }
+    }
+}
+
+pub struct AcknowledgeEventsRequestBuilder {
+    inner: AcknowledgeEventsRequest,
+}
+
+impl AcknowledgeEventsRequestBuilder {
+    pub fn close(mut self) -> Self {
+        self.inner.action.insert(AcknowledgeAction::Close);
+        self
+    }
+
+    pub fn acknowledge(mut self) -> Self {
+        self.inner.action.insert(AcknowledgeAction::Acknowledge);
+        self
+    }
+
+    pub fn unacknowledge(mut self) -> Self {
+        self.inner.action.insert(AcknowledgeAction::Unacknowledge);
+        self
+    }
+
+    pub fn message(mut self, message: impl ToString) -> Self {
+        self.inner.action.insert(AcknowledgeAction::AddMessage);
+        self.inner.message = Some(message.to_string());
+        self
+    }
+
+    pub fn severity(mut self, severity: EventSeverity) -> Self {
+        self.inner.action.insert(AcknowledgeAction::ChangeSeverity);
+        self.inner.severity = Some(severity);
+        self
+    }
+
+    /// Suppresses the problems until the given time, or indefinitely with `None`.
+    pub fn suppress(mut self, until: Option<SystemTime>) -> Self {
+        self.inner.action.insert(AcknowledgeAction::Suppress);
+        self.inner.suppress_until = Some(until.unwrap_or(UNIX_EPOCH));
+        self
+    }
+
+    pub fn unsuppress(mut self) -> Self {
+        self.inner.action.insert(AcknowledgeAction::Unsuppress);
+        self
+    }
+
+    /// Marks the problems as cause problems.
+    #[cfg(feature = "v7")]
+    pub fn rank_as_cause(mut self) -> Self {
+        self.inner
+            .action
+            .insert(AcknowledgeAction::ChangeRankToCause);
+        self
+    }
+
+    /// Marks the problems as symptoms of the given cause event.
+    #[cfg(feature = "v7")]
+    pub fn rank_as_symptom(mut self, cause_event_id: impl ToString) -> Self {
+        self.inner
+            .action
+            .insert(AcknowledgeAction::ChangeRankToSymptom);
+        self.inner.cause_event_id = Some(cause_event_id.to_string());
+        self
+    }
+
+    /// Checks that at least one action is set and that no actions contradict each other.
+    pub fn build(self) -> Result<AcknowledgeEventsRequest, ZabbixApiError> {
+        let request = self.inner;
+        let action = request.action;
+
+        let invalid = |message: &str| {
+            Err(ZabbixApiError::InvalidRequestError(format!(
+                "event acknowledge: {message}"
+            )))
+        };
+
+        if request.event_ids.is_empty() {
+            return invalid("at least one event id is required");
+        }
+
+        if action.is_empty() {
+            return invalid("at least one action is required");
+        }
+
+        if action.contains(AcknowledgeAction::Acknowledge)
+            && action.contains(AcknowledgeAction::Unacknowledge)
+        {
+            return invalid("acknowledge and unacknowledge are mutually exclusive");
+        }
+
+        if action.contains(AcknowledgeAction::Suppress)
+            && action.contains(AcknowledgeAction::Unsuppress)
+        {
+            return invalid("suppress and unsuppress are mutually exclusive");
+        }
+
+        #[cfg(feature = "v7")]
+        if action.contains(AcknowledgeAction::ChangeRankToCause)
+            && action.contains(AcknowledgeAction::ChangeRankToSymptom)
+        {
+            return invalid("rank can be changed either to cause or to symptom");
+        }
+
+        Ok(request)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AcknowledgeEventsResponse {
+    #[serde(rename = "eventids", deserialize_with = "deserialize_ids")]
+    pub event_ids: Vec<String>,
+}
+
+/// Some Zabbix versions return event IDs as numbers.
+fn deserialize_ids<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Id {
+        String(String),
+        Number(u64),
+    }
+
+    let ids = Vec::<Id>::deserialize(deserializer)?;
+
+    Ok(ids
+        .into_iter()
+        .map(|id| match id {
+            Id::String(id) => id,
+            Id::Number(id) => id.to_string(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod acknowledge_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{AcknowledgeAction, AcknowledgeEventsRequest, AcknowledgeEventsResponse};
+    use crate::event::model::EventSeverity;
+
+    #[test]
+    fn actions_are_encoded_as_bitmask() {
+        let request = AcknowledgeEventsRequest::builder(vec!["1".to_string(), "2".to_string()])
+            .acknowledge()
+            .message("on it")
+            .severity(EventSeverity::High)
+            .build()
+            .unwrap();
+
+        assert_eq!(request.action.bits(), 2 | 4 | 8);
+        assert!(request.action.contains(AcknowledgeAction::AddMessage));
+        assert!(!request.action.contains(AcknowledgeAction::Close));
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "eventids": ["1", "2"],
+                "action": 14,
+                "message": "on it",
+                "severity": "4"
+            })
+        );
+    }
+
+    #[test]
+    fn suppress_until_is_sent_as_timestamp() {
+        let request = AcknowledgeEventsRequest::builder(vec!["1".to_string()])
+            .suppress(Some(UNIX_EPOCH + Duration::from_secs(1700000000)))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["action"], 32);
+        assert_eq!(json["suppress_until"], 1700000000);
+
+        let request = AcknowledgeEventsRequest::builder(vec!["1".to_string()])
+            .suppress(None)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["suppress_until"], 0);
+    }
+
+    #[test]
+    fn contradicting_actions_are_rejected() {
+        assert!(AcknowledgeEventsRequest::builder(vec!["1".to_string()])
+            .build()
+            .is_err());
+
+        assert!(AcknowledgeEventsRequest::builder(vec![])
+            .close()
+            .build()
+            .is_err());
+
+        assert!(AcknowledgeEventsRequest::builder(vec!["1".to_string()])
+            .acknowledge()
+            .unacknowledge()
+            .build()
+            .is_err());
+
+        assert!(AcknowledgeEventsRequest::builder(vec!["1".to_string()])
+            .suppress(None)
+            .unsuppress()
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn action_set_is_combined_with_bitor() {
+        let actions = AcknowledgeAction::Close | AcknowledgeAction::Acknowledge;
+
+        assert_eq!(actions.bits(), 3);
+        assert_eq!((actions | AcknowledgeAction::Unsuppress).bits(), 67);
+    }
+
+    #[test]
+    fn response_accepts_numeric_ids() {
+        let response: AcknowledgeEventsResponse =
+            serde_json::from_str(r#"{"eventids":[20427,"20428"]}"#).unwrap();
+
+        assert_eq!(response.event_ids, vec!["20427", "20428"]);
+    }
+}
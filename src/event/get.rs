@@ -0,0 +1,96 @@
+use std::time::SystemTime;
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::event::model::{
+    EventObject, EventSeverity, EventSource, EventTagFilter, EventValue, TagEvalType,
+};
+use crate::timestamp::serialize_timestamp;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/event/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetEventsRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "eventids")]
+    pub event_ids: Option<Vec<String>>,
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "objectids")]
+    pub object_ids: Option<Vec<String>>,
+    pub source: Option<EventSource>,
+    pub object: Option<EventObject>,
+    pub acknowledged: Option<bool>,
+    pub suppressed: Option<bool>,
+    pub severities: Option<Vec<EventSeverity>>,
+    #[serde(rename = "evaltype")]
+    pub eval_type: Option<TagEvalType>,
+    pub tags: Option<Vec<EventTagFilter>>,
+    #[serde(rename = "eventid_from")]
+    pub event_id_from: Option<String>,
+    #[serde(rename = "eventid_till")]
+    pub event_id_till: Option<String>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_from: Option<SystemTime>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_till: Option<SystemTime>,
+    /// Return only events of problems that were active after the given time.
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub problem_time_from: Option<SystemTime>,
+    /// Return only events of problems that were active before the given time.
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub problem_time_till: Option<SystemTime>,
+    pub value: Option<Vec<EventValue>>,
+    #[serde(rename = "selectAcknowledges")]
+    pub select_acknowledges: Option<String>,
+    #[serde(rename = "selectTags")]
+    pub select_tags: Option<String>,
+    #[serde(rename = "selectSuppressionData")]
+    pub select_suppression_data: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<Vec<String>>,
+    #[serde(rename = "sortorder")]
+    pub sort_order: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[cfg(test)]
+mod get_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::GetEventsRequest;
+    use crate::event::model::{EventSeverity, EventTagFilter, EventValue, TagEvalType};
+
+    #[test]
+    fn serialize_test() {
+        let request = GetEventsRequest::<()> {
+            output: Some("extend".to_string()),
+            severities: Some(vec![EventSeverity::High, EventSeverity::Disaster]),
+            eval_type: Some(TagEvalType::AndOr),
+            tags: Some(vec![EventTagFilter::equals("service", "mysql")]),
+            time_from: Some(UNIX_EPOCH + Duration::from_secs(1700000000)),
+            value: Some(vec![EventValue::Problem]),
+            acknowledged: Some(false),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "output": "extend",
+                "acknowledged": false,
+                "severities": ["4", "5"],
+                "evaltype": "0",
+                "tags": [{"tag": "service", "value": "mysql", "operator": "1"}],
+                "time_from": 1700000000,
+                "value": ["1"]
+            })
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod acknowledge;
+pub mod get;
+pub mod model;
@@ -0,0 +1,283 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::de::deserialize_object_id;
+use crate::event::acknowledge::AcknowledgeActions;
+use crate::flag;
+use crate::timestamp::{deserialize_optional_timestamp, deserialize_timestamp};
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/event/object
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixEvent {
+    #[serde(rename = "eventid")]
+    pub event_id: String,
+    pub source: EventSource,
+    pub object: EventObject,
+    /// ID of the related object, e.g. the trigger ID.
+    #[serde(rename = "objectid")]
+    pub object_id: String,
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub clock: SystemTime,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub ns: Option<u32>,
+    pub value: EventValue,
+    pub name: Option<String>,
+    pub severity: Option<EventSeverity>,
+    #[serde(default, deserialize_with = "flag::deserialize_required")]
+    pub acknowledged: bool,
+    #[serde(default, deserialize_with = "flag::deserialize_required")]
+    pub suppressed: bool,
+    /// Recovery event, `None` while the problem is not resolved.
+    #[serde(
+        rename = "r_eventid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub recovery_event_id: Option<String>,
+    /// Problem event, for recovery events.
+    #[serde(
+        rename = "c_eventid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub cause_problem_event_id: Option<String>,
+    /// Cause event of a symptom event.
+    #[cfg(feature = "v7")]
+    #[serde(
+        rename = "cause_eventid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub cause_event_id: Option<String>,
+    #[serde(
+        rename = "correlationid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub correlation_id: Option<String>,
+    /// User who closed the problem manually.
+    #[serde(rename = "userid", default, deserialize_with = "deserialize_object_id")]
+    pub user_id: Option<String>,
+    /// Operational data with expanded macros.
+    pub opdata: Option<String>,
+    /// Returned with `selectAcknowledges`.
+    pub acknowledges: Option<Vec<ZabbixAcknowledge>>,
+    /// Returned with `selectTags`.
+    pub tags: Option<Vec<ZabbixEventTag>>,
+    /// Returned with `selectSuppressionData`.
+    pub suppression_data: Option<Vec<ZabbixSuppressionData>>,
+}
+
+/// Discriminants match the Zabbix values.
+#[derive(Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub enum EventSeverity {
+    #[serde(rename = "0")]
+    NotClassified = 0,
+    #[serde(rename = "1")]
+    Information = 1,
+    #[serde(rename = "2")]
+    Warning = 2,
+    #[serde(rename = "3")]
+    Average = 3,
+    #[serde(rename = "4")]
+    High = 4,
+    #[serde(rename = "5")]
+    Disaster = 5,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum EventSource {
+    #[serde(rename = "0")]
+    Trigger,
+    #[serde(rename = "1")]
+    Discovery,
+    #[serde(rename = "2")]
+    Autoregistration,
+    #[serde(rename = "3")]
+    Internal,
+    #[serde(rename = "4")]
+    Service,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum EventObject {
+    #[serde(rename = "0")]
+    Trigger,
+    #[serde(rename = "1")]
+    DiscoveredHost,
+    #[serde(rename = "2")]
+    DiscoveredService,
+    #[serde(rename = "3")]
+    AutoregisteredHost,
+    #[serde(rename = "4")]
+    Item,
+    #[serde(rename = "5")]
+    LldRule,
+    #[serde(rename = "6")]
+    Service,
+}
+
+/// State of the related object. Discovery events use `Ok` and `Problem` for
+/// up and down, internal events for normal and unknown state.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum EventValue {
+    #[serde(rename = "0")]
+    Ok,
+    #[serde(rename = "1")]
+    Problem,
+    #[serde(rename = "2")]
+    Discovered,
+    #[serde(rename = "3")]
+    Lost,
+}
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/event/object#event-tag
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixEventTag {
+    pub tag: String,
+    pub value: String,
+}
+
+/// Update of a problem: acknowledgement, message, severity change and so on.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/event/get
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixAcknowledge {
+    #[serde(rename = "acknowledgeid")]
+    pub acknowledge_id: String,
+    #[serde(rename = "userid")]
+    pub user_id: String,
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub clock: SystemTime,
+    pub message: String,
+    /// Performed actions.
+    #[serde_as(as = "DisplayFromStr")]
+    pub action: AcknowledgeActions,
+    pub old_severity: Option<EventSeverity>,
+    pub new_severity: Option<EventSeverity>,
+    pub username: Option<String>,
+}
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/event/object
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixSuppressionData {
+    /// Maintenance which suppresses the problem, `None` when suppressed manually.
+    #[serde(
+        rename = "maintenanceid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub maintenance_id: Option<String>,
+    /// User who suppressed the problem manually.
+    #[serde(rename = "userid", default, deserialize_with = "deserialize_object_id")]
+    pub user_id: Option<String>,
+    /// `None` when suppressed indefinitely.
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub suppress_until: Option<SystemTime>,
+}
+
+/// How tag filters are combined.
+#[derive(Serialize, PartialEq, Clone, Copy, Debug)]
+pub enum TagEvalType {
+    #[serde(rename = "0")]
+    AndOr,
+    #[serde(rename = "2")]
+    Or,
+}
+
+/// Tag filter of `problem.get` and `event.get`.
+#[derive(Serialize, PartialEq, Clone, Debug)]
+pub struct EventTagFilter {
+    pub tag: String,
+    pub value: String,
+    pub operator: TagOperator,
+}
+
+impl EventTagFilter {
+    pub fn equals(tag: impl ToString, value: impl ToString) -> Self {
+        Self::new(tag, TagOperator::Equals, value)
+    }
+
+    pub fn contains(tag: impl ToString, value: impl ToString) -> Self {
+        Self::new(tag, TagOperator::Contains, value)
+    }
+
+    pub fn exists(tag: impl ToString) -> Self {
+        Self::new(tag, TagOperator::Exists, "")
+    }
+
+    pub fn not_exists(tag: impl ToString) -> Self {
+        Self::new(tag, TagOperator::NotExists, "")
+    }
+
+    pub fn new(tag: impl ToString, operator: TagOperator, value: impl ToString) -> Self {
+        Self {
+            tag: tag.to_string(),
+            value: value.to_string(),
+            operator,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Clone, Copy, Debug)]
+pub enum TagOperator {
+    #[serde(rename = "0")]
+    Contains,
+    #[serde(rename = "1")]
+    Equals,
+    #[serde(rename = "2")]
+    NotContains,
+    #[serde(rename = "3")]
+    NotEquals,
+    #[serde(rename = "4")]
+    Exists,
+    #[serde(rename = "5")]
+    NotExists,
+}
+
+#[cfg(test)]
+mod event_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{EventObject, EventSeverity, EventSource, EventValue, ZabbixEvent};
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"eventid":"9695","source":"0","object":"0","objectid":"13926","clock":"1347970410","value":"1","acknowledged":"1","ns":"413316245","name":"MySQL is down","severity":"5","r_eventid":"0","c_eventid":"0","cause_eventid":"0","correlationid":"0","userid":"0","suppressed":"1","opdata":"","urls":[],
+             "acknowledges":[{"acknowledgeid":"1","userid":"1","clock":"1350640590","message":"Problem resolved.","action":"6","old_severity":"0","new_severity":"0","username":"Admin"}],
+             "tags":[{"tag":"service","value":"mysql"}],
+             "suppression_data":[{"maintenanceid":"15","userid":"0","suppress_until":"1472511600"},{"maintenanceid":"0","userid":"1","suppress_until":"0"}]}
+            "#;
+
+        let event: ZabbixEvent = serde_json::from_str(input).unwrap();
+
+        assert_eq!(event.source, EventSource::Trigger);
+        assert_eq!(event.object, EventObject::Trigger);
+        assert_eq!(event.value, EventValue::Problem);
+        assert_eq!(event.severity, Some(EventSeverity::Disaster));
+        assert_eq!(event.clock, UNIX_EPOCH + Duration::from_secs(1347970410));
+        assert!(event.acknowledged);
+        assert!(event.suppressed);
+        assert_eq!(event.recovery_event_id, None);
+        assert_eq!(event.user_id, None);
+
+        let acknowledges = event.acknowledges.unwrap();
+        assert_eq!(acknowledges[0].action.bits(), 6);
+        assert_eq!(
+            acknowledges[0].clock,
+            UNIX_EPOCH + Duration::from_secs(1350640590)
+        );
+
+        let suppression_data = event.suppression_data.unwrap();
+        assert_eq!(suppression_data[0].maintenance_id.as_deref(), Some("15"));
+        assert!(suppression_data[0].suppress_until.is_some());
+        assert_eq!(suppression_data[1].maintenance_id, None);
+        assert_eq!(suppression_data[1].suppress_until, None);
+    }
+}
@@ -0,0 +1,30 @@
+//! `0` or `1` flag, returned by Zabbix as a string.
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_u8(*value as u8),
+        None => serializer.serialize_none(),
+    }
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<String>::deserialize(deserializer)?;
+
+    Ok(value.map(|value| value == "1"))
+}
+
+/// Required flag, `false` when missing.
+pub fn deserialize_required<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(deserialize(deserializer)?.unwrap_or_default())
+}
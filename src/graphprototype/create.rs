@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::discoveryrule::model::PrototypeDiscover;
+use crate::graphprototype::model::{GraphItem, GraphType};
+
+/// At least one item prototype must be among the graph items.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphprototype/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug)]
+pub struct CreateGraphPrototypeRequest {
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+    #[serde(rename = "graphtype")]
+    pub graph_type: Option<GraphType>,
+    #[serde(rename = "gitems")]
+    pub graph_items: Vec<GraphItem>,
+    pub discover: Option<PrototypeDiscover>,
+}
+
+impl CreateGraphPrototypeRequest {
+    pub fn builder(name: impl ToString) -> CreateGraphPrototypeRequestBuilder {
+        CreateGraphPrototypeRequestBuilder::new(name)
+    }
+}
+
+pub struct CreateGraphPrototypeRequestBuilder {
+    inner: CreateGraphPrototypeRequest,
+}
+
+impl CreateGraphPrototypeRequestBuilder {
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            inner: CreateGraphPrototypeRequest {
+                name: name.to_string(),
+                width: 900,
+                height: 200,
+                graph_type: None,
+                graph_items: vec![],
+                discover: None,
+            },
+        }
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.inner.width = width;
+        self.inner.height = height;
+        self
+    }
+
+    pub fn graph_type(mut self, graph_type: GraphType) -> Self {
+        self.inner.graph_type = Some(graph_type);
+        self
+    }
+
+    pub fn item(mut self, item: GraphItem) -> Self {
+        self.inner.graph_items.push(item);
+        self
+    }
+
+    pub fn discover(mut self, discover: bool) -> Self {
+        self.inner.discover = Some(PrototypeDiscover::from(discover));
+        self
+    }
+
+    pub fn build(self) -> CreateGraphPrototypeRequest {
+        self.inner
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphprototype/create
+#[derive(Deserialize, Debug)]
+pub struct CreateGraphPrototypeResponse {
+    #[serde(rename = "graphids")]
+    pub graph_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateGraphPrototypeRequest;
+    use crate::graphprototype::model::{GraphItem, GraphItemCalcFunction, GraphType};
+
+    #[test]
+    fn serialization_test() {
+        let request = CreateGraphPrototypeRequest::builder("Traffic on {#IFNAME}")
+            .graph_type(GraphType::Stacked)
+            .item(GraphItem::new("27428", "00AA00"))
+            .item(
+                GraphItem::new("27429", "AA0000")
+                    .right_axis()
+                    .calc_function(GraphItemCalcFunction::Max),
+            )
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["width"], 900);
+        assert_eq!(json["graphtype"], "1");
+        assert_eq!(
+            json["gitems"][0],
+            serde_json::json!({"itemid": "27428", "color": "00AA00"})
+        );
+        assert_eq!(json["gitems"][1]["yaxisside"], "1");
+        assert_eq!(json["gitems"][1]["calc_fnc"], "4");
+    }
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::discoveryrule::model::{PrototypeDiscover, ZabbixDiscoveryRule};
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphprototype/object
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixGraphPrototype {
+    #[serde(rename = "graphid")]
+    pub graph_id: String,
+    pub name: String,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub width: Option<u32>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub height: Option<u32>,
+    #[serde(rename = "graphtype")]
+    pub graph_type: Option<GraphType>,
+    pub discover: Option<PrototypeDiscover>,
+    /// Graph items, returned with `selectGraphItems`.
+    #[serde(rename = "gitems")]
+    pub graph_items: Option<Vec<GraphItem>>,
+    /// Parent discovery rule, returned with `selectDiscoveryRule`.
+    #[serde(rename = "discoveryRule")]
+    pub discovery_rule: Option<ZabbixDiscoveryRule>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum GraphType {
+    #[serde(rename = "0")]
+    Normal,
+    #[serde(rename = "1")]
+    Stacked,
+    #[serde(rename = "2")]
+    Pie,
+    #[serde(rename = "3")]
+    Exploded,
+}
+
+/// Item drawn on a graph. Item prototypes as well as regular items can be used.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphitem/object
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct GraphItem {
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+    /// Hex color without `#`, e.g. `00AA00`.
+    pub color: String,
+    #[serde(rename = "drawtype")]
+    pub draw_type: Option<GraphItemDrawType>,
+    #[serde(rename = "sortorder")]
+    pub sort_order: Option<String>,
+    /// `0` - left side, `1` - right side.
+    #[serde(rename = "yaxisside")]
+    pub y_axis_side: Option<String>,
+    #[serde(rename = "calc_fnc")]
+    pub calc_function: Option<GraphItemCalcFunction>,
+}
+
+impl GraphItem {
+    pub fn new(item_id: impl ToString, color: impl ToString) -> Self {
+        Self {
+            item_id: item_id.to_string(),
+            color: color.to_string(),
+            draw_type: None,
+            sort_order: None,
+            y_axis_side: None,
+            calc_function: None,
+        }
+    }
+
+    pub fn draw_type(mut self, draw_type: GraphItemDrawType) -> Self {
+        self.draw_type = Some(draw_type);
+        self
+    }
+
+    pub fn right_axis(mut self) -> Self {
+        self.y_axis_side = Some("1".to_string());
+        self
+    }
+
+    pub fn calc_function(mut self, calc_function: GraphItemCalcFunction) -> Self {
+        self.calc_function = Some(calc_function);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum GraphItemDrawType {
+    #[serde(rename = "0")]
+    Line,
+    #[serde(rename = "1")]
+    FilledRegion,
+    #[serde(rename = "2")]
+    BoldLine,
+    #[serde(rename = "3")]
+    Dot,
+    #[serde(rename = "4")]
+    DashedLine,
+    #[serde(rename = "5")]
+    Gradient,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum GraphItemCalcFunction {
+    #[serde(rename = "1")]
+    Min,
+    #[serde(rename = "2")]
+    Average,
+    #[serde(rename = "4")]
+    Max,
+    #[serde(rename = "7")]
+    All,
+    #[serde(rename = "9")]
+    Last,
+}
+
+#[cfg(test)]
+mod graphprototype_tests {
+    use super::{GraphItemDrawType, GraphType, ZabbixGraphPrototype};
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"graphid":"3287","name":"Disk space usage {#FSNAME}","width":"600","height":"340","yaxismin":"0","yaxismax":"0","templateid":"0","show_work_period":"0","show_triggers":"0","graphtype":"2","show_legend":"1","show_3d":"1","percent_left":"0","percent_right":"0","ymin_type":"0","ymax_type":"0","ymin_itemid":"0","ymax_itemid":"0","flags":"2","discover":"0",
+             "gitems":[{"gitemid":"4200","graphid":"3287","itemid":"27428","drawtype":"0","sortorder":"0","color":"00AA00","yaxisside":"0","calc_fnc":"2","type":"0"}]}
+            "#;
+
+        let result: ZabbixGraphPrototype = serde_json::from_str(input).unwrap();
+
+        assert_eq!(result.width, Some(600));
+        assert_eq!(result.graph_type, Some(GraphType::Pie));
+
+        let items = result.graph_items.unwrap();
+        assert_eq!(items[0].item_id, "27428");
+        assert_eq!(items[0].draw_type, Some(GraphItemDrawType::Line));
+    }
+}
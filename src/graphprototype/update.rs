@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::discoveryrule::model::PrototypeDiscover;
+use crate::graphprototype::model::{GraphItem, GraphType};
+
+/// Properties left as `None` are not changed. Graph items replace the existing ones.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphprototype/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateGraphPrototypeRequest {
+    #[serde(rename = "graphid")]
+    pub graph_id: String,
+    pub name: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    #[serde(rename = "graphtype")]
+    pub graph_type: Option<GraphType>,
+    #[serde(rename = "gitems")]
+    pub graph_items: Option<Vec<GraphItem>>,
+    pub discover: Option<PrototypeDiscover>,
+}
+
+impl UpdateGraphPrototypeRequest {
+    pub fn builder(graph_id: impl ToString) -> UpdateGraphPrototypeRequestBuilder {
+        UpdateGraphPrototypeRequestBuilder {
+            inner: UpdateGraphPrototypeRequest {
+                graph_id: graph_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateGraphPrototypeRequestBuilder {
+    inner: UpdateGraphPrototypeRequest,
+}
+
+impl UpdateGraphPrototypeRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.inner.width = Some(width);
+        self.inner.height = Some(height);
+        self
+    }
+
+    pub fn graph_type(mut self, graph_type: GraphType) -> Self {
+        self.inner.graph_type = Some(graph_type);
+        self
+    }
+
+    pub fn items(mut self, items: Vec<GraphItem>) -> Self {
+        self.inner.graph_items = Some(items);
+        self
+    }
+
+    pub fn discover(mut self, discover: bool) -> Self {
+        self.inner.discover = Some(PrototypeDiscover::from(discover));
+        self
+    }
+
+    pub fn build(self) -> UpdateGraphPrototypeRequest {
+        self.inner
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/graphprototype/update
+#[derive(Deserialize, Debug)]
+pub struct UpdateGraphPrototypeResponse {
+    #[serde(rename = "graphids")]
+    pub graph_ids: Vec<String>,
+}
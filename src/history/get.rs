@@ -0,0 +1,79 @@
+use std::marker::PhantomData;
+use std::time::SystemTime;
+
+use serde::{Serialize, Serializer};
+use serde_with::skip_serializing_none;
+
+use crate::history::model::HistoryValueType;
+use crate::timestamp::serialize_timestamp;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/history/get
+///
+/// The history type is set by `V`, e.g. `GetHistoryRequest::<FloatHistory>`.
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(bound(serialize = "V: HistoryValueType"))]
+pub struct GetHistoryRequest<V: HistoryValueType> {
+    pub output: Option<String>,
+    #[serde(serialize_with = "serialize_history")]
+    pub history: PhantomData<V>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "itemids")]
+    pub item_ids: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_from: Option<SystemTime>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_till: Option<SystemTime>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    #[serde(rename = "sortorder")]
+    pub sort_order: Option<String>,
+    pub limit: Option<u32>,
+}
+
+fn serialize_history<V, S>(_: &PhantomData<V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    V: HistoryValueType,
+    S: Serializer,
+{
+    V::VALUE_TYPE.serialize(serializer)
+}
+
+#[cfg(test)]
+mod get_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::GetHistoryRequest;
+    use crate::history::model::{FloatHistory, LogHistory};
+
+    #[test]
+    fn history_is_set_by_value_type() {
+        let request = GetHistoryRequest::<FloatHistory> {
+            output: Some("extend".to_string()),
+            item_ids: Some(vec!["23296".to_string()]),
+            time_from: Some(UNIX_EPOCH + Duration::from_secs(1351090000)),
+            sort_field: Some("clock".to_string()),
+            limit: Some(10),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "output": "extend",
+                "history": "0",
+                "itemids": ["23296"],
+                "time_from": 1351090000,
+                "sortfield": "clock",
+                "limit": 10
+            })
+        );
+
+        let json = serde_json::to_value(GetHistoryRequest::<LogHistory>::default()).unwrap();
+
+        assert_eq!(json, serde_json::json!({"history": "2"}));
+    }
+}
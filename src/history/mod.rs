@@ -0,0 +1,5 @@
+pub mod get;
+pub mod model;
+#[cfg(feature = "v7")]
+pub mod push;
+pub mod window;
@@ -0,0 +1,161 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::de::deserialize_value;
+use crate::item::model::ItemValueType;
+use crate::timestamp::deserialize_timestamp;
+
+/// Type of history to retrieve. Decides the record type returned by `history.get`.
+pub trait HistoryValueType {
+    type Record: DeserializeOwned + Debug;
+
+    const VALUE_TYPE: ItemValueType;
+}
+
+/// Numeric float history.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FloatHistory;
+
+/// Numeric unsigned history.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnsignedHistory;
+
+/// Character history.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CharacterHistory;
+
+/// Log history.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogHistory;
+
+/// Text history.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TextHistory;
+
+impl HistoryValueType for FloatHistory {
+    type Record = ZabbixHistory<f64>;
+
+    const VALUE_TYPE: ItemValueType = ItemValueType::Float;
+}
+
+impl HistoryValueType for UnsignedHistory {
+    type Record = ZabbixHistory<u64>;
+
+    const VALUE_TYPE: ItemValueType = ItemValueType::Unsigned;
+}
+
+impl HistoryValueType for CharacterHistory {
+    type Record = ZabbixHistory<String>;
+
+    const VALUE_TYPE: ItemValueType = ItemValueType::Character;
+}
+
+impl HistoryValueType for LogHistory {
+    type Record = ZabbixLogHistory;
+
+    const VALUE_TYPE: ItemValueType = ItemValueType::Log;
+}
+
+impl HistoryValueType for TextHistory {
+    type Record = ZabbixHistory<String>;
+
+    const VALUE_TYPE: ItemValueType = ItemValueType::Text;
+}
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/history/object
+#[serde_as]
+#[derive(Deserialize, PartialEq, Clone, Debug)]
+#[serde(bound(deserialize = "T: FromStr, T::Err: Display"))]
+pub struct ZabbixHistory<T> {
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub clock: SystemTime,
+    #[serde_as(as = "DisplayFromStr")]
+    pub ns: u32,
+    #[serde(deserialize_with = "deserialize_value")]
+    pub value: T,
+}
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/history/object#log-history
+#[serde_as]
+#[derive(Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixLogHistory {
+    #[serde(rename = "id")]
+    pub history_id: String,
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub clock: SystemTime,
+    #[serde_as(as = "DisplayFromStr")]
+    pub ns: u32,
+    pub value: String,
+    /// Time of the log entry.
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub timestamp: SystemTime,
+    /// Log entry source.
+    pub source: String,
+    /// Log entry severity.
+    #[serde_as(as = "DisplayFromStr")]
+    pub severity: u32,
+    /// Log entry event ID.
+    #[serde(rename = "logeventid")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub log_event_id: u64,
+}
+
+#[cfg(test)]
+mod history_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{FloatHistory, HistoryValueType, LogHistory, UnsignedHistory};
+
+    #[test]
+    fn deserialize_numeric_history() {
+        let input = r#"[{"itemid":"23296","clock":"1351090996","value":"0.085","ns":"563157632"}]"#;
+
+        let records: Vec<<FloatHistory as HistoryValueType>::Record> =
+            serde_json::from_str(input).unwrap();
+
+        assert_eq!(records[0].value, 0.085);
+        assert_eq!(
+            records[0].clock,
+            UNIX_EPOCH + Duration::from_secs(1351090996)
+        );
+
+        let input = r#"[{"itemid":"23297","clock":"1351090996","value":"1024","ns":"0"}]"#;
+
+        let records: Vec<<UnsignedHistory as HistoryValueType>::Record> =
+            serde_json::from_str(input).unwrap();
+
+        assert_eq!(records[0].value, 1024);
+
+        let input = r#"[{"itemid":"23297","clock":"1351090996","value":"-1","ns":"0"}]"#;
+
+        assert!(
+            serde_json::from_str::<Vec<<UnsignedHistory as HistoryValueType>::Record>>(input)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn deserialize_log_history() {
+        let input = r#"[{"id":"1","itemid":"23304","clock":"1351090996","timestamp":"1351090990","source":"sshd","severity":"2","value":"Accepted password","logeventid":"15","ns":"0"}]"#;
+
+        let records: Vec<<LogHistory as HistoryValueType>::Record> =
+            serde_json::from_str(input).unwrap();
+
+        assert_eq!(records[0].source, "sshd");
+        assert_eq!(records[0].severity, 2);
+        assert_eq!(records[0].log_event_id, 15);
+        assert_eq!(
+            records[0].timestamp,
+            UNIX_EPOCH + Duration::from_secs(1351090990)
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::timestamp::serialize_timestamp;
+
+/// Value for a trapper or HTTP agent item, addressed either by item ID or by host and key.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/history/push
+#[skip_serializing_none]
+#[derive(Serialize, PartialEq, Clone, Debug)]
+pub struct HistoryPushValue {
+    #[serde(rename = "itemid")]
+    pub item_id: Option<String>,
+    pub host: Option<String>,
+    pub key: Option<String>,
+    pub value: String,
+    /// Time of the value, the server time is used when not set.
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub clock: Option<SystemTime>,
+    pub ns: Option<u32>,
+}
+
+impl HistoryPushValue {
+    pub fn for_item(item_id: impl ToString, value: impl ToString) -> Self {
+        Self {
+            item_id: Some(item_id.to_string()),
+            host: None,
+            key: None,
+            value: value.to_string(),
+            clock: None,
+            ns: None,
+        }
+    }
+
+    pub fn for_host_key(host: impl ToString, key: impl ToString, value: impl ToString) -> Self {
+        Self {
+            item_id: None,
+            host: Some(host.to_string()),
+            key: Some(key.to_string()),
+            value: value.to_string(),
+            clock: None,
+            ns: None,
+        }
+    }
+
+    /// Sets `clock` and `ns` from the given time.
+    pub fn at(mut self, time: SystemTime) -> Self {
+        let nanos = time
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.subsec_nanos())
+            .unwrap_or_default();
+
+        self.clock = Some(time);
+        self.ns = Some(nanos);
+        self
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct HistoryPushResponse {
+    pub response: String,
+    pub data: Vec<HistoryPushResult>,
+}
+
+/// Result for a single value, in the order of the pushed values.
+#[derive(Deserialize, PartialEq, Clone, Debug)]
+pub struct HistoryPushResult {
+    #[serde(rename = "itemid")]
+    pub item_id: Option<String>,
+    pub error: Option<String>,
+}
+
+impl HistoryPushResult {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+#[cfg(test)]
+mod push_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{HistoryPushResponse, HistoryPushValue};
+
+    #[test]
+    fn serialize_test() {
+        let values = vec![
+            HistoryPushValue::for_item("10600", 0.5)
+                .at(UNIX_EPOCH + Duration::new(1690891294, 71800)),
+            HistoryPushValue::for_host_key("Linux server", "trap", "text"),
+        ];
+
+        let json = serde_json::to_value(&values).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"itemid": "10600", "value": "0.5", "clock": 1690891294, "ns": 71800},
+                {"host": "Linux server", "key": "trap", "value": "text"}
+            ])
+        );
+    }
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"{"response":"success","data":[{"itemid":"10600"},{"itemid":"10601","error":"Item is disabled."},{"error":"No permissions to referred object or it does not exist."}]}"#;
+
+        let response: HistoryPushResponse = serde_json::from_str(input).unwrap();
+
+        assert!(response.data[0].is_success());
+        assert!(!response.data[1].is_success());
+        assert_eq!(response.data[2].item_id, None);
+    }
+}
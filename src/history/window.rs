@@ -0,0 +1,134 @@
+use std::time::{Duration, SystemTime};
+
+use crate::client::client::ZabbixApiClient;
+use crate::error::ZabbixApiError;
+use crate::history::get::GetHistoryRequest;
+use crate::history::model::HistoryValueType;
+
+/// Splits a time range into consecutive windows of `step` length.
+///
+/// Both window bounds are inclusive as in `history.get`, so each window ends one second
+/// before the next one starts.
+#[derive(Clone, Debug)]
+pub struct TimeWindows {
+    /// `None` once the next window would be past the end of `SystemTime`.
+    next: Option<SystemTime>,
+    till: SystemTime,
+    step: Duration,
+}
+
+impl TimeWindows {
+    /// Step is rounded up to at least one second.
+    pub fn new(from: SystemTime, till: SystemTime, step: Duration) -> Self {
+        Self {
+            next: Some(from),
+            till,
+            step: step.max(Duration::from_secs(1)),
+        }
+    }
+}
+
+impl Iterator for TimeWindows {
+    type Item = (SystemTime, SystemTime);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let from = self.next.filter(|next| *next <= self.till)?;
+
+        self.next = from.checked_add(self.step);
+
+        let till = match self.next {
+            Some(next) => (next - Duration::from_secs(1)).min(self.till),
+            None => self.till,
+        };
+
+        Some((from, till))
+    }
+}
+
+/// Fetches history window by window, one `history.get` call per window.
+pub struct HistoryWindows<'a, C: ZabbixApiClient, V: HistoryValueType + Clone> {
+    client: &'a C,
+    session: &'a str,
+    request: GetHistoryRequest<V>,
+    windows: TimeWindows,
+}
+
+impl<'a, C: ZabbixApiClient, V: HistoryValueType + Clone> HistoryWindows<'a, C, V> {
+    /// Iterates from `request.time_from` to `request.time_till`, or until now when
+    /// `time_till` is not set. `time_from` is required.
+    pub fn new(
+        client: &'a C,
+        session: &'a str,
+        request: GetHistoryRequest<V>,
+        step: Duration,
+    ) -> Result<Self, ZabbixApiError> {
+        let from = request.time_from.ok_or_else(|| {
+            ZabbixApiError::InvalidRequestError("history windows require time_from".to_string())
+        })?;
+
+        let till = request.time_till.unwrap_or_else(SystemTime::now);
+
+        Ok(Self {
+            client,
+            session,
+            request,
+            windows: TimeWindows::new(from, till, step),
+        })
+    }
+}
+
+impl<C: ZabbixApiClient, V: HistoryValueType + Clone> Iterator for HistoryWindows<'_, C, V> {
+    type Item = Result<Vec<V::Record>, ZabbixApiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (from, till) = self.windows.next()?;
+
+        let mut request = self.request.clone();
+        request.time_from = Some(from);
+        request.time_till = Some(till);
+
+        Some(self.client.get_history(self.session, &request))
+    }
+}
+
+#[cfg(test)]
+mod window_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::TimeWindows;
+
+    #[test]
+    fn windows_cover_range_without_overlap() {
+        let from = UNIX_EPOCH + Duration::from_secs(1000);
+        let till = UNIX_EPOCH + Duration::from_secs(1250);
+
+        let windows: Vec<(u64, u64)> = TimeWindows::new(from, till, Duration::from_secs(100))
+            .map(|(from, till)| {
+                (
+                    from.duration_since(UNIX_EPOCH).unwrap().as_secs(),
+                    till.duration_since(UNIX_EPOCH).unwrap().as_secs(),
+                )
+            })
+            .collect();
+
+        assert_eq!(windows, vec![(1000, 1099), (1100, 1199), (1200, 1250)]);
+    }
+
+    #[test]
+    fn empty_range_has_no_windows() {
+        let from = UNIX_EPOCH + Duration::from_secs(1000);
+        let till = UNIX_EPOCH + Duration::from_secs(999);
+
+        assert_eq!(TimeWindows::new(from, till, Duration::ZERO).count(), 0);
+    }
+
+    #[test]
+    fn huge_step_ends_at_range_end() {
+        let from = UNIX_EPOCH + Duration::from_secs(1000);
+        let till = UNIX_EPOCH + Duration::from_secs(1250);
+
+        let windows: Vec<_> = TimeWindows::new(from, till, Duration::MAX).collect();
+
+        assert_eq!(windows, vec![(from, till)]);
+    }
+}
@@ -1,4 +1,4 @@
-use super::model::{ZabbixHostInterface, ZabbixHostInventory, ZabbixHostTag};
+use super::model::{HostMonitoring, ZabbixHostInterface, ZabbixHostInventory, ZabbixHostTag};
 use crate::r#macro::create::CreateZabbixHostMacro;
 use crate::{hostgroup::model::ZabbixHostGroupId, template::model::ZabbixTemplateId};
 use serde::{Deserialize, Serialize};
@@ -57,9 +57,12 @@ pub struct CreateHostRequest {
     pub inventory: ZabbixHostInventory,
     #[serde(flatten)]
     pub tls_config: Option<TlsConfig>,
+    /// Server, proxy or proxy group monitoring the host.
+    #[serde(flatten)]
+    pub monitoring: Option<HostMonitoring>,
 }
 
-#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
 pub enum InventoryMode {
     #[default]
     #[serde(rename = "-1")]
@@ -195,6 +198,11 @@ impl CreateHostRequestBuilder {
         self
     }
 
+    pub fn monitored_by(mut self, monitoring: HostMonitoring) -> Self {
+        self.inner.monitoring = Some(monitoring);
+        self
+    }
+
     pub fn build(self) -> CreateHostRequest {
         self.inner
     }
@@ -24,12 +24,133 @@ impl FromStr for HostStatus {
 }
 
 /// API Object: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/host/object
-#[derive(Deserialize, PartialEq, Clone, Debug)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 pub struct ZabbixHost {
     #[serde(rename = "hostid")]
     pub host_id: String,
     pub host: String,
     pub status: HostStatus,
+    /// Proxy monitoring the host, `0` for the server.
+    #[cfg(feature = "v6")]
+    #[serde(rename = "proxy_hostid")]
+    pub proxy_host_id: Option<String>,
+    #[cfg(feature = "v7")]
+    pub monitored_by: Option<MonitoredBy>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "proxyid")]
+    pub proxy_id: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "proxy_groupid")]
+    pub proxy_group_id: Option<String>,
+}
+
+impl ZabbixHost {
+    /// Server, proxy or proxy group monitoring the host, when returned by Zabbix.
+    pub fn monitoring(&self) -> Option<HostMonitoring> {
+        #[cfg(feature = "v7")]
+        {
+            match self.monitored_by? {
+                MonitoredBy::Server => Some(HostMonitoring::Server),
+                MonitoredBy::Proxy => self.proxy_id.clone().map(HostMonitoring::Proxy),
+                MonitoredBy::ProxyGroup => {
+                    self.proxy_group_id.clone().map(HostMonitoring::ProxyGroup)
+                }
+            }
+        }
+        #[cfg(feature = "v6")]
+        {
+            match self.proxy_host_id.as_deref()? {
+                "0" => Some(HostMonitoring::Server),
+                proxy_host_id => Some(HostMonitoring::Proxy(proxy_host_id.to_string())),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "v7")]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum MonitoredBy {
+    #[serde(rename = "0")]
+    Server,
+    #[serde(rename = "1")]
+    Proxy,
+    #[serde(rename = "2")]
+    ProxyGroup,
+}
+
+/// Placement of a host on the server, a proxy or a proxy group (v7).
+///
+/// Serialized as `proxy_hostid` in 6.0 and as `monitored_by` with
+/// `proxyid` or `proxy_groupid` in 7.0.
+#[derive(PartialEq, Clone, Debug)]
+pub enum HostMonitoring {
+    Server,
+    Proxy(String),
+    #[cfg(feature = "v7")]
+    ProxyGroup(String),
+}
+
+#[skip_serializing_none]
+#[derive(Serialize)]
+struct RawHostMonitoring<'a> {
+    #[cfg(feature = "v6")]
+    proxy_hostid: &'a str,
+    #[cfg(feature = "v7")]
+    monitored_by: MonitoredBy,
+    #[cfg(feature = "v7")]
+    proxyid: Option<&'a str>,
+    #[cfg(feature = "v7")]
+    proxy_groupid: Option<&'a str>,
+}
+
+impl Serialize for HostMonitoring {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[cfg(feature = "v7")]
+        let raw = match self {
+            HostMonitoring::Server => RawHostMonitoring {
+                monitored_by: MonitoredBy::Server,
+                proxyid: None,
+                proxy_groupid: None,
+            },
+            HostMonitoring::Proxy(proxy_id) => RawHostMonitoring {
+                monitored_by: MonitoredBy::Proxy,
+                proxyid: Some(proxy_id),
+                proxy_groupid: None,
+            },
+            HostMonitoring::ProxyGroup(proxy_group_id) => RawHostMonitoring {
+                monitored_by: MonitoredBy::ProxyGroup,
+                proxyid: None,
+                proxy_groupid: Some(proxy_group_id),
+            },
+        };
+        #[cfg(feature = "v6")]
+        let raw = match self {
+            HostMonitoring::Server => RawHostMonitoring { proxy_hostid: "0" },
+            HostMonitoring::Proxy(proxy_host_id) => RawHostMonitoring {
+                proxy_hostid: proxy_host_id,
+            },
+        };
+
+        raw.serialize(serializer)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixHostId {
+    #[serde(rename = "hostid")]
+    pub host_id: String,
+}
+
+impl From<ZabbixHost> for ZabbixHostId {
+    fn from(value: ZabbixHost) -> Self {
+        ZabbixHostId {
+            host_id: value.host_id,
+        }
+    }
 }
 
 // API Object: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/host/object#host-tag
@@ -494,3 +615,44 @@ impl ZabbixHostInventoryBuilder {
         self.inner
     }
 }
+
+#[cfg(test)]
+mod host_tests {
+    use super::{HostMonitoring, ZabbixHost};
+
+    #[test]
+    fn serialize_monitoring_test() {
+        let server = serde_json::to_value(HostMonitoring::Server).unwrap();
+        let proxy = serde_json::to_value(HostMonitoring::Proxy("10451".to_string())).unwrap();
+
+        #[cfg(feature = "v7")]
+        {
+            assert_eq!(server, serde_json::json!({"monitored_by": "0"}));
+            assert_eq!(
+                proxy,
+                serde_json::json!({"monitored_by": "1", "proxyid": "10451"})
+            );
+            assert_eq!(
+                serde_json::to_value(HostMonitoring::ProxyGroup("3".to_string())).unwrap(),
+                serde_json::json!({"monitored_by": "2", "proxy_groupid": "3"})
+            );
+        }
+        #[cfg(feature = "v6")]
+        {
+            assert_eq!(server, serde_json::json!({"proxy_hostid": "0"}));
+            assert_eq!(proxy, serde_json::json!({"proxy_hostid": "10451"}));
+        }
+    }
+
+    #[test]
+    fn deserialize_monitoring_test() {
+        let input = r#"{"hostid":"10084","host":"Zabbix server","status":"0","proxy_hostid":"10451","monitored_by":"1","proxyid":"10451","proxy_groupid":"0"}"#;
+
+        let host: ZabbixHost = serde_json::from_str(input).unwrap();
+
+        assert_eq!(
+            host.monitoring(),
+            Some(HostMonitoring::Proxy("10451".to_string()))
+        );
+    }
+}
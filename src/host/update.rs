@@ -1,15 +1,29 @@
-use crate::host::model::HostStatus;
+use crate::host::model::{HostMonitoring, HostStatus};
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
 /// Represents a host update request in Zabbix API
-#[derive(Debug, Serialize)]
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Default)]
 pub struct UpdateHostRequest {
     /// The ID of the host to update
     pub hostid: String,
-    pub status: HostStatus,
+    pub status: Option<HostStatus>,
+    /// Moves the host to the server, a proxy or a proxy group.
+    #[serde(flatten)]
+    pub monitoring: Option<HostMonitoring>,
 }
 
 impl UpdateHostRequest {
+    pub fn builder(hostid: impl ToString) -> UpdateHostRequestBuilder {
+        UpdateHostRequestBuilder {
+            inner: UpdateHostRequest {
+                hostid: hostid.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
     /// Creates a new `UpdateHost` with the given host ID and disabled status (status = 1)
     ///
     /// # Arguments
@@ -20,11 +34,32 @@ impl UpdateHostRequest {
     pub fn disable_host(hostid: String) -> Self {
         Self {
             hostid,
-            status: HostStatus::Disabled,
+            status: Some(HostStatus::Disabled),
+            monitoring: None,
         }
     }
 }
 
+pub struct UpdateHostRequestBuilder {
+    inner: UpdateHostRequest,
+}
+
+impl UpdateHostRequestBuilder {
+    pub fn status(mut self, status: HostStatus) -> Self {
+        self.inner.status = Some(status);
+        self
+    }
+
+    pub fn monitored_by(mut self, monitoring: HostMonitoring) -> Self {
+        self.inner.monitoring = Some(monitoring);
+        self
+    }
+
+    pub fn build(self) -> UpdateHostRequest {
+        self.inner
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct UpdateHostResponse {
     #[serde(rename = "hostids")]
@@ -0,0 +1,124 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::host::model::ZabbixHostId;
+use crate::hostgroup::model::ZabbixHostGroupId;
+#[cfg(feature = "v6")]
+use crate::template::model::ZabbixTemplateId;
+
+/// Adds hosts to all the given host groups.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/massadd
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct MassAddHostGroupsRequest {
+    pub groups: Vec<ZabbixHostGroupId>,
+    pub hosts: Vec<ZabbixHostId>,
+    /// Zabbix 6.0 only, since 6.2 templates belong to template groups.
+    #[cfg(feature = "v6")]
+    pub templates: Option<Vec<ZabbixTemplateId>>,
+}
+
+impl MassAddHostGroupsRequest {
+    pub fn new(group_ids: Vec<String>, host_ids: Vec<String>) -> Self {
+        Self {
+            groups: to_group_ids(group_ids),
+            hosts: to_host_ids(host_ids),
+            #[cfg(feature = "v6")]
+            templates: None,
+        }
+    }
+}
+
+/// Removes hosts from the given host groups.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/massremove
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct MassRemoveHostGroupsRequest {
+    #[serde(rename = "groupids")]
+    pub group_ids: Vec<String>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Vec<String>,
+    /// Zabbix 6.0 only, since 6.2 templates belong to template groups.
+    #[cfg(feature = "v6")]
+    #[serde(rename = "templateids")]
+    pub template_ids: Option<Vec<String>>,
+}
+
+impl MassRemoveHostGroupsRequest {
+    pub fn new(group_ids: Vec<String>, host_ids: Vec<String>) -> Self {
+        Self {
+            group_ids,
+            host_ids,
+            #[cfg(feature = "v6")]
+            template_ids: None,
+        }
+    }
+}
+
+/// Replaces hosts of the given host groups. Hosts missing from `hosts`
+/// are unlinked from the groups, an empty list unlinks all of them.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/massupdate
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct MassUpdateHostGroupsRequest {
+    pub groups: Vec<ZabbixHostGroupId>,
+    pub hosts: Vec<ZabbixHostId>,
+    /// Zabbix 6.0 only, since 6.2 templates belong to template groups.
+    #[cfg(feature = "v6")]
+    pub templates: Option<Vec<ZabbixTemplateId>>,
+}
+
+impl MassUpdateHostGroupsRequest {
+    pub fn new(group_ids: Vec<String>, host_ids: Vec<String>) -> Self {
+        Self {
+            groups: to_group_ids(group_ids),
+            hosts: to_host_ids(host_ids),
+            #[cfg(feature = "v6")]
+            templates: None,
+        }
+    }
+}
+
+fn to_group_ids(group_ids: Vec<String>) -> Vec<ZabbixHostGroupId> {
+    group_ids
+        .into_iter()
+        .map(|group_id| ZabbixHostGroupId { group_id })
+        .collect()
+}
+
+fn to_host_ids(host_ids: Vec<String>) -> Vec<ZabbixHostId> {
+    host_ids
+        .into_iter()
+        .map(|host_id| ZabbixHostId { host_id })
+        .collect()
+}
+
+#[cfg(test)]
+mod mass_tests {
+    use super::{MassAddHostGroupsRequest, MassRemoveHostGroupsRequest};
+
+    #[test]
+    fn massadd_serialization_test() {
+        let request =
+            MassAddHostGroupsRequest::new(vec!["5".to_string()], vec!["10084".to_string()]);
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["groups"][0]["groupid"], "5");
+        assert_eq!(json["hosts"][0]["hostid"], "10084");
+    }
+
+    #[test]
+    fn massremove_serialization_test() {
+        let request =
+            MassRemoveHostGroupsRequest::new(vec!["5".to_string()], vec!["10084".to_string()]);
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["groupids"][0], "5");
+        assert_eq!(json["hostids"][0], "10084");
+    }
+}
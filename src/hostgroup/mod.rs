@@ -1,3 +1,7 @@
 pub mod create;
 pub mod get;
+pub mod mass;
 pub mod model;
+#[cfg(feature = "v7")]
+pub mod propagate;
+pub mod update;
@@ -0,0 +1,28 @@
+use serde::Serialize;
+
+use crate::hostgroup::model::ZabbixHostGroupId;
+
+/// Propagates permissions and tag filters of host groups to their subgroups.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/propagate
+#[derive(Serialize, Debug)]
+pub struct PropagateHostGroupsRequest {
+    pub groups: Vec<ZabbixHostGroupId>,
+    /// Apply permissions of the parent group to all subgroups.
+    pub permissions: bool,
+    /// Apply tag filters of the parent group to all subgroups.
+    pub tag_filters: bool,
+}
+
+impl PropagateHostGroupsRequest {
+    pub fn new(group_ids: Vec<String>, permissions: bool, tag_filters: bool) -> Self {
+        Self {
+            groups: group_ids
+                .into_iter()
+                .map(|group_id| ZabbixHostGroupId { group_id })
+                .collect(),
+            permissions,
+            tag_filters,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostgroup/update
+#[derive(Serialize, Debug)]
+pub struct UpdateHostGroupRequest {
+    #[serde(rename = "groupid")]
+    pub group_id: String,
+    pub name: String,
+}
+
+impl UpdateHostGroupRequest {
+    /// Creates a request renaming the host group with the given ID.
+    pub fn rename(group_id: impl ToString, name: impl ToString) -> Self {
+        Self {
+            group_id: group_id.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Shared by `hostgroup.update`, `hostgroup.delete`, `hostgroup.mass*`
+/// and `hostgroup.propagate`, they all return the affected group IDs.
+#[derive(Deserialize, Debug)]
+pub struct UpdateHostGroupResponse {
+    #[serde(rename = "groupids")]
+    pub group_ids: Vec<String>,
+}
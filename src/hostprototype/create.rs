@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::discoveryrule::model::PrototypeDiscover;
+use crate::host::create::InventoryMode;
+use crate::host::model::{HostStatus, ZabbixHostTag};
+use crate::hostgroup::model::ZabbixHostGroupId;
+use crate::hostprototype::model::HostGroupPrototype;
+use crate::r#macro::create::CreateZabbixHostMacro;
+use crate::template::model::ZabbixTemplateId;
+
+/// Host prototypes can be created only on discovery rules of hosts, not templates.
+/// At least one group link is required.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug)]
+pub struct CreateHostPrototypeRequest {
+    #[serde(rename = "ruleid")]
+    pub rule_id: String,
+    pub host: String,
+    pub name: Option<String>,
+    pub status: Option<HostStatus>,
+    #[serde(rename = "groupLinks")]
+    pub group_links: Vec<ZabbixHostGroupId>,
+    #[serde(rename = "groupPrototypes", skip_serializing_if = "Vec::is_empty")]
+    pub group_prototypes: Vec<HostGroupPrototype>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub templates: Vec<ZabbixTemplateId>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ZabbixHostTag>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub macros: Vec<CreateZabbixHostMacro>,
+    pub inventory_mode: Option<InventoryMode>,
+    pub discover: Option<PrototypeDiscover>,
+}
+
+impl CreateHostPrototypeRequest {
+    pub fn builder(
+        rule_id: impl ToString,
+        host: impl ToString,
+    ) -> CreateHostPrototypeRequestBuilder {
+        CreateHostPrototypeRequestBuilder::new(rule_id, host)
+    }
+}
+
+pub struct CreateHostPrototypeRequestBuilder {
+    inner: CreateHostPrototypeRequest,
+}
+
+impl CreateHostPrototypeRequestBuilder {
+    pub fn new(rule_id: impl ToString, host: impl ToString) -> Self {
+        Self {
+            inner: CreateHostPrototypeRequest {
+                rule_id: rule_id.to_string(),
+                host: host.to_string(),
+                name: None,
+                status: None,
+                group_links: vec![],
+                group_prototypes: vec![],
+                templates: vec![],
+                tags: vec![],
+                macros: vec![],
+                inventory_mode: None,
+                discover: None,
+            },
+        }
+    }
+
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: HostStatus) -> Self {
+        self.inner.status = Some(status);
+        self
+    }
+
+    /// Links discovered hosts to an existing host group.
+    pub fn group(mut self, group_id: impl ToString) -> Self {
+        self.inner.group_links.push(ZabbixHostGroupId {
+            group_id: group_id.to_string(),
+        });
+        self
+    }
+
+    /// Creates a host group for discovered hosts, e.g. `Cluster {#CLUSTER.NAME}`.
+    pub fn group_prototype(mut self, name: impl ToString) -> Self {
+        self.inner.group_prototypes.push(HostGroupPrototype {
+            name: name.to_string(),
+        });
+        self
+    }
+
+    pub fn template(mut self, template_id: impl ToString) -> Self {
+        self.inner.templates.push(ZabbixTemplateId {
+            template_id: template_id.to_string(),
+        });
+        self
+    }
+
+    pub fn tag(mut self, tag: impl ToString, value: impl ToString) -> Self {
+        self.inner.tags.push(ZabbixHostTag {
+            tag: tag.to_string(),
+            value: value.to_string(),
+        });
+        self
+    }
+
+    pub fn macro_entry(mut self, macro_entry: CreateZabbixHostMacro) -> Self {
+        self.inner.macros.push(macro_entry);
+        self
+    }
+
+    pub fn inventory_mode(mut self, mode: InventoryMode) -> Self {
+        self.inner.inventory_mode = Some(mode);
+        self
+    }
+
+    pub fn discover(mut self, discover: bool) -> Self {
+        self.inner.discover = Some(PrototypeDiscover::from(discover));
+        self
+    }
+
+    pub fn build(self) -> CreateHostPrototypeRequest {
+        self.inner
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/create
+#[derive(Deserialize, Debug)]
+pub struct CreateHostPrototypeResponse {
+    #[serde(rename = "hostids")]
+    pub host_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateHostPrototypeRequest;
+    use crate::host::create::InventoryMode;
+
+    #[test]
+    fn serialization_test() {
+        let request = CreateHostPrototypeRequest::builder("23542", "{#VM.NAME}")
+            .name("VM {#VM.NAME}")
+            .group("2")
+            .group_prototype("Cluster {#CLUSTER.NAME}")
+            .tag("vm", "{#VM.NAME}")
+            .inventory_mode(InventoryMode::Automatic)
+            .discover(true)
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["ruleid"], "23542");
+        assert_eq!(json["groupLinks"][0]["groupid"], "2");
+        assert_eq!(
+            json["groupPrototypes"][0]["name"],
+            "Cluster {#CLUSTER.NAME}"
+        );
+        assert_eq!(json["inventory_mode"], "1");
+        assert_eq!(json["discover"], "0");
+        assert!(json.get("templates").is_none());
+        assert!(json.get("status").is_none());
+    }
+}
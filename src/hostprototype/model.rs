@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::discoveryrule::model::{PrototypeDiscover, ZabbixDiscoveryRule};
+use crate::host::model::{HostStatus, ZabbixHostTag};
+use crate::hostgroup::model::ZabbixHostGroupId;
+use crate::template::model::ZabbixTemplate;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/object
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixHostPrototype {
+    #[serde(rename = "hostid")]
+    pub host_id: String,
+    /// Technical name, must contain an LLD macro, e.g. `{#VM.NAME}`.
+    pub host: String,
+    pub name: String,
+    pub status: HostStatus,
+    pub discover: Option<PrototypeDiscover>,
+    /// Existing host groups of discovered hosts, returned with `selectGroupLinks`.
+    #[serde(rename = "groupLinks")]
+    pub group_links: Option<Vec<ZabbixHostGroupId>>,
+    /// Host groups created for discovered hosts, returned with `selectGroupPrototypes`.
+    #[serde(rename = "groupPrototypes")]
+    pub group_prototypes: Option<Vec<HostGroupPrototype>>,
+    /// Returned with `selectTemplates`.
+    pub templates: Option<Vec<ZabbixTemplate>>,
+    /// Returned with `selectTags`.
+    pub tags: Option<Vec<ZabbixHostTag>>,
+    /// Parent discovery rule, returned with `selectDiscoveryRule`.
+    #[serde(rename = "discoveryRule")]
+    pub discovery_rule: Option<ZabbixDiscoveryRule>,
+}
+
+/// Host group created for discovered hosts, the name must contain an LLD macro.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/object#group-prototype
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct HostGroupPrototype {
+    pub name: String,
+}
+
+#[cfg(test)]
+mod hostprototype_tests {
+    use super::ZabbixHostPrototype;
+    use crate::host::model::HostStatus;
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"hostid":"10092","host":"{#VM.NAME}","name":"VM {#VM.NAME}","status":"0","templateid":"0","discover":"0","custom_interfaces":"0","inventory_mode":"-1",
+             "groupLinks":[{"group_prototypeid":"4","hostid":"10092","groupid":"2","templateid":"0"}],
+             "groupPrototypes":[{"group_prototypeid":"5","hostid":"10092","name":"VMs {#CLUSTER}","templateid":"0"}],
+             "tags":[{"tag":"vm","value":"{#VM.NAME}"}]}
+            "#;
+
+        let result: ZabbixHostPrototype = serde_json::from_str(input).unwrap();
+
+        assert_eq!(result.status, HostStatus::Enabled);
+        assert_eq!(result.group_links.unwrap()[0].group_id, "2");
+        assert_eq!(result.group_prototypes.unwrap()[0].name, "VMs {#CLUSTER}");
+        assert_eq!(result.tags.unwrap()[0].value, "{#VM.NAME}");
+    }
+}
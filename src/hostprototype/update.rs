@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::discoveryrule::model::PrototypeDiscover;
+use crate::host::model::{HostStatus, ZabbixHostTag};
+use crate::hostgroup::model::ZabbixHostGroupId;
+use crate::hostprototype::model::HostGroupPrototype;
+use crate::template::model::ZabbixTemplateId;
+
+/// Properties left as `None` are not changed. Lists replace the existing ones.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateHostPrototypeRequest {
+    #[serde(rename = "hostid")]
+    pub host_id: String,
+    pub host: Option<String>,
+    pub name: Option<String>,
+    pub status: Option<HostStatus>,
+    #[serde(rename = "groupLinks")]
+    pub group_links: Option<Vec<ZabbixHostGroupId>>,
+    #[serde(rename = "groupPrototypes")]
+    pub group_prototypes: Option<Vec<HostGroupPrototype>>,
+    pub templates: Option<Vec<ZabbixTemplateId>>,
+    pub tags: Option<Vec<ZabbixHostTag>>,
+    pub discover: Option<PrototypeDiscover>,
+}
+
+impl UpdateHostPrototypeRequest {
+    pub fn builder(host_id: impl ToString) -> UpdateHostPrototypeRequestBuilder {
+        UpdateHostPrototypeRequestBuilder {
+            inner: UpdateHostPrototypeRequest {
+                host_id: host_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateHostPrototypeRequestBuilder {
+    inner: UpdateHostPrototypeRequest,
+}
+
+impl UpdateHostPrototypeRequestBuilder {
+    pub fn host(mut self, host: impl ToString) -> Self {
+        self.inner.host = Some(host.to_string());
+        self
+    }
+
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: HostStatus) -> Self {
+        self.inner.status = Some(status);
+        self
+    }
+
+    pub fn group_links(mut self, group_links: Vec<ZabbixHostGroupId>) -> Self {
+        self.inner.group_links = Some(group_links);
+        self
+    }
+
+    pub fn group_prototypes(mut self, group_prototypes: Vec<HostGroupPrototype>) -> Self {
+        self.inner.group_prototypes = Some(group_prototypes);
+        self
+    }
+
+    pub fn templates(mut self, templates: Vec<ZabbixTemplateId>) -> Self {
+        self.inner.templates = Some(templates);
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<ZabbixHostTag>) -> Self {
+        self.inner.tags = Some(tags);
+        self
+    }
+
+    pub fn discover(mut self, discover: bool) -> Self {
+        self.inner.discover = Some(PrototypeDiscover::from(discover));
+        self
+    }
+
+    pub fn build(self) -> UpdateHostPrototypeRequest {
+        self.inner
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/hostprototype/update
+#[derive(Deserialize, Debug)]
+pub struct UpdateHostPrototypeResponse {
+    #[serde(rename = "hostids")]
+    pub host_ids: Vec<String>,
+}
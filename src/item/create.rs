@@ -1,9 +1,20 @@
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
 
+use crate::error::ZabbixApiError;
 use crate::host::model::ZabbixHostTag;
+use crate::item::model::{
+    HttpField, HttpPostType, HttpRequestMethod, HttpRetrieveMode, ItemType, ItemValueType,
+    ScriptParameter,
+};
+use crate::item::preprocessing::ItemPreprocessing;
 
-/// API: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/item/create
-#[derive(Serialize, Debug)]
+/// Use `CreateItemRequest::http_agent`, `CreateItemRequest::trapper` and other constructors
+/// to get a builder which checks the fields required by the item type.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/item/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
 pub struct CreateItemRequest {
     pub name: String,
     pub key_: String,
@@ -11,10 +22,473 @@ pub struct CreateItemRequest {
     pub host_id: String,
     pub r#type: u8,
     pub value_type: u8,
-    #[serde(rename = "interfaceid")]
+    /// Not sent if empty.
+    #[serde(rename = "interfaceid", skip_serializing_if = "String::is_empty")]
     pub interface_id: String,
     pub tags: Vec<ZabbixHostTag>,
+    /// Not sent if empty.
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub delay: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub preprocessing: Vec<ItemPreprocessing>,
+    pub description: Option<String>,
+    pub units: Option<String>,
+    pub history: Option<String>,
+    pub trends: Option<String>,
+    #[serde(rename = "valuemapid")]
+    pub value_map_id: Option<String>,
+    pub timeout: Option<String>,
+    #[serde(rename = "master_itemid")]
+    pub master_item_id: Option<String>,
+    /// Formula of calculated items, script of script items.
+    pub params: Option<String>,
+    pub parameters: Option<Vec<ScriptParameter>>,
+    pub snmp_oid: Option<String>,
+    pub trapper_hosts: Option<String>,
+    pub allow_traps: Option<u8>,
+    pub url: Option<String>,
+    #[serde(serialize_with = "serialize_query_fields")]
+    pub query_fields: Option<Vec<HttpField>>,
+    #[serde(serialize_with = "serialize_headers")]
+    pub headers: Option<Vec<HttpField>>,
+    pub request_method: Option<HttpRequestMethod>,
+    pub retrieve_mode: Option<HttpRetrieveMode>,
+    pub post_type: Option<HttpPostType>,
+    pub posts: Option<String>,
+    /// Comma separated list of expected HTTP status codes, e.g. `200,201,210-299`.
+    pub status_codes: Option<String>,
+    pub follow_redirects: Option<u8>,
+    /// `1` to convert the response to JSON.
+    pub output_format: Option<u8>,
+    pub verify_peer: Option<u8>,
+    pub verify_host: Option<u8>,
+}
+
+impl CreateItemRequest {
+    /// Zabbix agent item polled through the given host interface.
+    pub fn zabbix_agent(
+        host_id: impl ToString,
+        interface_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        value_type: ItemValueType,
+    ) -> CreateItemRequestBuilder {
+        CreateItemRequestBuilder::new(ItemType::ZabbixAgent, host_id, name, key, value_type)
+            .interface_id(interface_id)
+            .delay("1m")
+    }
+
+    /// Zabbix trapper item, receives values sent by `zabbix_sender` or `history.push`.
+    pub fn trapper(
+        host_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        value_type: ItemValueType,
+    ) -> CreateItemRequestBuilder {
+        CreateItemRequestBuilder::new(ItemType::ZabbixTrapper, host_id, name, key, value_type)
+    }
+
+    /// HTTP agent item polling the given URL with `GET`.
+    pub fn http_agent(
+        host_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        value_type: ItemValueType,
+        url: impl ToString,
+    ) -> CreateItemRequestBuilder {
+        let mut builder =
+            CreateItemRequestBuilder::new(ItemType::HttpAgent, host_id, name, key, value_type)
+                .delay("1m");
+        builder.inner.url = Some(url.to_string());
+        builder
+    }
+
+    /// SNMP agent item polling the given OID through the host SNMP interface.
+    pub fn snmp(
+        host_id: impl ToString,
+        interface_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        value_type: ItemValueType,
+        snmp_oid: impl ToString,
+    ) -> CreateItemRequestBuilder {
+        let mut builder =
+            CreateItemRequestBuilder::new(ItemType::Snmp, host_id, name, key, value_type)
+                .interface_id(interface_id)
+                .delay("1m");
+        builder.inner.snmp_oid = Some(snmp_oid.to_string());
+        builder
+    }
+
+    /// Dependent item, gets its value from the master item.
+    pub fn dependent(
+        host_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        value_type: ItemValueType,
+        master_item_id: impl ToString,
+    ) -> CreateItemRequestBuilder {
+        let mut builder =
+            CreateItemRequestBuilder::new(ItemType::Dependent, host_id, name, key, value_type);
+        builder.inner.master_item_id = Some(master_item_id.to_string());
+        builder
+    }
+
+    /// Calculated item, e.g. with formula `avg(/host/key,5m)*2`.
+    pub fn calculated(
+        host_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        value_type: ItemValueType,
+        formula: impl ToString,
+    ) -> CreateItemRequestBuilder {
+        let mut builder =
+            CreateItemRequestBuilder::new(ItemType::Calculated, host_id, name, key, value_type)
+                .delay("1m");
+        builder.inner.params = Some(formula.to_string());
+        builder
+    }
+
+    /// Script item running the given JavaScript code.
+    pub fn script(
+        host_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        value_type: ItemValueType,
+        script: impl ToString,
+    ) -> CreateItemRequestBuilder {
+        let mut builder =
+            CreateItemRequestBuilder::new(ItemType::Script, host_id, name, key, value_type)
+                .delay("1m");
+        builder.inner.params = Some(script.to_string());
+        builder
+    }
+}
+
+pub struct CreateItemRequestBuilder {
+    item_type: ItemType,
+    inner: CreateItemRequest,
+}
+
+impl CreateItemRequestBuilder {
+    fn new(
+        item_type: ItemType,
+        host_id: impl ToString,
+        name: impl ToString,
+        key: impl ToString,
+        value_type: ItemValueType,
+    ) -> Self {
+        Self {
+            item_type,
+            inner: CreateItemRequest {
+                name: name.to_string(),
+                key_: key.to_string(),
+                host_id: host_id.to_string(),
+                r#type: item_type as u8,
+                value_type: value_type as u8,
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn interface_id(mut self, interface_id: impl ToString) -> Self {
+        self.inner.interface_id = interface_id.to_string();
+        self
+    }
+
+    /// Update interval, e.g. `30s`. Polled item types default to `1m`.
+    pub fn delay(mut self, delay: impl ToString) -> Self {
+        self.inner.delay = delay.to_string();
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn units(mut self, units: impl ToString) -> Self {
+        self.inner.units = Some(units.to_string());
+        self
+    }
+
+    pub fn history(mut self, history: impl ToString) -> Self {
+        self.inner.history = Some(history.to_string());
+        self
+    }
+
+    pub fn trends(mut self, trends: impl ToString) -> Self {
+        self.inner.trends = Some(trends.to_string());
+        self
+    }
+
+    pub fn value_map_id(mut self, value_map_id: impl ToString) -> Self {
+        self.inner.value_map_id = Some(value_map_id.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: impl ToString) -> Self {
+        self.inner.timeout = Some(timeout.to_string());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl ToString, value: impl ToString) -> Self {
+        self.inner.tags.push(ZabbixHostTag {
+            tag: tag.to_string(),
+            value: value.to_string(),
+        });
+        self
+    }
+
+    pub fn preprocessing_step(mut self, step: impl Into<ItemPreprocessing>) -> Self {
+        self.inner.preprocessing.push(step.into());
+        self
+    }
+
+    /// Script item parameter.
+    pub fn parameter(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.inner
+            .parameters
+            .get_or_insert_with(Vec::new)
+            .push(ScriptParameter {
+                name: name.to_string(),
+                value: value.to_string(),
+            });
+        self
+    }
+
+    /// Hosts allowed to send values, for trapper items and HTTP agent items with traps allowed.
+    pub fn trapper_hosts(mut self, trapper_hosts: impl ToString) -> Self {
+        self.inner.trapper_hosts = Some(trapper_hosts.to_string());
+        self
+    }
+
+    /// Lets the HTTP agent item also accept values like a trapper item.
+    pub fn allow_traps(mut self) -> Self {
+        self.inner.allow_traps = Some(1);
+        self
+    }
+
+    pub fn query_field(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.inner
+            .query_fields
+            .get_or_insert_with(Vec::new)
+            .push(HttpField::new(name, value));
+        self
+    }
+
+    pub fn header(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.inner
+            .headers
+            .get_or_insert_with(Vec::new)
+            .push(HttpField::new(name, value));
+        self
+    }
+
+    pub fn request_method(mut self, request_method: HttpRequestMethod) -> Self {
+        self.inner.request_method = Some(request_method);
+        self
+    }
+
+    pub fn retrieve_mode(mut self, retrieve_mode: HttpRetrieveMode) -> Self {
+        self.inner.retrieve_mode = Some(retrieve_mode);
+        self
+    }
+
+    /// Request body.
+    pub fn posts(mut self, post_type: HttpPostType, posts: impl ToString) -> Self {
+        self.inner.post_type = Some(post_type);
+        self.inner.posts = Some(posts.to_string());
+        self
+    }
+
+    pub fn status_codes(mut self, status_codes: impl ToString) -> Self {
+        self.inner.status_codes = Some(status_codes.to_string());
+        self
+    }
+
+    pub fn follow_redirects(mut self, follow_redirects: bool) -> Self {
+        self.inner.follow_redirects = Some(follow_redirects as u8);
+        self
+    }
+
+    /// Converts the HTTP response to JSON.
+    pub fn json_output(mut self) -> Self {
+        self.inner.output_format = Some(1);
+        self
+    }
+
+    pub fn verify_peer(mut self, verify_peer: bool) -> Self {
+        self.inner.verify_peer = Some(verify_peer as u8);
+        self
+    }
+
+    pub fn verify_host(mut self, verify_host: bool) -> Self {
+        self.inner.verify_host = Some(verify_host as u8);
+        self
+    }
+
+    /// Checks that the fields match the item type.
+    pub fn build(self) -> Result<CreateItemRequest, ZabbixApiError> {
+        let item = &self.inner;
+        let item_type = self.item_type;
+
+        let invalid = |message: &str| {
+            Err(ZabbixApiError::InvalidRequestError(format!(
+                "item '{}': {message}",
+                item.key_
+            )))
+        };
+
+        if item.host_id.is_empty() {
+            return invalid("host id is required");
+        }
+
+        if item.name.is_empty() || item.key_.is_empty() {
+            return invalid("name and key are required");
+        }
+
+        let is_empty = |value: &Option<String>| value.as_deref().unwrap_or_default().is_empty();
+
+        match item_type {
+            ItemType::HttpAgent if is_empty(&item.url) => return invalid("url is required"),
+            ItemType::Snmp if is_empty(&item.snmp_oid) => return invalid("SNMP OID is required"),
+            ItemType::Snmp if item.interface_id.is_empty() => {
+                return invalid("SNMP items require an interface")
+            }
+            ItemType::Dependent if is_empty(&item.master_item_id) => {
+                return invalid("master item id is required")
+            }
+            ItemType::Calculated if is_empty(&item.params) => {
+                return invalid("formula is required")
+            }
+            ItemType::Script if is_empty(&item.params) => return invalid("script is required"),
+            _ => {}
+        }
+
+        let is_passive = matches!(item_type, ItemType::ZabbixTrapper | ItemType::Dependent);
+
+        if is_passive && !item.delay.is_empty() {
+            return invalid("trapper and dependent items don't have an update interval");
+        }
+
+        if !is_passive && item.delay.is_empty() {
+            return invalid("update interval is required");
+        }
+
+        let without_interface = matches!(
+            item_type,
+            ItemType::ZabbixTrapper | ItemType::Dependent | ItemType::Calculated | ItemType::Script
+        );
+
+        if without_interface && !item.interface_id.is_empty() {
+            return invalid("item type doesn't use host interfaces");
+        }
+
+        if without_interface && item_type != ItemType::Script && item.timeout.is_some() {
+            return invalid("item type doesn't support timeout");
+        }
+
+        let has_http_fields = item.url.is_some()
+            || item.query_fields.is_some()
+            || item.headers.is_some()
+            || item.request_method.is_some()
+            || item.retrieve_mode.is_some()
+            || item.posts.is_some()
+            || item.status_codes.is_some()
+            || item.follow_redirects.is_some()
+            || item.output_format.is_some()
+            || item.verify_peer.is_some()
+            || item.verify_host.is_some()
+            || item.allow_traps.is_some();
+
+        if item_type != ItemType::HttpAgent && has_http_fields {
+            return invalid("HTTP fields can only be used with HTTP agent items");
+        }
+
+        if item.request_method == Some(HttpRequestMethod::Head)
+            && item.retrieve_mode.unwrap_or(HttpRetrieveMode::Body) != HttpRetrieveMode::Headers
+        {
+            return invalid("HEAD requests can only retrieve headers");
+        }
+
+        if item.trapper_hosts.is_some()
+            && item_type != ItemType::ZabbixTrapper
+            && item.allow_traps.is_none()
+        {
+            return invalid(
+                "trapper hosts require a trapper item or an HTTP agent item with traps allowed",
+            );
+        }
+
+        if item.parameters.is_some() && item_type != ItemType::Script {
+            return invalid("parameters can only be used with script items");
+        }
+
+        Ok(self.inner)
+    }
+}
+
+/// Zabbix 7.0 expects an array of `{"name": ..., "value": ...}` objects.
+#[cfg(feature = "v7")]
+fn serialize_query_fields<S>(
+    fields: &Option<Vec<HttpField>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    fields.serialize(serializer)
+}
+
+/// Zabbix 6.0 expects an array of single-entry `{"<name>": "<value>"}` objects.
+#[cfg(feature = "v6")]
+fn serialize_query_fields<S>(
+    fields: &Option<Vec<HttpField>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use std::collections::BTreeMap;
+
+    fields
+        .as_ref()
+        .map(|fields| {
+            fields
+                .iter()
+                .map(|field| BTreeMap::from([(field.name.as_str(), field.value.as_str())]))
+                .collect::<Vec<_>>()
+        })
+        .serialize(serializer)
+}
+
+/// Zabbix 7.0 expects an array of `{"name": ..., "value": ...}` objects.
+#[cfg(feature = "v7")]
+fn serialize_headers<S>(headers: &Option<Vec<HttpField>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    headers.serialize(serializer)
+}
+
+/// Zabbix 6.0 expects a `{"<name>": "<value>"}` object.
+#[cfg(feature = "v6")]
+fn serialize_headers<S>(headers: &Option<Vec<HttpField>>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use std::collections::BTreeMap;
+
+    headers
+        .as_ref()
+        .map(|headers| {
+            headers
+                .iter()
+                .map(|header| (header.name.as_str(), header.value.as_str()))
+                .collect::<BTreeMap<_, _>>()
+        })
+        .serialize(serializer)
 }
 
 /// API: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/item/create
@@ -23,3 +497,139 @@ pub struct CreateItemResponse {
     #[serde(rename = "itemids")]
     pub item_ids: Vec<String>,
 }
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateItemRequest;
+    use crate::item::model::{HttpPostType, HttpRequestMethod, ItemValueType};
+    use crate::item::preprocessing::PreprocessingStep;
+
+    #[test]
+    fn http_agent_serialization_test() {
+        let request = CreateItemRequest::http_agent(
+            "10084",
+            "API status",
+            "api.status",
+            ItemValueType::Text,
+            "https://example.com/status",
+        )
+        .header("Authorization", "Bearer {$API.TOKEN}")
+        .posts(HttpPostType::Json, r#"{"check": true}"#)
+        .request_method(HttpRequestMethod::Post)
+        .preprocessing_step(PreprocessingStep::JsonPath {
+            path: "$.status".to_string(),
+        })
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["type"], 19);
+        assert_eq!(json["value_type"], 4);
+        assert_eq!(json["delay"], "1m");
+        assert_eq!(json["url"], "https://example.com/status");
+        assert_eq!(json["post_type"], "2");
+        assert_eq!(json["request_method"], "1");
+        assert_eq!(json["preprocessing"][0]["type"], "12");
+        assert!(json.get("interfaceid").is_none());
+
+        #[cfg(feature = "v7")]
+        assert_eq!(json["headers"][0]["name"], "Authorization");
+        #[cfg(feature = "v6")]
+        assert_eq!(json["headers"]["Authorization"], "Bearer {$API.TOKEN}");
+    }
+
+    #[test]
+    fn trapper_and_dependent_serialization_test() {
+        let request = CreateItemRequest::trapper(
+            "10084",
+            "Backup status",
+            "backup.status",
+            ItemValueType::Text,
+        )
+        .trapper_hosts("10.0.0.1")
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["type"], 2);
+        assert!(json.get("delay").is_none());
+        assert!(json.get("interfaceid").is_none());
+
+        let request = CreateItemRequest::dependent(
+            "10084",
+            "Backup size",
+            "backup.size",
+            ItemValueType::Unsigned,
+            "23296",
+        )
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["type"], 18);
+        assert_eq!(json["master_itemid"], "23296");
+    }
+
+    #[test]
+    fn invalid_combinations_are_rejected() {
+        assert!(
+            CreateItemRequest::trapper("10084", "Trap", "trap", ItemValueType::Text)
+                .delay("1m")
+                .build()
+                .is_err()
+        );
+
+        assert!(
+            CreateItemRequest::dependent("10084", "Size", "size", ItemValueType::Unsigned, "")
+                .build()
+                .is_err()
+        );
+
+        assert!(CreateItemRequest::calculated(
+            "10084",
+            "Total",
+            "total",
+            ItemValueType::Float,
+            "last(//a)+last(//b)"
+        )
+        .header("Accept", "application/json")
+        .build()
+        .is_err());
+
+        assert!(CreateItemRequest::script(
+            "10084",
+            "Script",
+            "script",
+            ItemValueType::Text,
+            "return 1;"
+        )
+        .interface_id("1")
+        .build()
+        .is_err());
+
+        assert!(CreateItemRequest::http_agent(
+            "10084",
+            "Head",
+            "head",
+            ItemValueType::Text,
+            "https://example.com"
+        )
+        .request_method(HttpRequestMethod::Head)
+        .build()
+        .is_err());
+
+        assert!(CreateItemRequest::snmp(
+            "10084",
+            "1",
+            "Uptime",
+            "uptime",
+            ItemValueType::Unsigned,
+            "1.3.6.1.2.1.1.3.0"
+        )
+        .build()
+        .is_ok());
+    }
+}
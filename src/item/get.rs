@@ -1,4 +1,5 @@
 use serde::Serialize;
+use serde_with::skip_serializing_none;
 
 /// API: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/item/get
 #[derive(Serialize, Debug)]
@@ -39,3 +40,38 @@ impl GetItemsRequestByKey<SearchByKey> {
         }
     }
 }
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/item/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetItemsRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "itemids")]
+    pub item_ids: Option<Vec<String>>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(rename = "templateids")]
+    pub template_ids: Option<Vec<String>>,
+    /// Return only items that belong to templates.
+    pub templated: Option<bool>,
+    pub with_triggers: Option<bool>,
+    #[serde(rename = "selectTags")]
+    pub select_tags: Option<String>,
+    #[serde(rename = "selectValueMap")]
+    pub select_value_map: Option<String>,
+    #[serde(rename = "selectPreprocessing")]
+    pub select_preprocessing: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct ItemFilter {
+    pub key_: Option<Vec<String>>,
+    pub name: Option<Vec<String>>,
+}
@@ -1,3 +1,5 @@
 pub mod create;
 pub mod get;
 pub mod model;
+pub mod preprocessing;
+pub mod update;
@@ -1,12 +1,318 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
 
-/// API Object: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/item/object
-#[derive(Deserialize, Debug)]
+use crate::host::model::ZabbixHostTag;
+use crate::item::preprocessing::ItemPreprocessing;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/item/object
+///
+/// Discriminants match the Zabbix values, so `item_type as u8` gives the raw type.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ItemType {
+    #[serde(rename = "0")]
+    ZabbixAgent = 0,
+    #[serde(rename = "2")]
+    ZabbixTrapper = 2,
+    #[serde(rename = "3")]
+    SimpleCheck = 3,
+    #[serde(rename = "5")]
+    ZabbixInternal = 5,
+    #[serde(rename = "7")]
+    ZabbixAgentActive = 7,
+    #[serde(rename = "9")]
+    WebItem = 9,
+    #[serde(rename = "10")]
+    ExternalCheck = 10,
+    #[serde(rename = "11")]
+    DatabaseMonitor = 11,
+    #[serde(rename = "12")]
+    Ipmi = 12,
+    #[serde(rename = "13")]
+    Ssh = 13,
+    #[serde(rename = "14")]
+    Telnet = 14,
+    #[serde(rename = "15")]
+    Calculated = 15,
+    #[serde(rename = "16")]
+    Jmx = 16,
+    #[serde(rename = "17")]
+    SnmpTrap = 17,
+    #[serde(rename = "18")]
+    Dependent = 18,
+    #[serde(rename = "19")]
+    HttpAgent = 19,
+    #[serde(rename = "20")]
+    Snmp = 20,
+    #[serde(rename = "21")]
+    Script = 21,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "22")]
+    Browser = 22,
+}
+
+/// Type of information of the item. Discriminants match the Zabbix values.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ItemValueType {
+    #[serde(rename = "0")]
+    Float = 0,
+    #[serde(rename = "1")]
+    Character = 1,
+    #[serde(rename = "2")]
+    Log = 2,
+    #[serde(rename = "3")]
+    Unsigned = 3,
+    #[serde(rename = "4")]
+    Text = 4,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "5")]
+    Binary = 5,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ItemStatus {
+    #[serde(rename = "0")]
+    Enabled,
+    #[serde(rename = "1")]
+    Disabled,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ItemState {
+    #[serde(rename = "0")]
+    Normal,
+    #[serde(rename = "1")]
+    NotSupported,
+}
+
+/// HTTP agent item header or query field.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct HttpField {
+    pub name: String,
+    pub value: String,
+}
+
+impl HttpField {
+    pub fn new(name: impl ToString, value: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+/// Script item parameter, available in the script as `value.<name>`.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ScriptParameter {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum HttpRequestMethod {
+    #[serde(rename = "0")]
+    Get,
+    #[serde(rename = "1")]
+    Post,
+    #[serde(rename = "2")]
+    Put,
+    #[serde(rename = "3")]
+    Head,
+}
+
+/// Type of the HTTP agent request body.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum HttpPostType {
+    #[serde(rename = "0")]
+    Raw,
+    #[serde(rename = "2")]
+    Json,
+    #[serde(rename = "3")]
+    Xml,
+}
+
+/// Which part of the HTTP response is stored as the item value.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum HttpRetrieveMode {
+    #[serde(rename = "0")]
+    Body,
+    #[serde(rename = "1")]
+    Headers,
+    #[serde(rename = "2")]
+    BodyAndHeaders,
+}
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/item/object
+///
+/// Properties are optional because they are returned only if requested in `output`.
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
 pub struct ZabbixItem {
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+
     pub name: String,
 
     pub key_: String,
 
     #[serde(rename = "hostid")]
     pub host_id: String,
+
+    pub r#type: Option<ItemType>,
+
+    pub value_type: Option<ItemValueType>,
+
+    pub status: Option<ItemStatus>,
+
+    /// Whether the item is supported. Read-only.
+    pub state: Option<ItemState>,
+
+    /// Error text if there are problems updating the item value. Read-only.
+    pub error: Option<String>,
+
+    pub description: Option<String>,
+
+    pub units: Option<String>,
+
+    /// Update interval, e.g. `1m`.
+    pub delay: Option<String>,
+
+    /// How long history data should be stored, e.g. `31d`.
+    pub history: Option<String>,
+
+    /// How long trends data should be stored, e.g. `365d`.
+    pub trends: Option<String>,
+
+    /// Last value of the item. Read-only.
+    #[serde(rename = "lastvalue")]
+    pub last_value: Option<String>,
+
+    /// Previous value of the item. Read-only.
+    #[serde(rename = "prevvalue")]
+    pub prev_value: Option<String>,
+
+    /// Time (unix timestamp) when the item was last updated. Read-only.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "lastclock", default)]
+    pub last_clock: Option<u64>,
+
+    /// ID of the parent template item. `0` for items not inherited from templates.
+    #[serde(rename = "templateid")]
+    pub template_id: Option<String>,
+
+    /// ID of the host interface. `0` for items which don't use interfaces.
+    #[serde(rename = "interfaceid")]
+    pub interface_id: Option<String>,
+
+    /// Origin of the item: `0` - plain item, `4` - discovered item.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub flags: Option<u8>,
+
+    /// ID of the master item, set for dependent items.
+    #[serde(rename = "master_itemid")]
+    pub master_item_id: Option<String>,
+
+    /// Item data polling request timeout, e.g. `3s`.
+    pub timeout: Option<String>,
+
+    #[serde(rename = "valuemapid")]
+    pub value_map_id: Option<String>,
+
+    /// Returned with `selectValueMap`. Zabbix returns an empty array for items without a value map.
+    #[serde(
+        rename = "valuemap",
+        default,
+        deserialize_with = "deserialize_value_map"
+    )]
+    pub value_map: Option<ZabbixItemValueMap>,
+
+    /// Returned with `selectTags`.
+    pub tags: Option<Vec<ZabbixHostTag>>,
+
+    /// Returned with `selectPreprocessing`.
+    pub preprocessing: Option<Vec<ItemPreprocessing>>,
+
+    pub uuid: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixItemId {
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+}
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/valuemap/object
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixItemValueMap {
+    #[serde(rename = "valuemapid")]
+    pub value_map_id: String,
+    pub name: String,
+    #[serde(default)]
+    pub mappings: Vec<ZabbixValueMapping>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixValueMapping {
+    /// Mapping match type: `0` - equal, `1` - greater or equal, `2` - less or equal,
+    /// `3` - in range, `4` - regexp, `5` - default.
+    #[serde(default)]
+    pub r#type: Option<String>,
+    pub value: String,
+    #[serde(rename = "newvalue")]
+    pub new_value: String,
+}
+
+fn deserialize_value_map<'de, D>(deserializer: D) -> Result<Option<ZabbixItemValueMap>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ValueMap {
+        Map(ZabbixItemValueMap),
+        Empty([(); 0]),
+    }
+
+    match Option::<ValueMap>::deserialize(deserializer)? {
+        Some(ValueMap::Map(value_map)) => Ok(Some(value_map)),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod item_tests {
+    use super::{ItemState, ItemStatus, ItemType, ItemValueType, ZabbixItem};
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"itemid":"23296","type":"0","snmp_oid":"","hostid":"10084","name":"CPU load","key_":"system.cpu.load","delay":"1m","history":"7d","trends":"365d","status":"0","value_type":"0","trapper_hosts":"","units":"","logtimefmt":"","templateid":"22680","valuemapid":"0","params":"","ipmi_sensor":"","authtype":"0","username":"","password":"","publickey":"","privatekey":"","flags":"0","interfaceid":"1","description":"","inventory_link":"0","lifetime":"30d","evaltype":"0","jmx_endpoint":"","master_itemid":"0","timeout":"3s","url":"","query_fields":[],"posts":"","status_codes":"200","follow_redirects":"1","post_type":"0","http_proxy":"","headers":[],"retrieve_mode":"0","request_method":"0","output_format":"0","ssl_cert_file":"","ssl_key_file":"","ssl_key_password":"","verify_peer":"0","verify_host":"0","allow_traps":"0","uuid":"","state":"1","error":"Unsupported item key.","parameters":[],"lastclock":"1700000000","lastns":"0","lastvalue":"0.05","prevvalue":"0.1","valuemap":[],"tags":[{"tag":"component","value":"cpu"}]}
+            "#;
+
+        let item: ZabbixItem = serde_json::from_str(input).unwrap();
+
+        assert_eq!(item.item_id, "23296");
+        assert_eq!(item.r#type, Some(ItemType::ZabbixAgent));
+        assert_eq!(item.value_type, Some(ItemValueType::Float));
+        assert_eq!(item.status, Some(ItemStatus::Enabled));
+        assert_eq!(item.state, Some(ItemState::NotSupported));
+        assert_eq!(item.last_clock, Some(1700000000));
+        assert_eq!(item.flags, Some(0));
+        assert!(item.value_map.is_none());
+        assert_eq!(item.tags.unwrap()[0].tag, "component");
+    }
+
+    #[test]
+    fn deserialize_value_map_test() {
+        let input = r#"
+            {"itemid":"1","name":"Status","key_":"status","hostid":"2","valuemap":{"valuemapid":"5","name":"Service state","mappings":[{"type":"0","value":"0","newvalue":"Down"},{"type":"0","value":"1","newvalue":"Up"}]}}
+            "#;
+
+        let item: ZabbixItem = serde_json::from_str(input).unwrap();
+
+        let value_map = item.value_map.unwrap();
+        assert_eq!(value_map.name, "Service state");
+        assert_eq!(value_map.mappings[1].new_value, "Up");
+    }
 }
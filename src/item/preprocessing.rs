@@ -0,0 +1,448 @@
+use serde::{Deserialize, Serialize};
+
+/// Item preprocessing step with its error handling.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/item/object#item-preprocessing
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(into = "RawItemPreprocessing", from = "RawItemPreprocessing")]
+pub struct ItemPreprocessing {
+    pub step: PreprocessingStep,
+    /// Action taken when the step fails.
+    pub error_handler: PreprocessingErrorHandler,
+}
+
+impl ItemPreprocessing {
+    pub fn new(step: PreprocessingStep) -> Self {
+        Self {
+            step,
+            error_handler: PreprocessingErrorHandler::Default,
+        }
+    }
+
+    pub fn on_fail(mut self, error_handler: PreprocessingErrorHandler) -> Self {
+        self.error_handler = error_handler;
+        self
+    }
+}
+
+impl From<PreprocessingStep> for ItemPreprocessing {
+    fn from(step: PreprocessingStep) -> Self {
+        ItemPreprocessing::new(step)
+    }
+}
+
+/// Preprocessing step kinds with their parameters.
+///
+/// Parameters are kept as strings because Zabbix accepts user macros in most of them.
+#[derive(PartialEq, Clone, Debug)]
+pub enum PreprocessingStep {
+    CustomMultiplier {
+        multiplier: String,
+    },
+    RightTrim {
+        chars: String,
+    },
+    LeftTrim {
+        chars: String,
+    },
+    Trim {
+        chars: String,
+    },
+    /// Extracts `output` (may reference capture groups like `\1`) from the value matching `pattern`.
+    Regex {
+        pattern: String,
+        output: String,
+    },
+    BooleanToDecimal,
+    OctalToDecimal,
+    HexadecimalToDecimal,
+    SimpleChange,
+    ChangePerSecond,
+    XmlXPath {
+        path: String,
+    },
+    JsonPath {
+        path: String,
+    },
+    InRange {
+        min: String,
+        max: String,
+    },
+    MatchesRegex {
+        pattern: String,
+    },
+    NotMatchesRegex {
+        pattern: String,
+    },
+    CheckJsonError {
+        path: String,
+    },
+    CheckXmlError {
+        path: String,
+    },
+    CheckRegexError {
+        pattern: String,
+        output: String,
+    },
+    /// Throttling: discards the value if it hasn't changed.
+    DiscardUnchanged,
+    /// Throttling: discards the unchanged value unless `heartbeat` (e.g. `1h`) has passed.
+    DiscardUnchangedWithHeartbeat {
+        heartbeat: String,
+    },
+    JavaScript {
+        script: String,
+    },
+    PrometheusPattern {
+        pattern: String,
+        output: PrometheusOutput,
+    },
+    PrometheusToJson {
+        pattern: String,
+    },
+    CsvToJson {
+        delimiter: String,
+        quote: String,
+        with_header: bool,
+    },
+    Replace {
+        search: String,
+        replace: String,
+    },
+    #[cfg(feature = "v6")]
+    CheckUnsupported,
+    #[cfg(feature = "v7")]
+    CheckUnsupported {
+        scope: UnsupportedErrorScope,
+        pattern: String,
+    },
+    XmlToJson,
+    /// Step kind not covered by this enum, with its raw type and parameters.
+    Other {
+        r#type: String,
+        params: Vec<String>,
+    },
+}
+
+impl PreprocessingStep {
+    fn type_and_params(&self) -> (&str, Vec<String>) {
+        match self {
+            PreprocessingStep::CustomMultiplier { multiplier } => ("1", vec![multiplier.clone()]),
+            PreprocessingStep::RightTrim { chars } => ("2", vec![chars.clone()]),
+            PreprocessingStep::LeftTrim { chars } => ("3", vec![chars.clone()]),
+            PreprocessingStep::Trim { chars } => ("4", vec![chars.clone()]),
+            PreprocessingStep::Regex { pattern, output } => {
+                ("5", vec![pattern.clone(), output.clone()])
+            }
+            PreprocessingStep::BooleanToDecimal => ("6", vec![]),
+            PreprocessingStep::OctalToDecimal => ("7", vec![]),
+            PreprocessingStep::HexadecimalToDecimal => ("8", vec![]),
+            PreprocessingStep::SimpleChange => ("9", vec![]),
+            PreprocessingStep::ChangePerSecond => ("10", vec![]),
+            PreprocessingStep::XmlXPath { path } => ("11", vec![path.clone()]),
+            PreprocessingStep::JsonPath { path } => ("12", vec![path.clone()]),
+            PreprocessingStep::InRange { min, max } => ("13", vec![min.clone(), max.clone()]),
+            PreprocessingStep::MatchesRegex { pattern } => ("14", vec![pattern.clone()]),
+            PreprocessingStep::NotMatchesRegex { pattern } => ("15", vec![pattern.clone()]),
+            PreprocessingStep::CheckJsonError { path } => ("16", vec![path.clone()]),
+            PreprocessingStep::CheckXmlError { path } => ("17", vec![path.clone()]),
+            PreprocessingStep::CheckRegexError { pattern, output } => {
+                ("18", vec![pattern.clone(), output.clone()])
+            }
+            PreprocessingStep::DiscardUnchanged => ("19", vec![]),
+            PreprocessingStep::DiscardUnchangedWithHeartbeat { heartbeat } => {
+                ("20", vec![heartbeat.clone()])
+            }
+            PreprocessingStep::JavaScript { script } => ("21", vec![script.clone()]),
+            PreprocessingStep::PrometheusPattern { pattern, output } => {
+                let (output_type, output_param) = match output {
+                    PrometheusOutput::Value => ("value", String::new()),
+                    PrometheusOutput::Label(label) => ("label", label.clone()),
+                    PrometheusOutput::Function(function) => ("function", function.clone()),
+                };
+
+                (
+                    "22",
+                    vec![pattern.clone(), output_type.to_string(), output_param],
+                )
+            }
+            PreprocessingStep::PrometheusToJson { pattern } => ("23", vec![pattern.clone()]),
+            PreprocessingStep::CsvToJson {
+                delimiter,
+                quote,
+                with_header,
+            } => (
+                "24",
+                vec![
+                    delimiter.clone(),
+                    quote.clone(),
+                    if *with_header { "1" } else { "0" }.to_string(),
+                ],
+            ),
+            PreprocessingStep::Replace { search, replace } => {
+                ("25", vec![search.clone(), replace.clone()])
+            }
+            #[cfg(feature = "v6")]
+            PreprocessingStep::CheckUnsupported => ("26", vec![]),
+            #[cfg(feature = "v7")]
+            PreprocessingStep::CheckUnsupported { scope, pattern } => {
+                let scope = match scope {
+                    UnsupportedErrorScope::Any => "0",
+                    UnsupportedErrorScope::Matches => "1",
+                    UnsupportedErrorScope::NotMatches => "2",
+                };
+
+                ("26", vec![scope.to_string(), pattern.clone()])
+            }
+            PreprocessingStep::XmlToJson => ("27", vec![]),
+            PreprocessingStep::Other { r#type, params } => (r#type.as_str(), params.clone()),
+        }
+    }
+
+    fn from_type_and_params(r#type: &str, params: &str) -> Self {
+        let lines: Vec<&str> = params.split('\n').collect();
+        let param = |index: usize| lines.get(index).copied().unwrap_or_default().to_string();
+
+        match r#type {
+            "1" => PreprocessingStep::CustomMultiplier {
+                multiplier: param(0),
+            },
+            "2" => PreprocessingStep::RightTrim { chars: param(0) },
+            "3" => PreprocessingStep::LeftTrim { chars: param(0) },
+            "4" => PreprocessingStep::Trim { chars: param(0) },
+            "5" => PreprocessingStep::Regex {
+                pattern: param(0),
+                output: param(1),
+            },
+            "6" => PreprocessingStep::BooleanToDecimal,
+            "7" => PreprocessingStep::OctalToDecimal,
+            "8" => PreprocessingStep::HexadecimalToDecimal,
+            "9" => PreprocessingStep::SimpleChange,
+            "10" => PreprocessingStep::ChangePerSecond,
+            "11" => PreprocessingStep::XmlXPath { path: param(0) },
+            "12" => PreprocessingStep::JsonPath { path: param(0) },
+            "13" => PreprocessingStep::InRange {
+                min: param(0),
+                max: param(1),
+            },
+            "14" => PreprocessingStep::MatchesRegex { pattern: param(0) },
+            "15" => PreprocessingStep::NotMatchesRegex { pattern: param(0) },
+            "16" => PreprocessingStep::CheckJsonError { path: param(0) },
+            "17" => PreprocessingStep::CheckXmlError { path: param(0) },
+            "18" => PreprocessingStep::CheckRegexError {
+                pattern: param(0),
+                output: param(1),
+            },
+            "19" => PreprocessingStep::DiscardUnchanged,
+            "20" => PreprocessingStep::DiscardUnchangedWithHeartbeat {
+                heartbeat: param(0),
+            },
+            // Scripts may contain line breaks, so they are taken as is.
+            "21" => PreprocessingStep::JavaScript {
+                script: params.to_string(),
+            },
+            "22" => PreprocessingStep::PrometheusPattern {
+                pattern: param(0),
+                output: match param(1).as_str() {
+                    "label" => PrometheusOutput::Label(param(2)),
+                    "function" => PrometheusOutput::Function(param(2)),
+                    _ => PrometheusOutput::Value,
+                },
+            },
+            "23" => PreprocessingStep::PrometheusToJson { pattern: param(0) },
+            "24" => PreprocessingStep::CsvToJson {
+                delimiter: param(0),
+                quote: param(1),
+                with_header: param(2) == "1",
+            },
+            "25" => PreprocessingStep::Replace {
+                search: param(0),
+                replace: param(1),
+            },
+            #[cfg(feature = "v6")]
+            "26" => PreprocessingStep::CheckUnsupported,
+            #[cfg(feature = "v7")]
+            "26" => PreprocessingStep::CheckUnsupported {
+                scope: match param(0).as_str() {
+                    "1" => UnsupportedErrorScope::Matches,
+                    "2" => UnsupportedErrorScope::NotMatches,
+                    _ => UnsupportedErrorScope::Any,
+                },
+                pattern: param(1),
+            },
+            "27" => PreprocessingStep::XmlToJson,
+            _ => PreprocessingStep::Other {
+                r#type: r#type.to_string(),
+                params: if params.is_empty() {
+                    vec![]
+                } else {
+                    lines.iter().map(|line| line.to_string()).collect()
+                },
+            },
+        }
+    }
+}
+
+/// What a Prometheus pattern step extracts from the matched metric.
+#[derive(PartialEq, Clone, Debug)]
+pub enum PrometheusOutput {
+    Value,
+    /// Value of the label with the given name.
+    Label(String),
+    /// Aggregation function applied to the matched values: `sum`, `min`, `max`, `avg` or `count`.
+    Function(String),
+}
+
+/// Which errors a "check for not supported value" step handles.
+#[cfg(feature = "v7")]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum UnsupportedErrorScope {
+    Any,
+    Matches,
+    NotMatches,
+}
+
+#[derive(PartialEq, Clone, Debug, Default)]
+pub enum PreprocessingErrorHandler {
+    /// Zabbix server sets the error message.
+    #[default]
+    Default,
+    DiscardValue,
+    SetValue(String),
+    SetError(String),
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawItemPreprocessing {
+    r#type: String,
+    #[serde(default)]
+    params: String,
+    #[serde(default)]
+    error_handler: String,
+    #[serde(default)]
+    error_handler_params: String,
+}
+
+impl From<ItemPreprocessing> for RawItemPreprocessing {
+    fn from(value: ItemPreprocessing) -> Self {
+        let (r#type, params) = value.step.type_and_params();
+
+        let (error_handler, error_handler_params) = match value.error_handler {
+            PreprocessingErrorHandler::Default => ("0", String::new()),
+            PreprocessingErrorHandler::DiscardValue => ("1", String::new()),
+            PreprocessingErrorHandler::SetValue(value) => ("2", value),
+            PreprocessingErrorHandler::SetError(message) => ("3", message),
+        };
+
+        RawItemPreprocessing {
+            r#type: r#type.to_string(),
+            params: params.join("\n"),
+            error_handler: error_handler.to_string(),
+            error_handler_params,
+        }
+    }
+}
+
+impl From<RawItemPreprocessing> for ItemPreprocessing {
+    fn from(value: RawItemPreprocessing) -> Self {
+        let error_handler = match value.error_handler.as_str() {
+            "1" => PreprocessingErrorHandler::DiscardValue,
+            "2" => PreprocessingErrorHandler::SetValue(value.error_handler_params),
+            "3" => PreprocessingErrorHandler::SetError(value.error_handler_params),
+            _ => PreprocessingErrorHandler::Default,
+        };
+
+        ItemPreprocessing {
+            step: PreprocessingStep::from_type_and_params(&value.r#type, &value.params),
+            error_handler,
+        }
+    }
+}
+
+#[cfg(test)]
+mod preprocessing_tests {
+    use super::{
+        ItemPreprocessing, PreprocessingErrorHandler, PreprocessingStep, PrometheusOutput,
+    };
+
+    #[test]
+    fn serialization_test() {
+        let steps = vec![
+            ItemPreprocessing::new(PreprocessingStep::JsonPath {
+                path: "$.data.value".to_string(),
+            })
+            .on_fail(PreprocessingErrorHandler::SetValue("0".to_string())),
+            ItemPreprocessing::new(PreprocessingStep::Regex {
+                pattern: "up (\\d+)".to_string(),
+                output: "\\1".to_string(),
+            }),
+            PreprocessingStep::ChangePerSecond.into(),
+        ];
+
+        let json = serde_json::to_value(&steps).unwrap();
+
+        assert_eq!(json[0]["type"], "12");
+        assert_eq!(json[0]["params"], "$.data.value");
+        assert_eq!(json[0]["error_handler"], "2");
+        assert_eq!(json[0]["error_handler_params"], "0");
+        assert_eq!(json[1]["type"], "5");
+        assert_eq!(json[1]["params"], "up (\\d+)\n\\1");
+        assert_eq!(json[1]["error_handler"], "0");
+        assert_eq!(json[2]["type"], "10");
+        assert_eq!(json[2]["params"], "");
+    }
+
+    #[test]
+    fn deserialization_test() {
+        let input = r#"[
+            {"type":"1","params":"8","error_handler":"1","error_handler_params":""},
+            {"type":"21","params":"var a = 1;\nreturn value * a;","error_handler":"3","error_handler_params":"script failed"},
+            {"type":"22","params":"http_requests_total{method=\"get\"}\nlabel\ncode","error_handler":"0","error_handler_params":""},
+            {"type":"20","params":"1h","error_handler":"0","error_handler_params":""},
+            {"type":"99","params":"a\nb","error_handler":"0","error_handler_params":""}
+        ]"#;
+
+        let steps: Vec<ItemPreprocessing> = serde_json::from_str(input).unwrap();
+
+        assert_eq!(
+            steps[0].step,
+            PreprocessingStep::CustomMultiplier {
+                multiplier: "8".to_string()
+            }
+        );
+        assert_eq!(
+            steps[0].error_handler,
+            PreprocessingErrorHandler::DiscardValue
+        );
+        assert_eq!(
+            steps[1].step,
+            PreprocessingStep::JavaScript {
+                script: "var a = 1;\nreturn value * a;".to_string()
+            }
+        );
+        assert_eq!(
+            steps[1].error_handler,
+            PreprocessingErrorHandler::SetError("script failed".to_string())
+        );
+        assert_eq!(
+            steps[2].step,
+            PreprocessingStep::PrometheusPattern {
+                pattern: "http_requests_total{method=\"get\"}".to_string(),
+                output: PrometheusOutput::Label("code".to_string())
+            }
+        );
+        assert_eq!(
+            steps[3].step,
+            PreprocessingStep::DiscardUnchangedWithHeartbeat {
+                heartbeat: "1h".to_string()
+            }
+        );
+        assert_eq!(
+            steps[4].step,
+            PreprocessingStep::Other {
+                r#type: "99".to_string(),
+                params: vec!["a".to_string(), "b".to_string()]
+            }
+        );
+    }
+}
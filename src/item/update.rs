@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::host::model::ZabbixHostTag;
+use crate::item::model::{ItemStatus, ItemType, ItemValueType};
+use crate::item::preprocessing::ItemPreprocessing;
+
+/// Properties left as `None` are not changed. Tags and preprocessing steps replace the existing ones.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/item/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateItemRequest {
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+    pub name: Option<String>,
+    pub key_: Option<String>,
+    pub r#type: Option<ItemType>,
+    pub value_type: Option<ItemValueType>,
+    pub status: Option<ItemStatus>,
+    pub description: Option<String>,
+    pub units: Option<String>,
+    pub delay: Option<String>,
+    pub history: Option<String>,
+    pub trends: Option<String>,
+    #[serde(rename = "interfaceid")]
+    pub interface_id: Option<String>,
+    #[serde(rename = "master_itemid")]
+    pub master_item_id: Option<String>,
+    pub timeout: Option<String>,
+    #[serde(rename = "valuemapid")]
+    pub value_map_id: Option<String>,
+    pub tags: Option<Vec<ZabbixHostTag>>,
+    pub preprocessing: Option<Vec<ItemPreprocessing>>,
+}
+
+impl UpdateItemRequest {
+    pub fn builder(item_id: impl ToString) -> UpdateItemRequestBuilder {
+        UpdateItemRequestBuilder {
+            inner: UpdateItemRequest {
+                item_id: item_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateItemRequestBuilder {
+    inner: UpdateItemRequest,
+}
+
+impl UpdateItemRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn key(mut self, key: impl ToString) -> Self {
+        self.inner.key_ = Some(key.to_string());
+        self
+    }
+
+    pub fn item_type(mut self, item_type: ItemType) -> Self {
+        self.inner.r#type = Some(item_type);
+        self
+    }
+
+    pub fn value_type(mut self, value_type: ItemValueType) -> Self {
+        self.inner.value_type = Some(value_type);
+        self
+    }
+
+    pub fn status(mut self, status: ItemStatus) -> Self {
+        self.inner.status = Some(status);
+        self
+    }
+
+    pub fn enable(self) -> Self {
+        self.status(ItemStatus::Enabled)
+    }
+
+    pub fn disable(self) -> Self {
+        self.status(ItemStatus::Disabled)
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn units(mut self, units: impl ToString) -> Self {
+        self.inner.units = Some(units.to_string());
+        self
+    }
+
+    pub fn delay(mut self, delay: impl ToString) -> Self {
+        self.inner.delay = Some(delay.to_string());
+        self
+    }
+
+    pub fn history(mut self, history: impl ToString) -> Self {
+        self.inner.history = Some(history.to_string());
+        self
+    }
+
+    pub fn trends(mut self, trends: impl ToString) -> Self {
+        self.inner.trends = Some(trends.to_string());
+        self
+    }
+
+    pub fn interface_id(mut self, interface_id: impl ToString) -> Self {
+        self.inner.interface_id = Some(interface_id.to_string());
+        self
+    }
+
+    pub fn master_item_id(mut self, master_item_id: impl ToString) -> Self {
+        self.inner.master_item_id = Some(master_item_id.to_string());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: impl ToString) -> Self {
+        self.inner.timeout = Some(timeout.to_string());
+        self
+    }
+
+    pub fn value_map_id(mut self, value_map_id: impl ToString) -> Self {
+        self.inner.value_map_id = Some(value_map_id.to_string());
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<ZabbixHostTag>) -> Self {
+        self.inner.tags = Some(tags);
+        self
+    }
+
+    pub fn preprocessing(mut self, steps: Vec<ItemPreprocessing>) -> Self {
+        self.inner.preprocessing = Some(steps);
+        self
+    }
+
+    pub fn build(self) -> UpdateItemRequest {
+        self.inner
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/item/update
+#[derive(Deserialize, Debug)]
+pub struct UpdateItemResponse {
+    #[serde(rename = "itemids")]
+    pub item_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod update_tests {
+    use super::UpdateItemRequest;
+
+    #[test]
+    fn builder_serialization_test() {
+        let request = UpdateItemRequest::builder("23296")
+            .disable()
+            .history("7d")
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["itemid"], "23296");
+        assert_eq!(json["status"], "1");
+        assert_eq!(json["history"], "7d");
+        assert!(json.get("name").is_none());
+    }
+}
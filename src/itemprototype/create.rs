@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::discoveryrule::model::PrototypeDiscover;
+use crate::item::create::CreateItemRequest;
+
+/// Item prototype is described with the same request as a regular item, so
+/// item type constructors like `CreateItemRequest::dependent` can be used.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/create
+#[derive(Serialize, Debug)]
+pub struct CreateItemPrototypeRequest {
+    /// ID of the parent discovery rule.
+    #[serde(rename = "ruleid")]
+    pub rule_id: String,
+    #[serde(flatten)]
+    pub item: CreateItemRequest,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discover: Option<PrototypeDiscover>,
+}
+
+impl CreateItemPrototypeRequest {
+    pub fn new(rule_id: impl ToString, item: CreateItemRequest) -> Self {
+        Self {
+            rule_id: rule_id.to_string(),
+            item,
+            discover: None,
+        }
+    }
+
+    pub fn discover(mut self, discover: bool) -> Self {
+        self.discover = Some(PrototypeDiscover::from(discover));
+        self
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/create
+#[derive(Deserialize, Debug)]
+pub struct CreateItemPrototypeResponse {
+    #[serde(rename = "itemids")]
+    pub item_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateItemPrototypeRequest;
+    use crate::item::create::CreateItemRequest;
+    use crate::item::model::ItemValueType;
+
+    #[test]
+    fn serialization_test() {
+        let item = CreateItemRequest::dependent(
+            "10084",
+            "Service {#SERVICE} state",
+            "service.state[{#SERVICE}]",
+            ItemValueType::Unsigned,
+            "23296",
+        )
+        .build()
+        .unwrap();
+
+        let request = CreateItemPrototypeRequest::new("27425", item).discover(false);
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["ruleid"], "27425");
+        assert_eq!(json["key_"], "service.state[{#SERVICE}]");
+        assert_eq!(json["master_itemid"], "23296");
+        assert_eq!(json["discover"], "1");
+    }
+}
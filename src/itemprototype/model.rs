@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+use crate::discoveryrule::model::{PrototypeDiscover, ZabbixDiscoveryRule};
+use crate::item::model::ZabbixItem;
+
+/// Item prototype: an item with `{#LLD.MACRO}` placeholders in its key and name.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/object
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixItemPrototype {
+    #[serde(flatten)]
+    pub item: ZabbixItem,
+
+    pub discover: Option<PrototypeDiscover>,
+
+    /// Parent discovery rule, returned with `selectDiscoveryRule`.
+    #[serde(rename = "discoveryRule")]
+    pub discovery_rule: Option<ZabbixDiscoveryRule>,
+}
+
+#[cfg(test)]
+mod itemprototype_tests {
+    use super::ZabbixItemPrototype;
+    use crate::discoveryrule::model::PrototypeDiscover;
+    use crate::item::model::ItemType;
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"itemid":"27428","type":"0","hostid":"10084","name":"Free disk space on {#FSNAME}","key_":"vfs.fs.size[{#FSNAME},free]","delay":"1m","status":"0","value_type":"3","units":"B","flags":"2","discover":"0","preprocessing":[{"type":"1","params":"1024","error_handler":"0","error_handler_params":""}],
+             "discoveryRule":{"itemid":"27425","hostid":"10084","name":"Mounted filesystem discovery","key_":"vfs.fs.discovery"}}
+            "#;
+
+        let prototype: ZabbixItemPrototype = serde_json::from_str(input).unwrap();
+
+        assert_eq!(prototype.item.key_, "vfs.fs.size[{#FSNAME},free]");
+        assert_eq!(prototype.item.r#type, Some(ItemType::ZabbixAgent));
+        assert_eq!(prototype.item.flags, Some(2));
+        assert_eq!(prototype.discover, Some(PrototypeDiscover::Discover));
+        assert_eq!(prototype.item.preprocessing.unwrap().len(), 1);
+        assert_eq!(prototype.discovery_rule.unwrap().item_id, "27425");
+    }
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::discoveryrule::model::PrototypeDiscover;
+use crate::item::update::UpdateItemRequest;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/update
+#[derive(Serialize, Debug)]
+pub struct UpdateItemPrototypeRequest {
+    #[serde(flatten)]
+    pub item: UpdateItemRequest,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discover: Option<PrototypeDiscover>,
+}
+
+impl UpdateItemPrototypeRequest {
+    pub fn new(item: UpdateItemRequest) -> Self {
+        Self {
+            item,
+            discover: None,
+        }
+    }
+
+    pub fn discover(mut self, discover: bool) -> Self {
+        self.discover = Some(PrototypeDiscover::from(discover));
+        self
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/update
+#[derive(Deserialize, Debug)]
+pub struct UpdateItemPrototypeResponse {
+    #[serde(rename = "itemids")]
+    pub item_ids: Vec<String>,
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/itemprototype/delete
+#[derive(Deserialize, Debug)]
+pub struct DeleteItemPrototypesResponse {
+    #[serde(rename = "prototypeids")]
+    pub prototype_ids: Vec<String>,
+}
@@ -15,8 +15,65 @@ pub mod trigger;
 #[cfg(feature = "webscenario")]
 pub mod webscenario;
 
+#[cfg(feature = "event")]
+pub mod event;
+
+#[cfg(feature = "event")]
+pub mod problem;
+
+#[cfg(feature = "history")]
+pub mod history;
+
+#[cfg(feature = "trend")]
+pub mod trend;
+
+#[cfg(feature = "maintenance")]
+pub mod maintenance;
+
+#[cfg(feature = "action")]
+pub mod action;
+
+#[cfg(feature = "mediatype")]
+pub mod mediatype;
+
+#[cfg(feature = "alert")]
+pub mod alert;
+
+#[cfg(feature = "script")]
+pub mod script;
+
+#[cfg(feature = "proxy")]
+pub mod proxy;
+
+#[cfg(all(feature = "proxy", feature = "v7"))]
+pub mod proxygroup;
+
+#[cfg(feature = "dashboard")]
+pub mod dashboard;
+
 pub mod template;
 
+#[cfg(all(feature = "template", feature = "v7"))]
+pub mod templategroup;
+
+#[cfg(feature = "configuration")]
+pub mod configuration;
+
+#[cfg(feature = "discovery")]
+pub mod discoveryrule;
+
+#[cfg(feature = "discovery")]
+pub mod itemprototype;
+
+#[cfg(feature = "discovery")]
+pub mod triggerprototype;
+
+#[cfg(feature = "discovery")]
+pub mod graphprototype;
+
+#[cfg(feature = "discovery")]
+pub mod hostprototype;
+
 pub mod r#macro;
 
 #[cfg(feature = "user")]
@@ -27,6 +84,12 @@ pub mod user;
 
 pub mod error;
 
+pub(crate) mod de;
+
+pub(crate) mod flag;
+
+pub(crate) mod timestamp;
+
 pub const ZABBIX_EXTEND_PROPERTY_VALUE: &str = "extend";
 
 #[cfg(test)]
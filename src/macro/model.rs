@@ -13,13 +13,15 @@ pub struct ZabbixGlobalMacro {
 }
 
 /// API Object: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/usermacro/object
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ZabbixHostMacro {
     #[serde(rename = "hostmacroid")]
     pub id: String,
     #[serde(rename = "hostid")]
     pub host_id: String,
     pub r#macro: String,
+    /// Not returned for secret macros.
+    #[serde(default)]
     pub value: String,
     pub r#type: MacroType,
     pub description: String,
@@ -0,0 +1,274 @@
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::error::ZabbixApiError;
+use crate::host::model::ZabbixHostId;
+use crate::hostgroup::model::ZabbixHostGroupId;
+use crate::maintenance::model::{
+    MaintenanceTagsEvalType, MaintenanceType, TimePeriod, ZabbixMaintenanceTag,
+};
+use crate::timestamp::serialize_required_timestamp;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/maintenance/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateMaintenanceRequest {
+    pub name: String,
+    pub maintenance_type: MaintenanceType,
+    pub description: Option<String>,
+    #[serde(serialize_with = "serialize_required_timestamp")]
+    pub active_since: SystemTime,
+    #[serde(serialize_with = "serialize_required_timestamp")]
+    pub active_till: SystemTime,
+    pub groups: Vec<ZabbixHostGroupId>,
+    pub hosts: Vec<ZabbixHostId>,
+    #[serde(rename = "timeperiods")]
+    pub time_periods: Vec<TimePeriod>,
+    pub tags: Vec<ZabbixMaintenanceTag>,
+    #[serde(rename = "tags_evaltype")]
+    pub tags_eval_type: Option<MaintenanceTagsEvalType>,
+}
+
+impl CreateMaintenanceRequest {
+    pub fn builder(
+        name: impl ToString,
+        active_since: SystemTime,
+        active_till: SystemTime,
+    ) -> CreateMaintenanceRequestBuilder {
+        CreateMaintenanceRequestBuilder {
+            inner: CreateMaintenanceRequest {
+                name: name.to_string(),
+                maintenance_type: MaintenanceType::WithDataCollection,
+                description: None,
+                active_since,
+                active_till,
+                groups: vec![],
+                hosts: vec![],
+                time_periods: vec![],
+                tags: vec![],
+                tags_eval_type: None,
+            },
+        }
+    }
+
+    /// Maintenance of the given hosts for `duration`, starting now.
+    ///
+    /// Zabbix rounds the start down to a minute.
+    pub fn for_hosts(
+        name: impl ToString,
+        host_ids: &[String],
+        duration: Duration,
+    ) -> Result<CreateMaintenanceRequest, ZabbixApiError> {
+        let now = SystemTime::now();
+
+        host_ids
+            .iter()
+            .fold(
+                CreateMaintenanceRequest::builder(name, now, now + duration),
+                |builder, host_id| builder.host(host_id),
+            )
+            .time_period(TimePeriod::OneTime {
+                start_date: now,
+                period: duration,
+            })
+            .build()
+    }
+}
+
+pub struct CreateMaintenanceRequestBuilder {
+    inner: CreateMaintenanceRequest,
+}
+
+impl CreateMaintenanceRequestBuilder {
+    /// Stops data collection for the hosts during maintenance.
+    pub fn without_data_collection(mut self) -> Self {
+        self.inner.maintenance_type = MaintenanceType::WithoutDataCollection;
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn host(mut self, host_id: impl ToString) -> Self {
+        self.inner.hosts.push(ZabbixHostId {
+            host_id: host_id.to_string(),
+        });
+        self
+    }
+
+    pub fn group(mut self, group_id: impl ToString) -> Self {
+        self.inner.groups.push(ZabbixHostGroupId {
+            group_id: group_id.to_string(),
+        });
+        self
+    }
+
+    pub fn time_period(mut self, time_period: TimePeriod) -> Self {
+        self.inner.time_periods.push(time_period);
+        self
+    }
+
+    pub fn tag(mut self, tag: ZabbixMaintenanceTag) -> Self {
+        self.inner.tags.push(tag);
+        self
+    }
+
+    pub fn tags_eval_type(mut self, tags_eval_type: MaintenanceTagsEvalType) -> Self {
+        self.inner.tags_eval_type = Some(tags_eval_type);
+        self
+    }
+
+    /// Checks targets, time periods and tags.
+    pub fn build(self) -> Result<CreateMaintenanceRequest, ZabbixApiError> {
+        let maintenance = self.inner;
+
+        let invalid = |message: &str| {
+            Err(ZabbixApiError::InvalidRequestError(format!(
+                "maintenance '{}': {message}",
+                maintenance.name
+            )))
+        };
+
+        if maintenance.hosts.is_empty() && maintenance.groups.is_empty() {
+            return invalid("at least one host or host group is required");
+        }
+
+        if maintenance.time_periods.is_empty() {
+            return invalid("at least one time period is required");
+        }
+
+        if maintenance.active_till <= maintenance.active_since {
+            return invalid("active till must be after active since");
+        }
+
+        if !maintenance.tags.is_empty()
+            && maintenance.maintenance_type == MaintenanceType::WithoutDataCollection
+        {
+            return invalid("tags are supported only with data collection");
+        }
+
+        Ok(maintenance)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateMaintenanceResponse {
+    #[serde(rename = "maintenanceids")]
+    pub maintenance_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::CreateMaintenanceRequest;
+    use crate::maintenance::model::{TimePeriod, Weekday, ZabbixMaintenanceTag};
+
+    #[test]
+    fn serialize_test() {
+        let since = UNIX_EPOCH + Duration::from_secs(1700000000);
+
+        let request = CreateMaintenanceRequest::builder(
+            "Nightly",
+            since,
+            since + Duration::from_secs(86400 * 30),
+        )
+        .group("2")
+        .time_period(TimePeriod::Daily {
+            every: 1,
+            start_time: Duration::from_secs(3600),
+            period: Duration::from_secs(1800),
+        })
+        .tag(ZabbixMaintenanceTag::contains("service", "backup"))
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Nightly",
+                "maintenance_type": "0",
+                "active_since": 1700000000,
+                "active_till": 1702592000,
+                "groups": [{"groupid": "2"}],
+                "hosts": [],
+                "timeperiods": [{"timeperiod_type": "2", "period": "1800", "start_time": "3600", "every": "1"}],
+                "tags": [{"tag": "service", "operator": "2", "value": "backup"}]
+            })
+        );
+    }
+
+    #[test]
+    fn maintenance_for_hosts_starts_now() {
+        let request = CreateMaintenanceRequest::for_hosts(
+            "Deploy",
+            &["10084".to_string(), "10085".to_string()],
+            Duration::from_secs(30 * 60),
+        )
+        .unwrap();
+
+        assert_eq!(request.hosts.len(), 2);
+        assert_eq!(
+            request
+                .active_till
+                .duration_since(request.active_since)
+                .unwrap(),
+            Duration::from_secs(30 * 60)
+        );
+        assert!(matches!(
+            request.time_periods[0],
+            TimePeriod::OneTime { period, .. } if period == Duration::from_secs(30 * 60)
+        ));
+    }
+
+    #[test]
+    fn invalid_requests_are_rejected() {
+        let since = UNIX_EPOCH + Duration::from_secs(1700000000);
+        let period = TimePeriod::Weekly {
+            every: 1,
+            days: Weekday::Sunday.into(),
+            start_time: Duration::ZERO,
+            period: Duration::from_secs(3600),
+        };
+
+        assert!(CreateMaintenanceRequest::builder(
+            "No targets",
+            since,
+            since + Duration::from_secs(60)
+        )
+        .time_period(period.clone())
+        .build()
+        .is_err());
+
+        assert!(CreateMaintenanceRequest::builder(
+            "No periods",
+            since,
+            since + Duration::from_secs(60)
+        )
+        .host("10084")
+        .build()
+        .is_err());
+
+        assert!(CreateMaintenanceRequest::builder("Reversed", since, since)
+            .host("10084")
+            .time_period(period.clone())
+            .build()
+            .is_err());
+
+        assert!(
+            CreateMaintenanceRequest::builder("Tags", since, since + Duration::from_secs(60))
+                .host("10084")
+                .time_period(period)
+                .without_data_collection()
+                .tag(ZabbixMaintenanceTag::equals("service", "mysql"))
+                .build()
+                .is_err()
+        );
+    }
+}
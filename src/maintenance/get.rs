@@ -0,0 +1,33 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/maintenance/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetMaintenancesRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "maintenanceids")]
+    pub maintenance_ids: Option<Vec<String>>,
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "selectHosts")]
+    pub select_hosts: Option<String>,
+    /// Return host groups in the `hostgroups` property.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "selectHostGroups")]
+    pub select_host_groups: Option<String>,
+    /// Return host groups in the `groups` property.
+    #[cfg(feature = "v6")]
+    #[serde(rename = "selectGroups")]
+    pub select_groups: Option<String>,
+    #[serde(rename = "selectTimeperiods")]
+    pub select_time_periods: Option<String>,
+    #[serde(rename = "selectTags")]
+    pub select_tags: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,511 @@
+use std::ops::BitOr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::host::model::ZabbixHost;
+use crate::hostgroup::model::ZabbixHostGroup;
+use crate::timestamp::deserialize_timestamp;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/maintenance/object
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixMaintenance {
+    #[serde(rename = "maintenanceid")]
+    pub maintenance_id: String,
+    pub name: String,
+    pub maintenance_type: MaintenanceType,
+    pub description: String,
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub active_since: SystemTime,
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub active_till: SystemTime,
+    #[serde(rename = "tags_evaltype")]
+    pub tags_eval_type: Option<MaintenanceTagsEvalType>,
+    /// Filled when requested with `selectTimeperiods`.
+    #[serde(rename = "timeperiods")]
+    pub time_periods: Option<Vec<TimePeriod>>,
+    /// Filled when requested with `selectTags`.
+    pub tags: Option<Vec<ZabbixMaintenanceTag>>,
+    /// Filled when requested with `selectHosts`.
+    pub hosts: Option<Vec<ZabbixHost>>,
+    /// Filled when requested with `selectHostGroups`.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "hostgroups")]
+    pub host_groups: Option<Vec<ZabbixHostGroup>>,
+    /// Filled when requested with `selectGroups`.
+    #[cfg(feature = "v6")]
+    pub groups: Option<Vec<ZabbixHostGroup>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug, Default)]
+pub enum MaintenanceType {
+    #[default]
+    #[serde(rename = "0")]
+    WithDataCollection,
+    #[serde(rename = "1")]
+    WithoutDataCollection,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum MaintenanceTagsEvalType {
+    #[serde(rename = "0")]
+    AndOr,
+    #[serde(rename = "2")]
+    Or,
+}
+
+/// Problem tag, only problems with matching tags are suppressed.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixMaintenanceTag {
+    pub tag: String,
+    pub operator: MaintenanceTagOperator,
+    pub value: String,
+}
+
+impl ZabbixMaintenanceTag {
+    pub fn equals(tag: impl ToString, value: impl ToString) -> Self {
+        Self {
+            tag: tag.to_string(),
+            operator: MaintenanceTagOperator::Equals,
+            value: value.to_string(),
+        }
+    }
+
+    pub fn contains(tag: impl ToString, value: impl ToString) -> Self {
+        Self {
+            tag: tag.to_string(),
+            operator: MaintenanceTagOperator::Contains,
+            value: value.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum MaintenanceTagOperator {
+    #[serde(rename = "0")]
+    Equals,
+    #[serde(rename = "2")]
+    Contains,
+}
+
+/// When the maintenance is in effect, within `active_since` and `active_till`.
+///
+/// `start_time` is the time of day the period starts at, `period` its length.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(into = "RawTimePeriod", try_from = "RawTimePeriod")]
+pub enum TimePeriod {
+    OneTime {
+        start_date: SystemTime,
+        period: Duration,
+    },
+    /// Every `every` days.
+    Daily {
+        every: u32,
+        start_time: Duration,
+        period: Duration,
+    },
+    /// On the given days every `every` weeks.
+    Weekly {
+        every: u32,
+        days: DaysOfWeek,
+        start_time: Duration,
+        period: Duration,
+    },
+    /// On the given day of the given months.
+    MonthlyByDay {
+        months: Months,
+        day: u32,
+        start_time: Duration,
+        period: Duration,
+    },
+    /// On the given days of the given week of the given months.
+    MonthlyByWeek {
+        months: Months,
+        week: WeekOfMonth,
+        days: DaysOfWeek,
+        start_time: Duration,
+        period: Duration,
+    },
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Set of weekdays, sent to Zabbix as a bitmask.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct DaysOfWeek(u32);
+
+impl DaysOfWeek {
+    pub fn all() -> Self {
+        Self(0b111_1111)
+    }
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn contains(self, day: Weekday) -> bool {
+        self.0 & day.bit() != 0
+    }
+}
+
+impl From<Weekday> for DaysOfWeek {
+    fn from(day: Weekday) -> Self {
+        Self(day.bit())
+    }
+}
+
+impl BitOr for Weekday {
+    type Output = DaysOfWeek;
+
+    fn bitor(self, rhs: Self) -> DaysOfWeek {
+        DaysOfWeek::from(self) | rhs
+    }
+}
+
+impl BitOr<Weekday> for DaysOfWeek {
+    type Output = DaysOfWeek;
+
+    fn bitor(self, rhs: Weekday) -> DaysOfWeek {
+        Self(self.0 | rhs.bit())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    pub fn bit(self) -> u32 {
+        1 << self as u32
+    }
+}
+
+/// Set of months, sent to Zabbix as a bitmask.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct Months(u32);
+
+impl Months {
+    pub fn all() -> Self {
+        Self(0b1111_1111_1111)
+    }
+
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    pub fn contains(self, month: Month) -> bool {
+        self.0 & month.bit() != 0
+    }
+}
+
+impl From<Month> for Months {
+    fn from(month: Month) -> Self {
+        Self(month.bit())
+    }
+}
+
+impl BitOr for Month {
+    type Output = Months;
+
+    fn bitor(self, rhs: Self) -> Months {
+        Months::from(self) | rhs
+    }
+}
+
+impl BitOr<Month> for Months {
+    type Output = Months;
+
+    fn bitor(self, rhs: Month) -> Months {
+        Self(self.0 | rhs.bit())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum WeekOfMonth {
+    First = 1,
+    Second = 2,
+    Third = 3,
+    Fourth = 4,
+    Last = 5,
+}
+
+/// Time period as sent and returned by Zabbix, fields depend on `timeperiod_type`.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Default)]
+struct RawTimePeriod {
+    #[serde_as(as = "DisplayFromStr")]
+    timeperiod_type: u32,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    period: Option<u64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    start_date: Option<u64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    start_time: Option<u64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    every: Option<u32>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    dayofweek: Option<u32>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    day: Option<u32>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    month: Option<u32>,
+}
+
+const ONE_TIME: u32 = 0;
+const DAILY: u32 = 2;
+const WEEKLY: u32 = 3;
+const MONTHLY: u32 = 4;
+
+impl From<TimePeriod> for RawTimePeriod {
+    fn from(time_period: TimePeriod) -> Self {
+        match time_period {
+            TimePeriod::OneTime { start_date, period } => RawTimePeriod {
+                timeperiod_type: ONE_TIME,
+                period: Some(period.as_secs()),
+                start_date: Some(
+                    start_date
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_secs(),
+                ),
+                ..Default::default()
+            },
+            TimePeriod::Daily {
+                every,
+                start_time,
+                period,
+            } => RawTimePeriod {
+                timeperiod_type: DAILY,
+                period: Some(period.as_secs()),
+                start_time: Some(start_time.as_secs()),
+                every: Some(every),
+                ..Default::default()
+            },
+            TimePeriod::Weekly {
+                every,
+                days,
+                start_time,
+                period,
+            } => RawTimePeriod {
+                timeperiod_type: WEEKLY,
+                period: Some(period.as_secs()),
+                start_time: Some(start_time.as_secs()),
+                every: Some(every),
+                dayofweek: Some(days.bits()),
+                ..Default::default()
+            },
+            TimePeriod::MonthlyByDay {
+                months,
+                day,
+                start_time,
+                period,
+            } => RawTimePeriod {
+                timeperiod_type: MONTHLY,
+                period: Some(period.as_secs()),
+                start_time: Some(start_time.as_secs()),
+                day: Some(day),
+                month: Some(months.bits()),
+                ..Default::default()
+            },
+            TimePeriod::MonthlyByWeek {
+                months,
+                week,
+                days,
+                start_time,
+                period,
+            } => RawTimePeriod {
+                timeperiod_type: MONTHLY,
+                period: Some(period.as_secs()),
+                start_time: Some(start_time.as_secs()),
+                every: Some(week as u32),
+                dayofweek: Some(days.bits()),
+                month: Some(months.bits()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl TryFrom<RawTimePeriod> for TimePeriod {
+    type Error = String;
+
+    fn try_from(raw: RawTimePeriod) -> Result<Self, Self::Error> {
+        let period = Duration::from_secs(raw.period.unwrap_or_default());
+        let start_time = Duration::from_secs(raw.start_time.unwrap_or_default());
+        let every = raw.every.unwrap_or(1);
+        let days = DaysOfWeek(raw.dayofweek.unwrap_or_default());
+        let months = Months(raw.month.unwrap_or_default());
+
+        match raw.timeperiod_type {
+            ONE_TIME => Ok(TimePeriod::OneTime {
+                start_date: UNIX_EPOCH + Duration::from_secs(raw.start_date.unwrap_or_default()),
+                period,
+            }),
+            DAILY => Ok(TimePeriod::Daily {
+                every,
+                start_time,
+                period,
+            }),
+            WEEKLY => Ok(TimePeriod::Weekly {
+                every,
+                days,
+                start_time,
+                period,
+            }),
+            MONTHLY => match raw.day.unwrap_or_default() {
+                0 => {
+                    let week = match every {
+                        1 => WeekOfMonth::First,
+                        2 => WeekOfMonth::Second,
+                        3 => WeekOfMonth::Third,
+                        4 => WeekOfMonth::Fourth,
+                        5 => WeekOfMonth::Last,
+                        _ => return Err(format!("unsupported week of month '{every}'")),
+                    };
+
+                    Ok(TimePeriod::MonthlyByWeek {
+                        months,
+                        week,
+                        days,
+                        start_time,
+                        period,
+                    })
+                }
+                day => Ok(TimePeriod::MonthlyByDay {
+                    months,
+                    day,
+                    start_time,
+                    period,
+                }),
+            },
+            other => Err(format!("unsupported time period type '{other}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod maintenance_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{
+        MaintenanceTagOperator, MaintenanceType, Month, Months, TimePeriod, WeekOfMonth, Weekday,
+        ZabbixMaintenance,
+    };
+
+    #[test]
+    fn serialize_time_periods() {
+        let periods = vec![
+            TimePeriod::OneTime {
+                start_date: UNIX_EPOCH + Duration::from_secs(1700000000),
+                period: Duration::from_secs(3600),
+            },
+            TimePeriod::Weekly {
+                every: 1,
+                days: Weekday::Monday | Weekday::Friday,
+                start_time: Duration::from_secs(64800),
+                period: Duration::from_secs(7200),
+            },
+            TimePeriod::MonthlyByWeek {
+                months: Month::January | Month::December,
+                week: WeekOfMonth::Last,
+                days: Weekday::Sunday.into(),
+                start_time: Duration::ZERO,
+                period: Duration::from_secs(3600),
+            },
+        ];
+
+        let json = serde_json::to_value(&periods).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {"timeperiod_type": "0", "period": "3600", "start_date": "1700000000"},
+                {"timeperiod_type": "3", "period": "7200", "start_time": "64800", "every": "1", "dayofweek": "17"},
+                {"timeperiod_type": "4", "period": "3600", "start_time": "0", "every": "5", "dayofweek": "64", "month": "2049"}
+            ])
+        );
+    }
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"maintenanceid":"3","name":"Sunday maintenance","maintenance_type":"0","description":"","active_since":"1358844540","active_till":"1390466940","tags_evaltype":"0",
+             "timeperiods":[
+                {"timeperiod_type":"3","every":"1","month":"0","dayofweek":"1","day":"0","start_time":"64800","period":"3600","start_date":"2147483647"},
+                {"timeperiod_type":"4","every":"1","month":"4095","dayofweek":"0","day":"15","start_time":"0","period":"7200","start_date":"0"}
+             ],
+             "tags":[{"tag":"service","operator":"0","value":"mysqld"}]}
+            "#;
+
+        let maintenance: ZabbixMaintenance = serde_json::from_str(input).unwrap();
+
+        assert_eq!(
+            maintenance.maintenance_type,
+            MaintenanceType::WithDataCollection
+        );
+        assert_eq!(
+            maintenance.active_since,
+            UNIX_EPOCH + Duration::from_secs(1358844540)
+        );
+
+        let periods = maintenance.time_periods.unwrap();
+
+        match &periods[0] {
+            TimePeriod::Weekly { days, period, .. } => {
+                assert!(days.contains(Weekday::Monday));
+                assert!(!days.contains(Weekday::Sunday));
+                assert_eq!(*period, Duration::from_secs(3600));
+            }
+            other => panic!("unexpected time period: {:?}", other),
+        }
+
+        assert_eq!(
+            periods[1],
+            TimePeriod::MonthlyByDay {
+                months: Months::all(),
+                day: 15,
+                start_time: Duration::ZERO,
+                period: Duration::from_secs(7200),
+            }
+        );
+
+        let tags = maintenance.tags.unwrap();
+        assert_eq!(tags[0].operator, MaintenanceTagOperator::Equals);
+    }
+}
@@ -0,0 +1,126 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::host::model::ZabbixHostId;
+use crate::hostgroup::model::ZabbixHostGroupId;
+use crate::maintenance::model::{
+    MaintenanceTagsEvalType, MaintenanceType, TimePeriod, ZabbixMaintenanceTag,
+};
+use crate::timestamp::serialize_timestamp;
+
+/// Hosts, groups, time periods and tags replace the existing ones when set.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/maintenance/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UpdateMaintenanceRequest {
+    #[serde(rename = "maintenanceid")]
+    pub maintenance_id: String,
+    pub name: Option<String>,
+    pub maintenance_type: Option<MaintenanceType>,
+    pub description: Option<String>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub active_since: Option<SystemTime>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub active_till: Option<SystemTime>,
+    pub groups: Option<Vec<ZabbixHostGroupId>>,
+    pub hosts: Option<Vec<ZabbixHostId>>,
+    #[serde(rename = "timeperiods")]
+    pub time_periods: Option<Vec<TimePeriod>>,
+    pub tags: Option<Vec<ZabbixMaintenanceTag>>,
+    #[serde(rename = "tags_evaltype")]
+    pub tags_eval_type: Option<MaintenanceTagsEvalType>,
+}
+
+impl UpdateMaintenanceRequest {
+    pub fn builder(maintenance_id: impl ToString) -> UpdateMaintenanceRequestBuilder {
+        UpdateMaintenanceRequestBuilder {
+            inner: UpdateMaintenanceRequest {
+                maintenance_id: maintenance_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateMaintenanceRequestBuilder {
+    inner: UpdateMaintenanceRequest,
+}
+
+impl UpdateMaintenanceRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn maintenance_type(mut self, maintenance_type: MaintenanceType) -> Self {
+        self.inner.maintenance_type = Some(maintenance_type);
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn active_since(mut self, active_since: SystemTime) -> Self {
+        self.inner.active_since = Some(active_since);
+        self
+    }
+
+    pub fn active_till(mut self, active_till: SystemTime) -> Self {
+        self.inner.active_till = Some(active_till);
+        self
+    }
+
+    pub fn hosts(mut self, host_ids: &[String]) -> Self {
+        self.inner.hosts = Some(
+            host_ids
+                .iter()
+                .map(|host_id| ZabbixHostId {
+                    host_id: host_id.to_string(),
+                })
+                .collect(),
+        );
+        self
+    }
+
+    pub fn groups(mut self, group_ids: &[String]) -> Self {
+        self.inner.groups = Some(
+            group_ids
+                .iter()
+                .map(|group_id| ZabbixHostGroupId {
+                    group_id: group_id.to_string(),
+                })
+                .collect(),
+        );
+        self
+    }
+
+    pub fn time_periods(mut self, time_periods: Vec<TimePeriod>) -> Self {
+        self.inner.time_periods = Some(time_periods);
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<ZabbixMaintenanceTag>) -> Self {
+        self.inner.tags = Some(tags);
+        self
+    }
+
+    pub fn tags_eval_type(mut self, tags_eval_type: MaintenanceTagsEvalType) -> Self {
+        self.inner.tags_eval_type = Some(tags_eval_type);
+        self
+    }
+
+    pub fn build(self) -> UpdateMaintenanceRequest {
+        self.inner
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateMaintenanceResponse {
+    #[serde(rename = "maintenanceids")]
+    pub maintenance_ids: Vec<String>,
+}
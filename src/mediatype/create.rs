@@ -0,0 +1,298 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::error::ZabbixApiError;
+use crate::mediatype::model::{
+    MediaTypeDetails, MediaTypeMessageTemplate, MediaTypeStatus, SmtpAuthentication,
+};
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/mediatype/create
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateMediaTypeRequest {
+    pub name: String,
+    #[serde(flatten)]
+    pub details: MediaTypeDetails,
+    pub status: Option<MediaTypeStatus>,
+    pub description: Option<String>,
+    #[serde(rename = "maxsessions")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub max_sessions: Option<u32>,
+    #[serde(rename = "maxattempts")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub max_attempts: Option<u32>,
+    pub attempt_interval: Option<String>,
+    pub message_templates: Option<Vec<MediaTypeMessageTemplate>>,
+}
+
+impl CreateMediaTypeRequest {
+    pub fn builder(
+        name: impl ToString,
+        details: MediaTypeDetails,
+    ) -> CreateMediaTypeRequestBuilder {
+        CreateMediaTypeRequestBuilder {
+            inner: CreateMediaTypeRequest {
+                name: name.to_string(),
+                details,
+                status: None,
+                description: None,
+                max_sessions: None,
+                max_attempts: None,
+                attempt_interval: None,
+                message_templates: None,
+            },
+        }
+    }
+}
+
+pub struct CreateMediaTypeRequestBuilder {
+    inner: CreateMediaTypeRequest,
+}
+
+impl CreateMediaTypeRequestBuilder {
+    pub fn disabled(mut self) -> Self {
+        self.inner.status = Some(MediaTypeStatus::Disabled);
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    /// Number of parallel alerts, `0` is unlimited.
+    pub fn max_sessions(mut self, max_sessions: u32) -> Self {
+        self.inner.max_sessions = Some(max_sessions);
+        self
+    }
+
+    /// Attempts to send an alert and the interval between them, e.g. `10s`.
+    pub fn attempts(mut self, max_attempts: u32, interval: impl ToString) -> Self {
+        self.inner.max_attempts = Some(max_attempts);
+        self.inner.attempt_interval = Some(interval.to_string());
+        self
+    }
+
+    pub fn message_template(mut self, message_template: MediaTypeMessageTemplate) -> Self {
+        self.inner
+            .message_templates
+            .get_or_insert_with(Vec::new)
+            .push(message_template);
+        self
+    }
+
+    /// Checks the settings required by the media type.
+    pub fn build(self) -> Result<CreateMediaTypeRequest, ZabbixApiError> {
+        let media_type = self.inner;
+
+        validate_details(&media_type.name, &media_type.details)?;
+
+        Ok(media_type)
+    }
+}
+
+pub(crate) fn validate_details(
+    name: &str,
+    details: &MediaTypeDetails,
+) -> Result<(), ZabbixApiError> {
+    let invalid = |message: &str| {
+        Err(ZabbixApiError::InvalidRequestError(format!(
+            "media type '{name}': {message}"
+        )))
+    };
+
+    match details {
+        MediaTypeDetails::Email(email) => {
+            if email.smtp_server.is_empty() || email.smtp_email.is_empty() {
+                return invalid("smtp server and sender email are required");
+            }
+
+            if email.smtp_authentication == Some(SmtpAuthentication::Password)
+                && email.username.as_deref().unwrap_or_default().is_empty()
+            {
+                return invalid("username is required for password authentication");
+            }
+        }
+        MediaTypeDetails::Script(script) => {
+            if script.exec_path.is_empty() {
+                return invalid("script name is required");
+            }
+        }
+        MediaTypeDetails::Sms(sms) => {
+            if sms.gsm_modem.is_empty() {
+                return invalid("gsm modem is required");
+            }
+        }
+        MediaTypeDetails::Webhook(webhook) => {
+            if webhook.script.trim().is_empty() {
+                return invalid("webhook script is required");
+            }
+
+            if webhook.show_event_menu == Some(true)
+                && (webhook.event_menu_url.is_none() || webhook.event_menu_name.is_none())
+            {
+                return invalid("event menu requires url and name");
+            }
+
+            let mut names = webhook.parameters.iter().flatten().map(|p| &p.name);
+
+            if names.clone().any(String::is_empty) {
+                return invalid("webhook parameter name is required");
+            }
+
+            let mut unique = std::collections::HashSet::new();
+
+            if !names.all(|name| unique.insert(name)) {
+                return invalid("webhook parameter names must be unique");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateMediaTypeResponse {
+    #[serde(rename = "mediatypeids")]
+    pub media_type_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateMediaTypeRequest;
+    use crate::event::model::EventSource;
+    use crate::mediatype::model::{
+        EmailSettings, MediaTypeDetails, MediaTypeMessageTemplate, MessageTemplateOperationMode,
+        ScriptSettings, SmtpSecurity, WebhookSettings,
+    };
+
+    #[test]
+    fn serialize_webhook_test() {
+        let request = CreateMediaTypeRequest::builder(
+            "Ops webhook",
+            MediaTypeDetails::Webhook(
+                WebhookSettings::new("return 'OK';")
+                    .parameter("to", "{ALERT.SENDTO}")
+                    .parameter("message", "{ALERT.MESSAGE}")
+                    .timeout("15s")
+                    .process_tags(true),
+            ),
+        )
+        .attempts(3, "10s")
+        .message_template(MediaTypeMessageTemplate::new(
+            EventSource::Trigger,
+            MessageTemplateOperationMode::Operations,
+            "Problem: {EVENT.NAME}",
+            "{EVENT.NAME} on {HOST.NAME}",
+        ))
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Ops webhook",
+                "type": "4",
+                "script": "return 'OK';",
+                "timeout": "15s",
+                "process_tags": 1,
+                "parameters": [
+                    {"name": "to", "value": "{ALERT.SENDTO}"},
+                    {"name": "message", "value": "{ALERT.MESSAGE}"}
+                ],
+                "maxattempts": "3",
+                "attempt_interval": "10s",
+                "message_templates": [{
+                    "eventsource": "0",
+                    "recovery": "0",
+                    "subject": "Problem: {EVENT.NAME}",
+                    "message": "{EVENT.NAME} on {HOST.NAME}"
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_email_test() {
+        let request = CreateMediaTypeRequest::builder(
+            "Email",
+            MediaTypeDetails::Email(
+                EmailSettings::new("mail.example.com", "example.com", "zabbix@example.com")
+                    .port(587)
+                    .security(SmtpSecurity::StartTls)
+                    .password_authentication("zabbix", "secret"),
+            ),
+        )
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["type"], "0");
+        assert_eq!(json["smtp_port"], "587");
+        assert_eq!(json["smtp_security"], "1");
+        assert_eq!(json["smtp_authentication"], "1");
+        assert_eq!(json["passwd"], "secret");
+    }
+
+    #[test]
+    fn serialize_script_parameters_test() {
+        let request = CreateMediaTypeRequest::builder(
+            "Script",
+            MediaTypeDetails::Script(
+                ScriptSettings::new("notify.sh")
+                    .parameter("{ALERT.SENDTO}")
+                    .parameter("{ALERT.SUBJECT}"),
+            ),
+        )
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        #[cfg(feature = "v7")]
+        assert_eq!(
+            json["parameters"],
+            serde_json::json!([
+                {"sortorder": "0", "value": "{ALERT.SENDTO}"},
+                {"sortorder": "1", "value": "{ALERT.SUBJECT}"}
+            ])
+        );
+        #[cfg(feature = "v6")]
+        assert_eq!(json["exec_params"], "{ALERT.SENDTO}\n{ALERT.SUBJECT}\n");
+    }
+
+    #[test]
+    fn invalid_requests_are_rejected() {
+        assert!(CreateMediaTypeRequest::builder(
+            "Empty script",
+            MediaTypeDetails::Webhook(WebhookSettings::new(" "))
+        )
+        .build()
+        .is_err());
+
+        assert!(CreateMediaTypeRequest::builder(
+            "Duplicate parameters",
+            MediaTypeDetails::Webhook(
+                WebhookSettings::new("return 'OK';")
+                    .parameter("to", "a")
+                    .parameter("to", "b")
+            )
+        )
+        .build()
+        .is_err());
+
+        assert!(CreateMediaTypeRequest::builder(
+            "No user",
+            MediaTypeDetails::Email(
+                EmailSettings::new("mail.example.com", "example.com", "zabbix@example.com")
+                    .password_authentication("", "secret")
+            )
+        )
+        .build()
+        .is_err());
+    }
+}
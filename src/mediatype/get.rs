@@ -0,0 +1,23 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/mediatype/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetMediaTypesRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "mediatypeids")]
+    pub media_type_ids: Option<Vec<String>>,
+    #[serde(rename = "mediaids")]
+    pub media_ids: Option<Vec<String>>,
+    #[serde(rename = "userids")]
+    pub user_ids: Option<Vec<String>>,
+    #[serde(rename = "selectMessageTemplates")]
+    pub select_message_templates: Option<String>,
+    #[serde(rename = "selectUsers")]
+    pub select_users: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    pub limit: Option<u32>,
+}
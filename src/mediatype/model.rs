@@ -0,0 +1,388 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::event::model::EventSource;
+use crate::flag;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/mediatype/object
+#[serde_as]
+#[derive(Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixMediaType {
+    #[serde(rename = "mediatypeid")]
+    pub media_type_id: String,
+    pub name: String,
+    /// Settings of the media type, selected by `type`.
+    #[serde(flatten)]
+    pub details: MediaTypeDetails,
+    pub status: MediaTypeStatus,
+    pub description: Option<String>,
+    /// Number of parallel alerts, `0` is unlimited.
+    #[serde(rename = "maxsessions")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub max_sessions: Option<u32>,
+    #[serde(rename = "maxattempts")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub max_attempts: Option<u32>,
+    pub attempt_interval: Option<String>,
+    /// Filled when requested with `selectMessageTemplates`.
+    pub message_templates: Option<Vec<MediaTypeMessageTemplate>>,
+}
+
+/// Settings by media type, serialized with the `type` field.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum MediaTypeDetails {
+    #[serde(rename = "0")]
+    Email(EmailSettings),
+    #[serde(rename = "1")]
+    Script(ScriptSettings),
+    #[serde(rename = "2")]
+    Sms(SmsSettings),
+    #[serde(rename = "4")]
+    Webhook(WebhookSettings),
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug, Default)]
+pub enum MediaTypeStatus {
+    #[default]
+    #[serde(rename = "0")]
+    Enabled,
+    #[serde(rename = "1")]
+    Disabled,
+}
+
+/// SMTP settings of an email media type.
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct EmailSettings {
+    pub smtp_server: String,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub smtp_port: Option<u16>,
+    pub smtp_helo: String,
+    /// Sender address.
+    pub smtp_email: String,
+    pub smtp_security: Option<SmtpSecurity>,
+    #[serde(default, with = "flag")]
+    pub smtp_verify_peer: Option<bool>,
+    #[serde(default, with = "flag")]
+    pub smtp_verify_host: Option<bool>,
+    pub smtp_authentication: Option<SmtpAuthentication>,
+    pub username: Option<String>,
+    /// Not returned by Zabbix.
+    pub passwd: Option<String>,
+    #[cfg(feature = "v7")]
+    pub message_format: Option<MessageFormat>,
+    #[cfg(feature = "v6")]
+    pub content_type: Option<MessageFormat>,
+}
+
+impl EmailSettings {
+    pub fn new(
+        smtp_server: impl ToString,
+        smtp_helo: impl ToString,
+        smtp_email: impl ToString,
+    ) -> Self {
+        Self {
+            smtp_server: smtp_server.to_string(),
+            smtp_helo: smtp_helo.to_string(),
+            smtp_email: smtp_email.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn port(mut self, port: u16) -> Self {
+        self.smtp_port = Some(port);
+        self
+    }
+
+    pub fn security(mut self, security: SmtpSecurity) -> Self {
+        self.smtp_security = Some(security);
+        self
+    }
+
+    /// Verifies the SMTP server certificate and host name.
+    pub fn verify(mut self, verify_peer: bool, verify_host: bool) -> Self {
+        self.smtp_verify_peer = Some(verify_peer);
+        self.smtp_verify_host = Some(verify_host);
+        self
+    }
+
+    pub fn password_authentication(
+        mut self,
+        username: impl ToString,
+        password: impl ToString,
+    ) -> Self {
+        self.smtp_authentication = Some(SmtpAuthentication::Password);
+        self.username = Some(username.to_string());
+        self.passwd = Some(password.to_string());
+        self
+    }
+
+    pub fn message_format(mut self, message_format: MessageFormat) -> Self {
+        #[cfg(feature = "v7")]
+        {
+            self.message_format = Some(message_format);
+        }
+        #[cfg(feature = "v6")]
+        {
+            self.content_type = Some(message_format);
+        }
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum SmtpSecurity {
+    #[serde(rename = "0")]
+    None,
+    #[serde(rename = "1")]
+    StartTls,
+    #[serde(rename = "2")]
+    SslTls,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum SmtpAuthentication {
+    #[serde(rename = "0")]
+    None,
+    #[serde(rename = "1")]
+    Password,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum MessageFormat {
+    #[serde(rename = "0")]
+    PlainText,
+    #[serde(rename = "1")]
+    Html,
+}
+
+/// Alert script in the `AlertScriptsPath` directory of the server.
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct ScriptSettings {
+    pub exec_path: String,
+    /// Script parameters in the order they are passed.
+    #[cfg(feature = "v7")]
+    pub parameters: Option<Vec<ScriptParameter>>,
+    /// Script parameters, each followed by a new line.
+    #[cfg(feature = "v6")]
+    pub exec_params: Option<String>,
+}
+
+impl ScriptSettings {
+    pub fn new(exec_path: impl ToString) -> Self {
+        Self {
+            exec_path: exec_path.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Appends a parameter, macros such as `{ALERT.SENDTO}` are supported.
+    pub fn parameter(mut self, value: impl ToString) -> Self {
+        #[cfg(feature = "v7")]
+        {
+            let parameters = self.parameters.get_or_insert_with(Vec::new);
+            let sort_order = parameters.len() as u32;
+
+            parameters.push(ScriptParameter {
+                sort_order,
+                value: value.to_string(),
+            });
+        }
+        #[cfg(feature = "v6")]
+        {
+            let parameters = self.exec_params.get_or_insert_with(String::new);
+
+            parameters.push_str(&value.to_string());
+            parameters.push('\n');
+        }
+        self
+    }
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ScriptParameter {
+    #[serde(rename = "sortorder")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub sort_order: u32,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct SmsSettings {
+    /// Serial device of the GSM modem.
+    pub gsm_modem: String,
+}
+
+impl SmsSettings {
+    pub fn new(gsm_modem: impl ToString) -> Self {
+        Self {
+            gsm_modem: gsm_modem.to_string(),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct WebhookSettings {
+    /// JavaScript code of the webhook.
+    pub script: String,
+    pub timeout: Option<String>,
+    /// Process the returned JSON property `tags` as problem tags.
+    #[serde(default, with = "flag")]
+    pub process_tags: Option<bool>,
+    /// Add an entry with `event_menu_url` and `event_menu_name` to the event menu.
+    #[serde(default, with = "flag")]
+    pub show_event_menu: Option<bool>,
+    pub event_menu_url: Option<String>,
+    pub event_menu_name: Option<String>,
+    pub parameters: Option<Vec<WebhookParameter>>,
+}
+
+impl WebhookSettings {
+    pub fn new(script: impl ToString) -> Self {
+        Self {
+            script: script.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn parameter(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.parameters
+            .get_or_insert_with(Vec::new)
+            .push(WebhookParameter::new(name, value));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: impl ToString) -> Self {
+        self.timeout = Some(timeout.to_string());
+        self
+    }
+
+    pub fn process_tags(mut self, process_tags: bool) -> Self {
+        self.process_tags = Some(process_tags);
+        self
+    }
+
+    pub fn event_menu(mut self, url: impl ToString, name: impl ToString) -> Self {
+        self.show_event_menu = Some(true);
+        self.event_menu_url = Some(url.to_string());
+        self.event_menu_name = Some(name.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct WebhookParameter {
+    pub name: String,
+    pub value: String,
+}
+
+impl WebhookParameter {
+    pub fn new(name: impl ToString, value: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+/// Default message of the media type for an event source and operation mode.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/mediatype/object#message-template
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct MediaTypeMessageTemplate {
+    #[serde(rename = "eventsource")]
+    pub event_source: EventSource,
+    pub recovery: MessageTemplateOperationMode,
+    pub subject: String,
+    pub message: String,
+}
+
+impl MediaTypeMessageTemplate {
+    pub fn new(
+        event_source: EventSource,
+        recovery: MessageTemplateOperationMode,
+        subject: impl ToString,
+        message: impl ToString,
+    ) -> Self {
+        Self {
+            event_source,
+            recovery,
+            subject: subject.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum MessageTemplateOperationMode {
+    #[serde(rename = "0")]
+    Operations,
+    #[serde(rename = "1")]
+    RecoveryOperations,
+    #[serde(rename = "2")]
+    UpdateOperations,
+}
+
+#[cfg(test)]
+mod mediatype_tests {
+    use super::{
+        MediaTypeDetails, MediaTypeStatus, MessageTemplateOperationMode, SmtpSecurity,
+        ZabbixMediaType,
+    };
+
+    #[test]
+    fn deserialize_email_media_type() {
+        let input = r#"
+            {"mediatypeid":"1","type":"0","name":"Email","smtp_server":"mail.example.com","smtp_helo":"example.com","smtp_email":"zabbix@example.com","exec_path":"","gsm_modem":"","username":"","status":"0","smtp_port":"25","smtp_security":"1","smtp_verify_peer":"0","smtp_verify_host":"0","smtp_authentication":"0","maxsessions":"1","maxattempts":"3","attempt_interval":"10s","message_format":"0","content_type":"0","script":"","timeout":"30s","process_tags":"0","show_event_menu":"0","event_menu_url":"","event_menu_name":"","description":"","parameters":[],
+             "message_templates":[{"eventsource":"0","recovery":"0","subject":"Problem: {EVENT.NAME}","message":"Problem started at {EVENT.TIME}"}]}
+            "#;
+
+        let media_type: ZabbixMediaType = serde_json::from_str(input).unwrap();
+
+        assert_eq!(media_type.status, MediaTypeStatus::Enabled);
+        assert_eq!(media_type.max_attempts, Some(3));
+
+        match media_type.details {
+            MediaTypeDetails::Email(email) => {
+                assert_eq!(email.smtp_server, "mail.example.com");
+                assert_eq!(email.smtp_port, Some(25));
+                assert_eq!(email.smtp_security, Some(SmtpSecurity::StartTls));
+                assert_eq!(email.smtp_verify_peer, Some(false));
+            }
+            other => panic!("unexpected media type: {:?}", other),
+        }
+
+        let templates = media_type.message_templates.unwrap();
+        assert_eq!(
+            templates[0].recovery,
+            MessageTemplateOperationMode::Operations
+        );
+    }
+
+    #[test]
+    fn deserialize_webhook_media_type() {
+        let input = r#"
+            {"mediatypeid":"33","type":"4","name":"Slack","status":"1","smtp_server":"","smtp_port":"25","script":"return 'OK';","timeout":"10s","process_tags":"1","show_event_menu":"1","event_menu_url":"{EVENT.TAGS.__message_link}","event_menu_name":"Slack","description":"","maxsessions":"1","maxattempts":"1","attempt_interval":"10s",
+             "parameters":[{"name":"bot_token","value":"{ALERT.SENDTO}"},{"name":"channel","value":"ops-alerts"}]}
+            "#;
+
+        let media_type: ZabbixMediaType = serde_json::from_str(input).unwrap();
+
+        match media_type.details {
+            MediaTypeDetails::Webhook(webhook) => {
+                assert_eq!(webhook.timeout.as_deref(), Some("10s"));
+                assert_eq!(webhook.process_tags, Some(true));
+                assert_eq!(webhook.parameters.unwrap()[1].value, "ops-alerts");
+            }
+            other => panic!("unexpected media type: {:?}", other),
+        }
+    }
+}
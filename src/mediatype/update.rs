@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr};
+
+use crate::error::ZabbixApiError;
+use crate::mediatype::create::validate_details;
+use crate::mediatype::model::{MediaTypeDetails, MediaTypeMessageTemplate, MediaTypeStatus};
+
+/// Message templates replace the existing ones when set.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/mediatype/update
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UpdateMediaTypeRequest {
+    #[serde(rename = "mediatypeid")]
+    pub media_type_id: String,
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub details: Option<MediaTypeDetails>,
+    pub status: Option<MediaTypeStatus>,
+    pub description: Option<String>,
+    #[serde(rename = "maxsessions")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub max_sessions: Option<u32>,
+    #[serde(rename = "maxattempts")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub max_attempts: Option<u32>,
+    pub attempt_interval: Option<String>,
+    pub message_templates: Option<Vec<MediaTypeMessageTemplate>>,
+}
+
+impl UpdateMediaTypeRequest {
+    pub fn builder(media_type_id: impl ToString) -> UpdateMediaTypeRequestBuilder {
+        UpdateMediaTypeRequestBuilder {
+            inner: UpdateMediaTypeRequest {
+                media_type_id: media_type_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateMediaTypeRequestBuilder {
+    inner: UpdateMediaTypeRequest,
+}
+
+impl UpdateMediaTypeRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    /// Replaces the type and settings of the media type.
+    pub fn details(mut self, details: MediaTypeDetails) -> Self {
+        self.inner.details = Some(details);
+        self
+    }
+
+    pub fn status(mut self, status: MediaTypeStatus) -> Self {
+        self.inner.status = Some(status);
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn max_sessions(mut self, max_sessions: u32) -> Self {
+        self.inner.max_sessions = Some(max_sessions);
+        self
+    }
+
+    pub fn attempts(mut self, max_attempts: u32, interval: impl ToString) -> Self {
+        self.inner.max_attempts = Some(max_attempts);
+        self.inner.attempt_interval = Some(interval.to_string());
+        self
+    }
+
+    pub fn message_templates(mut self, message_templates: Vec<MediaTypeMessageTemplate>) -> Self {
+        self.inner.message_templates = Some(message_templates);
+        self
+    }
+
+    /// Checks the settings when they are replaced.
+    pub fn build(self) -> Result<UpdateMediaTypeRequest, ZabbixApiError> {
+        let media_type = self.inner;
+
+        if let Some(details) = &media_type.details {
+            let name = media_type
+                .name
+                .as_deref()
+                .unwrap_or(&media_type.media_type_id);
+
+            validate_details(name, details)?;
+        }
+
+        Ok(media_type)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateMediaTypeResponse {
+    #[serde(rename = "mediatypeids")]
+    pub media_type_ids: Vec<String>,
+}
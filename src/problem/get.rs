@@ -0,0 +1,55 @@
+use std::time::SystemTime;
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::event::model::{EventObject, EventSeverity, EventSource, EventTagFilter, TagEvalType};
+use crate::timestamp::serialize_timestamp;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/problem/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetProblemsRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "eventids")]
+    pub event_ids: Option<Vec<String>>,
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "objectids")]
+    pub object_ids: Option<Vec<String>>,
+    pub source: Option<EventSource>,
+    pub object: Option<EventObject>,
+    pub acknowledged: Option<bool>,
+    pub suppressed: Option<bool>,
+    /// Return only symptom (`true`) or only cause (`false`) problems.
+    #[cfg(feature = "v7")]
+    pub symptom: Option<bool>,
+    pub severities: Option<Vec<EventSeverity>>,
+    #[serde(rename = "evaltype")]
+    pub eval_type: Option<TagEvalType>,
+    pub tags: Option<Vec<EventTagFilter>>,
+    /// Also return problems that were resolved recently.
+    pub recent: Option<bool>,
+    #[serde(rename = "eventid_from")]
+    pub event_id_from: Option<String>,
+    #[serde(rename = "eventid_till")]
+    pub event_id_till: Option<String>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_from: Option<SystemTime>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_till: Option<SystemTime>,
+    #[serde(rename = "selectAcknowledges")]
+    pub select_acknowledges: Option<String>,
+    #[serde(rename = "selectTags")]
+    pub select_tags: Option<String>,
+    #[serde(rename = "selectSuppressionData")]
+    pub select_suppression_data: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<Vec<String>>,
+    #[serde(rename = "sortorder")]
+    pub sort_order: Option<String>,
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,105 @@
+use std::time::SystemTime;
+
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::de::deserialize_object_id;
+use crate::event::model::{
+    EventObject, EventSeverity, EventSource, ZabbixAcknowledge, ZabbixEventTag,
+    ZabbixSuppressionData,
+};
+use crate::flag;
+use crate::timestamp::{deserialize_optional_timestamp, deserialize_timestamp};
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/problem/object
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixProblem {
+    #[serde(rename = "eventid")]
+    pub event_id: String,
+    pub source: EventSource,
+    pub object: EventObject,
+    /// ID of the related object, e.g. the trigger ID.
+    #[serde(rename = "objectid")]
+    pub object_id: String,
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub clock: SystemTime,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub ns: Option<u32>,
+    /// Recovery event, `None` while the problem is not resolved.
+    #[serde(
+        rename = "r_eventid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub recovery_event_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_timestamp")]
+    pub r_clock: Option<SystemTime>,
+    /// Cause event of a symptom problem.
+    #[cfg(feature = "v7")]
+    #[serde(
+        rename = "cause_eventid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub cause_event_id: Option<String>,
+    #[serde(
+        rename = "correlationid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub correlation_id: Option<String>,
+    /// User who closed the problem manually.
+    #[serde(rename = "userid", default, deserialize_with = "deserialize_object_id")]
+    pub user_id: Option<String>,
+    pub name: String,
+    #[serde(default, deserialize_with = "flag::deserialize_required")]
+    pub acknowledged: bool,
+    pub severity: EventSeverity,
+    #[serde(default, deserialize_with = "flag::deserialize_required")]
+    pub suppressed: bool,
+    /// Operational data with expanded macros.
+    pub opdata: Option<String>,
+    /// Returned with `selectAcknowledges`.
+    pub acknowledges: Option<Vec<ZabbixAcknowledge>>,
+    /// Returned with `selectTags`.
+    pub tags: Option<Vec<ZabbixEventTag>>,
+    /// Returned with `selectSuppressionData`.
+    pub suppression_data: Option<Vec<ZabbixSuppressionData>>,
+}
+
+#[cfg(test)]
+mod problem_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::ZabbixProblem;
+    use crate::event::model::{EventObject, EventSeverity, EventSource};
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"eventid":"1245463","source":"0","object":"0","objectid":"15112","clock":"1472457242","ns":"209442442","r_eventid":"1245468","r_clock":"1472457285","r_ns":"125644870","correlationid":"0","userid":"1","name":"Zabbix agent on localhost is unreachable for 5 minutes","acknowledged":"1","severity":"3","cause_eventid":"0","opdata":"","suppressed":"0",
+             "acknowledges":[{"acknowledgeid":"14443","userid":"1","eventid":"1245463","clock":"1472457281","message":"problem solved","action":"6","old_severity":"0","new_severity":"0","suppress_until":"0","taskid":"0"}],
+             "tags":[{"tag":"test tag","value":"test value"}]}
+            "#;
+
+        let problem: ZabbixProblem = serde_json::from_str(input).unwrap();
+
+        assert_eq!(problem.source, EventSource::Trigger);
+        assert_eq!(problem.object, EventObject::Trigger);
+        assert_eq!(problem.severity, EventSeverity::Average);
+        assert_eq!(problem.clock, UNIX_EPOCH + Duration::from_secs(1472457242));
+        assert_eq!(
+            problem.r_clock,
+            Some(UNIX_EPOCH + Duration::from_secs(1472457285))
+        );
+        assert_eq!(problem.recovery_event_id.as_deref(), Some("1245468"));
+        assert_eq!(problem.correlation_id, None);
+        assert_eq!(problem.user_id.as_deref(), Some("1"));
+        assert!(problem.acknowledged);
+        assert!(!problem.suppressed);
+        assert_eq!(problem.acknowledges.unwrap()[0].message, "problem solved");
+        assert_eq!(problem.tags.unwrap()[0].tag, "test tag");
+    }
+}
@@ -0,0 +1,260 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::error::ZabbixApiError;
+use crate::host::create::TlsConfig;
+use crate::host::model::ZabbixHostId;
+#[cfg(feature = "v6")]
+use crate::proxy::model::ProxyInterface;
+use crate::proxy::model::ProxyMode;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxy/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug)]
+pub struct CreateProxyRequest {
+    #[cfg_attr(feature = "v6", serde(rename = "host"))]
+    pub name: String,
+    #[cfg_attr(feature = "v7", serde(rename = "operating_mode"))]
+    #[cfg_attr(feature = "v6", serde(rename = "status"))]
+    pub mode: ProxyMode,
+    pub description: Option<String>,
+    #[cfg_attr(feature = "v6", serde(rename = "proxy_address"))]
+    pub allowed_addresses: Option<String>,
+    #[cfg(feature = "v7")]
+    pub address: Option<String>,
+    #[cfg(feature = "v7")]
+    pub port: Option<String>,
+    #[cfg(feature = "v6")]
+    pub interface: Option<ProxyInterface>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "proxy_groupid")]
+    pub proxy_group_id: Option<String>,
+    #[cfg(feature = "v7")]
+    pub local_address: Option<String>,
+    #[cfg(feature = "v7")]
+    pub local_port: Option<String>,
+    #[serde(flatten)]
+    pub tls_config: Option<TlsConfig>,
+    /// Hosts moved to the proxy.
+    pub hosts: Option<Vec<ZabbixHostId>>,
+}
+
+impl CreateProxyRequest {
+    /// Proxy connecting to the server.
+    pub fn active(name: impl ToString) -> CreateProxyRequestBuilder {
+        CreateProxyRequestBuilder {
+            inner: CreateProxyRequest {
+                name: name.to_string(),
+                mode: ProxyMode::Active,
+                description: None,
+                allowed_addresses: None,
+                #[cfg(feature = "v7")]
+                address: None,
+                #[cfg(feature = "v7")]
+                port: None,
+                #[cfg(feature = "v6")]
+                interface: None,
+                #[cfg(feature = "v7")]
+                proxy_group_id: None,
+                #[cfg(feature = "v7")]
+                local_address: None,
+                #[cfg(feature = "v7")]
+                local_port: None,
+                tls_config: None,
+                hosts: None,
+            },
+        }
+    }
+
+    /// Proxy the server connects to at `address` and `port`.
+    pub fn passive(
+        name: impl ToString,
+        address: impl ToString,
+        port: impl ToString,
+    ) -> CreateProxyRequestBuilder {
+        let mut builder = CreateProxyRequest::active(name);
+
+        builder.inner.mode = ProxyMode::Passive;
+
+        #[cfg(feature = "v7")]
+        {
+            builder.inner.address = Some(address.to_string());
+            builder.inner.port = Some(port.to_string());
+        }
+        #[cfg(feature = "v6")]
+        {
+            builder.inner.interface = Some(ProxyInterface::new(address, port));
+        }
+
+        builder
+    }
+}
+
+pub struct CreateProxyRequestBuilder {
+    inner: CreateProxyRequest,
+}
+
+impl CreateProxyRequestBuilder {
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    /// Comma separated addresses an active proxy may connect from.
+    pub fn allowed_addresses(mut self, allowed_addresses: impl ToString) -> Self {
+        self.inner.allowed_addresses = Some(allowed_addresses.to_string());
+        self
+    }
+
+    /// Adds the proxy to a proxy group, reachable by other group members at
+    /// `local_address` and `local_port`.
+    #[cfg(feature = "v7")]
+    pub fn proxy_group(
+        mut self,
+        proxy_group_id: impl ToString,
+        local_address: impl ToString,
+        local_port: impl ToString,
+    ) -> Self {
+        self.inner.proxy_group_id = Some(proxy_group_id.to_string());
+        self.inner.local_address = Some(local_address.to_string());
+        self.inner.local_port = Some(local_port.to_string());
+        self
+    }
+
+    pub fn tls_config(mut self, tls_config: TlsConfig) -> Self {
+        self.inner.tls_config = Some(tls_config);
+        self
+    }
+
+    pub fn host(mut self, host_id: impl ToString) -> Self {
+        self.inner
+            .hosts
+            .get_or_insert_with(Vec::new)
+            .push(ZabbixHostId {
+                host_id: host_id.to_string(),
+            });
+        self
+    }
+
+    pub fn build(self) -> Result<CreateProxyRequest, ZabbixApiError> {
+        let proxy = self.inner;
+
+        let invalid = |message: &str| {
+            Err(ZabbixApiError::InvalidRequestError(format!(
+                "proxy '{}': {message}",
+                proxy.name
+            )))
+        };
+
+        if proxy.name.trim().is_empty() {
+            return invalid("name is required");
+        }
+
+        if proxy.mode == ProxyMode::Passive && proxy.allowed_addresses.is_some() {
+            return invalid("allowed addresses are supported only by active proxies");
+        }
+
+        #[cfg(feature = "v7")]
+        if proxy.mode == ProxyMode::Passive
+            && proxy.address.as_deref().unwrap_or_default().is_empty()
+        {
+            return invalid("passive proxy address is required");
+        }
+
+        #[cfg(feature = "v6")]
+        if proxy.mode == ProxyMode::Passive
+            && proxy
+                .interface
+                .as_ref()
+                .is_none_or(|interface| interface.address().is_empty())
+        {
+            return invalid("passive proxy address is required");
+        }
+
+        Ok(proxy)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateProxyResponse {
+    #[serde(rename = "proxyids")]
+    pub proxy_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateProxyRequest;
+
+    #[test]
+    fn serialize_active_test() {
+        let request = CreateProxyRequest::active("Active proxy")
+            .allowed_addresses("10.0.0.5")
+            .host("10084")
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        #[cfg(feature = "v7")]
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Active proxy",
+                "operating_mode": "0",
+                "allowed_addresses": "10.0.0.5",
+                "hosts": [{"hostid": "10084"}]
+            })
+        );
+        #[cfg(feature = "v6")]
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "host": "Active proxy",
+                "status": "5",
+                "proxy_address": "10.0.0.5",
+                "hosts": [{"hostid": "10084"}]
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_passive_test() {
+        let request = CreateProxyRequest::passive("Passive proxy", "proxy.example.com", "10051")
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        #[cfg(feature = "v7")]
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Passive proxy",
+                "operating_mode": "1",
+                "address": "proxy.example.com",
+                "port": "10051"
+            })
+        );
+        #[cfg(feature = "v6")]
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "host": "Passive proxy",
+                "status": "6",
+                "interface": {"ip": "", "dns": "proxy.example.com", "useip": "0", "port": "10051"}
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_requests_are_rejected() {
+        assert!(CreateProxyRequest::passive("No address", "", "10051")
+            .build()
+            .is_err());
+
+        assert!(CreateProxyRequest::passive("Passive", "10.0.0.5", "10051")
+            .allowed_addresses("10.0.0.6")
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxy/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetProxiesRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "proxyids")]
+    pub proxy_ids: Option<Vec<String>>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "proxy_groupids")]
+    pub proxy_group_ids: Option<Vec<String>>,
+    #[serde(rename = "selectHosts")]
+    pub select_hosts: Option<String>,
+    /// Interface of passive proxies.
+    #[cfg(feature = "v6")]
+    #[serde(rename = "selectInterface")]
+    pub select_interface: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    pub limit: Option<u32>,
+}
@@ -0,0 +1,225 @@
+#[cfg(feature = "v6")]
+use std::net::IpAddr;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::timestamp::deserialize_optional_timestamp;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxy/object
+#[serde_as]
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixProxy {
+    #[serde(rename = "proxyid")]
+    pub proxy_id: String,
+    #[cfg_attr(feature = "v6", serde(rename = "host"))]
+    pub name: String,
+    #[cfg_attr(feature = "v7", serde(rename = "operating_mode"))]
+    #[cfg_attr(feature = "v6", serde(rename = "status"))]
+    pub mode: ProxyMode,
+    pub description: Option<String>,
+    /// Addresses allowed to connect to an active proxy.
+    #[cfg_attr(feature = "v6", serde(rename = "proxy_address"))]
+    pub allowed_addresses: Option<String>,
+    /// Address of a passive proxy.
+    #[cfg(feature = "v7")]
+    pub address: Option<String>,
+    #[cfg(feature = "v7")]
+    pub port: Option<String>,
+    /// Interface of a passive proxy, returned with `selectInterface`.
+    #[cfg(feature = "v6")]
+    #[serde(default, deserialize_with = "deserialize_interface")]
+    pub interface: Option<ProxyInterface>,
+    /// Proxy group of the proxy, `None` when the proxy is not in a group.
+    #[cfg(feature = "v7")]
+    #[serde(
+        rename = "proxy_groupid",
+        default,
+        deserialize_with = "crate::de::deserialize_object_id"
+    )]
+    pub proxy_group_id: Option<String>,
+    /// Address the proxy group uses to reach the proxy.
+    #[cfg(feature = "v7")]
+    pub local_address: Option<String>,
+    #[cfg(feature = "v7")]
+    pub local_port: Option<String>,
+    #[cfg(feature = "v7")]
+    pub state: Option<ProxyState>,
+    #[serde(
+        rename = "lastaccess",
+        default,
+        deserialize_with = "deserialize_optional_timestamp"
+    )]
+    pub last_access: Option<SystemTime>,
+    /// Version as a number, e.g. `70002` for 7.0.2.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub version: Option<u32>,
+    pub compatibility: Option<ProxyCompatibility>,
+    /// Returned with `selectHosts`.
+    pub hosts: Option<Vec<ZabbixProxyHost>>,
+}
+
+/// Active proxies connect to the server, the server connects to passive ones.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ProxyMode {
+    #[cfg_attr(feature = "v7", serde(rename = "0"))]
+    #[cfg_attr(feature = "v6", serde(rename = "5"))]
+    Active,
+    #[cfg_attr(feature = "v7", serde(rename = "1"))]
+    #[cfg_attr(feature = "v6", serde(rename = "6"))]
+    Passive,
+}
+
+#[cfg(feature = "v7")]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ProxyState {
+    #[serde(rename = "0")]
+    Unknown,
+    #[serde(rename = "1")]
+    Offline,
+    #[serde(rename = "2")]
+    Online,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ProxyCompatibility {
+    #[serde(rename = "0")]
+    Undefined,
+    #[serde(rename = "1")]
+    Current,
+    /// Outdated, only data collection still works.
+    #[serde(rename = "2")]
+    Outdated,
+    #[serde(rename = "3")]
+    Unsupported,
+}
+
+/// Interface of a passive proxy in 6.0.
+#[cfg(feature = "v6")]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ProxyInterface {
+    pub ip: String,
+    pub dns: String,
+    #[serde(rename = "useip")]
+    pub use_ip: String,
+    pub port: String,
+}
+
+#[cfg(feature = "v6")]
+impl ProxyInterface {
+    /// Connects by IP when `address` is an IP address, by DNS name otherwise.
+    pub fn new(address: impl ToString, port: impl ToString) -> Self {
+        let address = address.to_string();
+
+        if address.parse::<IpAddr>().is_ok() {
+            Self {
+                ip: address,
+                dns: String::new(),
+                use_ip: "1".to_string(),
+                port: port.to_string(),
+            }
+        } else {
+            Self {
+                ip: String::new(),
+                dns: address,
+                use_ip: "0".to_string(),
+                port: port.to_string(),
+            }
+        }
+    }
+
+    pub fn address(&self) -> &str {
+        if self.use_ip == "1" {
+            &self.ip
+        } else {
+            &self.dns
+        }
+    }
+}
+
+/// Active proxies are returned with an empty list instead of an interface.
+#[cfg(feature = "v6")]
+fn deserialize_interface<'de, D>(deserializer: D) -> Result<Option<ProxyInterface>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawInterface {
+        Interface(ProxyInterface),
+        Empty(Vec<ProxyInterface>),
+    }
+
+    match Option::<RawInterface>::deserialize(deserializer)? {
+        Some(RawInterface::Interface(interface)) => Ok(Some(interface)),
+        Some(RawInterface::Empty(interfaces)) => Ok(interfaces.into_iter().next()),
+        None => Ok(None),
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixProxyHost {
+    #[serde(rename = "hostid")]
+    pub host_id: String,
+    pub host: Option<String>,
+    pub name: Option<String>,
+}
+
+#[cfg(test)]
+mod proxy_tests {
+    use super::{ProxyCompatibility, ProxyMode, ZabbixProxy};
+
+    #[cfg(feature = "v7")]
+    #[test]
+    fn deserialize_test() {
+        use super::ProxyState;
+
+        let input = r#"
+            {"proxyid":"11","name":"Passive proxy","proxy_groupid":"2","local_address":"192.168.3.11","local_port":"10051","operating_mode":"1","description":"","allowed_addresses":"","address":"127.0.0.1","port":"10051","lastaccess":"1693391880","version":"70000","compatibility":"1","state":"2",
+             "hosts":[{"hostid":"10084","host":"Zabbix server"}]}
+            "#;
+
+        let proxy: ZabbixProxy = serde_json::from_str(input).unwrap();
+
+        assert_eq!(proxy.mode, ProxyMode::Passive);
+        assert_eq!(proxy.state, Some(ProxyState::Online));
+        assert_eq!(proxy.compatibility, Some(ProxyCompatibility::Current));
+        assert_eq!(proxy.version, Some(70000));
+        assert_eq!(proxy.address.as_deref(), Some("127.0.0.1"));
+        assert_eq!(proxy.proxy_group_id.as_deref(), Some("2"));
+        assert_eq!(proxy.hosts.unwrap()[0].host_id, "10084");
+
+        let input =
+            r#"{"proxyid":"12","name":"Active proxy","proxy_groupid":"0","operating_mode":"0"}"#;
+
+        let proxy: ZabbixProxy = serde_json::from_str(input).unwrap();
+
+        assert_eq!(proxy.proxy_group_id, None);
+    }
+
+    #[cfg(feature = "v6")]
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"proxyid":"10452","host":"Passive proxy","status":"6","description":"","lastaccess":"0","proxy_address":"","version":"60000","compatibility":"1",
+             "interface":{"interfaceid":"51","hostid":"10452","useip":"1","ip":"127.0.0.1","dns":"","port":"10051"}}
+            "#;
+
+        let proxy: ZabbixProxy = serde_json::from_str(input).unwrap();
+
+        assert_eq!(proxy.name, "Passive proxy");
+        assert_eq!(proxy.mode, ProxyMode::Passive);
+        assert_eq!(proxy.last_access, None);
+        assert_eq!(proxy.compatibility, Some(ProxyCompatibility::Current));
+        assert_eq!(proxy.interface.unwrap().address(), "127.0.0.1");
+
+        let input = r#"{"proxyid":"10451","host":"Active proxy","status":"5","interface":[]}"#;
+
+        let proxy: ZabbixProxy = serde_json::from_str(input).unwrap();
+
+        assert_eq!(proxy.mode, ProxyMode::Active);
+        assert_eq!(proxy.interface, None);
+    }
+}
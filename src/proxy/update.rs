@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::host::create::TlsConfig;
+use crate::host::model::ZabbixHostId;
+#[cfg(feature = "v6")]
+use crate::proxy::model::ProxyInterface;
+use crate::proxy::model::ProxyMode;
+
+/// Hosts replace the hosts of the proxy when set.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxy/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateProxyRequest {
+    #[serde(rename = "proxyid")]
+    pub proxy_id: String,
+    #[cfg_attr(feature = "v6", serde(rename = "host"))]
+    pub name: Option<String>,
+    #[cfg_attr(feature = "v7", serde(rename = "operating_mode"))]
+    #[cfg_attr(feature = "v6", serde(rename = "status"))]
+    pub mode: Option<ProxyMode>,
+    pub description: Option<String>,
+    #[cfg_attr(feature = "v6", serde(rename = "proxy_address"))]
+    pub allowed_addresses: Option<String>,
+    #[cfg(feature = "v7")]
+    pub address: Option<String>,
+    #[cfg(feature = "v7")]
+    pub port: Option<String>,
+    #[cfg(feature = "v6")]
+    pub interface: Option<ProxyInterface>,
+    /// `0` removes the proxy from its group.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "proxy_groupid")]
+    pub proxy_group_id: Option<String>,
+    #[cfg(feature = "v7")]
+    pub local_address: Option<String>,
+    #[cfg(feature = "v7")]
+    pub local_port: Option<String>,
+    #[serde(flatten)]
+    pub tls_config: Option<TlsConfig>,
+    pub hosts: Option<Vec<ZabbixHostId>>,
+}
+
+impl UpdateProxyRequest {
+    pub fn builder(proxy_id: impl ToString) -> UpdateProxyRequestBuilder {
+        UpdateProxyRequestBuilder {
+            inner: UpdateProxyRequest {
+                proxy_id: proxy_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateProxyRequestBuilder {
+    inner: UpdateProxyRequest,
+}
+
+impl UpdateProxyRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    /// Switches the proxy to active mode.
+    pub fn active(mut self, allowed_addresses: impl ToString) -> Self {
+        self.inner.mode = Some(ProxyMode::Active);
+        self.inner.allowed_addresses = Some(allowed_addresses.to_string());
+        self
+    }
+
+    /// Switches the proxy to passive mode.
+    pub fn passive(mut self, address: impl ToString, port: impl ToString) -> Self {
+        self.inner.mode = Some(ProxyMode::Passive);
+
+        #[cfg(feature = "v7")]
+        {
+            self.inner.address = Some(address.to_string());
+            self.inner.port = Some(port.to_string());
+        }
+        #[cfg(feature = "v6")]
+        {
+            self.inner.interface = Some(ProxyInterface::new(address, port));
+        }
+
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn proxy_group(
+        mut self,
+        proxy_group_id: impl ToString,
+        local_address: impl ToString,
+        local_port: impl ToString,
+    ) -> Self {
+        self.inner.proxy_group_id = Some(proxy_group_id.to_string());
+        self.inner.local_address = Some(local_address.to_string());
+        self.inner.local_port = Some(local_port.to_string());
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn without_proxy_group(mut self) -> Self {
+        self.inner.proxy_group_id = Some("0".to_string());
+        self
+    }
+
+    pub fn tls_config(mut self, tls_config: TlsConfig) -> Self {
+        self.inner.tls_config = Some(tls_config);
+        self
+    }
+
+    pub fn hosts(mut self, host_ids: &[String]) -> Self {
+        self.inner.hosts = Some(
+            host_ids
+                .iter()
+                .map(|host_id| ZabbixHostId {
+                    host_id: host_id.to_string(),
+                })
+                .collect(),
+        );
+        self
+    }
+
+    pub fn build(self) -> UpdateProxyRequest {
+        self.inner
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateProxyResponse {
+    #[serde(rename = "proxyids")]
+    pub proxy_ids: Vec<String>,
+}
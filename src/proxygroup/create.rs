@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::error::ZabbixApiError;
+
+/// Proxies join a group with `proxy.create` or `proxy.update`.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxygroup/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateProxyGroupRequest {
+    pub name: String,
+    pub failover_delay: Option<String>,
+    pub min_online: Option<String>,
+    pub description: Option<String>,
+}
+
+impl CreateProxyGroupRequest {
+    pub fn builder(name: impl ToString) -> CreateProxyGroupRequestBuilder {
+        CreateProxyGroupRequestBuilder {
+            inner: CreateProxyGroupRequest {
+                name: name.to_string(),
+                failover_delay: None,
+                min_online: None,
+                description: None,
+            },
+        }
+    }
+}
+
+pub struct CreateProxyGroupRequestBuilder {
+    inner: CreateProxyGroupRequest,
+}
+
+impl CreateProxyGroupRequestBuilder {
+    /// Time before hosts of an offline proxy move to other proxies, e.g. `1m`.
+    pub fn failover_delay(mut self, failover_delay: impl ToString) -> Self {
+        self.inner.failover_delay = Some(failover_delay.to_string());
+        self
+    }
+
+    /// Minimum number of online proxies, from 1 to 1000.
+    pub fn min_online(mut self, min_online: u32) -> Self {
+        self.inner.min_online = Some(min_online.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<CreateProxyGroupRequest, ZabbixApiError> {
+        let group = self.inner;
+
+        let invalid = |message: &str| {
+            Err(ZabbixApiError::InvalidRequestError(format!(
+                "proxy group '{}': {message}",
+                group.name
+            )))
+        };
+
+        if group.name.trim().is_empty() {
+            return invalid("name is required");
+        }
+
+        if let Some(min_online) = &group.min_online {
+            if !(1..=1000).contains(&min_online.parse::<u32>().unwrap_or_default()) {
+                return invalid("minimum number of online proxies must be from 1 to 1000");
+            }
+        }
+
+        Ok(group)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateProxyGroupResponse {
+    #[serde(rename = "proxy_groupids")]
+    pub proxy_group_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateProxyGroupRequest;
+
+    #[test]
+    fn serialize_test() {
+        let request = CreateProxyGroupRequest::builder("Riga")
+            .failover_delay("2m")
+            .min_online(2)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({"name": "Riga", "failover_delay": "2m", "min_online": "2"})
+        );
+
+        assert!(CreateProxyGroupRequest::builder("Riga")
+            .min_online(0)
+            .build()
+            .is_err());
+    }
+}
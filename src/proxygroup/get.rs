@@ -0,0 +1,19 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxygroup/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetProxyGroupsRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "proxy_groupids")]
+    pub proxy_group_ids: Option<Vec<String>>,
+    #[serde(rename = "proxyids")]
+    pub proxy_ids: Option<Vec<String>>,
+    #[serde(rename = "selectProxies")]
+    pub select_proxies: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    pub limit: Option<u32>,
+}
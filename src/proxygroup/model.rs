@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// Proxies sharing the monitoring of hosts, with failover between them.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxygroup/object
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixProxyGroup {
+    #[serde(rename = "proxy_groupid")]
+    pub proxy_group_id: String,
+    pub name: String,
+    /// Time before hosts of an offline proxy move to other proxies, e.g. `1m`.
+    pub failover_delay: Option<String>,
+    /// Minimum number of online proxies for the group to be online.
+    pub min_online: Option<String>,
+    pub description: Option<String>,
+    pub state: Option<ProxyGroupState>,
+    /// Returned with `selectProxies`.
+    pub proxies: Option<Vec<ZabbixProxyGroupMember>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ProxyGroupState {
+    #[serde(rename = "0")]
+    Unknown,
+    #[serde(rename = "1")]
+    Offline,
+    #[serde(rename = "2")]
+    Recovering,
+    #[serde(rename = "3")]
+    Online,
+    #[serde(rename = "4")]
+    Degrading,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixProxyGroupMember {
+    #[serde(rename = "proxyid")]
+    pub proxy_id: String,
+    pub name: Option<String>,
+}
+
+#[cfg(test)]
+mod proxygroup_tests {
+    use super::{ProxyGroupState, ZabbixProxyGroup};
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"proxy_groupid":"1","name":"Riga","failover_delay":"1m","min_online":"2","description":"","state":"3",
+             "proxies":[{"proxyid":"5","name":"Proxy 1"},{"proxyid":"6","name":"Proxy 2"}]}
+            "#;
+
+        let group: ZabbixProxyGroup = serde_json::from_str(input).unwrap();
+
+        assert_eq!(group.state, Some(ProxyGroupState::Online));
+        assert_eq!(group.min_online.as_deref(), Some("2"));
+        assert_eq!(group.proxies.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/proxygroup/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UpdateProxyGroupRequest {
+    #[serde(rename = "proxy_groupid")]
+    pub proxy_group_id: String,
+    pub name: Option<String>,
+    pub failover_delay: Option<String>,
+    pub min_online: Option<String>,
+    pub description: Option<String>,
+}
+
+impl UpdateProxyGroupRequest {
+    pub fn builder(proxy_group_id: impl ToString) -> UpdateProxyGroupRequestBuilder {
+        UpdateProxyGroupRequestBuilder {
+            inner: UpdateProxyGroupRequest {
+                proxy_group_id: proxy_group_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateProxyGroupRequestBuilder {
+    inner: UpdateProxyGroupRequest,
+}
+
+impl UpdateProxyGroupRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn failover_delay(mut self, failover_delay: impl ToString) -> Self {
+        self.inner.failover_delay = Some(failover_delay.to_string());
+        self
+    }
+
+    pub fn min_online(mut self, min_online: u32) -> Self {
+        self.inner.min_online = Some(min_online.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn build(self) -> UpdateProxyGroupRequest {
+        self.inner
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateProxyGroupResponse {
+    #[serde(rename = "proxy_groupids")]
+    pub proxy_group_ids: Vec<String>,
+}
@@ -0,0 +1,405 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::error::ZabbixApiError;
+#[cfg(feature = "v7")]
+use crate::flag;
+#[cfg(feature = "v7")]
+use crate::script::model::{ManualInputValidatorType, ScriptManualInput};
+use crate::script::model::{ScriptDetails, ScriptHostAccess, ScriptScope, SshAuthType};
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone)]
+pub struct CreateScriptRequest {
+    pub name: String,
+    #[serde(flatten)]
+    pub details: ScriptDetails,
+    pub scope: ScriptScope,
+    pub menu_path: Option<String>,
+    #[serde(rename = "usrgrpid")]
+    pub user_group_id: Option<String>,
+    #[serde(rename = "groupid")]
+    pub group_id: Option<String>,
+    pub host_access: Option<ScriptHostAccess>,
+    pub confirmation: Option<String>,
+    pub description: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput", with = "flag")]
+    pub manual_input: Option<bool>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_prompt")]
+    pub manual_input_prompt: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_validator")]
+    pub manual_input_validator: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_validator_type")]
+    pub manual_input_validator_type: Option<ManualInputValidatorType>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_default_value")]
+    pub manual_input_default_value: Option<String>,
+}
+
+impl CreateScriptRequest {
+    pub fn builder(
+        name: impl ToString,
+        details: ScriptDetails,
+        scope: ScriptScope,
+    ) -> CreateScriptRequestBuilder {
+        CreateScriptRequestBuilder {
+            inner: CreateScriptRequest {
+                name: name.to_string(),
+                details,
+                scope,
+                menu_path: None,
+                user_group_id: None,
+                group_id: None,
+                host_access: None,
+                confirmation: None,
+                description: None,
+                #[cfg(feature = "v7")]
+                manual_input: None,
+                #[cfg(feature = "v7")]
+                manual_input_prompt: None,
+                #[cfg(feature = "v7")]
+                manual_input_validator: None,
+                #[cfg(feature = "v7")]
+                manual_input_validator_type: None,
+                #[cfg(feature = "v7")]
+                manual_input_default_value: None,
+            },
+        }
+    }
+}
+
+pub struct CreateScriptRequestBuilder {
+    inner: CreateScriptRequest,
+}
+
+impl CreateScriptRequestBuilder {
+    /// Folders of the script in the host and event menus, e.g. `Diagnostics/Network`.
+    pub fn menu_path(mut self, menu_path: impl ToString) -> Self {
+        self.inner.menu_path = Some(menu_path.to_string());
+        self
+    }
+
+    /// Only members of the user group may run the script.
+    pub fn user_group(mut self, user_group_id: impl ToString) -> Self {
+        self.inner.user_group_id = Some(user_group_id.to_string());
+        self
+    }
+
+    /// The script may run only on hosts of the group.
+    pub fn host_group(mut self, group_id: impl ToString) -> Self {
+        self.inner.group_id = Some(group_id.to_string());
+        self
+    }
+
+    pub fn host_access(mut self, host_access: ScriptHostAccess) -> Self {
+        self.inner.host_access = Some(host_access);
+        self
+    }
+
+    pub fn confirmation(mut self, confirmation: impl ToString) -> Self {
+        self.inner.confirmation = Some(confirmation.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn manual_input(mut self, manual_input: ScriptManualInput) -> Self {
+        self.inner.manual_input = Some(true);
+        self.inner.manual_input_prompt = Some(manual_input.prompt);
+        self.inner.manual_input_validator = Some(manual_input.validator);
+        self.inner.manual_input_validator_type = Some(manual_input.validator_type);
+        self.inner.manual_input_default_value = manual_input.default_value;
+        self
+    }
+
+    /// Checks the script settings and the options allowed by the scope.
+    pub fn build(self) -> Result<CreateScriptRequest, ZabbixApiError> {
+        let script = self.inner;
+
+        validate_details(&script.name, &script.details)?;
+
+        let invalid = |message: &str| {
+            Err(ZabbixApiError::InvalidRequestError(format!(
+                "script '{}': {message}",
+                script.name
+            )))
+        };
+
+        if script.scope == ScriptScope::ActionOperation {
+            let has_manual_options = script.menu_path.is_some()
+                || script.user_group_id.is_some()
+                || script.host_access.is_some()
+                || script.confirmation.is_some();
+
+            if has_manual_options {
+                return invalid(
+                    "menu path, user group, host access and confirmation require a manual scope",
+                );
+            }
+
+            #[cfg(feature = "v7")]
+            if script.manual_input == Some(true) {
+                return invalid("manual input requires a manual scope");
+            }
+
+            #[cfg(feature = "v7")]
+            if matches!(script.details, ScriptDetails::Url(_)) {
+                return invalid("url scripts require a manual scope");
+            }
+        }
+
+        #[cfg(feature = "v7")]
+        if script.manual_input == Some(true) {
+            let validator = script.manual_input_validator.as_deref().unwrap_or_default();
+
+            if script
+                .manual_input_prompt
+                .as_deref()
+                .unwrap_or_default()
+                .is_empty()
+            {
+                return invalid("manual input prompt is required");
+            }
+
+            if validator.is_empty() {
+                return invalid("manual input validator is required");
+            }
+
+            if script.manual_input_validator_type == Some(ManualInputValidatorType::Dropdown) {
+                if let Some(default_value) = &script.manual_input_default_value {
+                    if !validator.split(',').any(|option| option == default_value) {
+                        return invalid("manual input default value is not a dropdown option");
+                    }
+                }
+            }
+        }
+
+        Ok(script)
+    }
+}
+
+pub(crate) fn validate_details(name: &str, details: &ScriptDetails) -> Result<(), ZabbixApiError> {
+    let invalid = |message: &str| {
+        Err(ZabbixApiError::InvalidRequestError(format!(
+            "script '{name}': {message}"
+        )))
+    };
+
+    if details.command().trim().is_empty() {
+        return invalid("command is required");
+    }
+
+    match details {
+        ScriptDetails::Ssh(ssh) => {
+            if ssh.username.is_empty() {
+                return invalid("ssh username is required");
+            }
+
+            if ssh.auth_type == Some(SshAuthType::PublicKey)
+                && (ssh.public_key.is_none() || ssh.private_key.is_none())
+            {
+                return invalid("public key authentication requires public and private key files");
+            }
+        }
+        ScriptDetails::Telnet(telnet) if telnet.username.is_empty() => {
+            return invalid("telnet username is required");
+        }
+        ScriptDetails::Webhook(webhook) => {
+            let mut names = HashSet::new();
+
+            for parameter in webhook.parameters.iter().flatten() {
+                if parameter.name.is_empty() {
+                    return invalid("webhook parameter name is required");
+                }
+
+                if !names.insert(&parameter.name) {
+                    return invalid("webhook parameter names must be unique");
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateScriptResponse {
+    #[serde(rename = "scriptids")]
+    pub script_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_tests {
+    use super::CreateScriptRequest;
+    use crate::script::model::{
+        CustomScript, ScriptDetails, ScriptExecuteOn, ScriptHostAccess, ScriptScope, SshScript,
+        WebhookScript,
+    };
+
+    #[test]
+    fn serialize_test() {
+        let request = CreateScriptRequest::builder(
+            "Disk usage",
+            ScriptDetails::Script(CustomScript::new("df -h", ScriptExecuteOn::Agent)),
+            ScriptScope::ManualHostAction,
+        )
+        .menu_path("Diagnostics")
+        .host_access(ScriptHostAccess::Write)
+        .user_group("7")
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Disk usage",
+                "type": "0",
+                "command": "df -h",
+                "execute_on": "0",
+                "scope": "2",
+                "menu_path": "Diagnostics",
+                "usrgrpid": "7",
+                "host_access": "3"
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_webhook_test() {
+        let request = CreateScriptRequest::builder(
+            "Open ticket",
+            ScriptDetails::Webhook(
+                WebhookScript::new("return 'OK';")
+                    .parameter("event", "{EVENT.ID}")
+                    .timeout("10s"),
+            ),
+            ScriptScope::ManualEventAction,
+        )
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["type"], "5");
+        assert_eq!(json["timeout"], "10s");
+        assert_eq!(
+            json["parameters"],
+            serde_json::json!([{"name": "event", "value": "{EVENT.ID}"}])
+        );
+    }
+
+    #[cfg(feature = "v7")]
+    #[test]
+    fn serialize_manual_input_test() {
+        use crate::script::model::ScriptManualInput;
+
+        let request = CreateScriptRequest::builder(
+            "Restart service",
+            ScriptDetails::Script(CustomScript::new(
+                "systemctl restart {MANUALINPUT}",
+                ScriptExecuteOn::Agent,
+            )),
+            ScriptScope::ManualHostAction,
+        )
+        .manual_input(
+            ScriptManualInput::dropdown("Service", &["nginx", "php-fpm"]).default_value("nginx"),
+        )
+        .build()
+        .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["manualinput"], 1);
+        assert_eq!(json["manualinput_prompt"], "Service");
+        assert_eq!(json["manualinput_validator"], "nginx,php-fpm");
+        assert_eq!(json["manualinput_validator_type"], "1");
+        assert_eq!(json["manualinput_default_value"], "nginx");
+    }
+
+    #[test]
+    fn invalid_requests_are_rejected() {
+        assert!(CreateScriptRequest::builder(
+            "Empty",
+            ScriptDetails::Script(CustomScript::new(" ", ScriptExecuteOn::Server)),
+            ScriptScope::ManualHostAction
+        )
+        .build()
+        .is_err());
+
+        assert!(CreateScriptRequest::builder(
+            "Menu on operation",
+            ScriptDetails::Script(CustomScript::new("uptime", ScriptExecuteOn::Agent)),
+            ScriptScope::ActionOperation
+        )
+        .menu_path("Diagnostics")
+        .build()
+        .is_err());
+
+        let mut ssh = SshScript::with_public_key("uptime", "zabbix", "id_rsa.pub", "id_rsa");
+        ssh.private_key = None;
+
+        assert!(CreateScriptRequest::builder(
+            "Missing key",
+            ScriptDetails::Ssh(ssh),
+            ScriptScope::ActionOperation
+        )
+        .build()
+        .is_err());
+
+        assert!(CreateScriptRequest::builder(
+            "Duplicate parameters",
+            ScriptDetails::Webhook(
+                WebhookScript::new("return 'OK';")
+                    .parameter("event", "1")
+                    .parameter("event", "2")
+            ),
+            ScriptScope::ManualEventAction
+        )
+        .build()
+        .is_err());
+    }
+
+    #[cfg(feature = "v7")]
+    #[test]
+    fn invalid_manual_input_is_rejected() {
+        use crate::script::model::ScriptManualInput;
+
+        assert!(CreateScriptRequest::builder(
+            "Manual input on operation",
+            ScriptDetails::Script(CustomScript::new(
+                "echo {MANUALINPUT}",
+                ScriptExecuteOn::Agent
+            )),
+            ScriptScope::ActionOperation
+        )
+        .manual_input(ScriptManualInput::string("Value", ".*"))
+        .build()
+        .is_err());
+
+        assert!(CreateScriptRequest::builder(
+            "Unknown default",
+            ScriptDetails::Script(CustomScript::new(
+                "echo {MANUALINPUT}",
+                ScriptExecuteOn::Agent
+            )),
+            ScriptScope::ManualHostAction
+        )
+        .manual_input(ScriptManualInput::dropdown("Value", &["a", "b"]).default_value("c"))
+        .build()
+        .is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none, DisplayFromStr, PickFirst};
+
+/// Runs a script on a host, or on the host of an event.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/execute
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ExecuteScriptRequest {
+    #[serde(rename = "scriptid")]
+    pub script_id: String,
+    #[serde(rename = "hostid")]
+    pub host_id: Option<String>,
+    #[serde(rename = "eventid")]
+    pub event_id: Option<String>,
+    /// Value for `{MANUALINPUT}`, checked against the validator of the script.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput")]
+    pub manual_input: Option<String>,
+}
+
+impl ExecuteScriptRequest {
+    pub fn on_host(script_id: impl ToString, host_id: impl ToString) -> Self {
+        Self {
+            script_id: script_id.to_string(),
+            host_id: Some(host_id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn on_event(script_id: impl ToString, event_id: impl ToString) -> Self {
+        Self {
+            script_id: script_id.to_string(),
+            event_id: Some(event_id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn manual_input(mut self, manual_input: impl ToString) -> Self {
+        self.manual_input = Some(manual_input.to_string());
+        self
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ScriptExecution {
+    /// `success` when the script has run.
+    pub response: String,
+    /// Output of the script, e.g. stdout of a command or the webhook result.
+    pub value: String,
+    /// Log of a webhook script.
+    pub debug: Option<ScriptDebug>,
+}
+
+impl ScriptExecution {
+    pub fn is_success(&self) -> bool {
+        self.response == "success"
+    }
+}
+
+#[serde_as]
+#[derive(Deserialize, Debug)]
+pub struct ScriptDebug {
+    pub logs: Vec<ScriptDebugLog>,
+    /// Duration of the webhook run in milliseconds.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub ms: u64,
+}
+
+#[serde_as]
+#[derive(Deserialize, Debug)]
+pub struct ScriptDebugLog {
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub level: u8,
+    /// Time since the start of the webhook in milliseconds.
+    #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+    pub ms: u64,
+    pub message: String,
+}
+
+#[cfg(test)]
+mod execute_tests {
+    use super::{ExecuteScriptRequest, ScriptExecution};
+
+    #[test]
+    fn serialize_test() {
+        let request = ExecuteScriptRequest::on_host("1", "10084");
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({"scriptid": "1", "hostid": "10084"})
+        );
+    }
+
+    #[test]
+    fn deserialize_webhook_result_test() {
+        let input = r#"
+            {"response":"success","value":"{\"status\":\"sent\"}","debug":{"logs":[{"level":3,"ms":480,"message":"[Webhook Script] HTTP status: 200."}],"ms":563}}
+            "#;
+
+        let execution: ScriptExecution = serde_json::from_str(input).unwrap();
+
+        assert!(execution.is_success());
+        assert_eq!(execution.value, r#"{"status":"sent"}"#);
+
+        let debug = execution.debug.unwrap();
+        assert_eq!(debug.ms, 563);
+        assert_eq!(debug.logs[0].level, 3);
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::script::model::ZabbixScript;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetScriptsRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "scriptids")]
+    pub script_ids: Option<Vec<String>>,
+    /// Return only scripts that can run on hosts of the groups.
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    /// Return only scripts that can run on the hosts.
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    /// Return only scripts used by the actions.
+    #[serde(rename = "actionids")]
+    pub action_ids: Option<Vec<String>>,
+    #[serde(rename = "usrgrpids")]
+    pub user_group_ids: Option<Vec<String>>,
+    #[serde(rename = "selectHostGroups")]
+    #[cfg(feature = "v7")]
+    pub select_host_groups: Option<String>,
+    #[serde(rename = "selectGroups")]
+    #[cfg(feature = "v6")]
+    pub select_groups: Option<String>,
+    #[serde(rename = "selectHosts")]
+    pub select_hosts: Option<String>,
+    #[serde(rename = "selectActions")]
+    pub select_actions: Option<String>,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    pub limit: Option<u32>,
+}
+
+/// Scripts available for the hosts, with macros resolved.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/getscriptsbyhosts
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "v6", serde(transparent))]
+pub struct GetScriptsByHostsRequest {
+    #[serde(rename = "hostids")]
+    pub host_ids: Vec<String>,
+    /// Resolve macros only in the given script.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "scriptid")]
+    pub script_id: Option<String>,
+    /// Value for `{MANUALINPUT}`, requires `script_id`.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput")]
+    pub manual_input: Option<String>,
+}
+
+impl GetScriptsByHostsRequest {
+    pub fn new(host_ids: &[String]) -> Self {
+        Self {
+            host_ids: host_ids.to_vec(),
+            #[cfg(feature = "v7")]
+            script_id: None,
+            #[cfg(feature = "v7")]
+            manual_input: None,
+        }
+    }
+}
+
+/// Scripts available for the events, with macros resolved.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/getscriptsbyevents
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+#[cfg_attr(feature = "v6", serde(transparent))]
+pub struct GetScriptsByEventsRequest {
+    #[serde(rename = "eventids")]
+    pub event_ids: Vec<String>,
+    /// Resolve macros only in the given script.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "scriptid")]
+    pub script_id: Option<String>,
+    /// Value for `{MANUALINPUT}`, requires `script_id`.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput")]
+    pub manual_input: Option<String>,
+}
+
+impl GetScriptsByEventsRequest {
+    pub fn new(event_ids: &[String]) -> Self {
+        Self {
+            event_ids: event_ids.to_vec(),
+            #[cfg(feature = "v7")]
+            script_id: None,
+            #[cfg(feature = "v7")]
+            manual_input: None,
+        }
+    }
+}
+
+/// Scripts by host or event ID. Zabbix returns an empty list instead of an
+/// empty object.
+#[derive(Debug)]
+pub(crate) struct ScriptsByObject(HashMap<String, Vec<ZabbixScript>>);
+
+impl ScriptsByObject {
+    pub(crate) fn into_map(self) -> HashMap<String, Vec<ZabbixScript>> {
+        self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for ScriptsByObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawScripts {
+            Map(HashMap<String, Vec<ZabbixScript>>),
+            List(Vec<ZabbixScript>),
+        }
+
+        match RawScripts::deserialize(deserializer)? {
+            RawScripts::Map(scripts) => Ok(ScriptsByObject(scripts)),
+            RawScripts::List(scripts) if scripts.is_empty() => Ok(ScriptsByObject(HashMap::new())),
+            RawScripts::List(_) => Err(serde::de::Error::custom("expected scripts by object id")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod get_tests {
+    use super::{GetScriptsByHostsRequest, ScriptsByObject};
+
+    #[test]
+    fn serialize_scripts_by_hosts_test() {
+        let request = GetScriptsByHostsRequest::new(&["10084".to_string()]);
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        #[cfg(feature = "v7")]
+        assert_eq!(json, serde_json::json!({"hostids": ["10084"]}));
+        #[cfg(feature = "v6")]
+        assert_eq!(json, serde_json::json!(["10084"]));
+    }
+
+    #[test]
+    fn deserialize_scripts_by_object_test() {
+        let input = r#"{"10084":[{"scriptid":"1","name":"Ping","command":"/bin/ping -c 3 127.0.0.1 2>&1","host_access":"2","usrgrpid":"0","groupid":"0","description":"","confirmation":"","type":"0","execute_on":"1","scope":"2","menu_path":"","hostid":"10084"}]}"#;
+
+        let scripts = serde_json::from_str::<ScriptsByObject>(input)
+            .unwrap()
+            .into_map();
+
+        assert_eq!(scripts["10084"][0].name, "Ping");
+
+        let empty = serde_json::from_str::<ScriptsByObject>("[]")
+            .unwrap()
+            .into_map();
+
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create;
+pub mod execute;
+pub mod get;
+pub mod model;
+pub mod update;
@@ -0,0 +1,391 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::de::deserialize_object_id;
+#[cfg(feature = "v7")]
+use crate::flag;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/object
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixScript {
+    #[serde(rename = "scriptid")]
+    pub script_id: String,
+    pub name: String,
+    /// Command and connection settings, selected by `type`.
+    #[serde(flatten)]
+    pub details: ScriptDetails,
+    pub scope: ScriptScope,
+    /// Folders of the script in the host and event menus, e.g. `Diagnostics/Network`.
+    pub menu_path: Option<String>,
+    /// User group allowed to run the script, `None` for all user groups.
+    #[serde(
+        rename = "usrgrpid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub user_group_id: Option<String>,
+    /// Host group the script can run on, `None` for all host groups.
+    #[serde(
+        rename = "groupid",
+        default,
+        deserialize_with = "deserialize_object_id"
+    )]
+    pub group_id: Option<String>,
+    pub host_access: Option<ScriptHostAccess>,
+    /// Confirmation text shown before a manual run.
+    pub confirmation: Option<String>,
+    pub description: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput", default, with = "flag")]
+    pub manual_input: Option<bool>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_prompt")]
+    pub manual_input_prompt: Option<String>,
+    /// Regular expression, or comma separated options of a dropdown.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_validator")]
+    pub manual_input_validator: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_validator_type")]
+    pub manual_input_validator_type: Option<ManualInputValidatorType>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_default_value")]
+    pub manual_input_default_value: Option<String>,
+}
+
+/// Script settings by type, serialized with the `type` field.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum ScriptDetails {
+    #[serde(rename = "0")]
+    Script(CustomScript),
+    #[serde(rename = "1")]
+    Ipmi(IpmiScript),
+    #[serde(rename = "2")]
+    Ssh(SshScript),
+    #[serde(rename = "3")]
+    Telnet(TelnetScript),
+    #[serde(rename = "5")]
+    Webhook(WebhookScript),
+    /// Opens a URL, available only as a manual script.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "6")]
+    Url(UrlScript),
+}
+
+impl ScriptDetails {
+    /// Command, JavaScript code or URL of the script.
+    pub fn command(&self) -> &str {
+        match self {
+            ScriptDetails::Script(script) => &script.command,
+            ScriptDetails::Ipmi(script) => &script.command,
+            ScriptDetails::Ssh(script) => &script.command,
+            ScriptDetails::Telnet(script) => &script.command,
+            ScriptDetails::Webhook(script) => &script.command,
+            #[cfg(feature = "v7")]
+            ScriptDetails::Url(script) => &script.url,
+        }
+    }
+}
+
+/// Shell command run by the agent, the server or the proxy.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct CustomScript {
+    pub command: String,
+    pub execute_on: ScriptExecuteOn,
+}
+
+impl CustomScript {
+    pub fn new(command: impl ToString, execute_on: ScriptExecuteOn) -> Self {
+        Self {
+            command: command.to_string(),
+            execute_on,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ScriptExecuteOn {
+    #[serde(rename = "0")]
+    Agent,
+    #[serde(rename = "1")]
+    Server,
+    /// Zabbix server or the proxy monitoring the host.
+    #[serde(rename = "2")]
+    ServerOrProxy,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct IpmiScript {
+    pub command: String,
+}
+
+impl IpmiScript {
+    pub fn new(command: impl ToString) -> Self {
+        Self {
+            command: command.to_string(),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct SshScript {
+    pub command: String,
+    #[serde(rename = "authtype")]
+    pub auth_type: Option<SshAuthType>,
+    pub username: String,
+    pub password: Option<String>,
+    /// Public key file name, for public key authentication.
+    #[serde(rename = "publickey")]
+    pub public_key: Option<String>,
+    /// Private key file name, for public key authentication.
+    #[serde(rename = "privatekey")]
+    pub private_key: Option<String>,
+    /// Port number or user macro, empty for the default port.
+    pub port: Option<String>,
+}
+
+impl SshScript {
+    pub fn with_password(
+        command: impl ToString,
+        username: impl ToString,
+        password: impl ToString,
+    ) -> Self {
+        Self {
+            command: command.to_string(),
+            auth_type: Some(SshAuthType::Password),
+            username: username.to_string(),
+            password: Some(password.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_public_key(
+        command: impl ToString,
+        username: impl ToString,
+        public_key: impl ToString,
+        private_key: impl ToString,
+    ) -> Self {
+        Self {
+            command: command.to_string(),
+            auth_type: Some(SshAuthType::PublicKey),
+            username: username.to_string(),
+            public_key: Some(public_key.to_string()),
+            private_key: Some(private_key.to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn port(mut self, port: impl ToString) -> Self {
+        self.port = Some(port.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum SshAuthType {
+    #[serde(rename = "0")]
+    Password,
+    #[serde(rename = "1")]
+    PublicKey,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct TelnetScript {
+    pub command: String,
+    pub username: String,
+    pub password: Option<String>,
+    /// Port number or user macro, empty for the default port.
+    pub port: Option<String>,
+}
+
+impl TelnetScript {
+    pub fn new(command: impl ToString, username: impl ToString, password: impl ToString) -> Self {
+        Self {
+            command: command.to_string(),
+            username: username.to_string(),
+            password: Some(password.to_string()),
+            port: None,
+        }
+    }
+
+    pub fn port(mut self, port: impl ToString) -> Self {
+        self.port = Some(port.to_string());
+        self
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct WebhookScript {
+    /// JavaScript code of the webhook.
+    pub command: String,
+    pub timeout: Option<String>,
+    pub parameters: Option<Vec<ScriptParameter>>,
+}
+
+impl WebhookScript {
+    pub fn new(command: impl ToString) -> Self {
+        Self {
+            command: command.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn parameter(mut self, name: impl ToString, value: impl ToString) -> Self {
+        self.parameters
+            .get_or_insert_with(Vec::new)
+            .push(ScriptParameter {
+                name: name.to_string(),
+                value: value.to_string(),
+            });
+        self
+    }
+
+    pub fn timeout(mut self, timeout: impl ToString) -> Self {
+        self.timeout = Some(timeout.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ScriptParameter {
+    pub name: String,
+    pub value: String,
+}
+
+#[cfg(feature = "v7")]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct UrlScript {
+    pub url: String,
+    #[serde(default, with = "flag")]
+    pub new_window: Option<bool>,
+}
+
+#[cfg(feature = "v7")]
+impl UrlScript {
+    pub fn new(url: impl ToString) -> Self {
+        Self {
+            url: url.to_string(),
+            new_window: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ScriptScope {
+    #[serde(rename = "1")]
+    ActionOperation,
+    #[serde(rename = "2")]
+    ManualHostAction,
+    #[serde(rename = "4")]
+    ManualEventAction,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ScriptHostAccess {
+    #[serde(rename = "2")]
+    Read,
+    #[serde(rename = "3")]
+    Write,
+}
+
+#[cfg(feature = "v7")]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum ManualInputValidatorType {
+    /// Input must match the regular expression in the validator.
+    #[serde(rename = "0")]
+    String,
+    /// Input is one of the comma separated options in the validator.
+    #[serde(rename = "1")]
+    Dropdown,
+}
+
+/// User input requested when a manual script is run, `{MANUALINPUT}` in the command.
+#[cfg(feature = "v7")]
+#[derive(PartialEq, Clone, Debug)]
+pub struct ScriptManualInput {
+    pub prompt: String,
+    pub validator_type: ManualInputValidatorType,
+    pub validator: String,
+    pub default_value: Option<String>,
+}
+
+#[cfg(feature = "v7")]
+impl ScriptManualInput {
+    /// Free text input matching `regex`.
+    pub fn string(prompt: impl ToString, regex: impl ToString) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            validator_type: ManualInputValidatorType::String,
+            validator: regex.to_string(),
+            default_value: None,
+        }
+    }
+
+    /// Choice of `options`, the first one is preselected.
+    pub fn dropdown(prompt: impl ToString, options: &[&str]) -> Self {
+        Self {
+            prompt: prompt.to_string(),
+            validator_type: ManualInputValidatorType::Dropdown,
+            validator: options.join(","),
+            default_value: None,
+        }
+    }
+
+    pub fn default_value(mut self, default_value: impl ToString) -> Self {
+        self.default_value = Some(default_value.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod script_tests {
+    use super::{ScriptDetails, ScriptExecuteOn, ScriptHostAccess, ScriptScope, ZabbixScript};
+
+    #[test]
+    fn deserialize_script_test() {
+        let input = r#"
+            {"scriptid":"1","name":"Ping","command":"/bin/ping -c 3 {HOST.CONN} 2>&1","host_access":"2","usrgrpid":"0","groupid":"0","description":"","confirmation":"","type":"0","execute_on":"1","timeout":"30s","scope":"2","port":"","authtype":"0","username":"","password":"","publickey":"","privatekey":"","menu_path":"","url":"","new_window":"1","manualinput":"0","manualinput_prompt":"","manualinput_validator":"","manualinput_validator_type":"0","manualinput_default_value":"","parameters":[]}
+            "#;
+
+        let script: ZabbixScript = serde_json::from_str(input).unwrap();
+
+        assert_eq!(script.scope, ScriptScope::ManualHostAction);
+        assert_eq!(script.host_access, Some(ScriptHostAccess::Read));
+        assert_eq!(script.user_group_id, None);
+        assert_eq!(script.group_id, None);
+
+        match script.details {
+            ScriptDetails::Script(custom) => {
+                assert_eq!(custom.command, "/bin/ping -c 3 {HOST.CONN} 2>&1");
+                assert_eq!(custom.execute_on, ScriptExecuteOn::Server);
+            }
+            other => panic!("unexpected script: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserialize_webhook_test() {
+        let input = r#"
+            {"scriptid":"5","name":"Open ticket","command":"return 'OK';","host_access":"3","usrgrpid":"7","groupid":"4","description":"","confirmation":"Open a ticket?","type":"5","execute_on":"2","timeout":"10s","scope":"4","port":"","authtype":"0","username":"","password":"","publickey":"","privatekey":"","menu_path":"Tickets","parameters":[{"name":"event","value":"{EVENT.ID}"}]}
+            "#;
+
+        let script: ZabbixScript = serde_json::from_str(input).unwrap();
+
+        assert_eq!(script.scope, ScriptScope::ManualEventAction);
+        assert_eq!(script.user_group_id.as_deref(), Some("7"));
+        assert_eq!(script.menu_path.as_deref(), Some("Tickets"));
+
+        match script.details {
+            ScriptDetails::Webhook(webhook) => {
+                assert_eq!(webhook.timeout.as_deref(), Some("10s"));
+                assert_eq!(webhook.parameters.unwrap()[0].value, "{EVENT.ID}");
+            }
+            other => panic!("unexpected script: {:?}", other),
+        }
+    }
+}
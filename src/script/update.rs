@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::error::ZabbixApiError;
+#[cfg(feature = "v7")]
+use crate::flag;
+use crate::script::create::validate_details;
+#[cfg(feature = "v7")]
+use crate::script::model::{ManualInputValidatorType, ScriptManualInput};
+use crate::script::model::{ScriptDetails, ScriptHostAccess, ScriptScope};
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/script/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct UpdateScriptRequest {
+    #[serde(rename = "scriptid")]
+    pub script_id: String,
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub details: Option<ScriptDetails>,
+    pub scope: Option<ScriptScope>,
+    pub menu_path: Option<String>,
+    #[serde(rename = "usrgrpid")]
+    pub user_group_id: Option<String>,
+    #[serde(rename = "groupid")]
+    pub group_id: Option<String>,
+    pub host_access: Option<ScriptHostAccess>,
+    pub confirmation: Option<String>,
+    pub description: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput", with = "flag")]
+    pub manual_input: Option<bool>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_prompt")]
+    pub manual_input_prompt: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_validator")]
+    pub manual_input_validator: Option<String>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_validator_type")]
+    pub manual_input_validator_type: Option<ManualInputValidatorType>,
+    #[cfg(feature = "v7")]
+    #[serde(rename = "manualinput_default_value")]
+    pub manual_input_default_value: Option<String>,
+}
+
+impl UpdateScriptRequest {
+    pub fn builder(script_id: impl ToString) -> UpdateScriptRequestBuilder {
+        UpdateScriptRequestBuilder {
+            inner: UpdateScriptRequest {
+                script_id: script_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateScriptRequestBuilder {
+    inner: UpdateScriptRequest,
+}
+
+impl UpdateScriptRequestBuilder {
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    /// Replaces the type, command and connection settings.
+    pub fn details(mut self, details: ScriptDetails) -> Self {
+        self.inner.details = Some(details);
+        self
+    }
+
+    pub fn scope(mut self, scope: ScriptScope) -> Self {
+        self.inner.scope = Some(scope);
+        self
+    }
+
+    pub fn menu_path(mut self, menu_path: impl ToString) -> Self {
+        self.inner.menu_path = Some(menu_path.to_string());
+        self
+    }
+
+    pub fn user_group(mut self, user_group_id: impl ToString) -> Self {
+        self.inner.user_group_id = Some(user_group_id.to_string());
+        self
+    }
+
+    pub fn host_group(mut self, group_id: impl ToString) -> Self {
+        self.inner.group_id = Some(group_id.to_string());
+        self
+    }
+
+    pub fn host_access(mut self, host_access: ScriptHostAccess) -> Self {
+        self.inner.host_access = Some(host_access);
+        self
+    }
+
+    pub fn confirmation(mut self, confirmation: impl ToString) -> Self {
+        self.inner.confirmation = Some(confirmation.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn manual_input(mut self, manual_input: ScriptManualInput) -> Self {
+        self.inner.manual_input = Some(true);
+        self.inner.manual_input_prompt = Some(manual_input.prompt);
+        self.inner.manual_input_validator = Some(manual_input.validator);
+        self.inner.manual_input_validator_type = Some(manual_input.validator_type);
+        self.inner.manual_input_default_value = manual_input.default_value;
+        self
+    }
+
+    #[cfg(feature = "v7")]
+    pub fn without_manual_input(mut self) -> Self {
+        self.inner.manual_input = Some(false);
+        self
+    }
+
+    /// Checks the script settings when they are replaced.
+    pub fn build(self) -> Result<UpdateScriptRequest, ZabbixApiError> {
+        let script = self.inner;
+
+        if let Some(details) = &script.details {
+            let name = script.name.as_deref().unwrap_or(&script.script_id);
+
+            validate_details(name, details)?;
+        }
+
+        Ok(script)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpdateScriptResponse {
+    #[serde(rename = "scriptids")]
+    pub script_ids: Vec<String>,
+}
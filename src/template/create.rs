@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::host::model::ZabbixHostTag;
+use crate::r#macro::create::CreateZabbixHostMacro;
+use crate::template::model::{TemplateGroupId, ZabbixTemplateId};
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/create
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct CreateTemplateRequest {
+    /// Technical name of the template.
+    pub host: String,
+    /// Visible name of the template, `host` is used when empty.
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// Template groups since Zabbix 6.2, host groups before that.
+    pub groups: Vec<TemplateGroupId>,
+    pub tags: Vec<ZabbixHostTag>,
+    /// Parent templates linked to the template.
+    pub templates: Vec<ZabbixTemplateId>,
+    pub macros: Vec<CreateZabbixHostMacro>,
+    /// Available since Zabbix 6.4.
+    pub vendor_name: Option<String>,
+    /// Available since Zabbix 6.4.
+    pub vendor_version: Option<String>,
+}
+
+impl CreateTemplateRequest {
+    pub fn builder(host: impl ToString) -> CreateTemplateRequestBuilder {
+        CreateTemplateRequestBuilder::new(host)
+    }
+}
+
+pub struct CreateTemplateRequestBuilder {
+    inner: CreateTemplateRequest,
+}
+
+impl CreateTemplateRequestBuilder {
+    pub fn new(host: impl ToString) -> Self {
+        Self {
+            inner: CreateTemplateRequest {
+                host: host.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn group(mut self, group_id: impl ToString) -> Self {
+        self.inner.groups.push(TemplateGroupId {
+            group_id: group_id.to_string(),
+        });
+        self
+    }
+
+    pub fn groups(mut self, groups: Vec<TemplateGroupId>) -> Self {
+        self.inner.groups = groups;
+        self
+    }
+
+    pub fn tag(mut self, tag: impl ToString, value: impl ToString) -> Self {
+        self.inner.tags.push(ZabbixHostTag {
+            tag: tag.to_string(),
+            value: value.to_string(),
+        });
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<ZabbixHostTag>) -> Self {
+        self.inner.tags = tags;
+        self
+    }
+
+    /// Links a parent template.
+    pub fn template(mut self, template_id: impl ToString) -> Self {
+        self.inner.templates.push(ZabbixTemplateId {
+            template_id: template_id.to_string(),
+        });
+        self
+    }
+
+    pub fn templates(mut self, templates: Vec<ZabbixTemplateId>) -> Self {
+        self.inner.templates = templates;
+        self
+    }
+
+    pub fn macro_entry(mut self, macro_entry: CreateZabbixHostMacro) -> Self {
+        self.inner.macros.push(macro_entry);
+        self
+    }
+
+    pub fn macros(mut self, macros: Vec<CreateZabbixHostMacro>) -> Self {
+        self.inner.macros = macros;
+        self
+    }
+
+    pub fn vendor(mut self, name: impl ToString, version: impl ToString) -> Self {
+        self.inner.vendor_name = Some(name.to_string());
+        self.inner.vendor_version = Some(version.to_string());
+        self
+    }
+
+    pub fn build(self) -> CreateTemplateRequest {
+        self.inner
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateTemplateResponse {
+    #[serde(rename = "templateids")]
+    pub template_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod create_template_tests {
+    use super::CreateTemplateRequest;
+    use crate::r#macro::create::CreateZabbixHostMacro;
+
+    #[test]
+    fn builder_serialization_test() {
+        let request = CreateTemplateRequest::builder("tpl-nginx")
+            .name("Nginx")
+            .group("12")
+            .tag("class", "software")
+            .template("10001")
+            .macro_entry(
+                CreateZabbixHostMacro::builder()
+                    .macro_name("{$NGINX.PORT}")
+                    .value("80")
+                    .build(),
+            )
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["host"], "tpl-nginx");
+        assert_eq!(json["name"], "Nginx");
+        assert_eq!(json["groups"][0]["groupid"], "12");
+        assert_eq!(json["tags"][0]["tag"], "class");
+        assert_eq!(json["templates"][0]["templateid"], "10001");
+        assert_eq!(json["macros"][0]["macro"], "{$NGINX.PORT}");
+        assert!(json.get("description").is_none());
+        assert!(json.get("vendor_name").is_none());
+    }
+}
@@ -0,0 +1,45 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetTemplatesRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "templateids")]
+    pub template_ids: Option<Vec<String>>,
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    /// Return only templates linked to the given hosts.
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    /// Return only templates that have the given templates linked.
+    #[serde(rename = "parentTemplateids")]
+    pub parent_template_ids: Option<Vec<String>>,
+    /// Return template groups in the `template_groups` property.
+    #[cfg(feature = "v7")]
+    #[serde(rename = "selectTemplateGroups")]
+    pub select_template_groups: Option<String>,
+    /// Return host groups in the `groups` property.
+    #[cfg(feature = "v6")]
+    #[serde(rename = "selectGroups")]
+    pub select_groups: Option<String>,
+    #[serde(rename = "selectTags")]
+    pub select_tags: Option<String>,
+    #[serde(rename = "selectMacros")]
+    pub select_macros: Option<String>,
+    #[serde(rename = "selectParentTemplates")]
+    pub select_parent_templates: Option<String>,
+    #[serde(rename = "selectHosts")]
+    pub select_hosts: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct TemplateFilter {
+    /// Technical names of templates.
+    pub host: Option<Vec<String>>,
+    /// Visible names of templates.
+    pub name: Option<Vec<String>>,
+}
@@ -0,0 +1,85 @@
+use serde::Serialize;
+
+use crate::host::model::ZabbixHostId;
+use crate::template::model::ZabbixTemplateId;
+use crate::template::update::to_template_ids;
+
+/// Links templates to hosts, sent as `host.massadd`.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/host/massadd
+#[derive(Serialize, Debug)]
+pub struct LinkTemplatesRequest {
+    pub hosts: Vec<ZabbixHostId>,
+    pub templates: Vec<ZabbixTemplateId>,
+}
+
+impl LinkTemplatesRequest {
+    pub fn new(template_ids: Vec<String>, host_ids: Vec<String>) -> Self {
+        Self {
+            hosts: host_ids
+                .into_iter()
+                .map(|host_id| ZabbixHostId { host_id })
+                .collect(),
+            templates: to_template_ids(template_ids),
+        }
+    }
+}
+
+/// Unlinks templates from hosts, sent as `host.massremove`.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/host/massremove
+#[derive(Serialize, Debug)]
+pub struct UnlinkTemplatesRequest {
+    #[serde(rename = "hostids")]
+    pub host_ids: Vec<String>,
+    #[serde(flatten)]
+    pub templates: UnlinkedTemplates,
+}
+
+#[derive(Serialize, Debug)]
+pub enum UnlinkedTemplates {
+    /// Entities inherited from the templates stay on hosts.
+    #[serde(rename = "templateids")]
+    Keep(Vec<String>),
+    /// Entities inherited from the templates are deleted from hosts.
+    #[serde(rename = "templateids_clear")]
+    Clear(Vec<String>),
+}
+
+impl UnlinkTemplatesRequest {
+    /// Unlinks templates, items, triggers and other inherited entities stay on hosts.
+    pub fn new(template_ids: Vec<String>, host_ids: Vec<String>) -> Self {
+        Self {
+            host_ids,
+            templates: UnlinkedTemplates::Keep(template_ids),
+        }
+    }
+
+    /// Unlinks templates and deletes inherited entities from hosts.
+    pub fn clear(template_ids: Vec<String>, host_ids: Vec<String>) -> Self {
+        Self {
+            host_ids,
+            templates: UnlinkedTemplates::Clear(template_ids),
+        }
+    }
+}
+
+#[cfg(test)]
+mod link_tests {
+    use super::UnlinkTemplatesRequest;
+
+    #[test]
+    fn unlink_serialization_test() {
+        let request = UnlinkTemplatesRequest::new(vec!["1".to_string()], vec!["2".to_string()]);
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["hostids"][0], "2");
+        assert_eq!(json["templateids"][0], "1");
+
+        let request = UnlinkTemplatesRequest::clear(vec!["1".to_string()], vec!["2".to_string()]);
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["templateids_clear"][0], "1");
+        assert!(json.get("templateids").is_none());
+    }
+}
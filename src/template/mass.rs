@@ -0,0 +1,144 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::r#macro::create::CreateZabbixHostMacro;
+use crate::template::model::{TemplateGroupId, ZabbixTemplateId};
+use crate::template::update::to_template_ids;
+
+/// Adds groups, macros and parent templates to all the given templates.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/massadd
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct MassAddTemplatesRequest {
+    pub templates: Vec<ZabbixTemplateId>,
+    pub groups: Option<Vec<TemplateGroupId>>,
+    pub macros: Option<Vec<CreateZabbixHostMacro>>,
+    /// Parent templates to link to the given templates.
+    pub templates_link: Option<Vec<ZabbixTemplateId>>,
+}
+
+impl MassAddTemplatesRequest {
+    pub fn new(template_ids: Vec<String>) -> Self {
+        Self {
+            templates: to_template_ids(template_ids),
+            ..Default::default()
+        }
+    }
+
+    pub fn groups(mut self, group_ids: Vec<String>) -> Self {
+        self.groups = Some(to_group_ids(group_ids));
+        self
+    }
+
+    pub fn macros(mut self, macros: Vec<CreateZabbixHostMacro>) -> Self {
+        self.macros = Some(macros);
+        self
+    }
+
+    pub fn templates_link(mut self, template_ids: Vec<String>) -> Self {
+        self.templates_link = Some(to_template_ids(template_ids));
+        self
+    }
+}
+
+/// Removes groups, macros and parent templates from all the given templates.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/massremove
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct MassRemoveTemplatesRequest {
+    #[serde(rename = "templateids")]
+    pub template_ids: Vec<String>,
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    /// Names of user macros to remove, e.g. `{$PORT}`.
+    pub macros: Option<Vec<String>>,
+    /// Parent templates to unlink and clear.
+    #[serde(rename = "templateids_clear")]
+    pub template_ids_clear: Option<Vec<String>>,
+    /// Parent templates to unlink without clearing.
+    #[serde(rename = "templateids_link")]
+    pub template_ids_link: Option<Vec<String>>,
+}
+
+impl MassRemoveTemplatesRequest {
+    pub fn new(template_ids: Vec<String>) -> Self {
+        Self {
+            template_ids,
+            ..Default::default()
+        }
+    }
+
+    pub fn groups(mut self, group_ids: Vec<String>) -> Self {
+        self.group_ids = Some(group_ids);
+        self
+    }
+
+    pub fn macros(mut self, macros: Vec<String>) -> Self {
+        self.macros = Some(macros);
+        self
+    }
+
+    pub fn templates_clear(mut self, template_ids: Vec<String>) -> Self {
+        self.template_ids_clear = Some(template_ids);
+        self
+    }
+
+    pub fn templates_link(mut self, template_ids: Vec<String>) -> Self {
+        self.template_ids_link = Some(template_ids);
+        self
+    }
+}
+
+/// Replaces groups, macros and parent templates of all the given templates.
+/// Properties left as `None` are not changed.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/massupdate
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct MassUpdateTemplatesRequest {
+    pub templates: Vec<ZabbixTemplateId>,
+    pub groups: Option<Vec<TemplateGroupId>>,
+    pub macros: Option<Vec<CreateZabbixHostMacro>>,
+    /// Parent templates to unlink and clear.
+    pub templates_clear: Option<Vec<ZabbixTemplateId>>,
+    /// Replaces linked parent templates.
+    pub templates_link: Option<Vec<ZabbixTemplateId>>,
+}
+
+impl MassUpdateTemplatesRequest {
+    pub fn new(template_ids: Vec<String>) -> Self {
+        Self {
+            templates: to_template_ids(template_ids),
+            ..Default::default()
+        }
+    }
+
+    pub fn groups(mut self, group_ids: Vec<String>) -> Self {
+        self.groups = Some(to_group_ids(group_ids));
+        self
+    }
+
+    pub fn macros(mut self, macros: Vec<CreateZabbixHostMacro>) -> Self {
+        self.macros = Some(macros);
+        self
+    }
+
+    pub fn templates_clear(mut self, template_ids: Vec<String>) -> Self {
+        self.templates_clear = Some(to_template_ids(template_ids));
+        self
+    }
+
+    pub fn templates_link(mut self, template_ids: Vec<String>) -> Self {
+        self.templates_link = Some(to_template_ids(template_ids));
+        self
+    }
+}
+
+fn to_group_ids(group_ids: Vec<String>) -> Vec<TemplateGroupId> {
+    group_ids
+        .into_iter()
+        .map(|group_id| TemplateGroupId { group_id })
+        .collect()
+}
@@ -1 +1,11 @@
+#[cfg(feature = "template")]
+pub mod create;
+#[cfg(feature = "template")]
+pub mod get;
+#[cfg(feature = "template")]
+pub mod link;
+#[cfg(feature = "template")]
+pub mod mass;
 pub mod model;
+#[cfg(feature = "template")]
+pub mod update;
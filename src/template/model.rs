@@ -1,7 +1,23 @@
 use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::host::model::{ZabbixHost, ZabbixHostTag};
+#[cfg(all(feature = "template", feature = "v6"))]
+use crate::hostgroup::model::ZabbixHostGroup;
+use crate::r#macro::model::ZabbixHostMacro;
+#[cfg(all(feature = "template", feature = "v7"))]
+use crate::templategroup::model::ZabbixTemplateGroup;
+
+#[cfg(all(feature = "template", feature = "v6"))]
+pub use crate::hostgroup::model::ZabbixHostGroupId as TemplateGroupId;
+/// Group reference used by template requests. Templates belong to template
+/// groups since Zabbix 6.2 and to host groups before that.
+#[cfg(all(feature = "template", feature = "v7"))]
+pub use crate::templategroup::model::ZabbixTemplateGroupId as TemplateGroupId;
 
 /// API Object: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/template/object
-#[derive(Serialize, Deserialize, Debug)]
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ZabbixTemplate {
     #[serde(rename = "templateid")]
     pub template_id: String,
@@ -9,9 +25,29 @@ pub struct ZabbixTemplate {
     pub description: String,
     pub name: String,
     pub uuid: String,
+    /// Available since Zabbix 6.4.
+    pub vendor_name: Option<String>,
+    /// Available since Zabbix 6.4.
+    pub vendor_version: Option<String>,
+    /// Filled when requested with `selectTemplateGroups`.
+    #[cfg(all(feature = "template", feature = "v7"))]
+    #[serde(rename = "templategroups")]
+    pub template_groups: Option<Vec<ZabbixTemplateGroup>>,
+    /// Filled when requested with `selectGroups`.
+    #[cfg(all(feature = "template", feature = "v6"))]
+    pub groups: Option<Vec<ZabbixHostGroup>>,
+    /// Filled when requested with `selectTags`.
+    pub tags: Option<Vec<ZabbixHostTag>>,
+    /// Filled when requested with `selectMacros`.
+    pub macros: Option<Vec<ZabbixHostMacro>>,
+    /// Templates linked to this template, filled when requested with `selectParentTemplates`.
+    #[serde(rename = "parentTemplates")]
+    pub parent_templates: Option<Vec<ZabbixTemplate>>,
+    /// Hosts linked to this template, filled when requested with `selectHosts`.
+    pub hosts: Option<Vec<ZabbixHost>>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 pub struct ZabbixTemplateId {
     #[serde(rename = "templateid")]
     pub template_id: String,
@@ -24,3 +60,22 @@ impl From<ZabbixTemplate> for ZabbixTemplateId {
         }
     }
 }
+
+#[cfg(test)]
+mod template_tests {
+    use super::ZabbixTemplate;
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"
+            {"templateid":"10001","host":"Linux by Zabbix agent","description":"","name":"Linux by Zabbix agent","uuid":"f8f7908280354f2abeed07dc788c3747","vendor_name":"Zabbix","vendor_version":"7.0-0","tags":[{"tag":"class","value":"os"}],"macros":[{"hostmacroid":"1","hostid":"10001","macro":"{$AGENT.TIMEOUT}","value":"3m","description":"","type":"0","automatic":"0"}],"parentTemplates":[{"templateid":"10002","host":"Base","description":"","name":"Base","uuid":"a8f7908280354f2abeed07dc788c3747"}],"hosts":[{"hostid":"10084","host":"Zabbix server","status":"0"}]}
+            "#;
+
+        let result: ZabbixTemplate = serde_json::from_str(input).unwrap();
+
+        assert_eq!(result.tags.unwrap().len(), 1);
+        assert_eq!(result.macros.unwrap()[0].value, "3m");
+        assert_eq!(result.parent_templates.unwrap()[0].template_id, "10002");
+        assert_eq!(result.hosts.unwrap()[0].host_id, "10084");
+    }
+}
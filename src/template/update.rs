@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::host::model::ZabbixHostTag;
+use crate::r#macro::create::CreateZabbixHostMacro;
+use crate::template::model::{TemplateGroupId, ZabbixTemplateId};
+
+/// Properties left as `None` are not changed. Collections replace the existing values.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/template/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateTemplateRequest {
+    #[serde(rename = "templateid")]
+    pub template_id: String,
+    pub host: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub groups: Option<Vec<TemplateGroupId>>,
+    pub tags: Option<Vec<ZabbixHostTag>>,
+    pub macros: Option<Vec<CreateZabbixHostMacro>>,
+    /// Replaces linked parent templates. Templates missing from the list are unlinked.
+    pub templates: Option<Vec<ZabbixTemplateId>>,
+    /// Parent templates to unlink and clear from the template.
+    pub templates_clear: Option<Vec<ZabbixTemplateId>>,
+    pub vendor_name: Option<String>,
+    pub vendor_version: Option<String>,
+}
+
+impl UpdateTemplateRequest {
+    pub fn builder(template_id: impl ToString) -> UpdateTemplateRequestBuilder {
+        UpdateTemplateRequestBuilder {
+            inner: UpdateTemplateRequest {
+                template_id: template_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateTemplateRequestBuilder {
+    inner: UpdateTemplateRequest,
+}
+
+impl UpdateTemplateRequestBuilder {
+    pub fn host(mut self, host: impl ToString) -> Self {
+        self.inner.host = Some(host.to_string());
+        self
+    }
+
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.inner.name = Some(name.to_string());
+        self
+    }
+
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn groups(mut self, group_ids: Vec<String>) -> Self {
+        self.inner.groups = Some(
+            group_ids
+                .into_iter()
+                .map(|group_id| TemplateGroupId { group_id })
+                .collect(),
+        );
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<ZabbixHostTag>) -> Self {
+        self.inner.tags = Some(tags);
+        self
+    }
+
+    pub fn macros(mut self, macros: Vec<CreateZabbixHostMacro>) -> Self {
+        self.inner.macros = Some(macros);
+        self
+    }
+
+    pub fn templates(mut self, template_ids: Vec<String>) -> Self {
+        self.inner.templates = Some(to_template_ids(template_ids));
+        self
+    }
+
+    pub fn templates_clear(mut self, template_ids: Vec<String>) -> Self {
+        self.inner.templates_clear = Some(to_template_ids(template_ids));
+        self
+    }
+
+    pub fn vendor(mut self, name: impl ToString, version: impl ToString) -> Self {
+        self.inner.vendor_name = Some(name.to_string());
+        self.inner.vendor_version = Some(version.to_string());
+        self
+    }
+
+    pub fn build(self) -> UpdateTemplateRequest {
+        self.inner
+    }
+}
+
+pub(crate) fn to_template_ids(template_ids: Vec<String>) -> Vec<ZabbixTemplateId> {
+    template_ids
+        .into_iter()
+        .map(|template_id| ZabbixTemplateId { template_id })
+        .collect()
+}
+
+/// Shared by `template.update`, `template.delete` and `template.mass*`,
+/// they all return the affected template IDs.
+#[derive(Deserialize, Debug)]
+pub struct UpdateTemplateResponse {
+    #[serde(rename = "templateids")]
+    pub template_ids: Vec<String>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/create
+#[derive(Serialize, Debug)]
+pub struct CreateTemplateGroupRequest {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct CreateTemplateGroupResponse {
+    #[serde(rename = "groupids")]
+    pub group_ids: Vec<String>,
+}
@@ -0,0 +1,24 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetTemplateGroupsRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(rename = "templateids")]
+    pub template_ids: Option<Vec<String>>,
+    /// Return only template groups that contain templates.
+    pub with_templates: Option<bool>,
+    #[serde(rename = "selectTemplates")]
+    pub select_templates: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct TemplateGroupFilter {
+    pub name: Option<Vec<String>>,
+}
@@ -0,0 +1,48 @@
+use serde::Serialize;
+
+use crate::template::model::ZabbixTemplateId;
+use crate::templategroup::model::ZabbixTemplateGroupId;
+
+/// Adds templates to all the given template groups.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/massadd
+#[derive(Serialize, Debug)]
+pub struct MassAddTemplateGroupsRequest {
+    pub groups: Vec<ZabbixTemplateGroupId>,
+    pub templates: Vec<ZabbixTemplateId>,
+}
+
+impl MassAddTemplateGroupsRequest {
+    pub fn new(group_ids: Vec<String>, template_ids: Vec<String>) -> Self {
+        Self {
+            groups: group_ids
+                .into_iter()
+                .map(|group_id| ZabbixTemplateGroupId { group_id })
+                .collect(),
+            templates: template_ids
+                .into_iter()
+                .map(|template_id| ZabbixTemplateId { template_id })
+                .collect(),
+        }
+    }
+}
+
+/// Removes templates from the given template groups.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/massremove
+#[derive(Serialize, Debug)]
+pub struct MassRemoveTemplateGroupsRequest {
+    #[serde(rename = "groupids")]
+    pub group_ids: Vec<String>,
+    #[serde(rename = "templateids")]
+    pub template_ids: Vec<String>,
+}
+
+impl MassRemoveTemplateGroupsRequest {
+    pub fn new(group_ids: Vec<String>, template_ids: Vec<String>) -> Self {
+        Self {
+            group_ids,
+            template_ids,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub mod create;
+pub mod get;
+pub mod mass;
+pub mod model;
+pub mod propagate;
+pub mod update;
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::template::model::ZabbixTemplate;
+
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/object
+#[skip_serializing_none]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ZabbixTemplateGroup {
+    #[serde(rename = "groupid")]
+    pub group_id: String,
+    pub name: String,
+    pub uuid: Option<String>,
+    /// Filled when requested with `selectTemplates`.
+    pub templates: Option<Vec<ZabbixTemplate>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixTemplateGroupId {
+    #[serde(rename = "groupid")]
+    pub group_id: String,
+}
+
+impl From<ZabbixTemplateGroup> for ZabbixTemplateGroupId {
+    fn from(value: ZabbixTemplateGroup) -> Self {
+        ZabbixTemplateGroupId {
+            group_id: value.group_id,
+        }
+    }
+}
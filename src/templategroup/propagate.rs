@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+use crate::templategroup::model::ZabbixTemplateGroupId;
+
+/// Propagates permissions of template groups to their subgroups.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/propagate
+#[derive(Serialize, Debug)]
+pub struct PropagateTemplateGroupsRequest {
+    pub groups: Vec<ZabbixTemplateGroupId>,
+    /// Apply permissions of the parent group to all subgroups.
+    pub permissions: bool,
+}
+
+impl PropagateTemplateGroupsRequest {
+    pub fn new(group_ids: Vec<String>) -> Self {
+        Self {
+            groups: group_ids
+                .into_iter()
+                .map(|group_id| ZabbixTemplateGroupId { group_id })
+                .collect(),
+            permissions: true,
+        }
+    }
+}
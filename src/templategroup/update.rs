@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/templategroup/update
+#[derive(Serialize, Debug)]
+pub struct UpdateTemplateGroupRequest {
+    #[serde(rename = "groupid")]
+    pub group_id: String,
+    pub name: String,
+}
+
+impl UpdateTemplateGroupRequest {
+    /// Creates a request renaming the template group with the given ID.
+    pub fn rename(group_id: impl ToString, name: impl ToString) -> Self {
+        Self {
+            group_id: group_id.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Shared by `templategroup.update`, `templategroup.delete`, `templategroup.mass*`
+/// and `templategroup.propagate`, they all return the affected group IDs.
+#[derive(Deserialize, Debug)]
+pub struct UpdateTemplateGroupResponse {
+    #[serde(rename = "groupids")]
+    pub group_ids: Vec<String>,
+}
@@ -30,6 +30,13 @@ pub struct TestEnvBuilder {
     pub latest_item_id: u32,
     pub latest_trigger_id: u32,
     pub latest_webscenario_id: u32,
+    #[cfg(feature = "v7")]
+    pub latest_template_group_id: u32,
+    pub latest_template_id: u32,
+    #[cfg(feature = "discovery")]
+    pub latest_discovery_rule_id: u32,
+    #[cfg(feature = "discovery")]
+    pub latest_item_prototype_id: u32,
 }
 
 impl TestEnvBuilder {
@@ -50,6 +57,13 @@ impl TestEnvBuilder {
             latest_item_id: 0,
             latest_trigger_id: 0,
             latest_webscenario_id: 0,
+            #[cfg(feature = "v7")]
+            latest_template_group_id: 0,
+            latest_template_id: 0,
+            #[cfg(feature = "discovery")]
+            latest_discovery_rule_id: 0,
+            #[cfg(feature = "discovery")]
+            latest_item_prototype_id: 0,
         }
     }
 
@@ -153,7 +167,11 @@ impl TestEnvBuilder {
                             "host: {:?}, update_host: {:?}",
                             host.status, update_host.status
                         );
-                        if host.status != update_host.status {
+                        if update_host
+                            .status
+                            .as_ref()
+                            .is_some_and(|status| status != &host.status)
+                        {
                             error!("host update error: {}", "host status not updated");
                             panic!("host status not updated");
                         }
@@ -204,6 +222,8 @@ impl TestEnvBuilder {
             interface_id: "0".to_string(),
             tags: vec![],
             delay: "60s".to_string(),
+            preprocessing: vec![],
+            ..Default::default()
         };
 
         match &self.client.create_item(&self.session, &params) {
@@ -280,4 +300,139 @@ impl TestEnvBuilder {
             }
         }
     }
+
+    #[cfg(feature = "v7")]
+    pub fn create_template_group(&mut self, name: &str) -> &mut Self {
+        use crate::templategroup::create::CreateTemplateGroupRequest;
+
+        let request = CreateTemplateGroupRequest {
+            name: name.to_string(),
+        };
+
+        match &self.client.create_template_group(&self.session, &request) {
+            Ok(template_group_id) => {
+                self.latest_template_group_id = template_group_id.to_owned();
+                self
+            }
+            Err(e) => {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("template group create error: {}", e);
+                panic!("{}", e)
+            }
+        }
+    }
+
+    /// Creates a group which can hold templates: template group for Zabbix 6.2+,
+    /// host group for earlier versions.
+    pub fn create_template_owner_group(&mut self, name: &str) -> &mut Self {
+        #[cfg(feature = "v7")]
+        return self.create_template_group(name);
+
+        #[cfg(feature = "v6")]
+        return self.create_host_group(name);
+    }
+
+    pub fn create_template(&mut self, name: &str, parent_template_id: Option<&str>) -> &mut Self {
+        use crate::template::create::CreateTemplateRequest;
+
+        #[cfg(feature = "v7")]
+        let group_id = self.latest_template_group_id.to_string();
+        #[cfg(feature = "v6")]
+        let group_id = self.latest_host_group_id.to_string();
+
+        let mut builder = CreateTemplateRequest::builder(name)
+            .group(&group_id)
+            .tag("class", "test");
+
+        if let Some(parent_template_id) = parent_template_id {
+            builder = builder.template(parent_template_id);
+        }
+
+        let request = builder.build();
+
+        match &self.client.create_template(&self.session, &request) {
+            Ok(template_id) => {
+                self.latest_template_id = template_id.to_owned();
+                self
+            }
+            Err(e) => {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("template create error: {}", e);
+                panic!("{}", e)
+            }
+        }
+    }
+
+    /// Creates a trapper discovery rule on the latest host.
+    #[cfg(feature = "discovery")]
+    pub fn create_discovery_rule(&mut self, key: &str) -> &mut Self {
+        use crate::discoveryrule::create::CreateDiscoveryRuleRequest;
+        use crate::discoveryrule::model::{LldFilter, LldFilterCondition};
+        use crate::item::model::ItemType;
+
+        let request = CreateDiscoveryRuleRequest::builder(
+            self.latest_host_id,
+            format!("Discovery {key}"),
+            key,
+            ItemType::ZabbixTrapper,
+        )
+        .macro_path("{#NAME}", "$.name")
+        .filter(LldFilter::and_or(vec![LldFilterCondition::matches(
+            "{#NAME}", ".+",
+        )]))
+        .build();
+
+        match &self.client.create_discovery_rule(&self.session, &request) {
+            Ok(rule_id) => {
+                self.latest_discovery_rule_id = rule_id.to_owned();
+                self
+            }
+            Err(e) => {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("discovery rule create error: {}", e);
+                panic!("{}", e)
+            }
+        }
+    }
+
+    #[cfg(feature = "discovery")]
+    pub fn create_item_prototype(&mut self, key: &str) -> &mut Self {
+        use crate::item::model::ItemValueType;
+        use crate::itemprototype::create::CreateItemPrototypeRequest;
+
+        let item = CreateItemRequest::trapper(
+            self.latest_host_id,
+            format!("Value of {{#NAME}} {key}"),
+            format!("{key}[{{#NAME}}]"),
+            ItemValueType::Unsigned,
+        )
+        .build()
+        .unwrap();
+
+        let request = CreateItemPrototypeRequest::new(self.latest_discovery_rule_id, item);
+
+        match &self.client.create_item_prototype(&self.session, &request) {
+            Ok(item_id) => {
+                self.latest_item_prototype_id = item_id.to_owned();
+                self
+            }
+            Err(e) => {
+                if let Some(inner_source) = e.source() {
+                    println!("Caused by: {}", inner_source);
+                }
+
+                error!("item prototype create error: {}", e);
+                panic!("{}", e)
+            }
+        }
+    }
 }
@@ -0,0 +1,57 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Unix timestamp in seconds, as a string.
+pub(crate) fn deserialize_timestamp<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let seconds = String::deserialize(deserializer)?
+        .parse::<u64>()
+        .map_err(serde::de::Error::custom)?;
+
+    Ok(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+/// Unix timestamp where `0` stands for no time.
+pub(crate) fn deserialize_optional_timestamp<'de, D>(
+    deserializer: D,
+) -> Result<Option<SystemTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let timestamp = deserialize_timestamp(deserializer)?;
+
+    Ok((timestamp != UNIX_EPOCH).then_some(timestamp))
+}
+
+/// Unix timestamp in seconds for optional request parameters.
+pub(crate) fn serialize_timestamp<S>(
+    timestamp: &Option<SystemTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match timestamp {
+        Some(timestamp) => serialize_required_timestamp(timestamp, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Unix timestamp in seconds for request parameters.
+pub(crate) fn serialize_required_timestamp<S>(
+    timestamp: &SystemTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let seconds = timestamp
+        .duration_since(UNIX_EPOCH)
+        .map_err(serde::ser::Error::custom)?
+        .as_secs();
+
+    serializer.serialize_u64(seconds)
+}
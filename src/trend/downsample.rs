@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::trend::model::{TrendValue, ZabbixTrend};
+
+const DAY: u64 = 24 * 3600;
+const WEEK: u64 = 7 * DAY;
+/// The Unix epoch was a Thursday, the first Monday is four days later.
+const FIRST_MONDAY: u64 = 4 * DAY;
+
+/// Coarser bucket for trends. Buckets are aligned to UTC.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum TrendBucket {
+    Day,
+    /// Week starting on Monday.
+    Week,
+}
+
+impl TrendBucket {
+    /// Start of the bucket which contains the given time. Times before the Unix epoch
+    /// fall into the bucket of the epoch, the first week starts on 1969-12-29.
+    pub fn start(self, time: SystemTime) -> SystemTime {
+        let seconds = time
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        let since_start = match self {
+            TrendBucket::Day => seconds % DAY,
+            // Shifted by a week, so times before the first Monday stay in its previous week
+            TrendBucket::Week => (seconds + WEEK - FIRST_MONDAY) % WEEK,
+        };
+
+        UNIX_EPOCH + Duration::from_secs(seconds) - Duration::from_secs(since_start)
+    }
+}
+
+/// Merges hourly trends into day or week buckets, per item.
+///
+/// Minimum and maximum are taken over the bucket, the average is weighted by the number of
+/// values. Results are ordered by item ID and time.
+pub fn downsample_trends<T: TrendValue>(
+    trends: &[ZabbixTrend<T>],
+    bucket: TrendBucket,
+) -> Vec<ZabbixTrend<T>> {
+    let mut buckets: BTreeMap<(&str, SystemTime), (ZabbixTrend<T>, f64)> = BTreeMap::new();
+
+    for trend in trends {
+        let key = (trend.item_id.as_str(), bucket.start(trend.clock));
+        let weighted_sum = trend.value_avg.to_f64() * trend.num as f64;
+
+        match buckets.get_mut(&key) {
+            Some((merged, sum)) => {
+                merged.num += trend.num;
+                *sum += weighted_sum;
+
+                if trend.value_min < merged.value_min {
+                    merged.value_min = trend.value_min;
+                }
+
+                if trend.value_max > merged.value_max {
+                    merged.value_max = trend.value_max;
+                }
+            }
+            None => {
+                let merged = ZabbixTrend {
+                    clock: key.1,
+                    ..trend.clone()
+                };
+
+                buckets.insert(key, (merged, weighted_sum));
+            }
+        }
+    }
+
+    buckets
+        .into_values()
+        .map(|(mut merged, sum)| {
+            if merged.num > 0 {
+                merged.value_avg = T::from_f64(sum / merged.num as f64);
+            }
+
+            merged
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod downsample_tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use super::{downsample_trends, TrendBucket};
+    use crate::trend::model::ZabbixTrend;
+
+    fn at(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    fn trend<T>(item_id: &str, clock: u64, num: u32, min: T, avg: T, max: T) -> ZabbixTrend<T> {
+        ZabbixTrend {
+            item_id: item_id.to_string(),
+            clock: at(clock),
+            num,
+            value_min: min,
+            value_avg: avg,
+            value_max: max,
+        }
+    }
+
+    #[test]
+    fn bucket_start_is_aligned() {
+        // 2024-01-03 (Wednesday) 15:00 UTC
+        let time = at(1704294000);
+
+        // 2024-01-03 00:00 UTC
+        assert_eq!(TrendBucket::Day.start(time), at(1704240000));
+        // 2024-01-01 (Monday) 00:00 UTC
+        assert_eq!(TrendBucket::Week.start(time), at(1704067200));
+    }
+
+    #[test]
+    fn first_weeks_start_on_monday() {
+        let monday_before_epoch = UNIX_EPOCH - Duration::from_secs(3 * 24 * 3600);
+
+        // 1970-01-02 (Friday)
+        assert_eq!(TrendBucket::Week.start(at(86400)), monday_before_epoch);
+        // 1970-01-05 (Monday) 10:00 UTC
+        assert_eq!(TrendBucket::Week.start(at(381600)), at(345600));
+    }
+
+    #[test]
+    fn daily_buckets_use_weighted_average() {
+        let day = 1704240000;
+
+        let trends = vec![
+            trend("2", day, 60, 1.0, 2.0, 3.0),
+            trend("1", day, 60, 1.0, 2.0, 3.0),
+            trend("1", day + 3600, 20, 0.5, 6.0, 9.0),
+            trend("1", day + 86400, 60, 4.0, 4.0, 4.0),
+        ];
+
+        let merged = downsample_trends(&trends, TrendBucket::Day);
+
+        assert_eq!(merged.len(), 3);
+
+        assert_eq!(merged[0].item_id, "1");
+        assert_eq!(merged[0].clock, at(day));
+        assert_eq!(merged[0].num, 80);
+        assert_eq!(merged[0].value_min, 0.5);
+        assert_eq!(merged[0].value_avg, 3.0);
+        assert_eq!(merged[0].value_max, 9.0);
+
+        assert_eq!(merged[1].clock, at(day + 86400));
+        assert_eq!(merged[2].item_id, "2");
+    }
+
+    #[test]
+    fn weekly_buckets_of_unsigned_values() {
+        let monday = 1704067200;
+
+        let trends = vec![
+            trend("1", monday, 1, 10_u64, 10, 10),
+            trend("1", monday + 6 * 86400, 2, 1, 5, 7),
+            trend("1", monday + 7 * 86400, 1, 3, 3, 3),
+        ];
+
+        let merged = downsample_trends(&trends, TrendBucket::Week);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].num, 3);
+        assert_eq!(merged[0].value_min, 1);
+        assert_eq!(merged[0].value_avg, 7);
+        assert_eq!(merged[0].value_max, 10);
+        assert_eq!(merged[1].clock, at(monday + 7 * 86400));
+    }
+}
@@ -0,0 +1,20 @@
+use std::time::SystemTime;
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::timestamp::serialize_timestamp;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trend/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GetTrendsRequest {
+    pub output: Option<String>,
+    #[serde(rename = "itemids")]
+    pub item_ids: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_from: Option<SystemTime>,
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub time_till: Option<SystemTime>,
+    pub limit: Option<u32>,
+}
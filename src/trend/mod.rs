@@ -0,0 +1,3 @@
+pub mod downsample;
+pub mod get;
+pub mod model;
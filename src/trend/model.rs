@@ -0,0 +1,87 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+use std::time::SystemTime;
+
+use serde::Deserialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::de::deserialize_value;
+use crate::timestamp::deserialize_timestamp;
+
+/// Trend value type: `f64` for float items, `u64` for unsigned items.
+pub trait TrendValue: FromStr<Err: Display> + PartialOrd + Copy + Debug {
+    fn to_f64(self) -> f64;
+
+    fn from_f64(value: f64) -> Self;
+}
+
+impl TrendValue for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+impl TrendValue for u64 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    /// Zabbix stores unsigned averages as integers too.
+    fn from_f64(value: f64) -> Self {
+        value.round() as u64
+    }
+}
+
+/// Hourly aggregate of a numeric item.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trend/object
+#[serde_as]
+#[derive(Deserialize, PartialEq, Clone, Debug)]
+#[serde(bound(deserialize = "T: FromStr, T::Err: Display"))]
+pub struct ZabbixTrend<T> {
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+    /// Start of the hour.
+    #[serde(deserialize_with = "deserialize_timestamp")]
+    pub clock: SystemTime,
+    /// Number of values within the hour.
+    #[serde_as(as = "DisplayFromStr")]
+    pub num: u32,
+    #[serde(deserialize_with = "deserialize_value")]
+    pub value_min: T,
+    #[serde(deserialize_with = "deserialize_value")]
+    pub value_avg: T,
+    #[serde(deserialize_with = "deserialize_value")]
+    pub value_max: T,
+}
+
+#[cfg(test)]
+mod trend_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::ZabbixTrend;
+
+    #[test]
+    fn deserialize_test() {
+        let input = r#"[{"itemid":"23715","clock":"1446199200","num":"60","value_min":"0.165","value_avg":"0.2168","value_max":"0.35"}]"#;
+
+        let trends: Vec<ZabbixTrend<f64>> = serde_json::from_str(input).unwrap();
+
+        assert_eq!(trends[0].num, 60);
+        assert_eq!(trends[0].value_avg, 0.2168);
+        assert_eq!(
+            trends[0].clock,
+            UNIX_EPOCH + Duration::from_secs(1446199200)
+        );
+
+        let input = r#"[{"itemid":"23716","clock":"1446199200","num":"60","value_min":"1","value_avg":"5","value_max":"12"}]"#;
+
+        let trends: Vec<ZabbixTrend<u64>> = serde_json::from_str(input).unwrap();
+
+        assert_eq!(trends[0].value_max, 12);
+    }
+}
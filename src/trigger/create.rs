@@ -17,12 +17,21 @@ pub struct CreateTriggerRequest {
     pub tags: Vec<ZabbixTriggerTag>,
 }
 
-#[derive(Serialize, Debug, Clone)]
+/// Reference to a trigger that another trigger depends on.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub struct ZabbixTriggerDependency {
-    #[serde(alias = "triggerid")]
+    #[serde(rename = "triggerid")]
     pub trigger_id: String,
 }
 
+impl ZabbixTriggerDependency {
+    pub fn new(trigger_id: impl ToString) -> Self {
+        Self {
+            trigger_id: trigger_id.to_string(),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct CreateTriggerResponse {
     #[serde(rename = "triggerids")]
@@ -0,0 +1,37 @@
+use serde::Serialize;
+
+/// Makes a trigger depend on another one: no problem is raised for `trigger`
+/// while `depends_on_trigger_id` is in the problem state.
+///
+/// API: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/trigger/adddependencies
+#[derive(Serialize, Debug, Clone)]
+pub struct AddTriggerDependencyRequest {
+    #[serde(rename = "triggerid")]
+    pub trigger_id: String,
+    #[serde(rename = "dependsOnTriggerid")]
+    pub depends_on_trigger_id: String,
+}
+
+impl AddTriggerDependencyRequest {
+    pub fn new(trigger_id: impl ToString, depends_on_trigger_id: impl ToString) -> Self {
+        Self {
+            trigger_id: trigger_id.to_string(),
+            depends_on_trigger_id: depends_on_trigger_id.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod dependency_tests {
+    use super::AddTriggerDependencyRequest;
+
+    #[test]
+    fn serialization_test() {
+        let request = AddTriggerDependencyRequest::new("14544", "13998");
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["triggerid"], "14544");
+        assert_eq!(json["dependsOnTriggerid"], "13998");
+    }
+}
@@ -0,0 +1,771 @@
+//! Typed builder for trigger expressions in the Zabbix 5.4+ syntax.
+//!
+//! ```
+//! use zabbix_api::trigger::expression::{ItemRef, Period, TriggerExpression};
+//!
+//! let load = ItemRef::new("Zabbix server", "system.cpu.load").param("all").param("avg1");
+//!
+//! let problem = TriggerExpression::last(load.clone())
+//!     .gt(5)
+//!     .and(TriggerExpression::min(load.clone().over(Period::duration("10m"))).gt(2));
+//!
+//! assert_eq!(
+//!     problem.to_string(),
+//!     "last(/Zabbix server/system.cpu.load[all,avg1])>5 and min(/Zabbix server/system.cpu.load[all,avg1],10m)>2"
+//! );
+//!
+//! let recovery = TriggerExpression::max(load.over(Period::duration("10m"))).lt(1);
+//!
+//! assert_eq!(recovery.to_string(), "max(/Zabbix server/system.cpu.load[all,avg1],10m)<1");
+//! ```
+//!
+//! API: https://www.zabbix.com/documentation/7.0/en/manual/config/triggers/expression
+
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Not, Sub};
+
+/// Item referenced by a trigger function, rendered as `/host/key[params]`.
+///
+/// Key parameters are quoted when needed, so they can be passed as they are.
+/// Calculated items may leave the host empty for the current host, use `*`
+/// wildcards and add an item filter: `/*/key?[group="Servers"]`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ItemRef {
+    pub host: String,
+    pub key: String,
+    pub params: Vec<String>,
+    /// Item filter of aggregate functions, without the `?[` `]` brackets.
+    pub filter: Option<String>,
+}
+
+impl ItemRef {
+    /// `key` is used as is. Use [`ItemRef::param`] to add parameters which need quoting.
+    pub fn new(host: impl ToString, key: impl ToString) -> Self {
+        Self {
+            host: host.to_string(),
+            key: key.to_string(),
+            params: vec![],
+            filter: None,
+        }
+    }
+
+    pub fn param(mut self, param: impl ToString) -> Self {
+        self.params.push(param.to_string());
+        self
+    }
+
+    /// Item filter like `group="Servers" and tag="db"`.
+    pub fn filter(mut self, filter: impl ToString) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Item key with parameters, as in `ZabbixItem.key_`.
+    pub fn full_key(&self) -> String {
+        if self.params.is_empty() {
+            self.key.clone()
+        } else {
+            let params: Vec<String> = self.params.iter().map(|p| quote_key_param(p)).collect();
+            format!("{}[{}]", self.key, params.join(","))
+        }
+    }
+
+    /// Evaluates the function over the given period, e.g. `avg(/host/key,5m)`.
+    pub fn over(self, period: Period) -> ItemQuery {
+        ItemQuery {
+            item: self,
+            period: Some(period),
+        }
+    }
+}
+
+impl Display for ItemRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "/{}/{}", self.host, self.full_key())?;
+
+        if let Some(filter) = &self.filter {
+            write!(f, "?[{filter}]")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// First function parameter: an item and the optional evaluation period.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ItemQuery {
+    pub item: ItemRef,
+    pub period: Option<Period>,
+}
+
+impl From<ItemRef> for ItemQuery {
+    fn from(item: ItemRef) -> Self {
+        Self { item, period: None }
+    }
+}
+
+impl Display for ItemQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.item)?;
+
+        if let Some(period) = &self.period {
+            write!(f, ",{period}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Evaluation period of a function: a time period like `5m` or the number of
+/// latest values like `#3`, optionally shifted back in time, e.g. `1h:now-1d`.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Period {
+    pub value: PeriodValue,
+    pub time_shift: Option<String>,
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum PeriodValue {
+    /// Time period with an optional suffix, e.g. `300`, `5m` or `{$PERIOD}`.
+    Duration(String),
+    /// Number of latest values, rendered as `#N`.
+    Count(u32),
+}
+
+impl Period {
+    pub fn seconds(seconds: u64) -> Self {
+        Self::duration(seconds)
+    }
+
+    pub fn duration(duration: impl ToString) -> Self {
+        Self {
+            value: PeriodValue::Duration(duration.to_string()),
+            time_shift: None,
+        }
+    }
+
+    pub fn count(count: u32) -> Self {
+        Self {
+            value: PeriodValue::Count(count),
+            time_shift: None,
+        }
+    }
+
+    /// Time shift like `now-1d` or `now/w`.
+    pub fn shift(mut self, time_shift: impl ToString) -> Self {
+        self.time_shift = Some(time_shift.to_string());
+        self
+    }
+}
+
+impl Display for Period {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            PeriodValue::Duration(duration) => write!(f, "{duration}")?,
+            PeriodValue::Count(count) => write!(f, "#{count}")?,
+        }
+
+        if let Some(time_shift) = &self.time_shift {
+            write!(f, ":{time_shift}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Function parameter.
+#[derive(PartialEq, Clone, Debug)]
+pub enum FunctionArg {
+    Query(ItemQuery),
+    Value(TriggerExpression),
+    /// Omitted optional parameter, e.g. the period in `logsource(/host/key,,"app")`.
+    Empty,
+}
+
+impl From<ItemRef> for FunctionArg {
+    fn from(item: ItemRef) -> Self {
+        FunctionArg::Query(item.into())
+    }
+}
+
+impl From<ItemQuery> for FunctionArg {
+    fn from(query: ItemQuery) -> Self {
+        FunctionArg::Query(query)
+    }
+}
+
+impl From<TriggerExpression> for FunctionArg {
+    fn from(expression: TriggerExpression) -> Self {
+        FunctionArg::Value(expression)
+    }
+}
+
+impl Display for FunctionArg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FunctionArg::Query(query) => write!(f, "{query}"),
+            FunctionArg::Value(expression) => write!(f, "{expression}"),
+            FunctionArg::Empty => Ok(()),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum UnaryOperator {
+    Minus,
+    Not,
+}
+
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum BinaryOperator {
+    Multiply,
+    Divide,
+    Add,
+    Subtract,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+    And,
+    Or,
+}
+
+impl BinaryOperator {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Less => "<",
+            BinaryOperator::LessOrEqual => "<=",
+            BinaryOperator::Greater => ">",
+            BinaryOperator::GreaterOrEqual => ">=",
+            BinaryOperator::Equal => "=",
+            BinaryOperator::NotEqual => "<>",
+            BinaryOperator::And => "and",
+            BinaryOperator::Or => "or",
+        }
+    }
+
+    /// Lower binds tighter.
+    fn precedence(&self) -> u8 {
+        match self {
+            BinaryOperator::Multiply | BinaryOperator::Divide => 3,
+            BinaryOperator::Add | BinaryOperator::Subtract => 4,
+            BinaryOperator::Less
+            | BinaryOperator::LessOrEqual
+            | BinaryOperator::Greater
+            | BinaryOperator::GreaterOrEqual => 5,
+            BinaryOperator::Equal | BinaryOperator::NotEqual => 6,
+            BinaryOperator::And => 7,
+            BinaryOperator::Or => 8,
+        }
+    }
+}
+
+/// Trigger expression tree. Renders to the expression string with [`Display`],
+/// adding parentheses only where operator precedence requires them.
+#[derive(PartialEq, Clone, Debug)]
+pub enum TriggerExpression {
+    /// Number with an optional suffix, e.g. `5`, `0.5`, `10M` or `1h`.
+    Number(String),
+    /// String constant, quoted and escaped on rendering.
+    String(String),
+    /// Macro used as is, e.g. `{$LOAD.MAX}`, `{$LOAD.MAX:"cpu0"}` or `{#FSNAME}`.
+    Macro(String),
+    Function {
+        name: String,
+        args: Vec<FunctionArg>,
+    },
+    Unary {
+        operator: UnaryOperator,
+        operand: Box<TriggerExpression>,
+    },
+    Binary {
+        operator: BinaryOperator,
+        left: Box<TriggerExpression>,
+        right: Box<TriggerExpression>,
+    },
+}
+
+impl TriggerExpression {
+    /// Number given as text, for values with suffixes like `10M` or `5m`.
+    pub fn number(value: impl ToString) -> Self {
+        TriggerExpression::Number(value.to_string())
+    }
+
+    pub fn string(value: impl ToString) -> Self {
+        TriggerExpression::String(value.to_string())
+    }
+
+    /// User macro, `name` without braces, e.g. `LOAD.MAX` for `{$LOAD.MAX}`.
+    pub fn user_macro(name: impl ToString) -> Self {
+        TriggerExpression::Macro(format!("{{${}}}", name.to_string()))
+    }
+
+    /// User macro with context, e.g. `{$LOW.SPACE.LIMIT:"/tmp"}`.
+    pub fn user_macro_with_context(name: impl ToString, context: impl ToString) -> Self {
+        TriggerExpression::Macro(format!(
+            "{{${}:{}}}",
+            name.to_string(),
+            quote_string(&context.to_string())
+        ))
+    }
+
+    /// Low-level discovery macro, `name` without braces, e.g. `FSNAME` for `{#FSNAME}`.
+    pub fn lld_macro(name: impl ToString) -> Self {
+        TriggerExpression::Macro(format!("{{#{}}}", name.to_string()))
+    }
+
+    /// Any function call, e.g. `count(/host/key,5m,"gt",10)` or `dayofweek()`.
+    pub fn function(name: impl ToString, args: Vec<FunctionArg>) -> Self {
+        TriggerExpression::Function {
+            name: name.to_string(),
+            args,
+        }
+    }
+
+    pub fn last(query: impl Into<ItemQuery>) -> Self {
+        Self::function("last", vec![FunctionArg::Query(query.into())])
+    }
+
+    pub fn avg(query: impl Into<ItemQuery>) -> Self {
+        Self::function("avg", vec![FunctionArg::Query(query.into())])
+    }
+
+    pub fn min(query: impl Into<ItemQuery>) -> Self {
+        Self::function("min", vec![FunctionArg::Query(query.into())])
+    }
+
+    pub fn max(query: impl Into<ItemQuery>) -> Self {
+        Self::function("max", vec![FunctionArg::Query(query.into())])
+    }
+
+    pub fn sum(query: impl Into<ItemQuery>) -> Self {
+        Self::function("sum", vec![FunctionArg::Query(query.into())])
+    }
+
+    pub fn change(query: impl Into<ItemQuery>) -> Self {
+        Self::function("change", vec![FunctionArg::Query(query.into())])
+    }
+
+    /// `nodata(/host/key,period)`, true when no data was received in the period.
+    pub fn nodata(item: ItemRef, period: impl ToString) -> Self {
+        Self::function(
+            "nodata",
+            vec![FunctionArg::Query(item.over(Period::duration(period)))],
+        )
+    }
+
+    /// `find(/host/key,period,"like",pattern)` style string checks. Without a
+    /// period the parameter is left empty: `find(/host/key,,"like",pattern)`.
+    pub fn find(
+        query: impl Into<ItemQuery>,
+        operator: impl ToString,
+        pattern: impl ToString,
+    ) -> Self {
+        let query = query.into();
+        let without_period = query.period.is_none();
+
+        let mut args = vec![FunctionArg::Query(query)];
+
+        if without_period {
+            args.push(FunctionArg::Empty);
+        }
+
+        args.extend([
+            FunctionArg::Value(TriggerExpression::string(operator)),
+            FunctionArg::Value(TriggerExpression::string(pattern)),
+        ]);
+
+        Self::function("find", args)
+    }
+
+    fn binary(self, operator: BinaryOperator, right: impl Into<TriggerExpression>) -> Self {
+        TriggerExpression::Binary {
+            operator,
+            left: Box::new(self),
+            right: Box::new(right.into()),
+        }
+    }
+
+    pub fn lt(self, right: impl Into<TriggerExpression>) -> Self {
+        self.binary(BinaryOperator::Less, right)
+    }
+
+    pub fn le(self, right: impl Into<TriggerExpression>) -> Self {
+        self.binary(BinaryOperator::LessOrEqual, right)
+    }
+
+    pub fn gt(self, right: impl Into<TriggerExpression>) -> Self {
+        self.binary(BinaryOperator::Greater, right)
+    }
+
+    pub fn ge(self, right: impl Into<TriggerExpression>) -> Self {
+        self.binary(BinaryOperator::GreaterOrEqual, right)
+    }
+
+    /// `=` comparison.
+    pub fn equals(self, right: impl Into<TriggerExpression>) -> Self {
+        self.binary(BinaryOperator::Equal, right)
+    }
+
+    /// `<>` comparison.
+    pub fn not_equals(self, right: impl Into<TriggerExpression>) -> Self {
+        self.binary(BinaryOperator::NotEqual, right)
+    }
+
+    pub fn and(self, right: impl Into<TriggerExpression>) -> Self {
+        self.binary(BinaryOperator::And, right)
+    }
+
+    pub fn or(self, right: impl Into<TriggerExpression>) -> Self {
+        self.binary(BinaryOperator::Or, right)
+    }
+
+    /// Items referenced by the expression, in order of appearance.
+    pub fn item_refs(&self) -> Vec<&ItemRef> {
+        let mut refs = vec![];
+        self.visit(&mut |expression| {
+            if let TriggerExpression::Function { args, .. } = expression {
+                for arg in args {
+                    if let FunctionArg::Query(query) = arg {
+                        refs.push(&query.item);
+                    }
+                }
+            }
+        });
+        refs
+    }
+
+    /// Points item references of host `from` to host `to`, e.g. when cloning a trigger.
+    pub fn replace_host(&mut self, from: &str, to: &str) {
+        self.visit_mut(&mut |expression| {
+            if let TriggerExpression::Function { args, .. } = expression {
+                for arg in args.iter_mut() {
+                    if let FunctionArg::Query(query) = arg {
+                        if query.item.host == from {
+                            query.item.host = to.to_string();
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Calls `f` for this node and all nested nodes, parents first.
+    pub fn visit<'a>(&'a self, f: &mut impl FnMut(&'a TriggerExpression)) {
+        f(self);
+
+        match self {
+            TriggerExpression::Function { args, .. } => {
+                for arg in args {
+                    if let FunctionArg::Value(value) = arg {
+                        value.visit(f);
+                    }
+                }
+            }
+            TriggerExpression::Unary { operand, .. } => operand.visit(f),
+            TriggerExpression::Binary { left, right, .. } => {
+                left.visit(f);
+                right.visit(f);
+            }
+            _ => {}
+        }
+    }
+
+    /// Mutable version of [`TriggerExpression::visit`].
+    pub fn visit_mut(&mut self, f: &mut impl FnMut(&mut TriggerExpression)) {
+        f(self);
+
+        match self {
+            TriggerExpression::Function { args, .. } => {
+                for arg in args.iter_mut() {
+                    if let FunctionArg::Value(value) = arg {
+                        value.visit_mut(f);
+                    }
+                }
+            }
+            TriggerExpression::Unary { operand, .. } => operand.visit_mut(f),
+            TriggerExpression::Binary { left, right, .. } => {
+                left.visit_mut(f);
+                right.visit_mut(f);
+            }
+            _ => {}
+        }
+    }
+
+    /// Lower binds tighter, `0` is an operand.
+    fn precedence(&self) -> u8 {
+        match self {
+            TriggerExpression::Number(value) if value.starts_with('-') => 1,
+            TriggerExpression::Unary {
+                operator: UnaryOperator::Minus,
+                ..
+            } => 1,
+            TriggerExpression::Unary {
+                operator: UnaryOperator::Not,
+                ..
+            } => 2,
+            TriggerExpression::Binary { operator, .. } => operator.precedence(),
+            _ => 0,
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut Formatter<'_>, parenthesize: bool) -> std::fmt::Result {
+        if parenthesize {
+            write!(f, "({self})")
+        } else {
+            write!(f, "{self}")
+        }
+    }
+}
+
+impl Display for TriggerExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriggerExpression::Number(value) => write!(f, "{value}"),
+            TriggerExpression::String(value) => write!(f, "{}", quote_string(value)),
+            TriggerExpression::Macro(value) => write!(f, "{value}"),
+            TriggerExpression::Function { name, args } => {
+                let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+                write!(f, "{name}({})", args.join(","))
+            }
+            TriggerExpression::Unary { operator, operand } => {
+                match operator {
+                    UnaryOperator::Minus => write!(f, "-")?,
+                    UnaryOperator::Not => write!(f, "not ")?,
+                }
+                // Nested unary operators are parenthesized too, `--1` is not valid
+                operand.fmt_operand(f, operand.precedence() != 0)
+            }
+            TriggerExpression::Binary {
+                operator,
+                left,
+                right,
+            } => {
+                let precedence = operator.precedence();
+
+                left.fmt_operand(f, left.precedence() > precedence)?;
+
+                match operator {
+                    BinaryOperator::And | BinaryOperator::Or => {
+                        write!(f, " {} ", operator.symbol())?
+                    }
+                    _ => write!(f, "{}", operator.symbol())?,
+                }
+
+                // Operators are left-associative, so `a-(b-c)` keeps its parentheses,
+                // and `a-(-b)` avoids the `--` sequence
+                let sign_after_sign = right.precedence() == 1
+                    && matches!(operator, BinaryOperator::Add | BinaryOperator::Subtract);
+
+                right.fmt_operand(f, right.precedence() >= precedence || sign_after_sign)
+            }
+        }
+    }
+}
+
+macro_rules! number_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for TriggerExpression {
+                fn from(value: $t) -> Self {
+                    TriggerExpression::Number(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+number_from!(i32, i64, u32, u64, f64);
+
+impl Add for TriggerExpression {
+    type Output = TriggerExpression;
+
+    fn add(self, right: TriggerExpression) -> TriggerExpression {
+        self.binary(BinaryOperator::Add, right)
+    }
+}
+
+impl Sub for TriggerExpression {
+    type Output = TriggerExpression;
+
+    fn sub(self, right: TriggerExpression) -> TriggerExpression {
+        self.binary(BinaryOperator::Subtract, right)
+    }
+}
+
+impl Mul for TriggerExpression {
+    type Output = TriggerExpression;
+
+    fn mul(self, right: TriggerExpression) -> TriggerExpression {
+        self.binary(BinaryOperator::Multiply, right)
+    }
+}
+
+impl Div for TriggerExpression {
+    type Output = TriggerExpression;
+
+    fn div(self, right: TriggerExpression) -> TriggerExpression {
+        self.binary(BinaryOperator::Divide, right)
+    }
+}
+
+impl Neg for TriggerExpression {
+    type Output = TriggerExpression;
+
+    fn neg(self) -> TriggerExpression {
+        TriggerExpression::Unary {
+            operator: UnaryOperator::Minus,
+            operand: Box::new(self),
+        }
+    }
+}
+
+impl Not for TriggerExpression {
+    type Output = TriggerExpression;
+
+    fn not(self) -> TriggerExpression {
+        TriggerExpression::Unary {
+            operator: UnaryOperator::Not,
+            operand: Box::new(self),
+        }
+    }
+}
+
+/// String constants and function parameters: `"` and `\` are escaped with `\`.
+fn quote_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Item key parameters are quoted only when they contain `,` or `]`, or start
+/// with `"`, `[` or a space. Inside quotes only `"` is escaped.
+fn quote_key_param(param: &str) -> String {
+    let needs_quotes = param.contains([',', ']']) || param.starts_with(['"', '[', ' ']);
+
+    if needs_quotes {
+        format!("\"{}\"", param.replace('"', "\\\""))
+    } else {
+        param.to_string()
+    }
+}
+
+#[cfg(test)]
+mod expression_tests {
+    use super::{FunctionArg, ItemRef, Period, TriggerExpression};
+
+    #[test]
+    fn item_key_params_are_quoted_when_needed() {
+        let item = ItemRef::new("web01", "web.page.regexp")
+            .param("example.com")
+            .param("")
+            .param("/api")
+            .param("status: \"ok\", code [0-9]+");
+
+        assert_eq!(
+            TriggerExpression::last(item).to_string(),
+            r#"last(/web01/web.page.regexp[example.com,,/api,"status: \"ok\", code [0-9]+"])"#
+        );
+    }
+
+    #[test]
+    fn periods_are_rendered() {
+        let item = ItemRef::new("db", "mysql.qps");
+
+        assert_eq!(
+            TriggerExpression::avg(item.clone().over(Period::duration("1h").shift("now-1d")))
+                .to_string(),
+            "avg(/db/mysql.qps,1h:now-1d)"
+        );
+        assert_eq!(
+            TriggerExpression::last(item.over(Period::count(3))).to_string(),
+            "last(/db/mysql.qps,#3)"
+        );
+    }
+
+    #[test]
+    fn function_string_params_are_escaped() {
+        let expression = TriggerExpression::find(
+            ItemRef::new("srv", "log[/var/log/app.log]").over(Period::seconds(300)),
+            "regexp",
+            r#"error "\d+""#,
+        )
+        .equals(1);
+
+        assert_eq!(
+            expression.to_string(),
+            r#"find(/srv/log[/var/log/app.log],300,"regexp","error \"\\d+\"")=1"#
+        );
+    }
+
+    #[test]
+    fn find_without_period_leaves_period_empty() {
+        let expression = TriggerExpression::find(ItemRef::new("h", "log"), "like", "err");
+
+        assert_eq!(expression.to_string(), r#"find(/h/log,,"like","err")"#);
+    }
+
+    #[test]
+    fn parentheses_follow_precedence() {
+        let free =
+            TriggerExpression::last(ItemRef::new("srv", "vfs.fs.size").param("/").param("free"));
+        let total =
+            TriggerExpression::last(ItemRef::new("srv", "vfs.fs.size").param("/").param("total"));
+
+        let expression = (free.clone() / total.clone() * TriggerExpression::from(100))
+            .lt(TriggerExpression::user_macro_with_context(
+                "VFS.FS.PFREE.MIN",
+                "/",
+            ))
+            .and(
+                free.clone()
+                    .lt(TriggerExpression::number("5G"))
+                    .or(total.clone().lt(10)),
+            );
+
+        assert_eq!(
+            expression.to_string(),
+            r#"last(/srv/vfs.fs.size[/,free])/last(/srv/vfs.fs.size[/,total])*100<{$VFS.FS.PFREE.MIN:"/"} and (last(/srv/vfs.fs.size[/,free])<5G or last(/srv/vfs.fs.size[/,total])<10)"#
+        );
+
+        let expression = free.clone() - (total.clone() - TriggerExpression::from(1));
+        assert_eq!(
+            expression.to_string(),
+            "last(/srv/vfs.fs.size[/,free])-(last(/srv/vfs.fs.size[/,total])-1)"
+        );
+
+        let expression = !free.equals(0);
+        assert_eq!(
+            expression.to_string(),
+            "not (last(/srv/vfs.fs.size[/,free])=0)"
+        );
+
+        let expression = -total - TriggerExpression::from(-1);
+        assert_eq!(
+            expression.to_string(),
+            "-last(/srv/vfs.fs.size[/,total])-(-1)"
+        );
+    }
+
+    #[test]
+    fn nested_functions_and_macros() {
+        let expression = TriggerExpression::function(
+            "abs",
+            vec![FunctionArg::Value(TriggerExpression::change(
+                ItemRef::new("{HOST.HOST}", "net.if.in")
+                    .param(TriggerExpression::lld_macro("IFNAME")),
+            ))],
+        )
+        .gt(TriggerExpression::user_macro("IF.DELTA.MAX"))
+        .and(TriggerExpression::function("dayofweek", vec![]).le(5));
+
+        assert_eq!(
+            expression.to_string(),
+            "abs(change(/{HOST.HOST}/net.if.in[{#IFNAME}]))>{$IF.DELTA.MAX} and dayofweek()<=5"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use serde::Serialize;
+use serde_with::skip_serializing_none;
 
 use crate::ZABBIX_EXTEND_PROPERTY_VALUE;
 
@@ -38,3 +39,85 @@ impl GetTriggerByDescriptionRequest {
 pub struct TriggerNameSearch {
     pub description: String,
 }
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/trigger/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetTriggersRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "triggerids")]
+    pub trigger_ids: Option<Vec<String>>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "groupids")]
+    pub group_ids: Option<Vec<String>>,
+    #[serde(rename = "itemids")]
+    pub item_ids: Option<Vec<String>>,
+    #[serde(rename = "selectFunctions")]
+    pub select_functions: Option<String>,
+    #[serde(rename = "selectHosts")]
+    pub select_hosts: Option<String>,
+    #[serde(rename = "selectItems")]
+    pub select_items: Option<String>,
+    #[serde(rename = "selectTags")]
+    pub select_tags: Option<String>,
+    #[serde(rename = "selectDependencies")]
+    pub select_dependencies: Option<String>,
+    /// Return expressions with item references instead of `{functionid}`.
+    #[serde(rename = "expandExpression", skip_serializing_if = "is_false")]
+    pub expand_expression: bool,
+    /// Expand macros in trigger names.
+    #[serde(rename = "expandDescription", skip_serializing_if = "is_false")]
+    pub expand_description: bool,
+    /// Return only triggers that have recently been in the problem state.
+    #[serde(skip_serializing_if = "is_false")]
+    pub only_true: bool,
+    /// Return only triggers with severity greater than or equal to the given one.
+    pub min_severity: Option<u8>,
+    /// Return only triggers whose last event is not acknowledged.
+    #[serde(
+        rename = "withLastEventUnacknowledged",
+        skip_serializing_if = "is_false"
+    )]
+    pub with_last_event_unacknowledged: bool,
+    /// Return only enabled triggers of monitored hosts.
+    #[serde(skip_serializing_if = "is_false")]
+    pub active: bool,
+    #[serde(rename = "sortfield")]
+    pub sort_field: Option<String>,
+    pub limit: Option<u32>,
+}
+
+/// Flag parameters are enabled by any value, so they are sent only when set.
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+#[cfg(test)]
+mod get_tests {
+    use super::GetTriggersRequest;
+
+    #[test]
+    fn flags_are_skipped_unless_set() {
+        let request = GetTriggersRequest::<()> {
+            output: Some("extend".to_string()),
+            expand_expression: true,
+            only_true: true,
+            min_severity: Some(3),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "output": "extend",
+                "expandExpression": true,
+                "only_true": true,
+                "min_severity": 3
+            })
+        );
+    }
+}
@@ -1,3 +1,7 @@
 pub mod create;
+pub mod dependency;
+pub mod expression;
 pub mod get;
 pub mod model;
+pub mod parse;
+pub mod update;
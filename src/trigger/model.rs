@@ -2,6 +2,15 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use serde_with::DisplayFromStr;
 
+#[cfg(feature = "host")]
+use crate::host::model::ZabbixHost;
+#[cfg(feature = "item")]
+use crate::item::model::ZabbixItem;
+#[cfg(all(feature = "host", feature = "item"))]
+use crate::trigger::expression::{ItemRef, TriggerExpression};
+#[cfg(all(feature = "host", feature = "item"))]
+use crate::trigger::parse::ExpressionError;
+
 /// API Object: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/trigger/object
 #[serde_as]
 #[derive(Deserialize, Clone, Debug)]
@@ -54,6 +63,141 @@ pub struct ZabbixTrigger {
 
     /// Reduced trigger recovery expression.
     pub recovery_expression: String,
+
+    /// Whether the trigger is enabled.
+    pub status: Option<TriggerStatus>,
+
+    /// Whether the trigger is in the problem state.
+    pub value: Option<TriggerValue>,
+
+    /// Whether the trigger can be evaluated, see `error` otherwise.
+    pub state: Option<TriggerState>,
+
+    /// Time when the trigger last changed its value, in seconds since the epoch.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "lastchange", default)]
+    pub last_change: Option<u64>,
+
+    /// Error text if the trigger cannot be evaluated.
+    pub error: Option<String>,
+
+    /// Additional description of the trigger.
+    pub comments: Option<String>,
+
+    /// Whether problems of the trigger can be closed manually.
+    pub manual_close: Option<TriggerManualClose>,
+
+    /// Functions used in the expressions, returned with `selectFunctions`.
+    pub functions: Option<Vec<ZabbixTriggerFunction>>,
+
+    /// Hosts the trigger belongs to, returned with `selectHosts`.
+    #[cfg(feature = "host")]
+    pub hosts: Option<Vec<ZabbixHost>>,
+
+    /// Items used in the expressions, returned with `selectItems`.
+    #[cfg(feature = "item")]
+    pub items: Option<Vec<ZabbixItem>>,
+
+    /// Returned with `selectTags`.
+    pub tags: Option<Vec<ZabbixTriggerTag>>,
+
+    /// Triggers this trigger depends on, returned with `selectDependencies`.
+    pub dependencies: Option<Vec<ZabbixTrigger>>,
+}
+
+#[cfg(all(feature = "host", feature = "item"))]
+impl ZabbixTrigger {
+    /// Parses `expression`, expanding `{functionid}` references with the trigger
+    /// functions, items and hosts. Those must be requested with `selectFunctions`,
+    /// `selectItems` and `selectHosts` unless the expression was requested with
+    /// `expandExpression`.
+    pub fn parse_expression(&self) -> Result<TriggerExpression, ExpressionError> {
+        self.parse_with_functions(&self.expression)
+    }
+
+    /// Same as [`ZabbixTrigger::parse_expression`], `None` when no recovery expression is set.
+    pub fn parse_recovery_expression(&self) -> Result<Option<TriggerExpression>, ExpressionError> {
+        if self.recovery_expression.is_empty() {
+            return Ok(None);
+        }
+
+        self.parse_with_functions(&self.recovery_expression)
+            .map(Some)
+    }
+
+    fn parse_with_functions(&self, text: &str) -> Result<TriggerExpression, ExpressionError> {
+        let mut expression = TriggerExpression::parse(text)?;
+
+        let functions = self.functions.as_deref().unwrap_or_default();
+
+        expression.expand_function_ids(functions, |item_id| {
+            let item = self
+                .items
+                .as_ref()?
+                .iter()
+                .find(|item| item.item_id == item_id)?;
+            let host = self
+                .hosts
+                .as_ref()?
+                .iter()
+                .find(|host| host.host_id == item.host_id)?;
+
+            Some(ItemRef::new(&host.host, &item.key_))
+        })?;
+
+        Ok(expression)
+    }
+}
+
+/// Whether the trigger is enabled.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum TriggerStatus {
+    #[serde(rename = "0")]
+    Enabled,
+    #[serde(rename = "1")]
+    Disabled,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum TriggerValue {
+    #[serde(rename = "0")]
+    Ok,
+    #[serde(rename = "1")]
+    Problem,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum TriggerState {
+    #[serde(rename = "0")]
+    Normal,
+    #[serde(rename = "1")]
+    Unknown,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum TriggerManualClose {
+    #[serde(rename = "0")]
+    NotAllowed,
+    #[serde(rename = "1")]
+    Allowed,
+}
+
+/// Function used in a trigger expression. Expressions returned by the API refer
+/// to functions as `{functionid}` unless `expandExpression` is set.
+///
+/// API Object: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/trigger/get
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct ZabbixTriggerFunction {
+    #[serde(rename = "functionid")]
+    pub function_id: String,
+    #[serde(rename = "itemid")]
+    pub item_id: String,
+    #[serde(rename = "triggerid")]
+    pub trigger_id: Option<String>,
+    /// Function name, e.g. `last`.
+    pub function: String,
+    /// Function parameters, `$` stands for the item.
+    pub parameter: String,
 }
 
 /// API Object: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/trigger/object#trigger-tag
@@ -65,7 +209,7 @@ pub struct ZabbixTriggerTag {
 
 #[cfg(test)]
 mod trigger_tests {
-    use super::ZabbixTrigger;
+    use super::{TriggerManualClose, TriggerState, TriggerStatus, TriggerValue, ZabbixTrigger};
 
     #[test]
     fn deserialize_test() {
@@ -78,4 +222,56 @@ mod trigger_tests {
         assert_eq!(result.priority, 4);
         assert_eq!(result.recovery_mode, 1);
     }
+
+    #[test]
+    #[cfg(all(feature = "host", feature = "item"))]
+    fn deserialize_selected_objects_test() {
+        let input = r#"
+            {"triggerid":"24100","expression":"last(/example.com/web.test.fail[Site])<>0","description":"{HOST.NAME} is down","url":"","status":"1","value":"1","priority":"4","lastchange":"1715602921","comments":"Check the web server","error":"","state":"0","recovery_mode":"0","recovery_expression":"","manual_close":"1","event_name":"",
+             "functions":[{"functionid":"34521","itemid":"48176","triggerid":"24100","parameter":"$","function":"last"}],
+             "hosts":[{"hostid":"10084","host":"example.com","status":"0"}],
+             "items":[{"itemid":"48176","hostid":"10084","name":"Failed step","key_":"web.test.fail[Site]"}],
+             "tags":[{"tag":"scope","value":"availability"}],
+             "dependencies":[{"triggerid":"24099","expression":"{34519}>=1","description":"Gateway is unavailable","url":"","priority":"5","recovery_mode":"0","recovery_expression":"","event_name":""}]}
+            "#;
+
+        let result: ZabbixTrigger = serde_json::from_str(input).unwrap();
+
+        assert_eq!(result.status, Some(TriggerStatus::Disabled));
+        assert_eq!(result.value, Some(TriggerValue::Problem));
+        assert_eq!(result.state, Some(TriggerState::Normal));
+        assert_eq!(result.last_change, Some(1715602921));
+        assert_eq!(result.manual_close, Some(TriggerManualClose::Allowed));
+        assert_eq!(result.functions.unwrap()[0].function, "last");
+        assert_eq!(result.hosts.unwrap()[0].host, "example.com");
+        assert_eq!(result.items.unwrap()[0].key_, "web.test.fail[Site]");
+        assert_eq!(result.tags.unwrap()[0].tag, "scope");
+        assert_eq!(result.dependencies.unwrap()[0].trigger_id, "24099");
+    }
+
+    #[test]
+    #[cfg(all(feature = "host", feature = "item"))]
+    fn parse_expression_test() {
+        let input = r#"
+            {"triggerid":"24099","expression":"{34519}>=1","description":"Site is unavailable","url":"","priority":"4","recovery_mode":"1","recovery_expression":"{34520}=0","event_name":"",
+             "functions":[{"functionid":"34519","itemid":"48175","triggerid":"24099","parameter":"$,#3","function":"avg"},{"functionid":"34520","itemid":"48175","triggerid":"24099","parameter":"$","function":"last"}],
+             "hosts":[{"hostid":"10084","host":"example.com","status":"0"}],
+             "items":[{"itemid":"48175","hostid":"10084","name":"Failed step","key_":"web.test.fail[Site]"}]}
+            "#;
+
+        let result: ZabbixTrigger = serde_json::from_str(input).unwrap();
+
+        assert_eq!(
+            result.parse_expression().unwrap().to_string(),
+            "avg(/example.com/web.test.fail[Site],#3)>=1"
+        );
+        assert_eq!(
+            result
+                .parse_recovery_expression()
+                .unwrap()
+                .unwrap()
+                .to_string(),
+            "last(/example.com/web.test.fail[Site])=0"
+        );
+    }
 }
@@ -0,0 +1,968 @@
+//! Local parser for trigger and calculated item expressions in the Zabbix 5.4+ syntax.
+//!
+//! ```
+//! use zabbix_api::trigger::expression::TriggerExpression;
+//!
+//! let mut expression =
+//!     TriggerExpression::parse("last(/web01/web.test.fail[Site])<>0 and nodata(/web01/agent.ping,5m)=0")
+//!         .unwrap();
+//!
+//! let keys: Vec<String> = expression.item_refs().iter().map(|item| item.full_key()).collect();
+//! assert_eq!(keys, vec!["web.test.fail[Site]", "agent.ping"]);
+//!
+//! expression.replace_host("web01", "web02");
+//!
+//! assert_eq!(
+//!     expression.to_string(),
+//!     "last(/web02/web.test.fail[Site])<>0 and nodata(/web02/agent.ping,5m)=0"
+//! );
+//!
+//! let error = TriggerExpression::parse("last(/web01/agent.ping)>").unwrap_err();
+//! assert_eq!(error.to_string(), "unexpected end of expression at position 24");
+//! ```
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::expression::{
+    BinaryOperator, FunctionArg, ItemQuery, ItemRef, Period, PeriodValue, TriggerExpression,
+    UnaryOperator,
+};
+use super::model::ZabbixTriggerFunction;
+
+#[derive(Error, PartialEq, Clone, Debug)]
+pub enum ExpressionError {
+    /// `position` is a character offset in the parsed text.
+    #[error("{message} at position {position}")]
+    Syntax { position: usize, message: String },
+
+    #[error("function {{{0}}} is not among the trigger functions")]
+    UnknownFunction(String),
+
+    #[error("item {0} is not among the trigger items")]
+    UnknownItem(String),
+
+    #[error("trigger expression must reference at least one item")]
+    NoItems,
+}
+
+impl TriggerExpression {
+    /// Parses an expression. Redundant parentheses and whitespace are not kept,
+    /// so the parsed expression renders in the normalized form.
+    pub fn parse(text: &str) -> Result<TriggerExpression, ExpressionError> {
+        let mut parser = Parser::new(text);
+
+        let expression = parser.parse_or()?;
+
+        parser.skip_whitespace();
+
+        match parser.peek() {
+            Some(c) => Err(parser.error(format!("unexpected '{c}'"))),
+            None => Ok(expression),
+        }
+    }
+
+    /// Replaces `{functionid}` references, as returned by `trigger.get` without
+    /// `expandExpression`, with the functions they stand for.
+    ///
+    /// `item_ref` resolves an item ID to the item host and key.
+    pub fn expand_function_ids(
+        &mut self,
+        functions: &[ZabbixTriggerFunction],
+        item_ref: impl Fn(&str) -> Option<ItemRef>,
+    ) -> Result<(), ExpressionError> {
+        let mut result = Ok(());
+
+        self.visit_mut(&mut |expression| {
+            if result.is_err() {
+                return;
+            }
+
+            if let TriggerExpression::Macro(value) = expression {
+                if let Some(function_id) = function_id(value) {
+                    match expand_function(function_id, functions, &item_ref) {
+                        Ok(function) => *expression = function,
+                        Err(e) => result = Err(e),
+                    }
+                }
+            }
+        });
+
+        result
+    }
+}
+
+impl FromStr for TriggerExpression {
+    type Err = ExpressionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TriggerExpression::parse(s)
+    }
+}
+
+/// Parses a trigger expression and checks that it references at least one item,
+/// directly or through a `{functionid}`, as Zabbix requires for triggers.
+pub fn validate_trigger_expression(text: &str) -> Result<TriggerExpression, ExpressionError> {
+    let expression = TriggerExpression::parse(text)?;
+
+    let mut has_function_ids = false;
+    expression.visit(&mut |node| {
+        if let TriggerExpression::Macro(value) = node {
+            has_function_ids |= function_id(value).is_some();
+        }
+    });
+
+    if expression.item_refs().is_empty() && !has_function_ids {
+        return Err(ExpressionError::NoItems);
+    }
+
+    Ok(expression)
+}
+
+/// `{12345}` references a trigger function by ID.
+fn function_id(value: &str) -> Option<&str> {
+    let id = value.strip_prefix('{')?.strip_suffix('}')?;
+
+    if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+fn expand_function(
+    function_id: &str,
+    functions: &[ZabbixTriggerFunction],
+    item_ref: &impl Fn(&str) -> Option<ItemRef>,
+) -> Result<TriggerExpression, ExpressionError> {
+    let function = functions
+        .iter()
+        .find(|function| function.function_id == function_id)
+        .ok_or_else(|| ExpressionError::UnknownFunction(function_id.to_string()))?;
+
+    let item = item_ref(&function.item_id)
+        .ok_or_else(|| ExpressionError::UnknownItem(function.item_id.clone()))?;
+
+    // `$` stands for the item in function parameters, e.g. `$,#3`
+    let params = function
+        .parameter
+        .strip_prefix('$')
+        .unwrap_or(&function.parameter);
+
+    TriggerExpression::parse(&format!("{}({item}{params})", function.function))
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.position += 1;
+        }
+        c
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn error(&self, message: impl ToString) -> ExpressionError {
+        self.error_at(self.position, message)
+    }
+
+    fn error_at(&self, position: usize, message: impl ToString) -> ExpressionError {
+        ExpressionError::Syntax {
+            position,
+            message: message.to_string(),
+        }
+    }
+
+    fn unexpected(&self) -> ExpressionError {
+        match self.peek() {
+            Some(c) => self.error(format!("unexpected '{c}'")),
+            None => self.error("unexpected end of expression"),
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let matches = token
+            .chars()
+            .enumerate()
+            .all(|(i, c)| self.peek_at(i) == Some(c));
+
+        if matches {
+            self.position += token.chars().count();
+        }
+
+        matches
+    }
+
+    /// Keywords must not be followed by a name character: `order` is not `or`.
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let len = keyword.chars().count();
+        let matches = keyword
+            .chars()
+            .enumerate()
+            .all(|(i, c)| self.peek_at(i) == Some(c))
+            && !self.peek_at(len).is_some_and(is_name_char);
+
+        if matches {
+            self.position += len;
+        }
+
+        matches
+    }
+
+    fn parse_or(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        let mut left = self.parse_and()?;
+
+        loop {
+            self.skip_whitespace();
+
+            if self.eat_keyword("or") {
+                left = binary(BinaryOperator::Or, left, self.parse_and()?);
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    fn parse_and(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        let mut left = self.parse_equality()?;
+
+        loop {
+            self.skip_whitespace();
+
+            if self.eat_keyword("and") {
+                left = binary(BinaryOperator::And, left, self.parse_equality()?);
+            } else {
+                return Ok(left);
+            }
+        }
+    }
+
+    fn parse_equality(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        let mut left = self.parse_comparison()?;
+
+        loop {
+            self.skip_whitespace();
+
+            let operator = if self.eat("<>") {
+                BinaryOperator::NotEqual
+            } else if self.eat("=") {
+                BinaryOperator::Equal
+            } else {
+                return Ok(left);
+            };
+
+            left = binary(operator, left, self.parse_comparison()?);
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        let mut left = self.parse_additive()?;
+
+        loop {
+            self.skip_whitespace();
+
+            // `<>` belongs to the equality level
+            if self.peek() == Some('<') && self.peek_at(1) == Some('>') {
+                return Ok(left);
+            }
+
+            let operator = if self.eat("<=") {
+                BinaryOperator::LessOrEqual
+            } else if self.eat(">=") {
+                BinaryOperator::GreaterOrEqual
+            } else if self.eat("<") {
+                BinaryOperator::Less
+            } else if self.eat(">") {
+                BinaryOperator::Greater
+            } else {
+                return Ok(left);
+            };
+
+            left = binary(operator, left, self.parse_additive()?);
+        }
+    }
+
+    fn parse_additive(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        let mut left = self.parse_multiplicative()?;
+
+        loop {
+            self.skip_whitespace();
+
+            let operator = if self.eat("+") {
+                BinaryOperator::Add
+            } else if self.eat("-") {
+                BinaryOperator::Subtract
+            } else {
+                return Ok(left);
+            };
+
+            left = binary(operator, left, self.parse_multiplicative()?);
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        let mut left = self.parse_unary()?;
+
+        loop {
+            self.skip_whitespace();
+
+            let operator = if self.eat("*") {
+                BinaryOperator::Multiply
+            } else if self.eat("/") {
+                BinaryOperator::Divide
+            } else {
+                return Ok(left);
+            };
+
+            left = binary(operator, left, self.parse_unary()?);
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        self.skip_whitespace();
+
+        let operator = if self.eat("-") {
+            UnaryOperator::Minus
+        } else if self.eat_keyword("not") {
+            UnaryOperator::Not
+        } else {
+            return self.parse_primary();
+        };
+
+        Ok(TriggerExpression::Unary {
+            operator,
+            operand: Box::new(self.parse_unary()?),
+        })
+    }
+
+    fn parse_primary(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('(') => {
+                let start = self.position;
+                self.bump();
+
+                let expression = self.parse_or()?;
+
+                self.skip_whitespace();
+
+                match self.bump() {
+                    Some(')') => Ok(expression),
+                    Some(c) => Err(self.error_at(self.position - 1, format!("unexpected '{c}'"))),
+                    None => Err(self.error_at(start, "unclosed parenthesis")),
+                }
+            }
+            Some('"') => Ok(TriggerExpression::String(self.parse_string()?)),
+            Some('{') => Ok(TriggerExpression::Macro(self.parse_macro()?)),
+            Some(c) if c.is_ascii_digit() || c == '.' => self.parse_number(),
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => self.parse_function(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        let start = self.position;
+
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+        }
+
+        if self.peek() == Some('.') {
+            self.bump();
+
+            if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                return Err(self.error("expected digit"));
+            }
+
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.bump();
+            }
+        }
+
+        if matches!(self.peek(), Some('e' | 'E'))
+            && (self.peek_at(1).is_some_and(|c| c.is_ascii_digit())
+                || (matches!(self.peek_at(1), Some('+' | '-'))
+                    && self.peek_at(2).is_some_and(|c| c.is_ascii_digit())))
+        {
+            self.position += 2;
+
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.bump();
+            }
+        }
+
+        if self.peek().is_some_and(|c| "smhdwKMGT".contains(c)) {
+            self.bump();
+        }
+
+        if self.peek().is_some_and(is_name_char) {
+            return Err(self.unexpected());
+        }
+
+        Ok(TriggerExpression::Number(
+            self.chars[start..self.position].iter().collect(),
+        ))
+    }
+
+    /// Quoted string, only `\"` and `\\` are escape sequences.
+    fn parse_string(&mut self) -> Result<String, ExpressionError> {
+        let start = self.position;
+        self.bump();
+
+        let mut value = String::new();
+
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(value),
+                Some('\\') if matches!(self.peek(), Some('"' | '\\')) => {
+                    value.push(self.bump().unwrap_or_default());
+                }
+                Some(c) => value.push(c),
+                None => return Err(self.error_at(start, "unterminated string")),
+            }
+        }
+    }
+
+    /// Macro kept with braces, quoted macro context may contain `}`.
+    fn parse_macro(&mut self) -> Result<String, ExpressionError> {
+        let start = self.position;
+        self.bump();
+
+        let mut quoted = false;
+
+        loop {
+            match self.bump() {
+                Some('}') if !quoted => break,
+                Some('"') => quoted = !quoted,
+                Some('\\') if quoted => {
+                    self.bump();
+                }
+                Some(_) => {}
+                None => return Err(self.error_at(start, "unterminated macro")),
+            }
+        }
+
+        if self.position - start == 2 {
+            return Err(self.error_at(start, "empty macro"));
+        }
+
+        Ok(self.chars[start..self.position].iter().collect())
+    }
+
+    fn parse_function(&mut self) -> Result<TriggerExpression, ExpressionError> {
+        let start = self.position;
+
+        while self.peek().is_some_and(is_name_char) {
+            self.bump();
+        }
+
+        let name: String = self.chars[start..self.position].iter().collect();
+
+        if self.peek() != Some('(') {
+            return Err(self.error_at(start, format!("unknown token '{name}'")));
+        }
+
+        self.bump();
+
+        let mut args = vec![];
+
+        self.skip_whitespace();
+
+        if self.eat(")") {
+            return Ok(TriggerExpression::Function { name, args });
+        }
+
+        loop {
+            self.skip_whitespace();
+
+            let arg = match self.peek() {
+                Some(',' | ')') => FunctionArg::Empty,
+                Some('/') => FunctionArg::Query(self.parse_item_query(args.is_empty())?),
+                _ => FunctionArg::Value(self.parse_or()?),
+            };
+
+            args.push(arg);
+
+            self.skip_whitespace();
+
+            match self.bump() {
+                Some(',') => {}
+                Some(')') => return Ok(TriggerExpression::Function { name, args }),
+                Some(c) => {
+                    return Err(self.error_at(
+                        self.position - 1,
+                        format!("expected ',' or ')' instead of '{c}'"),
+                    ))
+                }
+                None => return Err(self.error_at(start, "unclosed function call")),
+            }
+        }
+    }
+
+    /// `/host/key[params]?[filter]`, followed by the period for the first parameter.
+    fn parse_item_query(&mut self, with_period: bool) -> Result<ItemQuery, ExpressionError> {
+        let item = self.parse_item_ref()?;
+
+        let mut query = ItemQuery { item, period: None };
+
+        self.skip_whitespace();
+
+        if with_period && self.peek() == Some(',') {
+            let comma = self.position;
+            self.bump();
+            self.skip_whitespace();
+
+            match self.peek() {
+                // Not a period: a string or a nested expression
+                Some('"' | '(') => self.position = comma,
+                // Omitted period, kept as an empty parameter
+                Some(',' | ')') => self.position = comma,
+                _ => query.period = Some(self.parse_period()),
+            }
+        }
+
+        Ok(query)
+    }
+
+    fn parse_item_ref(&mut self) -> Result<ItemRef, ExpressionError> {
+        self.bump();
+
+        let host_start = self.position;
+
+        loop {
+            match self.peek() {
+                Some('/') => break,
+                Some(',' | ')' | '(') | None => {
+                    return Err(self.error("expected '/' between host and item key"))
+                }
+                Some(_) => {
+                    self.bump();
+                }
+            }
+        }
+
+        let host: String = self.chars[host_start..self.position].iter().collect();
+
+        self.bump();
+
+        let key_start = self.position;
+        let mut params = vec![];
+
+        if !self.eat("*") {
+            while self
+                .peek()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || "_.-".contains(c))
+            {
+                self.bump();
+            }
+
+            if self.position == key_start {
+                return Err(self.error("expected item key"));
+            }
+
+            if self.peek() == Some('[') {
+                params = self.parse_key_params()?;
+            }
+        }
+
+        let key: String = self.chars[key_start..self.position].iter().collect();
+
+        let mut item = split_key_params(host, key, params);
+
+        if self.peek() == Some('?') && self.peek_at(1) == Some('[') {
+            item.filter = Some(self.parse_item_filter()?);
+        }
+
+        Ok(item)
+    }
+
+    /// Item key parameters are quoted only at the start of a parameter and may
+    /// hold one level of nested arrays. Returns the unquoted top level parameters.
+    fn parse_key_params(&mut self) -> Result<Vec<String>, ExpressionError> {
+        let start = self.position;
+        let mut depth = 0;
+        let mut param_start = true;
+        let mut params = vec![];
+        let mut param = String::new();
+
+        loop {
+            match self.bump() {
+                Some('[') => {
+                    depth += 1;
+                    param_start = true;
+                }
+                Some(']') => {
+                    depth -= 1;
+                    param_start = false;
+
+                    if depth == 0 {
+                        params.push(param);
+                        return Ok(params);
+                    }
+                }
+                Some(',') => {
+                    if depth == 1 {
+                        params.push(std::mem::take(&mut param));
+                    }
+                    param_start = true;
+                }
+                Some(' ') if param_start => {}
+                Some('"') if param_start => {
+                    loop {
+                        match self.bump() {
+                            Some('"') => break,
+                            Some('\\') if self.peek() == Some('"') => {
+                                self.bump();
+                                param.push('"');
+                            }
+                            Some(c) => param.push(c),
+                            None => {
+                                return Err(self.error_at(start, "unterminated item key parameters"))
+                            }
+                        }
+                    }
+                    param_start = false;
+                }
+                Some(c) => {
+                    param.push(c);
+                    param_start = false;
+                }
+                None => return Err(self.error_at(start, "unterminated item key parameters")),
+            }
+        }
+    }
+
+    fn parse_item_filter(&mut self) -> Result<String, ExpressionError> {
+        let start = self.position;
+        self.position += 2;
+
+        let mut quoted = false;
+
+        loop {
+            match self.bump() {
+                Some(']') if !quoted => break,
+                Some('"') => quoted = !quoted,
+                Some('\\') if quoted => {
+                    self.bump();
+                }
+                Some(_) => {}
+                None => return Err(self.error_at(start, "unterminated item filter")),
+            }
+        }
+
+        Ok(self.chars[start + 2..self.position - 1].iter().collect())
+    }
+
+    /// `5m`, `#3`, `1h:now-1d`, `{$PERIOD}` or nothing.
+    fn parse_period(&mut self) -> Period {
+        let start = self.position;
+        let mut braces = 0;
+
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => braces += 1,
+                '}' => braces -= 1,
+                ',' | ')' if braces == 0 => break,
+                _ => {}
+            }
+
+            self.bump();
+        }
+
+        let text: String = self.chars[start..self.position].iter().collect();
+        let text = text.trim();
+
+        let (value, time_shift) = match text.split_once(':') {
+            Some((value, time_shift)) if !value.contains('{') => (value, Some(time_shift)),
+            _ => (text, None),
+        };
+
+        let value = match value.strip_prefix('#').map(str::parse::<u32>) {
+            Some(Ok(count)) => PeriodValue::Count(count),
+            _ => PeriodValue::Duration(value.to_string()),
+        };
+
+        Period {
+            value,
+            time_shift: time_shift.map(str::to_string),
+        }
+    }
+}
+
+fn binary(
+    operator: BinaryOperator,
+    left: TriggerExpression,
+    right: TriggerExpression,
+) -> TriggerExpression {
+    TriggerExpression::Binary {
+        operator,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
+
+/// Splits `key[params]` the way [`ItemRef::param`] builds it. Keys which would
+/// not render back to the same text, e.g. with extra quotes or nested arrays,
+/// are kept whole as Zabbix compares item keys as strings.
+fn split_key_params(host: String, key: String, params: Vec<String>) -> ItemRef {
+    let Some((name, _)) = key.split_once('[') else {
+        return ItemRef::new(host, key);
+    };
+
+    let item = ItemRef {
+        host,
+        key: name.to_string(),
+        params,
+        filter: None,
+    };
+
+    if item.full_key() == key {
+        item
+    } else {
+        ItemRef::new(item.host, key)
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use super::{validate_trigger_expression, ExpressionError};
+    use crate::trigger::expression::{ItemRef, PeriodValue, TriggerExpression};
+    use crate::trigger::model::ZabbixTriggerFunction;
+
+    fn assert_round_trip(text: &str) {
+        let expression = TriggerExpression::parse(text).unwrap();
+        assert_eq!(expression.to_string(), text);
+    }
+
+    #[test]
+    fn normalized_expressions_round_trip() {
+        assert_round_trip("last(/Zabbix server/system.cpu.load[all,avg1])>5");
+        assert_round_trip(
+            r#"count(/srv/log[/var/log/app.log,"error, warning"],5m:now-1h,"regexp","\"\\d+")>=3"#,
+        );
+        assert_round_trip(
+            "avg(/db/mysql.qps,#10)/avg(/db/mysql.qps,#10:now-1d)*100>{$QPS.MAX:\"db\"}",
+        );
+        assert_round_trip("logsource(/srv/eventlog[System],,\"Disk\")=1");
+        assert_round_trip("not (nodata(/srv/agent.ping,5m)=0) or -last(//temp)<-(-5)");
+        assert_round_trip(
+            "last(/{#HOST}/net.if.in[{#IFNAME}])-last(/{#HOST}/net.if.in[{#IFNAME}],#2)>1M",
+        );
+        assert_round_trip(
+            r#"avg_foreach(/*/mysql.qps?[group="Databases" and tag="env:prod"],5m)>1e3"#,
+        );
+        assert_round_trip("dayofweek()>5 and (time()<080000 or time()>200000)");
+    }
+
+    #[test]
+    fn whitespace_and_redundant_parentheses_are_normalized() {
+        let expression =
+            TriggerExpression::parse(" ( last( /h/k , #3 ) > 5 )  and\n((min(/h/k,1h)) < 2)")
+                .unwrap();
+
+        assert_eq!(expression.to_string(), "last(/h/k,#3)>5 and min(/h/k,1h)<2");
+    }
+
+    #[test]
+    fn periods_are_typed() {
+        let expression = TriggerExpression::parse("last(/h/k,#3:now-1d)=0").unwrap();
+
+        let TriggerExpression::Binary { left, .. } = expression else {
+            panic!("binary expression expected")
+        };
+        let TriggerExpression::Function { args, .. } = *left else {
+            panic!("function expected")
+        };
+        let crate::trigger::expression::FunctionArg::Query(query) = &args[0] else {
+            panic!("item query expected")
+        };
+
+        let period = query.period.clone().unwrap();
+        assert_eq!(period.value, PeriodValue::Count(3));
+        assert_eq!(period.time_shift.as_deref(), Some("now-1d"));
+    }
+
+    #[test]
+    fn syntax_errors_have_positions() {
+        let cases = [
+            ("last(/h/k)>", 11, "unexpected end of expression"),
+            ("last(/h/k) > 5 5", 15, "unexpected '5'"),
+            ("last(/h/k", 0, "unclosed function call"),
+            ("last(/hk)=0", 8, "expected '/' between host and item key"),
+            ("last(/h/k[a,\"b)=0", 9, "unterminated item key parameters"),
+            ("find(/h/k,,\"like)=1", 11, "unterminated string"),
+            ("(last(/h/k)=0", 0, "unclosed parenthesis"),
+            ("lastt /h/k", 0, "unknown token 'lastt'"),
+            ("last(/h/k)=5x", 12, "unexpected 'x'"),
+        ];
+
+        for (text, position, message) in cases {
+            assert_eq!(
+                TriggerExpression::parse(text),
+                Err(ExpressionError::Syntax {
+                    position,
+                    message: message.to_string()
+                }),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn parsed_key_params_match_built_ones() {
+        let built = TriggerExpression::last(ItemRef::new("h", "system.cpu.load").param("all"))
+            .gt(5)
+            .and(TriggerExpression::find(
+                ItemRef::new("h", "log")
+                    .param("/var/log/app.log")
+                    .param("a, b"),
+                "like",
+                "err",
+            ));
+
+        let parsed = TriggerExpression::parse(&built.to_string()).unwrap();
+
+        assert_eq!(parsed, built);
+
+        let item = parsed.item_refs()[0];
+        assert_eq!(item.key, "system.cpu.load");
+        assert_eq!(item.params, vec!["all"]);
+    }
+
+    #[test]
+    fn built_nodata_round_trips() {
+        let built = TriggerExpression::nodata(ItemRef::new("h", "agent.ping"), "5m").equals(1);
+
+        assert_eq!(built.to_string(), "nodata(/h/agent.ping,5m)=1");
+        assert_eq!(TriggerExpression::parse(&built.to_string()).unwrap(), built);
+    }
+
+    #[test]
+    fn keys_which_do_not_render_back_are_kept_whole() {
+        for key in [
+            r#"net.if.in["eth0"]"#,
+            "vfs.fs.size[/, free]",
+            "key[a,[b,c]]",
+        ] {
+            let expression = TriggerExpression::parse(&format!("last(/h/{key})")).unwrap();
+            let item = expression.item_refs()[0];
+
+            assert_eq!(item.key, key);
+            assert!(item.params.is_empty());
+            assert_eq!(expression.to_string(), format!("last(/h/{key})"));
+        }
+    }
+
+    #[test]
+    fn item_refs_are_extracted_and_rewritten() {
+        let mut expression: TriggerExpression =
+            "last(/web01/web.test.fail[Site])<>0 and abs(change(/web01/net.if.in[\"eth0\"]))>10 or last(/gw/icmpping)=0"
+                .parse()
+                .unwrap();
+
+        let refs: Vec<(String, String)> = expression
+            .item_refs()
+            .iter()
+            .map(|item| (item.host.clone(), item.full_key()))
+            .collect();
+
+        assert_eq!(
+            refs,
+            vec![
+                ("web01".to_string(), "web.test.fail[Site]".to_string()),
+                ("web01".to_string(), "net.if.in[\"eth0\"]".to_string()),
+                ("gw".to_string(), "icmpping".to_string()),
+            ]
+        );
+
+        expression.replace_host("web01", "web02");
+
+        assert_eq!(
+            expression.to_string(),
+            "last(/web02/web.test.fail[Site])<>0 and abs(change(/web02/net.if.in[\"eth0\"]))>10 or last(/gw/icmpping)=0"
+        );
+    }
+
+    #[test]
+    fn function_ids_are_expanded() {
+        let functions = vec![
+            ZabbixTriggerFunction {
+                function_id: "34519".to_string(),
+                item_id: "48175".to_string(),
+                trigger_id: Some("24099".to_string()),
+                function: "avg".to_string(),
+                parameter: "$,#3".to_string(),
+            },
+            ZabbixTriggerFunction {
+                function_id: "34520".to_string(),
+                item_id: "48175".to_string(),
+                trigger_id: Some("24099".to_string()),
+                function: "last".to_string(),
+                parameter: "$".to_string(),
+            },
+        ];
+
+        let item_ref = |item_id: &str| {
+            (item_id == "48175").then(|| ItemRef::new("example.com", "web.test.fail[Site]"))
+        };
+
+        let mut expression = TriggerExpression::parse("{34519}>=1 and {34520}<>0").unwrap();
+
+        expression
+            .expand_function_ids(&functions, item_ref)
+            .unwrap();
+
+        assert_eq!(
+            expression.to_string(),
+            "avg(/example.com/web.test.fail[Site],#3)>=1 and last(/example.com/web.test.fail[Site])<>0"
+        );
+
+        let mut expression = TriggerExpression::parse("{1}=0").unwrap();
+
+        assert_eq!(
+            expression.expand_function_ids(&functions, item_ref),
+            Err(ExpressionError::UnknownFunction("1".to_string()))
+        );
+    }
+
+    #[test]
+    fn trigger_expression_requires_items() {
+        assert!(validate_trigger_expression("{34519}>=1").is_ok());
+        assert!(validate_trigger_expression("last(/h/k)>0").is_ok());
+        assert_eq!(
+            validate_trigger_expression("{$LIMIT}>1"),
+            Err(ExpressionError::NoItems)
+        );
+    }
+}
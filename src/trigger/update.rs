@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use super::create::ZabbixTriggerDependency;
+use super::model::{TriggerStatus, ZabbixTriggerTag};
+
+/// Properties left as `None` are not changed. Dependencies and tags replace the existing ones.
+///
+/// API: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/trigger/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default, Clone)]
+pub struct UpdateTriggerRequest {
+    #[serde(rename = "triggerid")]
+    pub trigger_id: String,
+    pub description: Option<String>,
+    pub expression: Option<String>,
+    pub priority: Option<u8>,
+    pub status: Option<TriggerStatus>,
+    pub recovery_mode: Option<u8>,
+    pub recovery_expression: Option<String>,
+    pub url: Option<String>,
+    pub event_name: Option<String>,
+    pub comments: Option<String>,
+    pub dependencies: Option<Vec<ZabbixTriggerDependency>>,
+    pub tags: Option<Vec<ZabbixTriggerTag>>,
+}
+
+impl UpdateTriggerRequest {
+    pub fn builder(trigger_id: impl ToString) -> UpdateTriggerRequestBuilder {
+        UpdateTriggerRequestBuilder {
+            inner: UpdateTriggerRequest {
+                trigger_id: trigger_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateTriggerRequestBuilder {
+    inner: UpdateTriggerRequest,
+}
+
+impl UpdateTriggerRequestBuilder {
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn expression(mut self, expression: impl ToString) -> Self {
+        self.inner.expression = Some(expression.to_string());
+        self
+    }
+
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.inner.priority = Some(priority);
+        self
+    }
+
+    pub fn status(mut self, status: TriggerStatus) -> Self {
+        self.inner.status = Some(status);
+        self
+    }
+
+    pub fn enable(self) -> Self {
+        self.status(TriggerStatus::Enabled)
+    }
+
+    pub fn disable(self) -> Self {
+        self.status(TriggerStatus::Disabled)
+    }
+
+    pub fn recovery_expression(mut self, recovery_expression: impl ToString) -> Self {
+        self.inner.recovery_mode = Some(1);
+        self.inner.recovery_expression = Some(recovery_expression.to_string());
+        self
+    }
+
+    pub fn url(mut self, url: impl ToString) -> Self {
+        self.inner.url = Some(url.to_string());
+        self
+    }
+
+    pub fn event_name(mut self, event_name: impl ToString) -> Self {
+        self.inner.event_name = Some(event_name.to_string());
+        self
+    }
+
+    pub fn comments(mut self, comments: impl ToString) -> Self {
+        self.inner.comments = Some(comments.to_string());
+        self
+    }
+
+    pub fn depends_on(mut self, trigger_id: impl ToString) -> Self {
+        self.inner
+            .dependencies
+            .get_or_insert_with(Vec::new)
+            .push(ZabbixTriggerDependency::new(trigger_id));
+        self
+    }
+
+    /// Removes all dependencies of the trigger.
+    pub fn no_dependencies(mut self) -> Self {
+        self.inner.dependencies = Some(vec![]);
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<ZabbixTriggerTag>) -> Self {
+        self.inner.tags = Some(tags);
+        self
+    }
+
+    pub fn build(self) -> UpdateTriggerRequest {
+        self.inner
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/6.0/en/manual/api/reference/trigger/update
+#[derive(Deserialize, Debug, Clone)]
+pub struct UpdateTriggerResponse {
+    #[serde(rename = "triggerids")]
+    pub trigger_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod update_tests {
+    use super::UpdateTriggerRequest;
+
+    #[test]
+    fn serialization_test() {
+        let request = UpdateTriggerRequest::builder("14062")
+            .disable()
+            .priority(2)
+            .depends_on("14063")
+            .build();
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "triggerid": "14062",
+                "status": "1",
+                "priority": 2,
+                "dependencies": [{"triggerid": "14063"}]
+            })
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::discoveryrule::model::PrototypeDiscover;
+use crate::trigger::create::CreateTriggerRequest;
+
+/// Trigger prototype is described with the same request as a regular trigger.
+/// Its discovery rule is defined by the item prototypes used in the expression.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/create
+#[derive(Serialize, Debug)]
+pub struct CreateTriggerPrototypeRequest {
+    #[serde(flatten)]
+    pub trigger: CreateTriggerRequest,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discover: Option<PrototypeDiscover>,
+}
+
+impl CreateTriggerPrototypeRequest {
+    pub fn new(trigger: CreateTriggerRequest) -> Self {
+        Self {
+            trigger,
+            discover: None,
+        }
+    }
+
+    pub fn discover(mut self, discover: bool) -> Self {
+        self.discover = Some(PrototypeDiscover::from(discover));
+        self
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/create
+#[derive(Deserialize, Debug)]
+pub struct CreateTriggerPrototypeResponse {
+    #[serde(rename = "triggerids")]
+    pub trigger_ids: Vec<String>,
+}
@@ -0,0 +1,25 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/get
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct GetTriggerPrototypesRequest<F: Serialize> {
+    pub output: Option<String>,
+    pub filter: Option<F>,
+    #[serde(rename = "triggerids")]
+    pub trigger_ids: Option<Vec<String>>,
+    /// IDs of the parent discovery rules.
+    #[serde(rename = "discoveryids")]
+    pub discovery_ids: Option<Vec<String>>,
+    #[serde(rename = "hostids")]
+    pub host_ids: Option<Vec<String>>,
+    #[serde(rename = "templateids")]
+    pub template_ids: Option<Vec<String>>,
+    #[serde(rename = "selectFunctions")]
+    pub select_functions: Option<String>,
+    #[serde(rename = "selectTags")]
+    pub select_tags: Option<String>,
+    #[serde(rename = "selectDiscoveryRule")]
+    pub select_discovery_rule: Option<String>,
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::discoveryrule::model::{PrototypeDiscover, ZabbixDiscoveryRule};
+use crate::trigger::model::ZabbixTrigger;
+
+/// Trigger prototype: a trigger whose expression refers to item prototypes,
+/// e.g. `last(/host/vfs.fs.size[{#FSNAME},pfree])<10`.
+///
+/// API Object: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/object
+#[derive(Deserialize, Clone, Debug)]
+pub struct ZabbixTriggerPrototype {
+    #[serde(flatten)]
+    pub trigger: ZabbixTrigger,
+
+    pub discover: Option<PrototypeDiscover>,
+
+    /// Parent discovery rule, returned with `selectDiscoveryRule`.
+    #[serde(rename = "discoveryRule")]
+    pub discovery_rule: Option<ZabbixDiscoveryRule>,
+}
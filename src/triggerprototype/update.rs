@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::discoveryrule::model::PrototypeDiscover;
+use crate::trigger::model::{TriggerStatus, ZabbixTriggerTag};
+
+/// Properties left as `None` are not changed. Tags replace the existing ones.
+///
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/update
+#[skip_serializing_none]
+#[derive(Serialize, Debug, Default)]
+pub struct UpdateTriggerPrototypeRequest {
+    #[serde(rename = "triggerid")]
+    pub trigger_id: String,
+    pub description: Option<String>,
+    pub expression: Option<String>,
+    pub priority: Option<u8>,
+    pub status: Option<TriggerStatus>,
+    pub recovery_mode: Option<u8>,
+    pub recovery_expression: Option<String>,
+    pub event_name: Option<String>,
+    pub url: Option<String>,
+    pub tags: Option<Vec<ZabbixTriggerTag>>,
+    pub discover: Option<PrototypeDiscover>,
+}
+
+impl UpdateTriggerPrototypeRequest {
+    pub fn builder(trigger_id: impl ToString) -> UpdateTriggerPrototypeRequestBuilder {
+        UpdateTriggerPrototypeRequestBuilder {
+            inner: UpdateTriggerPrototypeRequest {
+                trigger_id: trigger_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub struct UpdateTriggerPrototypeRequestBuilder {
+    inner: UpdateTriggerPrototypeRequest,
+}
+
+impl UpdateTriggerPrototypeRequestBuilder {
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.inner.description = Some(description.to_string());
+        self
+    }
+
+    pub fn expression(mut self, expression: impl ToString) -> Self {
+        self.inner.expression = Some(expression.to_string());
+        self
+    }
+
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.inner.priority = Some(priority);
+        self
+    }
+
+    pub fn status(mut self, status: TriggerStatus) -> Self {
+        self.inner.status = Some(status);
+        self
+    }
+
+    pub fn enable(self) -> Self {
+        self.status(TriggerStatus::Enabled)
+    }
+
+    pub fn disable(self) -> Self {
+        self.status(TriggerStatus::Disabled)
+    }
+
+    pub fn recovery_expression(mut self, recovery_expression: impl ToString) -> Self {
+        self.inner.recovery_mode = Some(1);
+        self.inner.recovery_expression = Some(recovery_expression.to_string());
+        self
+    }
+
+    pub fn event_name(mut self, event_name: impl ToString) -> Self {
+        self.inner.event_name = Some(event_name.to_string());
+        self
+    }
+
+    pub fn url(mut self, url: impl ToString) -> Self {
+        self.inner.url = Some(url.to_string());
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<ZabbixTriggerTag>) -> Self {
+        self.inner.tags = Some(tags);
+        self
+    }
+
+    pub fn discover(mut self, discover: bool) -> Self {
+        self.inner.discover = Some(PrototypeDiscover::from(discover));
+        self
+    }
+
+    pub fn build(self) -> UpdateTriggerPrototypeRequest {
+        self.inner
+    }
+}
+
+/// API: https://www.zabbix.com/documentation/7.0/en/manual/api/reference/triggerprototype/update
+#[derive(Deserialize, Debug)]
+pub struct UpdateTriggerPrototypeResponse {
+    #[serde(rename = "triggerids")]
+    pub trigger_ids: Vec<String>,
+}